@@ -0,0 +1,286 @@
+//! Threshold alarms on the measured channels, e.g. to drive a buzzer or an indicator LED.
+//!
+//! Register an [`AlarmRule`] per condition with [`Alarms::register`] and feed each measurement to
+//! [`Alarms::update`], which returns the alarms raised or cleared by it. Clearing at a threshold
+//! on the safe side of the raising threshold avoids toggling alarms with values fluctuating
+//! around it.
+
+use crate::data::{Channel, Measurement};
+
+/// Side of the threshold raising an [`AlarmRule`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AlarmDirection {
+    /// The alarm is raised once the value reaches the threshold from below.
+    Above,
+    /// The alarm is raised once the value reaches the threshold from above.
+    Below,
+}
+
+/// Condition raising and clearing an alarm on one channel.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AlarmRule {
+    /// Channel the rule applies to.
+    pub channel: Channel,
+    /// Side of the threshold raising the alarm.
+    pub direction: AlarmDirection,
+    /// Value raising the alarm.
+    pub raise: f32,
+    /// Value clearing the alarm once passed, on the safe side of `raise`.
+    pub clear: f32,
+}
+
+impl AlarmRule {
+    /// Creates a rule raising an alarm once `channel` reaches `raise` and clearing it below
+    /// `clear`.
+    pub const fn above(channel: Channel, raise: f32, clear: f32) -> Self {
+        Self {
+            channel,
+            direction: AlarmDirection::Above,
+            raise,
+            clear,
+        }
+    }
+
+    /// Creates a rule raising an alarm once `channel` falls to `raise` and clearing it above
+    /// `clear`, e.g. for a low relative humidity.
+    pub const fn below(channel: Channel, raise: f32, clear: f32) -> Self {
+        Self {
+            channel,
+            direction: AlarmDirection::Below,
+            raise,
+            clear,
+        }
+    }
+
+    fn raises(&self, value: f32) -> bool {
+        match self.direction {
+            AlarmDirection::Above => value >= self.raise,
+            AlarmDirection::Below => value <= self.raise,
+        }
+    }
+
+    fn clears(&self, value: f32) -> bool {
+        match self.direction {
+            AlarmDirection::Above => value < self.clear,
+            AlarmDirection::Below => value > self.clear,
+        }
+    }
+}
+
+/// Identifier of an alarm registered with [`Alarms::register`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AlarmId(usize);
+
+impl AlarmId {
+    /// Returns the slot of the alarm.
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// Change of the state of an alarm.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AlarmTransition {
+    /// The alarm was raised.
+    Raised {
+        /// Alarm raised.
+        id: AlarmId,
+        /// Channel of the alarm.
+        channel: Channel,
+        /// Value raising the alarm.
+        value: f32,
+    },
+    /// The alarm was cleared.
+    Cleared {
+        /// Alarm cleared.
+        id: AlarmId,
+        /// Channel of the alarm.
+        channel: Channel,
+        /// Value clearing the alarm.
+        value: f32,
+    },
+}
+
+impl AlarmTransition {
+    /// Returns the alarm of the transition.
+    pub fn id(&self) -> AlarmId {
+        match self {
+            AlarmTransition::Raised { id, .. } | AlarmTransition::Cleared { id, .. } => *id,
+        }
+    }
+}
+
+/// Set of up to `N` alarms evaluated on each measurement.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Alarms<const N: usize> {
+    rules: [Option<AlarmRule>; N],
+    active: [bool; N],
+}
+
+impl<const N: usize> Alarms<N> {
+    /// Creates a set without alarms.
+    pub const fn new() -> Self {
+        Self {
+            rules: [None; N],
+            active: [false; N],
+        }
+    }
+
+    /// Registers the alarm of the `rule` and returns its identifier. Returns the `rule` back if
+    /// all `N` slots are taken.
+    pub fn register(&mut self, rule: AlarmRule) -> Result<AlarmId, AlarmRule> {
+        let index = self.rules.iter().position(Option::is_none).ok_or(rule)?;
+        self.rules[index] = Some(rule);
+        self.active[index] = false;
+        Ok(AlarmId(index))
+    }
+
+    /// Removes the alarm `id` and returns its rule, freeing its slot.
+    pub fn remove(&mut self, id: AlarmId) -> Option<AlarmRule> {
+        self.active[id.0] = false;
+        self.rules[id.0].take()
+    }
+
+    /// Returns the rule of the alarm `id`.
+    pub fn rule(&self, id: AlarmId) -> Option<AlarmRule> {
+        self.rules[id.0]
+    }
+
+    /// Evaluates the alarms on the `measurement` and returns the alarms raised or cleared by it.
+    /// Unavailable values neither raise nor clear an alarm.
+    pub fn update(
+        &mut self,
+        measurement: &Measurement,
+    ) -> impl Iterator<Item = AlarmTransition> + use<N> {
+        let mut transitions = [None; N];
+        for (index, rule) in self.rules.iter().enumerate() {
+            let Some((rule, value)) =
+                rule.and_then(|rule| Some((rule, rule.channel.value(measurement)?)))
+            else {
+                continue;
+            };
+            let id = AlarmId(index);
+            let channel = rule.channel;
+            if self.active[index] && rule.clears(value) {
+                self.active[index] = false;
+                transitions[index] = Some(AlarmTransition::Cleared { id, channel, value });
+            } else if !self.active[index] && rule.raises(value) {
+                self.active[index] = true;
+                transitions[index] = Some(AlarmTransition::Raised { id, channel, value });
+            }
+        }
+        transitions.into_iter().flatten()
+    }
+
+    /// Returns whether the alarm `id` is raised.
+    pub fn is_active(&self, id: AlarmId) -> bool {
+        self.active[id.0]
+    }
+
+    /// Returns whether any alarm is raised.
+    pub fn any_active(&self) -> bool {
+        self.active.contains(&true)
+    }
+
+    /// Returns the raised alarms.
+    pub fn active(&self) -> impl Iterator<Item = AlarmId> + '_ {
+        self.active
+            .iter()
+            .enumerate()
+            .filter(|&(_, &active)| active)
+            .map(|(index, _)| AlarmId(index))
+    }
+
+    /// Clears all alarms without reporting transitions, e.g. after a user acknowledged them.
+    pub fn reset(&mut self) {
+        self.active = [false; N];
+    }
+}
+
+impl<const N: usize> Default for Alarms<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(pm2_5: f32, relative_humidity: f32, co2: u16) -> Measurement {
+        Measurement {
+            pm2_5,
+            pm4_0: pm2_5,
+            pm10_0: pm2_5,
+            relative_humidity,
+            co2,
+            ..Measurement::fixture()
+        }
+    }
+
+    #[test]
+    fn alarms_raise_and_clear_with_hysteresis() {
+        let mut alarms = Alarms::<2>::new();
+        let pm = alarms
+            .register(AlarmRule::above(Channel::Pm2_5, 35.0, 30.0))
+            .unwrap();
+        let dry = alarms
+            .register(AlarmRule::below(Channel::RelativeHumidity, 30.0, 35.0))
+            .unwrap();
+
+        let mut transitions = alarms.update(&measurement(40.0, 25.0, 420));
+        assert_eq!(
+            transitions.next(),
+            Some(AlarmTransition::Raised {
+                id: pm,
+                channel: Channel::Pm2_5,
+                value: 40.0,
+            })
+        );
+        assert_eq!(
+            transitions.next().map(|transition| transition.id()),
+            Some(dry)
+        );
+        assert_eq!(transitions.next(), None);
+        assert!(alarms.is_active(pm));
+
+        assert_eq!(alarms.update(&measurement(32.0, 33.0, 420)).count(), 0);
+        let mut transitions = alarms.update(&measurement(29.0, 33.0, 420));
+        assert_eq!(
+            transitions.next(),
+            Some(AlarmTransition::Cleared {
+                id: pm,
+                channel: Channel::Pm2_5,
+                value: 29.0,
+            })
+        );
+        assert_eq!(transitions.next(), None);
+        assert_eq!(alarms.active().collect::<Vec<_>>(), [dry]);
+    }
+
+    #[test]
+    fn alarms_reject_rules_when_full() {
+        let mut alarms = Alarms::<1>::default();
+        let rule = AlarmRule::above(Channel::Co2, 1_500.0, 1_200.0);
+        let co2 = alarms.register(rule).unwrap();
+        assert_eq!(alarms.register(rule), Err(rule));
+
+        let mut unavailable = measurement(1.0, 40.0, Measurement::CO2_UNAVAILABLE);
+        assert_eq!(alarms.update(&unavailable).count(), 0);
+        unavailable.co2 = 1_600;
+        assert_eq!(alarms.update(&unavailable).count(), 1);
+        assert!(alarms.any_active());
+
+        assert_eq!(alarms.remove(co2), Some(rule));
+        assert!(!alarms.any_active());
+        assert_eq!(alarms.register(rule), Ok(co2));
+    }
+}
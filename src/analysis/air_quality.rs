@@ -0,0 +1,219 @@
+use crate::data::{Channel, Measurement};
+
+/// Overall air quality reported by an [`AirQualityClassifier`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AirQuality {
+    /// All values are below their fair boundary.
+    Good,
+    /// At least one value reached its fair boundary.
+    Fair,
+    /// At least one value reached its poor boundary.
+    Poor,
+}
+
+/// Boundaries of the air quality categories for one channel.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct QualityBoundaries {
+    /// Value from which the air quality is fair.
+    pub fair: f32,
+    /// Value from which the air quality is poor.
+    pub poor: f32,
+    /// Distance the value has to fall below a boundary to return to the better category.
+    pub hysteresis: f32,
+}
+
+impl QualityBoundaries {
+    /// Creates boundaries for a fair air quality from `fair` and a poor one from `poor`,
+    /// returning to the better category `hysteresis` below the boundary.
+    pub const fn new(fair: f32, poor: f32, hysteresis: f32) -> Self {
+        Self {
+            fair,
+            poor,
+            hysteresis,
+        }
+    }
+
+    fn category(&self, value: f32, offset: f32) -> AirQuality {
+        if value >= self.poor - offset {
+            AirQuality::Poor
+        } else if value >= self.fair - offset {
+            AirQuality::Fair
+        } else {
+            AirQuality::Good
+        }
+    }
+
+    /// Returns the category of `value`, worsening immediately and improving only once the value
+    /// falls below the boundary of the `current` category by the hysteresis.
+    fn classify(&self, current: AirQuality, value: f32) -> AirQuality {
+        let category = self.category(value, 0.0);
+        if category >= current {
+            category
+        } else {
+            self.category(value, self.hysteresis).min(current)
+        }
+    }
+}
+
+/// Boundaries of the air quality categories for all channels considered by an
+/// [`AirQualityClassifier`]. `None` ignores the channel.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AirQualityBoundaries {
+    /// Boundaries of the PM2.5 mass concentration in ug/m³.
+    pub pm2_5: Option<QualityBoundaries>,
+    /// Boundaries of the VOC index.
+    pub voc_index: Option<QualityBoundaries>,
+    /// Boundaries of the NOx index.
+    pub nox_index: Option<QualityBoundaries>,
+    /// Boundaries of the CO2 concentration in ppm.
+    pub co2: Option<QualityBoundaries>,
+}
+
+impl AirQualityBoundaries {
+    fn get(&self, channel: Channel) -> Option<QualityBoundaries> {
+        match channel {
+            Channel::Pm2_5 => self.pm2_5,
+            Channel::VocIndex => self.voc_index,
+            Channel::NoxIndex => self.nox_index,
+            Channel::Co2 => self.co2,
+            _ => None,
+        }
+    }
+}
+
+impl Default for AirQualityBoundaries {
+    /// Returns boundaries of 15 and 35 ug/m³ PM2.5, following the WHO's 24h guideline and the
+    /// US 24h standard, VOC indices of 150 and 250 and NOx indices of 20 and 150, following the
+    /// interpretation of the indices by Sensirion, and 1000 and 1500 ppm CO2.
+    fn default() -> Self {
+        Self {
+            pm2_5: Some(QualityBoundaries::new(15.0, 35.0, 2.0)),
+            voc_index: Some(QualityBoundaries::new(150.0, 250.0, 20.0)),
+            nox_index: Some(QualityBoundaries::new(20.0, 150.0, 5.0)),
+            co2: Some(QualityBoundaries::new(1_000.0, 1_500.0, 100.0)),
+        }
+    }
+}
+
+/// Channels considered by the [`AirQualityClassifier`].
+const CHANNELS: [Channel; 4] = [
+    Channel::Pm2_5,
+    Channel::VocIndex,
+    Channel::NoxIndex,
+    Channel::Co2,
+];
+
+/// Folds the PM2.5 mass concentration, the gas indices and the CO2 concentration into a single
+/// [`AirQuality`], the worst category of the channels. Each channel returns to a better
+/// category only once its value fell below the boundary by the hysteresis, so a display does
+/// not flicker between categories.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AirQualityClassifier {
+    boundaries: AirQualityBoundaries,
+    categories: [AirQuality; 4],
+}
+
+impl AirQualityClassifier {
+    /// Creates a classifier using the `boundaries`.
+    pub const fn new(boundaries: AirQualityBoundaries) -> Self {
+        Self {
+            boundaries,
+            categories: [AirQuality::Good; 4],
+        }
+    }
+
+    /// Processes the `measurement` and returns the overall air quality. An unavailable value
+    /// keeps the category of its channel.
+    pub fn update(&mut self, measurement: &Measurement) -> AirQuality {
+        for (category, channel) in self.categories.iter_mut().zip(CHANNELS) {
+            if let (Some(boundaries), Some(value)) =
+                (self.boundaries.get(channel), channel.value(measurement))
+            {
+                *category = boundaries.classify(*category, value);
+            }
+        }
+        self.quality()
+    }
+
+    /// Returns the overall air quality.
+    pub fn quality(&self) -> AirQuality {
+        self.categories
+            .into_iter()
+            .max()
+            .unwrap_or(AirQuality::Good)
+    }
+
+    /// Returns the category of `channel`. `None` if the channel is not considered.
+    pub fn channel_quality(&self, channel: Channel) -> Option<AirQuality> {
+        self.boundaries.get(channel)?;
+        CHANNELS
+            .iter()
+            .position(|&considered| considered == channel)
+            .map(|index| self.categories[index])
+    }
+
+    /// Returns to a good air quality for all channels.
+    pub fn reset(&mut self) {
+        self.categories = [AirQuality::Good; 4];
+    }
+}
+
+impl Default for AirQualityClassifier {
+    fn default() -> Self {
+        Self::new(AirQualityBoundaries::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(pm2_5: f32, co2: u16) -> Measurement {
+        Measurement {
+            pm2_5,
+            pm4_0: pm2_5,
+            pm10_0: pm2_5,
+            co2,
+            ..Measurement::fixture()
+        }
+    }
+
+    #[test]
+    fn classifier_applies_hysteresis_per_boundary() {
+        let mut classifier = AirQualityClassifier::default();
+        assert_eq!(classifier.update(&measurement(5.0, 600)), AirQuality::Good);
+        assert_eq!(classifier.update(&measurement(36.0, 600)), AirQuality::Poor);
+        assert_eq!(classifier.update(&measurement(34.0, 600)), AirQuality::Poor);
+        assert_eq!(classifier.update(&measurement(20.0, 600)), AirQuality::Fair);
+        assert_eq!(classifier.update(&measurement(14.0, 600)), AirQuality::Fair);
+        assert_eq!(classifier.update(&measurement(12.0, 600)), AirQuality::Good);
+    }
+
+    #[test]
+    fn classifier_reports_worst_channel() {
+        let mut classifier = AirQualityClassifier::new(AirQualityBoundaries {
+            voc_index: None,
+            ..Default::default()
+        });
+        assert_eq!(
+            classifier.update(&measurement(20.0, 1_600)),
+            AirQuality::Poor
+        );
+        assert_eq!(
+            classifier.channel_quality(Channel::Pm2_5),
+            Some(AirQuality::Fair)
+        );
+        assert_eq!(classifier.channel_quality(Channel::VocIndex), None);
+
+        let unavailable = measurement(5.0, Measurement::CO2_UNAVAILABLE);
+        assert_eq!(classifier.update(&unavailable), AirQuality::Poor);
+        classifier.reset();
+        assert_eq!(classifier.quality(), AirQuality::Good);
+    }
+}
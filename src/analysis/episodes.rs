@@ -0,0 +1,306 @@
+use crate::data::{Channel, Measurement, Timestamped};
+
+/// Kind of an air quality [`Episode`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EpisodeKind {
+    /// Elevated PM2.5 mass concentration, e.g. from cooking or smoke.
+    PmEvent,
+    /// Elevated CO2 concentration, e.g. from insufficient ventilation.
+    HighCo2,
+    /// Elevated VOC index.
+    HighVoc,
+    /// Elevated NOx index.
+    HighNox,
+}
+
+impl EpisodeKind {
+    const ALL: [EpisodeKind; 4] = [
+        EpisodeKind::PmEvent,
+        EpisodeKind::HighCo2,
+        EpisodeKind::HighVoc,
+        EpisodeKind::HighNox,
+    ];
+
+    fn channel(self) -> Channel {
+        match self {
+            EpisodeKind::PmEvent => Channel::Pm2_5,
+            EpisodeKind::HighCo2 => Channel::Co2,
+            EpisodeKind::HighVoc => Channel::VocIndex,
+            EpisodeKind::HighNox => Channel::NoxIndex,
+        }
+    }
+}
+
+/// Period in which a value exceeded its threshold.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Episode {
+    /// Kind of the episode.
+    pub kind: EpisodeKind,
+    /// Timestamp of the first measurement exceeding the threshold in ms.
+    pub start_ms: u64,
+    /// Timestamp of the first measurement below the end threshold in ms.
+    pub end_ms: u64,
+    /// Peak value during the episode.
+    pub peak: f32,
+}
+
+impl Episode {
+    /// Returns the duration of the episode in ms.
+    pub fn duration_ms(&self) -> u64 {
+        self.end_ms - self.start_ms
+    }
+}
+
+/// Thresholds starting and ending an episode. Ending below a lower threshold than the start
+/// avoids fragmenting episodes with values fluctuating around the threshold.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EpisodeThreshold {
+    /// An episode starts once a value reaches this threshold.
+    pub start: f32,
+    /// An episode ends once a value falls below this threshold.
+    pub end: f32,
+}
+
+impl EpisodeThreshold {
+    /// Creates a threshold starting episodes at `start` and ending them below `end`.
+    pub const fn new(start: f32, end: f32) -> Self {
+        Self { start, end }
+    }
+}
+
+/// Thresholds for all kinds of episodes. `None` disables the kind of episode.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EpisodeThresholds {
+    /// Threshold of the PM2.5 mass concentration in ug/m³.
+    pub pm2_5: Option<EpisodeThreshold>,
+    /// Threshold of the CO2 concentration in ppm.
+    pub co2: Option<EpisodeThreshold>,
+    /// Threshold of the VOC index.
+    pub voc_index: Option<EpisodeThreshold>,
+    /// Threshold of the NOx index.
+    pub nox_index: Option<EpisodeThreshold>,
+}
+
+impl EpisodeThresholds {
+    fn get(&self, kind: EpisodeKind) -> Option<EpisodeThreshold> {
+        match kind {
+            EpisodeKind::PmEvent => self.pm2_5,
+            EpisodeKind::HighCo2 => self.co2,
+            EpisodeKind::HighVoc => self.voc_index,
+            EpisodeKind::HighNox => self.nox_index,
+        }
+    }
+}
+
+impl Default for EpisodeThresholds {
+    /// Returns thresholds of 35 ug/m³ PM2.5, 1000 ppm CO2, a VOC index of 250 and a NOx index
+    /// of 20, each ending 20% below the start.
+    fn default() -> Self {
+        Self {
+            pm2_5: Some(EpisodeThreshold::new(35.0, 28.0)),
+            co2: Some(EpisodeThreshold::new(1_000.0, 800.0)),
+            voc_index: Some(EpisodeThreshold::new(250.0, 200.0)),
+            nox_index: Some(EpisodeThreshold::new(20.0, 16.0)),
+        }
+    }
+}
+
+/// Segments a measurement stream into [`Episode`]s and keeps the last `N` completed episodes,
+/// e.g. for a later upload over a narrowband link. Once full, the oldest episode is dropped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EpisodeLog<const N: usize> {
+    thresholds: EpisodeThresholds,
+    min_duration_ms: u64,
+    active: [Option<Episode>; 4],
+    episodes: [Option<Episode>; N],
+    next: usize,
+    len: usize,
+    dropped: u32,
+}
+
+impl<const N: usize> EpisodeLog<N> {
+    /// Creates an empty log detecting episodes using the `thresholds`.
+    pub const fn new(thresholds: EpisodeThresholds) -> Self {
+        Self {
+            thresholds,
+            min_duration_ms: 0,
+            active: [None; 4],
+            episodes: [None; N],
+            next: 0,
+            len: 0,
+            dropped: 0,
+        }
+    }
+
+    /// Returns the log discarding episodes shorter than `min_duration_ms` ms.
+    pub const fn with_min_duration(mut self, min_duration_ms: u64) -> Self {
+        self.min_duration_ms = min_duration_ms;
+        self
+    }
+
+    /// Processes the `measurement` and returns the number of episodes completed by it.
+    /// Unavailable values neither start nor end an episode.
+    pub fn update(&mut self, measurement: &Timestamped<Measurement>) -> usize {
+        let mut completed = 0;
+        for (slot, kind) in EpisodeKind::ALL.into_iter().enumerate() {
+            let (Some(threshold), Some(value)) = (
+                self.thresholds.get(kind),
+                kind.channel().value(&measurement.value),
+            ) else {
+                continue;
+            };
+            match &mut self.active[slot] {
+                Some(episode) if value < threshold.end => {
+                    let episode = Episode {
+                        end_ms: measurement.timestamp_ms,
+                        ..*episode
+                    };
+                    self.active[slot] = None;
+                    if episode.duration_ms() >= self.min_duration_ms {
+                        self.push(episode);
+                        completed += 1;
+                    }
+                }
+                Some(episode) => episode.peak = episode.peak.max(value),
+                None if value >= threshold.start => {
+                    self.active[slot] = Some(Episode {
+                        kind,
+                        start_ms: measurement.timestamp_ms,
+                        end_ms: measurement.timestamp_ms,
+                        peak: value,
+                    });
+                }
+                None => {}
+            }
+        }
+        completed
+    }
+
+    /// Returns the ongoing episode of `kind`, with its end set to its start.
+    pub fn active(&self, kind: EpisodeKind) -> Option<Episode> {
+        self.active[kind as usize]
+    }
+
+    /// Returns the number of completed episodes in the log.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the log contains no completed episodes.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of episodes dropped since the log was last cleared, as the log was
+    /// full.
+    pub fn dropped(&self) -> u32 {
+        self.dropped
+    }
+
+    /// Returns the completed episodes, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &Episode> {
+        let start = (self.next + N - self.len) % N.max(1);
+        (0..self.len).filter_map(move |i| self.episodes[(start + i) % N].as_ref())
+    }
+
+    /// Removes all completed episodes, e.g. after uploading them. Ongoing episodes are kept.
+    pub fn clear(&mut self) {
+        self.episodes = [None; N];
+        self.next = 0;
+        self.len = 0;
+        self.dropped = 0;
+    }
+
+    fn push(&mut self, episode: Episode) {
+        if N == 0 {
+            self.dropped = self.dropped.saturating_add(1);
+            return;
+        }
+        if self.len == N {
+            self.dropped = self.dropped.saturating_add(1);
+        } else {
+            self.len += 1;
+        }
+        self.episodes[self.next] = Some(episode);
+        self.next = (self.next + 1) % N;
+    }
+}
+
+impl<const N: usize> Default for EpisodeLog<N> {
+    /// Returns an empty log using the default thresholds.
+    fn default() -> Self {
+        Self::new(EpisodeThresholds::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(timestamp_ms: u64, pm2_5: f32, co2: u16) -> Timestamped<Measurement> {
+        Timestamped::new(
+            timestamp_ms,
+            Measurement {
+                pm2_5,
+                co2,
+                ..Measurement::fixture()
+            },
+        )
+    }
+
+    #[test]
+    fn episode_log_segments_episodes() {
+        let mut log = EpisodeLog::<4>::default();
+        assert_eq!(log.update(&measurement(0, 10.0, 600)), 0);
+        assert_eq!(log.update(&measurement(1_000, 40.0, 1_200)), 0);
+        assert_eq!(log.update(&measurement(2_000, 60.0, 900)), 0);
+        assert_eq!(log.active(EpisodeKind::PmEvent).unwrap().peak, 60.0);
+        assert_eq!(log.update(&measurement(3_000, 20.0, 700)), 2);
+        assert_eq!(
+            log.iter().copied().collect::<Vec<_>>(),
+            [
+                Episode {
+                    kind: EpisodeKind::PmEvent,
+                    start_ms: 1_000,
+                    end_ms: 3_000,
+                    peak: 60.0,
+                },
+                Episode {
+                    kind: EpisodeKind::HighCo2,
+                    start_ms: 1_000,
+                    end_ms: 3_000,
+                    peak: 1_200.0,
+                },
+            ]
+        );
+        assert_eq!(log.active(EpisodeKind::PmEvent), None);
+    }
+
+    #[test]
+    fn episode_log_drops_oldest_and_short_episodes() {
+        let mut log = EpisodeLog::<2>::new(EpisodeThresholds {
+            pm2_5: Some(EpisodeThreshold::new(35.0, 35.0)),
+            co2: None,
+            voc_index: None,
+            nox_index: None,
+        })
+        .with_min_duration(1_000);
+        for i in 0..3 {
+            log.update(&measurement(i * 10_000, 50.0 + i as f32, 2_000));
+            log.update(&measurement(i * 10_000 + 5_000, 1.0, 2_000));
+        }
+        // Too short to be logged
+        log.update(&measurement(40_000, 50.0, 2_000));
+        log.update(&measurement(40_500, 1.0, 2_000));
+        assert_eq!(log.len(), 2);
+        assert_eq!(log.dropped(), 1);
+        let peaks: Vec<f32> = log.iter().map(|episode| episode.peak).collect();
+        assert_eq!(peaks, [51.0, 52.0]);
+        log.clear();
+        assert!(log.is_empty());
+    }
+}
@@ -0,0 +1,234 @@
+use crate::{
+    data::{Channel, Measurement},
+    error::DataError,
+};
+
+/// Histogram of one [`Channel`] with `N` fixed bins. Bin `i` counts values from its lower bound
+/// up to the lower bound of bin `i + 1`; the last bin is open-ended. Values below the first
+/// bound are counted separately. Use [`encode`](Histogram::encode) to serialize the counts
+/// compactly, e.g. to report the share of time with a CO2 concentration above 1000 ppm.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Histogram<const N: usize> {
+    channel: Channel,
+    lower_bounds: [f32; N],
+    counts: [u32; N],
+    below: u32,
+}
+
+impl<const N: usize> Histogram<N> {
+    /// Creates an empty histogram of the `channel` with bins starting at the ascending
+    /// `lower_bounds`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValueOutOfRange`](crate::error::DataError::ValueOutOfRange): If the bounds are not
+    ///   strictly ascending or more than 255 bins are requested.
+    pub fn new(channel: Channel, lower_bounds: [f32; N]) -> Result<Self, DataError> {
+        let ascending = lower_bounds.windows(2).all(|bounds| bounds[0] < bounds[1]);
+        if !ascending || N > usize::from(u8::MAX) {
+            return Err(DataError::ValueOutOfRange {
+                parameter: "Histogram Bins",
+                min: 0,
+                max: i32::from(u8::MAX),
+                unit: "",
+            });
+        }
+        Ok(Self {
+            channel,
+            lower_bounds,
+            counts: [0; N],
+            below: 0,
+        })
+    }
+
+    /// Returns the channel of the histogram.
+    pub fn channel(&self) -> Channel {
+        self.channel
+    }
+
+    /// Returns the lower bounds of the bins.
+    pub fn lower_bounds(&self) -> &[f32; N] {
+        &self.lower_bounds
+    }
+
+    /// Returns the counts per bin.
+    pub fn counts(&self) -> &[u32; N] {
+        &self.counts
+    }
+
+    /// Returns the number of values below the lower bound of the first bin.
+    pub fn below(&self) -> u32 {
+        self.below
+    }
+
+    /// Returns the total number of values.
+    pub fn total(&self) -> u32 {
+        self.counts
+            .iter()
+            .fold(self.below, |total, count| total.saturating_add(*count))
+    }
+
+    /// Adds the value of the histogram's channel in the `measurement`. Unavailable values are
+    /// skipped.
+    pub fn add(&mut self, measurement: &Measurement) {
+        if let Some(value) = self.channel.value(measurement) {
+            self.add_value(value);
+        }
+    }
+
+    /// Adds the `value`.
+    pub fn add_value(&mut self, value: f32) {
+        if value.is_nan() {
+            return;
+        }
+        match self.lower_bounds.iter().rposition(|bound| value >= *bound) {
+            Some(bin) => self.counts[bin] = self.counts[bin].saturating_add(1),
+            None => self.below = self.below.saturating_add(1),
+        }
+    }
+
+    /// Returns the fraction of values in the bins starting at or above `value`, e.g. the share
+    /// of time with a CO2 concentration of at least 1000 ppm if 1000 is a bin bound.
+    pub fn fraction_at_least(&self, value: f32) -> f32 {
+        let total = self.total();
+        if total == 0 {
+            return 0.0;
+        }
+        let above = self
+            .lower_bounds
+            .iter()
+            .zip(self.counts)
+            .filter(|(bound, _)| **bound >= value)
+            .fold(0u32, |sum, (_, count)| sum.saturating_add(count));
+        above as f32 / total as f32
+    }
+
+    /// Clears all counts.
+    pub fn reset(&mut self) {
+        self.counts = [0; N];
+        self.below = 0;
+    }
+
+    /// Encodes the counts into `buffer` and returns the number of bytes written. The encoding
+    /// consists of the channel, the number of bins, the count below the first bin and the count
+    /// of each bin, with counts encoded as LEB128 variable-length integers.
+    ///
+    /// # Errors
+    ///
+    /// - [`BufferTooSmall`](crate::error::DataError::BufferTooSmall): If the encoded counts do
+    ///   not fit into `buffer`.
+    pub fn encode(&self, buffer: &mut [u8]) -> Result<usize, DataError> {
+        let mut position = 0;
+        let mut write = |byte: u8| {
+            let slot = buffer.get_mut(position).ok_or(DataError::BufferTooSmall)?;
+            *slot = byte;
+            position += 1;
+            Ok::<_, DataError>(())
+        };
+        write(self.channel as u8)?;
+        write(N as u8)?;
+        for mut count in core::iter::once(self.below).chain(self.counts) {
+            while count >= 0x80 {
+                write(count as u8 | 0x80)?;
+                count >>= 7;
+            }
+            write(count as u8)?;
+        }
+        Ok(position)
+    }
+
+    /// Restores the counts from their encoding created by [`encode`](Histogram::encode).
+    ///
+    /// # Errors
+    ///
+    /// - [`ReceivedBufferWrongSize`](crate::error::DataError::ReceivedBufferWrongSize): If the
+    ///   data does not match the histogram's channel and number of bins or is malformed.
+    pub fn decode(&mut self, data: &[u8]) -> Result<(), DataError> {
+        let (&[channel, bins], mut data) = data
+            .split_first_chunk::<2>()
+            .ok_or(DataError::ReceivedBufferWrongSize)?;
+        if channel != self.channel as u8 || usize::from(bins) != N {
+            return Err(DataError::ReceivedBufferWrongSize);
+        }
+        let mut read = || {
+            let mut value = 0u32;
+            for shift in (0..35).step_by(7) {
+                let (&byte, rest) = data
+                    .split_first()
+                    .ok_or(DataError::ReceivedBufferWrongSize)?;
+                data = rest;
+                value |= u32::from(byte & 0x7F)
+                    .checked_shl(shift)
+                    .ok_or(DataError::ReceivedBufferWrongSize)?;
+                if byte & 0x80 == 0 {
+                    return Ok(value);
+                }
+            }
+            Err(DataError::ReceivedBufferWrongSize)
+        };
+        let below = read()?;
+        let mut counts = [0; N];
+        for count in counts.iter_mut() {
+            *count = read()?;
+        }
+        self.below = below;
+        self.counts = counts;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn histogram_counts_values_per_bin() {
+        let mut histogram = Histogram::new(Channel::Co2, [400.0, 800.0, 1_000.0]).unwrap();
+        for value in [300.0, 400.0, 799.0, 800.0, 1_000.0, 5_000.0, f32::NAN] {
+            histogram.add_value(value);
+        }
+        assert_eq!(histogram.counts(), &[2, 1, 2]);
+        assert_eq!(histogram.below(), 1);
+        assert_eq!(histogram.total(), 6);
+        assert_eq!(histogram.fraction_at_least(1_000.0), 2.0 / 6.0);
+        histogram.reset();
+        assert_eq!(histogram.total(), 0);
+        assert_eq!(histogram.fraction_at_least(1_000.0), 0.0);
+    }
+
+    #[test]
+    fn histogram_requires_ascending_bounds() {
+        assert!(Histogram::new(Channel::Co2, [400.0, 400.0]).is_err());
+        assert!(Histogram::<0>::new(Channel::Co2, []).is_ok());
+    }
+
+    #[test]
+    fn histogram_roundtrips_through_encoding() {
+        let mut histogram = Histogram::new(Channel::Pm2_5, [0.0, 12.0, 35.0]).unwrap();
+        for _ in 0..200 {
+            histogram.add_value(5.0);
+        }
+        histogram.add_value(40.0);
+        let mut buffer = [0; 16];
+        let len = histogram.encode(&mut buffer).unwrap();
+        assert_eq!(buffer[..len], [1, 3, 0, 0xC8, 0x01, 0, 1]);
+
+        let mut restored = Histogram::new(Channel::Pm2_5, [0.0, 12.0, 35.0]).unwrap();
+        restored.decode(&buffer[..len]).unwrap();
+        assert_eq!(restored, histogram);
+        assert_eq!(
+            restored.decode(&buffer[..len - 1]),
+            Err(DataError::ReceivedBufferWrongSize)
+        );
+        assert_eq!(
+            Histogram::new(Channel::Co2, [0.0, 12.0, 35.0])
+                .unwrap()
+                .decode(&buffer[..len]),
+            Err(DataError::ReceivedBufferWrongSize)
+        );
+        assert_eq!(
+            histogram.encode(&mut buffer[..4]),
+            Err(DataError::BufferTooSmall)
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::data::{Channel, Measurement};
+
+/// Ring buffer keeping the last `N` measurements, e.g. to draw a trend of recent values. Once
+/// full, the oldest measurement is overwritten.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct History<const N: usize> {
+    measurements: [Option<Measurement>; N],
+    next: usize,
+    len: usize,
+}
+
+impl<const N: usize> History<N> {
+    /// Creates an empty history.
+    pub const fn new() -> Self {
+        Self {
+            measurements: [None; N],
+            next: 0,
+            len: 0,
+        }
+    }
+
+    /// Adds the `measurement`, overwriting the oldest one if the history is full.
+    pub fn push(&mut self, measurement: Measurement) {
+        if N == 0 {
+            return;
+        }
+        self.measurements[self.next] = Some(measurement);
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+    }
+
+    /// Returns the number of measurements in the history.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the history contains no measurements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the most recent measurement.
+    pub fn latest(&self) -> Option<&Measurement> {
+        self.iter().last()
+    }
+
+    /// Returns the measurements, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &Measurement> + Clone {
+        let start = (self.next + N - self.len) % N.max(1);
+        (0..self.len).filter_map(move |i| self.measurements[(start + i) % N].as_ref())
+    }
+
+    /// Returns the values of the `channel`, oldest first. `None` for values which were not
+    /// available.
+    pub fn values(&self, channel: Channel) -> impl Iterator<Item = Option<f32>> + Clone + '_ {
+        self.iter()
+            .map(move |measurement| channel.value(measurement))
+    }
+
+    /// Removes all measurements.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl<const N: usize> Default for History<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(co2: u16) -> Measurement {
+        Measurement {
+            co2,
+            ..Measurement::fixture()
+        }
+    }
+
+    #[test]
+    fn history_overwrites_oldest_measurement() {
+        let mut history = History::<3>::new();
+        assert!(history.is_empty());
+        for co2 in [400, 500, 600, Measurement::CO2_UNAVAILABLE] {
+            history.push(measurement(co2));
+        }
+        assert_eq!(history.len(), 3);
+        assert_eq!(
+            history.values(Channel::Co2).collect::<Vec<_>>(),
+            [Some(500.0), Some(600.0), None]
+        );
+        assert_eq!(
+            history.latest().map(|m| m.co2),
+            Some(Measurement::CO2_UNAVAILABLE)
+        );
+        let mut sparkline = String::new();
+        crate::report::Sparkline::new(crate::report::SparklineStyle::Ascii, 3)
+            .write_to(history.values(Channel::Co2), &mut sparkline)
+            .unwrap();
+        assert_eq!(sparkline, "_# ");
+        history.clear();
+        assert_eq!(history.latest(), None);
+    }
+
+    #[test]
+    fn empty_history_ignores_measurements() {
+        let mut history = History::<0>::new();
+        history.push(measurement(400));
+        assert!(history.is_empty());
+        assert_eq!(history.iter().count(), 0);
+    }
+}
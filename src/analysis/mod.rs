@@ -0,0 +1,24 @@
+//! Analysis of measurement series on the device, reducing them to compact summaries.
+
+mod air_quality;
+mod episodes;
+mod histogram;
+mod history;
+mod mold;
+mod profile;
+mod trend;
+mod ventilation;
+mod voc_events;
+
+pub use air_quality::{AirQuality, AirQualityBoundaries, AirQualityClassifier, QualityBoundaries};
+pub use episodes::{Episode, EpisodeKind, EpisodeLog, EpisodeThreshold, EpisodeThresholds};
+pub use histogram::Histogram;
+pub use history::History;
+pub use mold::{MoldRisk, critical_humidity, mold_risk};
+pub use profile::{DailyProfile, HourStatistics};
+pub use trend::{Co2Trend, Co2TrendEstimator};
+pub use ventilation::{
+    DEFAULT_OUTDOOR_CO2, IndoorAirCategory, PETTENKOFER_LIMIT, VentilationAssessment,
+    VentilationClassifier,
+};
+pub use voc_events::{VocEvent, VocEventDetector, VocEventState};
@@ -0,0 +1,94 @@
+use crate::data::{Channel, Measurement};
+
+/// Risk of mold growth at the conditions of a series of measurements, as assessed by
+/// [`mold_risk`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MoldRisk {
+    /// Conditions allowing mold growth occurred in less than 10 % of the window.
+    None,
+    /// Conditions allowing mold growth occurred in at least 10 % of the window.
+    Low,
+    /// Conditions allowing mold growth occurred in at least 30 % of the window.
+    Moderate,
+    /// Conditions allowing mold growth occurred in at least 60 % of the window.
+    High,
+}
+
+/// Returns the relative humidity in % above which mold can grow at `temperature` in °C, based on
+/// the lowest isopleth of the VTT mold growth model (Hukka and Viitanen, 1999). `None` outside of
+/// 0 °C to 50 °C, where mold does not grow.
+pub fn critical_humidity(temperature: f32) -> Option<f32> {
+    match temperature {
+        t if !(0.0..=50.0).contains(&t) => None,
+        t if t <= 20.0 => Some(-0.00267 * t * t * t + 0.160 * t * t - 3.13 * t + 100.0),
+        _ => Some(80.0),
+    }
+}
+
+/// Assesses the risk of mold growth from the share of `measurements` with a relative humidity
+/// above the [`critical_humidity`], e.g. from a [`History`](crate::analysis::History) covering
+/// several days. The measurements are expected to be taken at a regular interval. Mold grows on
+/// surfaces, so place the sensor close to the surface of concern, e.g. a cold outer wall.
+/// `None` if no measurement provides the temperature and relative humidity.
+pub fn mold_risk<'a>(measurements: impl IntoIterator<Item = &'a Measurement>) -> Option<MoldRisk> {
+    let (total, critical) = measurements
+        .into_iter()
+        .filter_map(|measurement| {
+            let temperature = Channel::Temperature.value(measurement)?;
+            let relative_humidity = Channel::RelativeHumidity.value(measurement)?;
+            Some(critical_humidity(temperature).is_some_and(|limit| relative_humidity > limit))
+        })
+        .fold((0_u32, 0_u32), |(total, critical), exceeded| {
+            (total + 1, critical + u32::from(exceeded))
+        });
+    if total == 0 {
+        return None;
+    }
+    let share = critical as f32 / total as f32;
+    Some(if share >= 0.6 {
+        MoldRisk::High
+    } else if share >= 0.3 {
+        MoldRisk::Moderate
+    } else if share >= 0.1 {
+        MoldRisk::Low
+    } else {
+        MoldRisk::None
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(temperature: f32, relative_humidity: f32) -> Measurement {
+        Measurement {
+            relative_humidity,
+            temperature,
+            ..Measurement::fixture()
+        }
+    }
+
+    #[test]
+    fn critical_humidity_follows_isopleth() {
+        assert_eq!(critical_humidity(0.0), Some(100.0));
+        assert!((critical_humidity(10.0).unwrap() - 82.03).abs() < 0.01);
+        assert_eq!(critical_humidity(25.0), Some(80.0));
+        assert_eq!(critical_humidity(-5.0), None);
+    }
+
+    #[test]
+    fn risk_grows_with_share_of_critical_conditions() {
+        let dry = measurement(21.0, 50.0);
+        let damp = measurement(21.0, 85.0);
+        let unavailable = measurement(Measurement::TEMPERATURE_UNAVAILABLE, 85.0);
+        assert_eq!(mold_risk(&[dry; 10]), Some(MoldRisk::None));
+        assert_eq!(
+            mold_risk(&[dry, dry, dry, dry, dry, dry, damp, damp, damp, unavailable]),
+            Some(MoldRisk::Moderate)
+        );
+        assert_eq!(mold_risk(&[damp, damp, dry]), Some(MoldRisk::High));
+        assert_eq!(mold_risk(&[unavailable]), None);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::data::{Channel, Measurement, Timestamped};
+
+/// Duration of one hour in ms.
+const HOUR_MS: i64 = 3_600_000;
+/// Number of hours per day.
+const HOURS: usize = 24;
+
+/// Statistics of one channel within one hour of the day.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HourStatistics {
+    /// Mean of all values.
+    pub mean: f32,
+    /// Maximum of all values.
+    pub max: f32,
+    /// Number of values.
+    pub count: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Accumulator {
+    sum: f32,
+    max: f32,
+    count: u32,
+}
+
+impl Accumulator {
+    const EMPTY: Self = Self {
+        sum: 0.0,
+        max: f32::NEG_INFINITY,
+        count: 0,
+    };
+}
+
+/// Aggregates per-hour-of-day statistics of `C` channels over multiple days, so typical daily
+/// patterns can be reported without processing on a server. Memory is bounded to one
+/// accumulator per hour and channel. Timestamps are expected in ms since the UNIX epoch to
+/// determine the hour of the day.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DailyProfile<const C: usize> {
+    utc_offset_s: i32,
+    channels: [Channel; C],
+    hours: [[Accumulator; C]; HOURS],
+}
+
+impl<const C: usize> DailyProfile<C> {
+    /// Creates an empty profile of the `channels` with hours in UTC.
+    pub const fn new(channels: [Channel; C]) -> Self {
+        Self {
+            utc_offset_s: 0,
+            channels,
+            hours: [[Accumulator::EMPTY; C]; HOURS],
+        }
+    }
+
+    /// Returns the profile with hours in the time zone `utc_offset_s` seconds ahead of UTC.
+    pub const fn with_utc_offset(mut self, utc_offset_s: i32) -> Self {
+        self.utc_offset_s = utc_offset_s;
+        self
+    }
+
+    /// Returns the profiled channels.
+    pub fn channels(&self) -> &[Channel; C] {
+        &self.channels
+    }
+
+    /// Adds the `measurement` to the hour of the day it was taken in. Unavailable values are
+    /// skipped.
+    pub fn update(&mut self, measurement: &Timestamped<Measurement>) {
+        let local_ms = measurement.timestamp_ms as i64 + i64::from(self.utc_offset_s) * 1_000;
+        let hour = (local_ms.div_euclid(HOUR_MS) % HOURS as i64) as usize;
+        for (channel, accumulator) in self.channels.iter().zip(self.hours[hour].iter_mut()) {
+            let Some(value) = channel.value(&measurement.value) else {
+                continue;
+            };
+            accumulator.sum += value;
+            accumulator.max = accumulator.max.max(value);
+            accumulator.count = accumulator.count.saturating_add(1);
+        }
+    }
+
+    /// Returns the statistics of `channel` within `hour` of the day. `None` if the channel is
+    /// not profiled, the hour is invalid or no values were added.
+    pub fn statistics(&self, channel: Channel, hour: usize) -> Option<HourStatistics> {
+        let index = self.channels.iter().position(|c| *c == channel)?;
+        let accumulator = self.hours.get(hour)?[index];
+        (accumulator.count > 0).then(|| HourStatistics {
+            mean: accumulator.sum / accumulator.count as f32,
+            max: accumulator.max,
+            count: accumulator.count,
+        })
+    }
+
+    /// Returns the statistics of `channel` for each hour of the day, starting at midnight.
+    pub fn day(&self, channel: Channel) -> [Option<HourStatistics>; HOURS] {
+        core::array::from_fn(|hour| self.statistics(channel, hour))
+    }
+
+    /// Clears all statistics.
+    pub fn reset(&mut self) {
+        self.hours = [[Accumulator::EMPTY; C]; HOURS];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(timestamp_ms: u64, co2: u16) -> Timestamped<Measurement> {
+        Timestamped::new(
+            timestamp_ms,
+            Measurement {
+                pm2_5: Measurement::PM_UNAVAILABLE,
+                co2,
+                ..Measurement::fixture()
+            },
+        )
+    }
+
+    #[test]
+    fn daily_profile_aggregates_per_hour_over_days() {
+        const DAY_MS: u64 = 24 * 3_600_000;
+        let mut profile = DailyProfile::new([Channel::Co2, Channel::Pm2_5]).with_utc_offset(7_200);
+        // 06:30 UTC and 08:30 local time on two days
+        profile.update(&measurement(6 * 3_600_000 + 1_800_000, 600));
+        profile.update(&measurement(DAY_MS + 6 * 3_600_000 + 1_800_000, 1_000));
+        assert_eq!(
+            profile.statistics(Channel::Co2, 8),
+            Some(HourStatistics {
+                mean: 800.0,
+                max: 1_000.0,
+                count: 2,
+            })
+        );
+        assert_eq!(profile.statistics(Channel::Co2, 6), None);
+        assert_eq!(profile.statistics(Channel::Pm2_5, 8), None);
+        assert_eq!(profile.statistics(Channel::Temperature, 8), None);
+        assert_eq!(profile.statistics(Channel::Co2, 24), None);
+        assert_eq!(profile.day(Channel::Co2).iter().flatten().count(), 1);
+        profile.reset();
+        assert_eq!(profile.statistics(Channel::Co2, 8), None);
+    }
+}
@@ -0,0 +1,161 @@
+use crate::data::{Channel, Measurement, Timestamped};
+
+/// Direction of the CO2 concentration reported by a [`Co2TrendEstimator`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Co2Trend {
+    /// The concentration decreases, e.g. after opening a window or leaving the room.
+    Falling,
+    /// The concentration is approximately constant.
+    Steady,
+    /// The concentration increases, e.g. from occupancy.
+    Rising,
+}
+
+/// Estimates the rate of change of the CO2 concentration and classifies it into a [`Co2Trend`].
+///
+/// The rate between consecutive samples is smoothed exponentially with a time constant, as the
+/// CO2 signal is too noisy to differentiate directly. The trend becomes rising or falling once
+/// the magnitude of the rate reaches the threshold and returns to steady once it falls below
+/// the threshold minus the hysteresis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Co2TrendEstimator {
+    threshold: f32,
+    hysteresis: f32,
+    time_constant_ms: u64,
+    last: Option<(u64, f32)>,
+    rate: Option<f32>,
+    trend: Co2Trend,
+}
+
+impl Co2TrendEstimator {
+    /// Creates an estimator reporting a rising or falling trend from a rate of `threshold`
+    /// ppm/min and returning to steady below `threshold - hysteresis` ppm/min.
+    pub const fn new(threshold: f32, hysteresis: f32) -> Self {
+        Self {
+            threshold,
+            hysteresis,
+            time_constant_ms: 60_000,
+            last: None,
+            rate: None,
+            trend: Co2Trend::Steady,
+        }
+    }
+
+    /// Returns the estimator smoothing the rate with a time constant of `time_constant_ms` ms.
+    pub const fn with_time_constant(mut self, time_constant_ms: u64) -> Self {
+        self.time_constant_ms = time_constant_ms;
+        self
+    }
+
+    /// Processes the `measurement` and returns the current trend. An unavailable CO2
+    /// concentration leaves the estimate unchanged.
+    pub fn update(&mut self, measurement: &Timestamped<Measurement>) -> Co2Trend {
+        match Channel::Co2.value(&measurement.value) {
+            Some(co2) => self.update_co2(measurement.timestamp_ms, co2),
+            None => self.trend,
+        }
+    }
+
+    /// Processes the CO2 concentration `co2` in ppm taken at `timestamp_ms` ms and returns the
+    /// current trend. A timestamp before the previous one restarts the estimation.
+    pub fn update_co2(&mut self, timestamp_ms: u64, co2: f32) -> Co2Trend {
+        let Some((last_ms, last_co2)) = self.last else {
+            self.last = Some((timestamp_ms, co2));
+            return self.trend;
+        };
+        if timestamp_ms < last_ms {
+            self.reset();
+            self.last = Some((timestamp_ms, co2));
+            return self.trend;
+        }
+        if timestamp_ms == last_ms {
+            return self.trend;
+        }
+        let elapsed_ms = (timestamp_ms - last_ms) as f32;
+        let sample_rate = (co2 - last_co2) * 60_000.0 / elapsed_ms;
+        let rate = match self.rate {
+            Some(rate) => {
+                let alpha = elapsed_ms / (self.time_constant_ms as f32 + elapsed_ms);
+                rate + alpha * (sample_rate - rate)
+            }
+            None => sample_rate,
+        };
+        self.last = Some((timestamp_ms, co2));
+        self.rate = Some(rate);
+        let release = self.threshold - self.hysteresis;
+        self.trend = match self.trend {
+            _ if rate >= self.threshold => Co2Trend::Rising,
+            _ if rate <= -self.threshold => Co2Trend::Falling,
+            Co2Trend::Rising if rate >= release => Co2Trend::Rising,
+            Co2Trend::Falling if rate <= -release => Co2Trend::Falling,
+            _ => Co2Trend::Steady,
+        };
+        self.trend
+    }
+
+    /// Returns the smoothed rate of change in ppm/min. `None` until two samples were processed.
+    pub fn rate_ppm_per_min(&self) -> Option<f32> {
+        self.rate
+    }
+
+    /// Returns the current trend.
+    pub fn trend(&self) -> Co2Trend {
+        self.trend
+    }
+
+    /// Discards all samples and returns to a steady trend.
+    pub fn reset(&mut self) {
+        self.last = None;
+        self.rate = None;
+        self.trend = Co2Trend::Steady;
+    }
+}
+
+impl Default for Co2TrendEstimator {
+    /// Returns an estimator with a threshold of 10 ppm/min, a hysteresis of 5 ppm/min and a time
+    /// constant of 60 s.
+    fn default() -> Self {
+        Self::new(10.0, 5.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trend_follows_rate_with_hysteresis() {
+        let mut estimator = Co2TrendEstimator::default().with_time_constant(0);
+        assert_eq!(estimator.update_co2(0, 600.0), Co2Trend::Steady);
+        assert_eq!(estimator.rate_ppm_per_min(), None);
+        assert_eq!(estimator.update_co2(60_000, 620.0), Co2Trend::Rising);
+        assert_eq!(estimator.rate_ppm_per_min(), Some(20.0));
+        assert_eq!(estimator.update_co2(120_000, 627.0), Co2Trend::Rising);
+        assert_eq!(estimator.update_co2(180_000, 630.0), Co2Trend::Steady);
+        assert_eq!(estimator.update_co2(240_000, 600.0), Co2Trend::Falling);
+        assert_eq!(estimator.update_co2(300_000, 594.0), Co2Trend::Falling);
+        assert_eq!(estimator.update_co2(360_000, 592.0), Co2Trend::Steady);
+    }
+
+    #[test]
+    fn rate_is_smoothed() {
+        let mut estimator = Co2TrendEstimator::default();
+        estimator.update_co2(0, 600.0);
+        estimator.update_co2(60_000, 660.0);
+        estimator.update_co2(120_000, 660.0);
+        assert_eq!(estimator.rate_ppm_per_min(), Some(30.0));
+        assert_eq!(estimator.trend(), Co2Trend::Rising);
+    }
+
+    #[test]
+    fn time_going_backwards_restarts_estimation() {
+        let mut estimator = Co2TrendEstimator::default();
+        estimator.update_co2(60_000, 600.0);
+        estimator.update_co2(120_000, 700.0);
+        assert_eq!(estimator.trend(), Co2Trend::Rising);
+        assert_eq!(estimator.update_co2(0, 500.0), Co2Trend::Steady);
+        assert_eq!(estimator.rate_ppm_per_min(), None);
+    }
+}
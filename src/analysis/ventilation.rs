@@ -0,0 +1,142 @@
+use crate::data::{Channel, Measurement};
+
+/// CO2 concentration in ppm recommended as the upper limit for indoor air by Max von
+/// Pettenkofer.
+pub const PETTENKOFER_LIMIT: u16 = 1000;
+
+/// Outdoor CO2 concentration in ppm assumed by [`VentilationClassifier::default`].
+pub const DEFAULT_OUTDOOR_CO2: u16 = 420;
+
+/// Indoor air category of EN 16798-1 based on the CO2 concentration above outdoor air.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum IndoorAirCategory {
+    /// High level of expectation, up to 550 ppm above outdoor air.
+    I,
+    /// Medium level of expectation, up to 800 ppm above outdoor air.
+    II,
+    /// Moderate level of expectation, up to 1350 ppm above outdoor air.
+    III,
+    /// Low level of expectation, more than 1350 ppm above outdoor air.
+    IV,
+}
+
+impl IndoorAirCategory {
+    /// Returns the category of a CO2 concentration `excess` in ppm above outdoor air.
+    pub fn from_excess(excess: u16) -> Self {
+        match excess {
+            0..=550 => IndoorAirCategory::I,
+            551..=800 => IndoorAirCategory::II,
+            801..=1350 => IndoorAirCategory::III,
+            _ => IndoorAirCategory::IV,
+        }
+    }
+}
+
+/// Classification of a CO2 concentration by a [`VentilationClassifier`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct VentilationAssessment {
+    /// Indoor air category of EN 16798-1.
+    pub category: IndoorAirCategory,
+    /// CO2 concentration in ppm above the outdoor baseline, zero if below.
+    pub excess: u16,
+    /// Whether the CO2 concentration exceeds the [`PETTENKOFER_LIMIT`].
+    pub exceeds_pettenkofer: bool,
+}
+
+/// Classifies CO2 concentrations into the indoor air categories of EN 16798-1 relative to an
+/// outdoor baseline, e.g. for demand-controlled ventilation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct VentilationClassifier {
+    outdoor_co2: u16,
+}
+
+impl VentilationClassifier {
+    /// Creates a classifier for an outdoor CO2 concentration of `outdoor_co2` in ppm.
+    pub const fn new(outdoor_co2: u16) -> Self {
+        Self { outdoor_co2 }
+    }
+
+    /// Returns the outdoor CO2 concentration in ppm.
+    pub fn outdoor_co2(&self) -> u16 {
+        self.outdoor_co2
+    }
+
+    /// Classifies the CO2 concentration `co2` in ppm.
+    pub fn classify(&self, co2: u16) -> VentilationAssessment {
+        let excess = co2.saturating_sub(self.outdoor_co2);
+        VentilationAssessment {
+            category: IndoorAirCategory::from_excess(excess),
+            excess,
+            exceeds_pettenkofer: co2 > PETTENKOFER_LIMIT,
+        }
+    }
+
+    /// Classifies the CO2 concentration of the `measurement`. `None` if the CO2 concentration is
+    /// not available.
+    pub fn classify_measurement(&self, measurement: &Measurement) -> Option<VentilationAssessment> {
+        Channel::Co2
+            .value(measurement)
+            .map(|co2| self.classify(co2 as u16))
+    }
+}
+
+impl Default for VentilationClassifier {
+    fn default() -> Self {
+        Self::new(DEFAULT_OUTDOOR_CO2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_uses_outdoor_baseline() {
+        let classifier = VentilationClassifier::default();
+        assert_eq!(
+            classifier.classify(950),
+            VentilationAssessment {
+                category: IndoorAirCategory::I,
+                excess: 530,
+                exceeds_pettenkofer: false,
+            }
+        );
+        assert_eq!(
+            classifier.classify(1200),
+            VentilationAssessment {
+                category: IndoorAirCategory::II,
+                excess: 780,
+                exceeds_pettenkofer: true,
+            }
+        );
+        assert_eq!(classifier.classify(1700).category, IndoorAirCategory::III);
+        assert_eq!(classifier.classify(1800).category, IndoorAirCategory::IV);
+        assert_eq!(classifier.classify(380).excess, 0);
+
+        let classifier = VentilationClassifier::new(500);
+        assert_eq!(classifier.classify(1350).category, IndoorAirCategory::III);
+    }
+
+    #[test]
+    fn classify_measurement_skips_unavailable_co2() {
+        let mut measurement = Measurement {
+            co2: 800,
+            ..Measurement::fixture()
+        };
+        let classifier = VentilationClassifier::default();
+        assert_eq!(
+            classifier
+                .classify_measurement(&measurement)
+                .map(|assessment| assessment.category),
+            Some(IndoorAirCategory::I)
+        );
+        measurement.co2 = Measurement::CO2_UNAVAILABLE;
+        assert_eq!(classifier.classify_measurement(&measurement), None);
+    }
+}
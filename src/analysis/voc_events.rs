@@ -0,0 +1,179 @@
+use crate::data::{Channel, Measurement, Timestamped};
+
+/// State of a [`VocEventDetector`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum VocEventState {
+    /// No event is ongoing.
+    Idle,
+    /// An event is ongoing and the VOC index is at its peak.
+    Rising,
+    /// An event is ongoing and the VOC index decays from its peak.
+    Decaying,
+}
+
+/// Period in which the VOC index indicated an event, i.e. a change of the VOC concentration
+/// relative to the average conditions of the past 24 h.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct VocEvent {
+    /// Timestamp of the onset in ms, i.e. the VOC index reaching the onset threshold.
+    pub start_ms: u64,
+    /// Timestamp of the peak in ms.
+    pub peak_ms: u64,
+    /// Timestamp of the end in ms, i.e. the VOC index falling below the end threshold.
+    pub end_ms: u64,
+    /// Peak VOC index during the event.
+    pub peak: f32,
+}
+
+impl VocEvent {
+    /// Returns the duration of the event in ms.
+    pub fn duration_ms(&self) -> u64 {
+        self.end_ms - self.start_ms
+    }
+
+    /// Returns the duration of the decay from the peak to the end in ms.
+    pub fn decay_ms(&self) -> u64 {
+        self.end_ms - self.peak_ms
+    }
+}
+
+/// Detects VOC events following the interpretation of the VOC index by Sensirion.
+///
+/// The VOC index is relative to the average conditions of the past 24 h, mapped to 100. An event
+/// starts once the index reaches the onset threshold above 100 and ends once it falls below the
+/// end threshold. Ending below a lower threshold than the onset avoids fragmenting an event, as
+/// the index decays slowly towards 100 after the source is removed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VocEventDetector {
+    onset: f32,
+    end: f32,
+    state: VocEventState,
+    event: Option<VocEvent>,
+}
+
+impl VocEventDetector {
+    /// Creates a detector starting events at a VOC index of `onset` and ending them below `end`.
+    pub const fn new(onset: f32, end: f32) -> Self {
+        Self {
+            onset,
+            end,
+            state: VocEventState::Idle,
+            event: None,
+        }
+    }
+
+    /// Processes the `measurement` and returns the event completed by it. An unavailable VOC
+    /// index, e.g. during the warm-up, leaves the state unchanged.
+    pub fn update(&mut self, measurement: &Timestamped<Measurement>) -> Option<VocEvent> {
+        let index = Channel::VocIndex.value(&measurement.value)?;
+        self.update_index(measurement.timestamp_ms, index)
+    }
+
+    /// Processes the VOC `index` taken at `timestamp_ms` ms and returns the event completed by
+    /// it.
+    pub fn update_index(&mut self, timestamp_ms: u64, index: f32) -> Option<VocEvent> {
+        match &mut self.event {
+            Some(event) if index < self.end => {
+                let event = VocEvent {
+                    end_ms: timestamp_ms,
+                    ..*event
+                };
+                self.event = None;
+                self.state = VocEventState::Idle;
+                return Some(event);
+            }
+            Some(event) if index >= event.peak => {
+                event.peak = index;
+                event.peak_ms = timestamp_ms;
+                self.state = VocEventState::Rising;
+            }
+            Some(_) => self.state = VocEventState::Decaying,
+            None if index >= self.onset => {
+                self.event = Some(VocEvent {
+                    start_ms: timestamp_ms,
+                    peak_ms: timestamp_ms,
+                    end_ms: timestamp_ms,
+                    peak: index,
+                });
+                self.state = VocEventState::Rising;
+            }
+            None => {}
+        }
+        None
+    }
+
+    /// Returns the state of the detector.
+    pub fn state(&self) -> VocEventState {
+        self.state
+    }
+
+    /// Returns the ongoing event, with its end set to its start.
+    pub fn active(&self) -> Option<VocEvent> {
+        self.event
+    }
+
+    /// Discards the ongoing event.
+    pub fn reset(&mut self) {
+        self.state = VocEventState::Idle;
+        self.event = None;
+    }
+}
+
+impl Default for VocEventDetector {
+    /// Returns a detector starting events at a VOC index of 150 and ending them below 110.
+    fn default() -> Self {
+        Self::new(150.0, 110.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detector_tracks_onset_peak_and_decay() {
+        let mut detector = VocEventDetector::default();
+        assert_eq!(detector.update_index(0, 140.0), None);
+        assert_eq!(detector.state(), VocEventState::Idle);
+        assert_eq!(detector.update_index(1_000, 160.0), None);
+        assert_eq!(detector.state(), VocEventState::Rising);
+        assert_eq!(detector.update_index(2_000, 300.0), None);
+        assert_eq!(detector.update_index(3_000, 200.0), None);
+        assert_eq!(detector.state(), VocEventState::Decaying);
+        assert_eq!(detector.update_index(4_000, 120.0), None);
+        assert_eq!(detector.active().map(|event| event.peak), Some(300.0));
+
+        let event = detector.update_index(6_000, 105.0).unwrap();
+        assert_eq!(
+            event,
+            VocEvent {
+                start_ms: 1_000,
+                peak_ms: 2_000,
+                end_ms: 6_000,
+                peak: 300.0,
+            }
+        );
+        assert_eq!(event.duration_ms(), 5_000);
+        assert_eq!(event.decay_ms(), 4_000);
+        assert_eq!(detector.state(), VocEventState::Idle);
+    }
+
+    #[test]
+    fn detector_skips_unavailable_index() {
+        let mut detector = VocEventDetector::default();
+        detector.update_index(0, 200.0);
+        let measurement = Timestamped::new(
+            1_000,
+            Measurement {
+                voc_index: Measurement::INDEX_UNAVAILABLE,
+                ..Measurement::fixture()
+            },
+        );
+        assert_eq!(detector.update(&measurement), None);
+        assert_eq!(detector.state(), VocEventState::Rising);
+    }
+}
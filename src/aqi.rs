@@ -0,0 +1,440 @@
+//! Air quality indices computed from the PM2.5 and PM10 mass concentrations.
+//!
+//! Supports the US EPA AQI, the European Common Air Quality Index (CAQI) and the European Air
+//! Quality Index (EAQI) of the European Environment Agency, selected with an [`AqiScale`].
+//!
+//! The indices are defined on averaged concentrations, e.g. the 24 h mean for the US AQI and the
+//! hourly mean for the CAQI. Pass averaged values, e.g. from
+//! [`PmAverage`](crate::maintenance::PmAverage), for a regulatory comparable index, or
+//! instantaneous values of a [`Measurement`] for a live indication.
+
+use crate::data::{Channel, Measurement};
+
+/// Pollutant an air quality index is computed from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Pollutant {
+    /// PM2.5 mass concentration.
+    Pm2_5,
+    /// PM10 mass concentration.
+    Pm10,
+}
+
+/// Category of the US EPA air quality index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AqiCategory {
+    /// AQI from 0 to 50.
+    Good,
+    /// AQI from 51 to 100.
+    Moderate,
+    /// AQI from 101 to 150.
+    UnhealthyForSensitiveGroups,
+    /// AQI from 151 to 200.
+    Unhealthy,
+    /// AQI from 201 to 300.
+    VeryUnhealthy,
+    /// AQI from 301 to 500.
+    Hazardous,
+}
+
+impl AqiCategory {
+    /// Returns the category of the AQI `value`.
+    pub fn from_value(value: u16) -> Self {
+        match value {
+            0..=50 => AqiCategory::Good,
+            51..=100 => AqiCategory::Moderate,
+            101..=150 => AqiCategory::UnhealthyForSensitiveGroups,
+            151..=200 => AqiCategory::Unhealthy,
+            201..=300 => AqiCategory::VeryUnhealthy,
+            _ => AqiCategory::Hazardous,
+        }
+    }
+}
+
+/// US EPA air quality index, the maximum of the sub-indices of PM2.5 and PM10.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Aqi {
+    /// Index value from 0 to 500.
+    pub value: u16,
+    /// Category of the index value.
+    pub category: AqiCategory,
+    /// Pollutant determining the index value.
+    pub dominant: Pollutant,
+}
+
+/// Category of the European Common Air Quality Index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CaqiCategory {
+    /// CAQI below 25.
+    VeryLow,
+    /// CAQI from 25 to below 50.
+    Low,
+    /// CAQI from 50 to below 75.
+    Medium,
+    /// CAQI from 75 to 100.
+    High,
+    /// CAQI above 100.
+    VeryHigh,
+}
+
+impl CaqiCategory {
+    /// Returns the category of the CAQI `value`.
+    pub fn from_value(value: u16) -> Self {
+        match value {
+            0..25 => CaqiCategory::VeryLow,
+            25..50 => CaqiCategory::Low,
+            50..75 => CaqiCategory::Medium,
+            75..=100 => CaqiCategory::High,
+            _ => CaqiCategory::VeryHigh,
+        }
+    }
+}
+
+/// European Common Air Quality Index of the hourly background grid, the maximum of the
+/// sub-indices of PM2.5 and PM10.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Caqi {
+    /// Index value, exceeding 100 for very high pollution.
+    pub value: u16,
+    /// Category of the index value.
+    pub category: CaqiCategory,
+    /// Pollutant determining the index value.
+    pub dominant: Pollutant,
+}
+
+/// Band of the European Air Quality Index of the European Environment Agency.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum EaqiCategory {
+    /// PM2.5 up to 10 ug/m³ and PM10 up to 20 ug/m³.
+    Good,
+    /// PM2.5 up to 20 ug/m³ and PM10 up to 40 ug/m³.
+    Fair,
+    /// PM2.5 up to 25 ug/m³ and PM10 up to 50 ug/m³.
+    Moderate,
+    /// PM2.5 up to 50 ug/m³ and PM10 up to 100 ug/m³.
+    Poor,
+    /// PM2.5 up to 75 ug/m³ and PM10 up to 150 ug/m³.
+    VeryPoor,
+    /// PM2.5 above 75 ug/m³ or PM10 above 150 ug/m³.
+    ExtremelyPoor,
+}
+
+/// European Air Quality Index, the worst band of PM2.5 and PM10.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Eaqi {
+    /// Band of the index.
+    pub category: EaqiCategory,
+    /// Pollutant determining the band.
+    pub dominant: Pollutant,
+}
+
+/// Air quality index scale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AqiScale {
+    /// US EPA air quality index.
+    UsEpa,
+    /// European Common Air Quality Index.
+    Caqi,
+    /// European Air Quality Index of the European Environment Agency.
+    Eaqi,
+}
+
+impl AqiScale {
+    /// Returns the index of the PM2.5 and PM10 mass concentrations in ug/m³ on this scale.
+    pub fn index(self, pm2_5: f32, pm10: f32) -> AirQualityIndex {
+        match self {
+            AqiScale::UsEpa => AirQualityIndex::UsEpa(us_aqi(pm2_5, pm10)),
+            AqiScale::Caqi => AirQualityIndex::Caqi(caqi(pm2_5, pm10)),
+            AqiScale::Eaqi => AirQualityIndex::Eaqi(eaqi(pm2_5, pm10)),
+        }
+    }
+
+    /// Returns the index of the instantaneous concentrations of the `measurement` on this scale.
+    /// `None` if the PM2.5 or PM10 mass concentration is not available.
+    pub fn measured_index(self, measurement: &Measurement) -> Option<AirQualityIndex> {
+        Some(self.index(
+            Channel::Pm2_5.value(measurement)?,
+            Channel::Pm10_0.value(measurement)?,
+        ))
+    }
+}
+
+/// Air quality index on one of the [`AqiScale`]s.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AirQualityIndex {
+    /// US EPA air quality index.
+    UsEpa(Aqi),
+    /// European Common Air Quality Index.
+    Caqi(Caqi),
+    /// European Air Quality Index.
+    Eaqi(Eaqi),
+}
+
+impl AirQualityIndex {
+    /// Returns the pollutant determining the index.
+    pub fn dominant(&self) -> Pollutant {
+        match self {
+            AirQualityIndex::UsEpa(aqi) => aqi.dominant,
+            AirQualityIndex::Caqi(caqi) => caqi.dominant,
+            AirQualityIndex::Eaqi(eaqi) => eaqi.dominant,
+        }
+    }
+}
+
+/// Breakpoints of the hourly PM2.5 concentration in ug/m³ and the CAQI.
+const CAQI_PM2_5_BREAKPOINTS: [(f32, f32); 5] = [
+    (0.0, 0.0),
+    (15.0, 25.0),
+    (30.0, 50.0),
+    (55.0, 75.0),
+    (110.0, 100.0),
+];
+
+/// Breakpoints of the hourly PM10 concentration in ug/m³ and the CAQI.
+const CAQI_PM10_BREAKPOINTS: [(f32, f32); 5] = [
+    (0.0, 0.0),
+    (25.0, 25.0),
+    (50.0, 50.0),
+    (90.0, 75.0),
+    (180.0, 100.0),
+];
+
+/// Upper limits of the EAQI bands below [`EaqiCategory::ExtremelyPoor`] for PM2.5 and PM10 in
+/// ug/m³, as defined before the EEA revised them in 2024.
+const EAQI_LIMITS: [(EaqiCategory, f32, f32); 5] = [
+    (EaqiCategory::Good, 10.0, 20.0),
+    (EaqiCategory::Fair, 20.0, 40.0),
+    (EaqiCategory::Moderate, 25.0, 50.0),
+    (EaqiCategory::Poor, 50.0, 100.0),
+    (EaqiCategory::VeryPoor, 75.0, 150.0),
+];
+
+/// Breakpoints of the PM2.5 concentration in 0.1 ug/m³ and the AQI, revised in 2024.
+const PM2_5_BREAKPOINTS: [(u32, u32, u16, u16); 6] = [
+    (0, 90, 0, 50),
+    (91, 354, 51, 100),
+    (355, 554, 101, 150),
+    (555, 1_254, 151, 200),
+    (1_255, 2_254, 201, 300),
+    (2_255, 3_254, 301, 500),
+];
+
+/// Breakpoints of the PM10 concentration in ug/m³ and the AQI.
+const PM10_BREAKPOINTS: [(u32, u32, u16, u16); 6] = [
+    (0, 54, 0, 50),
+    (55, 154, 51, 100),
+    (155, 254, 101, 150),
+    (255, 354, 151, 200),
+    (355, 424, 201, 300),
+    (425, 604, 301, 500),
+];
+
+/// Returns the US AQI sub-index of the PM2.5 mass `concentration` in ug/m³, truncated to
+/// 0.1 ug/m³. Concentrations above the highest breakpoint yield 500.
+pub fn pm2_5_sub_index(concentration: f32) -> u16 {
+    sub_index(&PM2_5_BREAKPOINTS, truncate(concentration * 10.0))
+}
+
+/// Returns the US AQI sub-index of the PM10 mass `concentration` in ug/m³, truncated to
+/// 1 ug/m³. Concentrations above the highest breakpoint yield 500.
+pub fn pm10_sub_index(concentration: f32) -> u16 {
+    sub_index(&PM10_BREAKPOINTS, truncate(concentration))
+}
+
+/// Returns the US AQI of the PM2.5 and PM10 mass concentrations in ug/m³.
+pub fn us_aqi(pm2_5: f32, pm10: f32) -> Aqi {
+    let (value, dominant) = [
+        (pm2_5_sub_index(pm2_5), Pollutant::Pm2_5),
+        (pm10_sub_index(pm10), Pollutant::Pm10),
+    ]
+    .into_iter()
+    .fold((0, Pollutant::Pm2_5), |max, index| {
+        if index.0 > max.0 { index } else { max }
+    });
+    Aqi {
+        value,
+        category: AqiCategory::from_value(value),
+        dominant,
+    }
+}
+
+/// Returns the CAQI of the hourly PM2.5 and PM10 mass concentrations in ug/m³. Above the
+/// highest breakpoint, the index is extrapolated linearly.
+pub fn caqi(pm2_5: f32, pm10: f32) -> Caqi {
+    let pm2_5_index = caqi_sub_index(&CAQI_PM2_5_BREAKPOINTS, pm2_5);
+    let pm10_index = caqi_sub_index(&CAQI_PM10_BREAKPOINTS, pm10);
+    let (value, dominant) = if pm10_index > pm2_5_index {
+        (pm10_index, Pollutant::Pm10)
+    } else {
+        (pm2_5_index, Pollutant::Pm2_5)
+    };
+    Caqi {
+        value,
+        category: CaqiCategory::from_value(value),
+        dominant,
+    }
+}
+
+/// Returns the EAQI of the PM2.5 and PM10 mass concentrations in ug/m³.
+pub fn eaqi(pm2_5: f32, pm10: f32) -> Eaqi {
+    let band = |concentration: f32, limit: fn(&(EaqiCategory, f32, f32)) -> f32| {
+        EAQI_LIMITS
+            .iter()
+            .find(|band| concentration <= limit(band))
+            .map_or(EaqiCategory::ExtremelyPoor, |band| band.0)
+    };
+    let pm2_5_band = band(pm2_5, |band| band.1);
+    let pm10_band = band(pm10, |band| band.2);
+    if pm10_band > pm2_5_band {
+        Eaqi {
+            category: pm10_band,
+            dominant: Pollutant::Pm10,
+        }
+    } else {
+        Eaqi {
+            category: pm2_5_band,
+            dominant: Pollutant::Pm2_5,
+        }
+    }
+}
+
+/// Returns the US AQI of the instantaneous concentrations of the `measurement`. `None` if the
+/// PM2.5 or PM10 mass concentration is not available.
+pub fn measured_us_aqi(measurement: &Measurement) -> Option<Aqi> {
+    Some(us_aqi(
+        Channel::Pm2_5.value(measurement)?,
+        Channel::Pm10_0.value(measurement)?,
+    ))
+}
+
+/// Interpolates the CAQI linearly between the breakpoints enclosing the `concentration`.
+fn caqi_sub_index(breakpoints: &[(f32, f32); 5], concentration: f32) -> u16 {
+    let concentration = concentration.max(0.0);
+    let upper = breakpoints[1..]
+        .iter()
+        .position(|&(high, _)| concentration <= high)
+        .unwrap_or(breakpoints.len() - 2)
+        + 1;
+    let (c_low, i_low) = breakpoints[upper - 1];
+    let (c_high, i_high) = breakpoints[upper];
+    let index = i_low + (concentration - c_low) * (i_high - i_low) / (c_high - c_low);
+    (index + 0.5) as u16
+}
+
+/// Truncates a non-negative concentration to an integer, tolerating the representation error
+/// of `f32`, e.g. 12.3 * 10 yielding 122.99999.
+fn truncate(concentration: f32) -> u32 {
+    (concentration + 1e-3).max(0.0) as u32
+}
+
+/// Interpolates the index linearly between the breakpoints enclosing the `concentration`.
+fn sub_index(breakpoints: &[(u32, u32, u16, u16)], concentration: u32) -> u16 {
+    breakpoints
+        .iter()
+        .find(|&&(_, high, _, _)| concentration <= high)
+        .map_or(500, |&(c_low, c_high, i_low, i_high)| {
+            let span = c_high - c_low;
+            let offset = u32::from(i_high - i_low) * (concentration - c_low);
+            i_low + ((2 * offset + span) / (2 * span)) as u16
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sub_indices_match_epa_breakpoints() {
+        assert_eq!(pm2_5_sub_index(0.0), 0);
+        assert_eq!(pm2_5_sub_index(9.0), 50);
+        assert_eq!(pm2_5_sub_index(9.1), 51);
+        assert_eq!(pm2_5_sub_index(12.3), 57);
+        assert_eq!(pm2_5_sub_index(35.49), 100);
+        assert_eq!(pm2_5_sub_index(400.0), 500);
+        assert_eq!(pm10_sub_index(54.9), 50);
+        assert_eq!(pm10_sub_index(100.0), 73);
+        assert_eq!(pm10_sub_index(-1.0), 0);
+    }
+
+    #[test]
+    fn aqi_is_dominated_by_highest_sub_index() {
+        assert_eq!(
+            us_aqi(12.3, 200.0),
+            Aqi {
+                value: 123,
+                category: AqiCategory::UnhealthyForSensitiveGroups,
+                dominant: Pollutant::Pm10,
+            }
+        );
+        let measurement = Measurement {
+            pm1_0: 5.0,
+            pm2_5: 40.0,
+            pm4_0: 45.0,
+            pm10_0: 50.0,
+            ..Measurement::fixture()
+        };
+        let aqi = measured_us_aqi(&measurement).unwrap();
+        assert_eq!(aqi.dominant, Pollutant::Pm2_5);
+        assert_eq!(aqi.category, AqiCategory::UnhealthyForSensitiveGroups);
+        assert_eq!(
+            AqiScale::UsEpa.measured_index(&measurement),
+            Some(AirQualityIndex::UsEpa(aqi))
+        );
+        assert_eq!(
+            measured_us_aqi(&Measurement {
+                pm10_0: Measurement::PM_UNAVAILABLE,
+                ..measurement
+            }),
+            None
+        );
+    }
+
+    #[test]
+    fn european_indices_match_bands() {
+        assert_eq!(
+            caqi(20.0, 10.0),
+            Caqi {
+                value: 33,
+                category: CaqiCategory::Low,
+                dominant: Pollutant::Pm2_5,
+            }
+        );
+        assert_eq!(caqi(0.0, 180.0).value, 100);
+        assert_eq!(caqi(0.0, 360.0).category, CaqiCategory::VeryHigh);
+        assert_eq!(caqi(0.0, 360.0).value, 150);
+
+        assert_eq!(
+            eaqi(8.0, 45.0),
+            Eaqi {
+                category: EaqiCategory::Moderate,
+                dominant: Pollutant::Pm10,
+            }
+        );
+        assert_eq!(eaqi(80.0, 10.0).category, EaqiCategory::ExtremelyPoor);
+        assert_eq!(
+            AqiScale::Eaqi.index(10.0, 20.0),
+            AirQualityIndex::Eaqi(Eaqi {
+                category: EaqiCategory::Good,
+                dominant: Pollutant::Pm2_5,
+            })
+        );
+        assert_eq!(AqiScale::Caqi.index(20.0, 60.0).dominant(), Pollutant::Pm10);
+    }
+}
@@ -1,8 +1,32 @@
 //! SEN66 I2C Commands.
 
+use core::marker::PhantomData;
+
+#[cfg(feature = "co2-calibration")]
+use crate::configuration::Co2Correction;
+#[cfg(feature = "sen68")]
+use crate::data::Sen68Measurement;
+#[cfg(feature = "raw-measurements")]
+use crate::data::{Concentrations, RawMeasurement};
+
+use crate::{
+    configuration::{AmbientPressure, SensorAltitude},
+    data::{
+        AscState, DataStatus, DeviceStatusRegister, Measurement, MeasurementFixed, ProductName,
+        Sen63cMeasurement, Sen65Measurement, SerialNumber,
+    },
+    error::DataError,
+};
+#[cfg(feature = "tuning")]
+use crate::{
+    configuration::{NoxTuning, VocTuning},
+    data::VocAlgorithmState,
+};
+
 /// I2C Commands for the SEN66 according to its [interface
 /// description](https://sensirion.com/media/documents/FAFC548D/6731FFFA/Sensirion_Datasheet_SEN6x.pdf).
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Command {
     /// Starts a continuous measurement and moves chip to measuring state. After the sending the command
     /// it might take some time until the first measurement is ready.
@@ -31,6 +55,23 @@ pub enum Command {
     /// Exec. Time: 20ms
     /// <div class="warning">Only available in measuring state</div>
     ReadMeasurement = 0x0300,
+    /// SEN63C variant of [`ReadMeasurement`](Command::ReadMeasurement), reporting neither the
+    /// VOC nor the NOx index.
+    /// Exec. Time: 20ms
+    /// <div class="warning">Only available in measuring state</div>
+    ReadSen63cMeasurement = 0x0471,
+    /// SEN65 variant of [`ReadMeasurement`](Command::ReadMeasurement), reporting no CO2
+    /// concentration.
+    /// Exec. Time: 20ms
+    /// <div class="warning">Only available in measuring state</div>
+    ReadSen65Measurement = 0x0446,
+    /// SEN68 variant of [`ReadMeasurement`](Command::ReadMeasurement), reporting the
+    /// formaldehyde (HCHO) concentration in ppb instead of the CO2 concentration. For the first
+    /// 10-11s after power-on or device reset the HCHO value will be `0xFFFF`.
+    /// Exec. Time: 20ms
+    /// <div class="warning">Only available in measuring state</div>
+    #[cfg(feature = "sen68")]
+    ReadSen68Measurement = 0x0467,
     /// If a measurement is available reads out the measured raw values. If no new data is available
     /// the previous measurement is returned. If no data is available all data is set to the maximum
     /// value (`0xFFFF` for `u16`, `0x7FFF` for `i16`). The measurement contains the raw relative
@@ -130,12 +171,16 @@ impl Command {
     }
 
     /// Returns the execution_time of the command in ms.
-    pub(crate) const fn execution_time_ms(&self) -> u32 {
+    pub const fn execution_time_ms(&self) -> u32 {
         match self {
             Command::StartContinuousMeasurement => 50,
             Command::StopMeasurement => 1000,
             Command::GetDataReady => 20,
             Command::ReadMeasurement => 20,
+            Command::ReadSen63cMeasurement => 20,
+            Command::ReadSen65Measurement => 20,
+            #[cfg(feature = "sen68")]
+            Command::ReadSen68Measurement => 20,
             Command::ReadRawMeasurement => 20,
             Command::ReadNumberConcentrationValues => 20,
             Command::SetTemperatureOffsetParameters => 20,
@@ -158,6 +203,121 @@ impl Command {
     }
 }
 
+/// Data read from the sensor in response to a [`Command`], e.g. with
+/// [`try_fetch`](crate::asynch::Sen66SplitPhase::try_fetch).
+pub trait Response: for<'a> TryFrom<&'a [u8], Error = DataError> {
+    /// Size of the response including the CRCs in bytes.
+    const SIZE: usize;
+}
+
+/// Read issued to the sensor, whose response becomes available after [`wait_ms`](Self::wait_ms).
+/// Created by [`request`](crate::asynch::Sen66SplitPhase::request) and completed by
+/// [`complete`](crate::asynch::Sen66SplitPhase::complete) once the caller's scheduler has waited.
+#[derive(Debug, PartialEq)]
+#[must_use = "the response has to be completed"]
+pub struct PendingRead<T> {
+    command: Command,
+    response: PhantomData<T>,
+}
+
+impl<T: Response> PendingRead<T> {
+    pub(crate) fn new(command: Command) -> Self {
+        Self {
+            command,
+            response: PhantomData,
+        }
+    }
+
+    /// Returns the command the response is read for.
+    pub fn command(&self) -> Command {
+        self.command
+    }
+
+    /// Returns the time to wait before completing the read in ms.
+    pub fn wait_ms(&self) -> u32 {
+        self.command.execution_time_ms()
+    }
+}
+
+impl Response for DataStatus {
+    const SIZE: usize = 3;
+}
+
+impl Response for Measurement {
+    const SIZE: usize = 27;
+}
+
+impl Response for MeasurementFixed {
+    const SIZE: usize = 27;
+}
+
+impl Response for Sen63cMeasurement {
+    const SIZE: usize = 21;
+}
+
+impl Response for Sen65Measurement {
+    const SIZE: usize = 24;
+}
+
+#[cfg(feature = "sen68")]
+impl Response for Sen68Measurement {
+    const SIZE: usize = 27;
+}
+
+#[cfg(feature = "raw-measurements")]
+impl Response for RawMeasurement {
+    const SIZE: usize = 15;
+}
+
+#[cfg(feature = "raw-measurements")]
+impl Response for Concentrations {
+    const SIZE: usize = 15;
+}
+
+impl Response for ProductName {
+    const SIZE: usize = 48;
+}
+
+impl Response for SerialNumber {
+    const SIZE: usize = 48;
+}
+
+impl Response for DeviceStatusRegister {
+    const SIZE: usize = 6;
+}
+
+#[cfg(feature = "tuning")]
+impl Response for VocTuning {
+    const SIZE: usize = 18;
+}
+
+#[cfg(feature = "tuning")]
+impl Response for NoxTuning {
+    const SIZE: usize = 18;
+}
+
+#[cfg(feature = "tuning")]
+impl Response for VocAlgorithmState {
+    const SIZE: usize = 12;
+}
+
+#[cfg(feature = "co2-calibration")]
+impl Response for Co2Correction {
+    const SIZE: usize = 3;
+}
+
+impl Response for AscState {
+    const SIZE: usize = 3;
+}
+
+impl Response for AmbientPressure {
+    const SIZE: usize = 3;
+}
+
+impl Response for SensorAltitude {
+    const SIZE: usize = 3;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,6 +330,8 @@ mod tests {
             (StopMeasurement, [0x01, 0x04]),
             (GetDataReady, [0x02, 0x02]),
             (ReadMeasurement, [0x03, 0x00]),
+            (ReadSen63cMeasurement, [0x04, 0x71]),
+            (ReadSen65Measurement, [0x04, 0x46]),
             (ReadRawMeasurement, [0x04, 0x05]),
             (ReadNumberConcentrationValues, [0x03, 0x16]),
             (SetTemperatureOffsetParameters, [0x60, 0xB2]),
@@ -192,5 +354,7 @@ mod tests {
         for (command, result) in data {
             assert_eq!(command.to_be_bytes(), result);
         }
+        #[cfg(feature = "sen68")]
+        assert_eq!(ReadSen68Measurement.to_be_bytes(), [0x04, 0x67]);
     }
 }
@@ -0,0 +1,118 @@
+use crate::data::Measurement;
+
+/// VOC index reported by the algorithm during its startup phase.
+const VOC_PLATEAU: f32 = 100.0;
+/// NOx index reported by the algorithm during its startup phase.
+const NOX_PLATEAU: f32 = 1.0;
+
+/// Tracks the first-power-on conditioning of a sensor. The gas indices should only be trusted
+/// after the sensor has been operated for the conditioning duration and the VOC index has left
+/// its startup plateau.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BurnIn {
+    duration_s: u32,
+    elapsed_s: u32,
+    voc_active: bool,
+    nox_active: bool,
+}
+
+impl BurnIn {
+    /// Creates a tracker for a conditioning period of `duration_s` seconds of operation.
+    pub const fn new(duration_s: u32) -> Self {
+        Self {
+            duration_s,
+            elapsed_s: 0,
+            voc_active: false,
+            nox_active: false,
+        }
+    }
+
+    /// Records a measurement taken `elapsed_s` seconds after the previous one.
+    pub fn update(&mut self, measurement: &Measurement, elapsed_s: u32) {
+        self.elapsed_s = self.elapsed_s.saturating_add(elapsed_s);
+        self.voc_active |= left_plateau(measurement.voc_index, VOC_PLATEAU);
+        self.nox_active |= left_plateau(measurement.nox_index, NOX_PLATEAU);
+    }
+
+    /// Returns the current conditioning status.
+    pub fn status(&self) -> BurnInStatus {
+        BurnInStatus {
+            elapsed_s: self.elapsed_s,
+            remaining_s: self.duration_s.saturating_sub(self.elapsed_s),
+            voc_active: self.voc_active,
+            nox_active: self.nox_active,
+        }
+    }
+}
+
+fn left_plateau(index: f32, plateau: f32) -> bool {
+    index != plateau && index != Measurement::INDEX_UNAVAILABLE
+}
+
+/// Conditioning status reported by [`BurnIn`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BurnInStatus {
+    /// Time the sensor has been operated in s.
+    pub elapsed_s: u32,
+    /// Time remaining until the conditioning duration is reached in s.
+    pub remaining_s: u32,
+    /// Whether the VOC index has left its startup plateau of 100.
+    pub voc_active: bool,
+    /// Whether the NOx index has left its startup plateau of 1. In clean air the NOx index
+    /// remains at 1, so this is informational only.
+    pub nox_active: bool,
+}
+
+impl BurnInStatus {
+    /// Returns whether the conditioning duration has been reached and the VOC index is active.
+    pub fn is_ready(&self) -> bool {
+        self.remaining_s == 0 && self.voc_active
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(voc_index: f32, nox_index: f32) -> Measurement {
+        Measurement {
+            voc_index,
+            nox_index,
+            ..Measurement::fixture()
+        }
+    }
+
+    #[test]
+    fn burn_in_requires_duration_and_active_voc_index() {
+        let mut burn_in = BurnIn::new(10);
+        burn_in.update(
+            &measurement(
+                Measurement::INDEX_UNAVAILABLE,
+                Measurement::INDEX_UNAVAILABLE,
+            ),
+            5,
+        );
+        burn_in.update(&measurement(100.0, 1.0), 5);
+        let status = burn_in.status();
+        assert_eq!(status.remaining_s, 0);
+        assert!(!status.voc_active);
+        assert!(!status.is_ready());
+
+        burn_in.update(&measurement(103.0, 1.0), 1);
+        let status = burn_in.status();
+        assert_eq!(status.elapsed_s, 11);
+        assert!(status.is_ready());
+        assert!(!status.nox_active);
+    }
+
+    #[test]
+    fn burn_in_is_not_ready_before_duration() {
+        let mut burn_in = BurnIn::new(10);
+        burn_in.update(&measurement(98.0, 2.0), 1);
+        let status = burn_in.status();
+        assert_eq!(status.remaining_s, 9);
+        assert!(status.nox_active);
+        assert!(!status.is_ready());
+    }
+}
@@ -0,0 +1,17 @@
+//! Helpers for conditioning the sensor before its readings are trusted.
+
+mod burn_in;
+mod nox_conditioning;
+#[cfg(feature = "tuning")]
+mod voc_checkpoint;
+mod voc_learning;
+mod warmup;
+
+pub use burn_in::{BurnIn, BurnInStatus};
+pub use nox_conditioning::{NoxConditioning, NoxReading};
+#[cfg(feature = "tuning")]
+pub use voc_checkpoint::{
+    DEFAULT_CHECKPOINT_INTERVAL_MS, FnStore, VocCheckpointScheduler, VocStateStore,
+};
+pub use voc_learning::{VocLearning, VocLearningStatus};
+pub use warmup::WarmupTracker;
@@ -0,0 +1,259 @@
+#[cfg(feature = "storage")]
+use embedded_storage::{ReadStorage, Storage};
+
+use crate::{
+    data::{Channel, Measurement},
+    error::DataError,
+    util::ByteReader,
+};
+#[cfg(feature = "storage")]
+use crate::{
+    error::StorageError,
+    storage::{read_record, write_record},
+};
+
+/// Default conditioning period of the NOx signal in s.
+const DEFAULT_CONDITIONING_S: u32 = 6 * 3_600;
+/// Measurement interval of the SEN66 in s.
+const MEASUREMENT_INTERVAL_S: u32 = 1;
+/// Default power-off duration after which the NOx signal requires conditioning again in s.
+const DEFAULT_LONG_POWER_OFF_S: u32 = 24 * 3_600;
+
+/// NOx index flagged by [`NoxConditioning`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum NoxReading {
+    /// The NOx signal is still conditioning, the index might not be accurate.
+    Provisional(f32),
+    /// The NOx signal is conditioned.
+    Conditioned(f32),
+}
+
+impl NoxReading {
+    /// Returns the NOx index regardless of its conditioning.
+    pub fn index(&self) -> f32 {
+        match self {
+            NoxReading::Provisional(index) | NoxReading::Conditioned(index) => *index,
+        }
+    }
+}
+
+/// Tracks the conditioning of the NOx signal, which requires a multi-hour period of operation
+/// after a long power-off. Persist the tracker across power cycles by saving it to an
+/// `embedded_storage::Storage` with the `storage` feature, or using
+/// [`to_bytes`](NoxConditioning::to_bytes) and [`TryFrom<&[u8]>`](NoxConditioning::try_from).
+/// After restoring it, report the power-off duration via
+/// [`record_power_off`](NoxConditioning::record_power_off).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NoxConditioning {
+    conditioning_s: u32,
+    long_power_off_s: u32,
+    operated_s: u32,
+}
+
+impl NoxConditioning {
+    /// Size of the persisted representation in bytes.
+    pub const SERIALIZED_SIZE: usize = 12;
+
+    /// Creates a tracker for an unconditioned sensor.
+    /// - `conditioning_s`: Operating time required to condition the NOx signal in s.
+    /// - `long_power_off_s`: Power-off duration after which conditioning is required again in s.
+    pub const fn new(conditioning_s: u32, long_power_off_s: u32) -> Self {
+        Self {
+            conditioning_s,
+            long_power_off_s,
+            operated_s: 0,
+        }
+    }
+
+    /// Records `elapsed_s` seconds of operation.
+    pub fn update(&mut self, elapsed_s: u32) {
+        self.operated_s = self.operated_s.saturating_add(elapsed_s);
+    }
+
+    /// Records `count` measurements read at the SEN66's measurement interval of 1 s, for
+    /// applications without a time source.
+    pub fn record_measurements(&mut self, count: u32) {
+        self.update(count.saturating_mul(MEASUREMENT_INTERVAL_S));
+    }
+
+    /// Records that the sensor was powered off for `duration_s` seconds. Conditioning restarts if
+    /// the power-off was long.
+    pub fn record_power_off(&mut self, duration_s: u32) {
+        if duration_s >= self.long_power_off_s {
+            self.operated_s = 0;
+        }
+    }
+
+    /// Returns the remaining conditioning time in s.
+    pub fn remaining_s(&self) -> u32 {
+        self.conditioning_s.saturating_sub(self.operated_s)
+    }
+
+    /// Returns whether the NOx signal is conditioned.
+    pub fn is_conditioned(&self) -> bool {
+        self.remaining_s() == 0
+    }
+
+    /// Flags the `nox_index` as provisional while the NOx signal is conditioning.
+    pub fn flag(&self, nox_index: f32) -> NoxReading {
+        if self.is_conditioned() {
+            NoxReading::Conditioned(nox_index)
+        } else {
+            NoxReading::Provisional(nox_index)
+        }
+    }
+
+    /// Flags the NOx index of the `measurement` as provisional while the NOx signal is
+    /// conditioning. `None` if the NOx index is not available.
+    pub fn flag_measurement(&self, measurement: &Measurement) -> Option<NoxReading> {
+        Channel::NoxIndex
+            .value(measurement)
+            .map(|nox_index| self.flag(nox_index))
+    }
+
+    /// Returns the persisted representation of the tracker.
+    pub fn to_bytes(&self) -> [u8; Self::SERIALIZED_SIZE] {
+        let mut data = [0; Self::SERIALIZED_SIZE];
+        data[0..4].copy_from_slice(&self.conditioning_s.to_be_bytes());
+        data[4..8].copy_from_slice(&self.long_power_off_s.to_be_bytes());
+        data[8..12].copy_from_slice(&self.operated_s.to_be_bytes());
+        data
+    }
+
+    /// Saves the tracker with a CRC to the `storage` at `offset`, occupying
+    /// [`SERIALIZED_SIZE`](NoxConditioning::SERIALIZED_SIZE) + 1 bytes.
+    ///
+    /// # Errors
+    ///
+    /// - [`Flash`](crate::error::StorageError::Flash): If writing the storage fails.
+    #[cfg(feature = "storage")]
+    pub fn save<S: Storage>(
+        &self,
+        storage: &mut S,
+        offset: u32,
+    ) -> Result<(), StorageError<S::Error>> {
+        write_record(storage, offset, &self.to_bytes())
+    }
+
+    /// Loads a tracker saved with [`save`](NoxConditioning::save) from the `storage` at `offset`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Flash`](crate::error::StorageError::Flash): If reading the storage fails.
+    /// - [`Data`](crate::error::StorageError::Data): If the stored data is corrupted or was never
+    ///   written.
+    #[cfg(feature = "storage")]
+    pub fn load<S: ReadStorage>(
+        storage: &mut S,
+        offset: u32,
+    ) -> Result<Self, StorageError<S::Error>> {
+        let data = read_record::<_, { Self::SERIALIZED_SIZE }>(storage, offset)?;
+        Ok(Self::try_from(&data[..])?)
+    }
+}
+
+impl Default for NoxConditioning {
+    /// Returns a tracker for an unconditioned sensor, requiring 6h of conditioning after a
+    /// power-off of 24h or longer.
+    fn default() -> Self {
+        Self::new(DEFAULT_CONDITIONING_S, DEFAULT_LONG_POWER_OFF_S)
+    }
+}
+
+impl TryFrom<&[u8]> for NoxConditioning {
+    type Error = DataError;
+
+    /// Restores the tracker from its persisted representation.
+    ///
+    /// # Errors
+    ///
+    /// - [`ReceivedBufferWrongSize`](crate::error::DataError::ReceivedBufferWrongSize): If the
+    ///   data is not the expected size.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let mut reader = ByteReader::new(data);
+        let conditioning = Self {
+            conditioning_s: u32::from_be_bytes(reader.take()?),
+            long_power_off_s: u32::from_be_bytes(reader.take()?),
+            operated_s: u32::from_be_bytes(reader.take()?),
+        };
+        reader.finish()?;
+        Ok(conditioning)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nox_conditioning_flags_provisional_values() {
+        let mut conditioning = NoxConditioning::new(100, 1_000);
+        assert_eq!(conditioning.flag(2.0), NoxReading::Provisional(2.0));
+        conditioning.update(60);
+        assert_eq!(conditioning.remaining_s(), 40);
+        conditioning.update(40);
+        assert_eq!(conditioning.flag(2.0), NoxReading::Conditioned(2.0));
+        assert_eq!(conditioning.flag(2.0).index(), 2.0);
+    }
+
+    #[test]
+    fn nox_conditioning_counts_measurements() {
+        let mut conditioning = NoxConditioning::new(100, 1_000);
+        let mut measurement = Measurement::fixture();
+        conditioning.record_measurements(99);
+        assert_eq!(
+            conditioning.flag_measurement(&measurement),
+            Some(NoxReading::Provisional(1.0))
+        );
+        conditioning.record_measurements(1);
+        assert_eq!(
+            conditioning.flag_measurement(&measurement),
+            Some(NoxReading::Conditioned(1.0))
+        );
+        measurement.nox_index = Measurement::INDEX_UNAVAILABLE;
+        assert_eq!(conditioning.flag_measurement(&measurement), None);
+    }
+
+    #[test]
+    fn nox_conditioning_restarts_after_long_power_off() {
+        let mut conditioning = NoxConditioning::new(100, 1_000);
+        conditioning.update(100);
+        conditioning.record_power_off(999);
+        assert!(conditioning.is_conditioned());
+        conditioning.record_power_off(1_000);
+        assert_eq!(conditioning.remaining_s(), 100);
+    }
+
+    #[test]
+    fn nox_conditioning_roundtrips_through_bytes() {
+        let mut conditioning = NoxConditioning::default();
+        conditioning.update(1_234);
+        let data = conditioning.to_bytes();
+        assert_eq!(NoxConditioning::try_from(&data[..]).unwrap(), conditioning);
+        assert_eq!(
+            NoxConditioning::try_from(&data[1..]),
+            Err(DataError::ReceivedBufferWrongSize)
+        );
+    }
+
+    #[cfg(feature = "storage")]
+    #[test]
+    fn nox_conditioning_roundtrips_through_storage() {
+        let mut storage = crate::storage::MockStorage([0xFF; 64]);
+        assert_eq!(
+            NoxConditioning::load(&mut storage, 8),
+            Err(StorageError::Data(DataError::CrcFailed))
+        );
+        let mut conditioning = NoxConditioning::default();
+        conditioning.update(1_234);
+        conditioning.save(&mut storage, 8).unwrap();
+        assert_eq!(NoxConditioning::load(&mut storage, 8), Ok(conditioning));
+        storage.0[10] ^= 0x01;
+        assert_eq!(
+            NoxConditioning::load(&mut storage, 8),
+            Err(StorageError::Data(DataError::CrcFailed))
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use crate::data::VocAlgorithmState;
+
+/// Interval between two VOC algorithm state checkpoints recommended by Sensirion, one hour in ms.
+pub const DEFAULT_CHECKPOINT_INTERVAL_MS: u64 = 3_600_000;
+
+/// Backend the VOC algorithm state is checkpointed to by a [`VocCheckpointScheduler`].
+///
+/// Implemented for closures wrapped in a [`FnStore`], for mutable references to stores and for
+/// the `FlashCheckpoint` of the `storage` feature.
+pub trait VocStateStore {
+    /// Error emitted if saving fails.
+    type Error;
+
+    /// Saves the VOC algorithm `state`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Self::Error`]: If the state could not be saved.
+    fn save(&mut self, state: &VocAlgorithmState) -> Result<(), Self::Error>;
+}
+
+impl<S: VocStateStore + ?Sized> VocStateStore for &mut S {
+    type Error = S::Error;
+
+    fn save(&mut self, state: &VocAlgorithmState) -> Result<(), Self::Error> {
+        (**self).save(state)
+    }
+}
+
+/// [`VocStateStore`] calling a closure with each checkpointed state.
+#[derive(Clone, Copy, Debug)]
+pub struct FnStore<F>(pub F);
+
+impl<F, E> VocStateStore for FnStore<F>
+where
+    F: FnMut(&VocAlgorithmState) -> Result<(), E>,
+{
+    type Error = E;
+
+    fn save(&mut self, state: &VocAlgorithmState) -> Result<(), E> {
+        (self.0)(state)
+    }
+}
+
+/// Schedules periodic checkpoints of the VOC algorithm state to a [`VocStateStore`], driven by
+/// [`checkpoint_voc_state`](crate::asynch::Sen66::checkpoint_voc_state).
+///
+/// The state is lost on a reset or power cycle. Restore the latest checkpoint with
+/// [`set_voc_algorithm_state`](crate::asynch::Sen66::set_voc_algorithm_state) before starting
+/// the measurement, as it is only available in idle state.
+#[derive(Clone, Copy, Debug)]
+pub struct VocCheckpointScheduler<S> {
+    store: S,
+    now_ms: fn() -> u64,
+    interval_ms: u64,
+    last_ms: Option<u64>,
+}
+
+impl<S: VocStateStore> VocCheckpointScheduler<S> {
+    /// Creates a scheduler checkpointing every [`DEFAULT_CHECKPOINT_INTERVAL_MS`].
+    /// - `store`: Backend the state is saved to.
+    /// - `now_ms`: Monotonic time source in ms.
+    pub const fn new(store: S, now_ms: fn() -> u64) -> Self {
+        Self::with_interval(store, now_ms, DEFAULT_CHECKPOINT_INTERVAL_MS)
+    }
+
+    /// Creates a scheduler checkpointing every `interval_ms`.
+    /// - `store`: Backend the state is saved to.
+    /// - `now_ms`: Monotonic time source in ms.
+    /// - `interval_ms`: Interval between two checkpoints in ms.
+    pub const fn with_interval(store: S, now_ms: fn() -> u64, interval_ms: u64) -> Self {
+        Self {
+            store,
+            now_ms,
+            interval_ms,
+            last_ms: None,
+        }
+    }
+
+    /// Returns the interval between two checkpoints in ms.
+    pub fn interval_ms(&self) -> u64 {
+        self.interval_ms
+    }
+
+    /// Returns whether a checkpoint is due. The first checkpoint is due one interval after the
+    /// scheduler was created or restarted, once the algorithm had time to learn.
+    pub fn is_due(&mut self) -> bool {
+        let now_ms = (self.now_ms)();
+        let last_ms = *self.last_ms.get_or_insert(now_ms);
+        now_ms.saturating_sub(last_ms) >= self.interval_ms
+    }
+
+    /// Restarts the interval, e.g. after restoring a checkpoint.
+    pub fn restart(&mut self) {
+        self.last_ms = Some((self.now_ms)());
+    }
+
+    /// Saves the `state` to the store and restarts the interval. The interval is not restarted
+    /// if saving fails, so the next attempt is made immediately.
+    ///
+    /// # Errors
+    ///
+    /// - [`S::Error`](VocStateStore::Error): If the store failed to save the state.
+    pub fn checkpoint(&mut self, state: &VocAlgorithmState) -> Result<(), S::Error> {
+        self.store.save(state)?;
+        self.restart();
+        Ok(())
+    }
+
+    /// Returns a reference to the store.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Releases the store.
+    pub fn release(self) -> S {
+        self.store
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicU64, Ordering};
+
+    use super::*;
+
+    #[test]
+    fn checkpoints_are_due_every_interval() {
+        static NOW_MS: AtomicU64 = AtomicU64::new(0);
+        let mut saved = Vec::new();
+        let mut scheduler = VocCheckpointScheduler::new(
+            FnStore(|state: &VocAlgorithmState| -> Result<(), ()> {
+                saved.push(*state);
+                Ok(())
+            }),
+            || NOW_MS.load(Ordering::Relaxed),
+        );
+        assert!(!scheduler.is_due());
+        NOW_MS.store(DEFAULT_CHECKPOINT_INTERVAL_MS - 1, Ordering::Relaxed);
+        assert!(!scheduler.is_due());
+        NOW_MS.store(DEFAULT_CHECKPOINT_INTERVAL_MS, Ordering::Relaxed);
+        assert!(scheduler.is_due());
+        let state = VocAlgorithmState::from([1, 2, 3, 4]);
+        assert_eq!(scheduler.checkpoint(&state), Ok(()));
+        assert!(!scheduler.is_due());
+        assert_eq!(saved, [state]);
+    }
+}
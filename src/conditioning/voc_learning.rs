@@ -0,0 +1,140 @@
+use crate::data::Measurement;
+
+/// Time after starting a measurement during which the VOC algorithm reports no index in s.
+const BLACKOUT_S: u32 = 45;
+/// Duration of the VOC algorithm's initial learning phase in s, after which its estimate of the
+/// mean and variance of the raw signal has settled.
+const INITIAL_LEARNING_S: u32 = 5_220;
+/// VOC index reported before the algorithm has learned any deviation from the mean.
+const INDEX_MEAN: f32 = 100.0;
+
+/// Learning phase of the VOC algorithm as estimated by [`VocLearning`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum VocLearningStatus {
+    /// The VOC index is not yet available.
+    Blackout,
+    /// The VOC algorithm is still learning, index values are not yet meaningful.
+    Learning {
+        /// Estimated progress of the initial learning phase in %.
+        progress_percent: u8,
+    },
+    /// The VOC algorithm finished its initial learning phase.
+    Learned,
+}
+
+impl VocLearningStatus {
+    /// Returns whether VOC index values are not yet meaningful.
+    pub fn is_learning(&self) -> bool {
+        *self != VocLearningStatus::Learned
+    }
+}
+
+/// Estimates whether the VOC algorithm is still in its initial learning phase, based on the time
+/// in operation and the dynamics of the VOC index. The phase is considered finished once the
+/// sensor has been operated for the initial learning duration and the index has started to
+/// deviate from its mean of 100.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VocLearning {
+    elapsed_s: u32,
+    available: bool,
+    dynamic: bool,
+}
+
+impl VocLearning {
+    /// Creates a tracker for a VOC algorithm starting from scratch.
+    pub const fn new() -> Self {
+        Self {
+            elapsed_s: 0,
+            available: false,
+            dynamic: false,
+        }
+    }
+
+    /// Creates a tracker for a VOC algorithm whose state was restored via
+    /// [`set_voc_algorithm_state`](crate::asynch::Sen66::set_voc_algorithm_state), skipping the
+    /// learning phase.
+    pub const fn restored() -> Self {
+        Self {
+            elapsed_s: INITIAL_LEARNING_S,
+            available: true,
+            dynamic: true,
+        }
+    }
+
+    /// Records a measurement taken `elapsed_s` seconds after the previous one.
+    pub fn update(&mut self, measurement: &Measurement, elapsed_s: u32) {
+        self.elapsed_s = self.elapsed_s.saturating_add(elapsed_s);
+        let index = measurement.voc_index;
+        if index == Measurement::INDEX_UNAVAILABLE {
+            return;
+        }
+        self.available = true;
+        self.dynamic |= index != INDEX_MEAN;
+    }
+
+    /// Returns the estimated learning status.
+    pub fn status(&self) -> VocLearningStatus {
+        if !self.available || self.elapsed_s < BLACKOUT_S {
+            VocLearningStatus::Blackout
+        } else if self.elapsed_s >= INITIAL_LEARNING_S && self.dynamic {
+            VocLearningStatus::Learned
+        } else {
+            let progress = self.elapsed_s.min(INITIAL_LEARNING_S) as u64 * 100;
+            // Without any index dynamics the phase is not considered complete
+            let progress_percent = (progress / INITIAL_LEARNING_S as u64).min(99) as u8;
+            VocLearningStatus::Learning { progress_percent }
+        }
+    }
+}
+
+impl Default for VocLearning {
+    /// Returns a tracker for a VOC algorithm starting from scratch.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(voc_index: f32) -> Measurement {
+        Measurement {
+            voc_index,
+            ..Measurement::fixture()
+        }
+    }
+
+    #[test]
+    fn voc_learning_progresses_through_phases() {
+        let mut learning = VocLearning::new();
+        learning.update(&measurement(Measurement::INDEX_UNAVAILABLE), 10);
+        assert_eq!(learning.status(), VocLearningStatus::Blackout);
+
+        learning.update(&measurement(100.0), 2_600);
+        assert_eq!(
+            learning.status(),
+            VocLearningStatus::Learning {
+                progress_percent: 50
+            }
+        );
+
+        learning.update(&measurement(100.0), 3_000);
+        assert_eq!(
+            learning.status(),
+            VocLearningStatus::Learning {
+                progress_percent: 99
+            }
+        );
+
+        learning.update(&measurement(112.0), 1);
+        assert_eq!(learning.status(), VocLearningStatus::Learned);
+        assert!(!learning.status().is_learning());
+    }
+
+    #[test]
+    fn restored_voc_learning_is_learned() {
+        assert_eq!(VocLearning::restored().status(), VocLearningStatus::Learned);
+    }
+}
@@ -0,0 +1,150 @@
+use crate::data::{Channel, ChannelSet, Measurement, ValidatedMeasurement};
+
+/// Time until the fan has spun up and the PM readings settled in ms.
+const PM_STABILIZATION_MS: u32 = 30_000;
+/// Time during which the gas index algorithms report no index in ms.
+const GAS_INDEX_BLACKOUT_MS: u32 = 45_000;
+/// Time until the CO2 sensor reports its first valid sample in ms.
+const CO2_STABILIZATION_MS: u32 = 11_000;
+
+/// Default stabilization times in ms, in the order of [`Channel::ALL`].
+const DEFAULT_STABILIZATION_MS: [u32; Channel::COUNT] = [
+    PM_STABILIZATION_MS,
+    PM_STABILIZATION_MS,
+    PM_STABILIZATION_MS,
+    PM_STABILIZATION_MS,
+    0,
+    0,
+    GAS_INDEX_BLACKOUT_MS,
+    GAS_INDEX_BLACKOUT_MS,
+    CO2_STABILIZATION_MS,
+];
+
+/// Tracks the stabilization of each channel after starting a measurement, so readings taken
+/// while the sensor is still warming up can be discarded.
+///
+/// The tracker is driven by the time elapsed since
+/// [`start_measurement`](crate::asynch::Sen66::start_measurement), e.g. the `measuring_for_ms`
+/// of the driver's [`summary`](crate::asynch::Sen66::summary), or the number of measurements read
+/// multiplied by the measurement interval of 1 s. The default stabilization times only cover the
+/// startup of a measurement. The initial learning of the gas indices after a long power-off is
+/// tracked by [`VocLearning`](super::VocLearning) and [`NoxConditioning`](super::NoxConditioning).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct WarmupTracker {
+    stabilization_ms: [u32; Channel::COUNT],
+}
+
+impl WarmupTracker {
+    /// Creates a tracker with stabilization times of 30 s for the PM channels, 45 s for the gas
+    /// indices and 11 s for CO2. Humidity and temperature are ready with the first sample.
+    pub const fn new() -> Self {
+        Self {
+            stabilization_ms: DEFAULT_STABILIZATION_MS,
+        }
+    }
+
+    /// Returns the tracker with the stabilization time of `channel` set to `stabilization_ms` ms.
+    pub const fn with_stabilization(mut self, channel: Channel, stabilization_ms: u32) -> Self {
+        self.stabilization_ms[channel as usize] = stabilization_ms;
+        self
+    }
+
+    /// Returns the stabilization time of `channel` in ms.
+    pub fn stabilization_ms(&self, channel: Channel) -> u32 {
+        self.stabilization_ms[channel as usize]
+    }
+
+    /// Returns the remaining stabilization time of `channel` `elapsed_ms` ms after starting the
+    /// measurement in ms.
+    pub fn remaining_ms(&self, channel: Channel, elapsed_ms: u64) -> u64 {
+        u64::from(self.stabilization_ms(channel)).saturating_sub(elapsed_ms)
+    }
+
+    /// Returns the channels which are stable `elapsed_ms` ms after starting the measurement.
+    pub fn ready(&self, elapsed_ms: u64) -> ChannelSet {
+        Channel::ALL
+            .into_iter()
+            .filter(|&channel| self.remaining_ms(channel, elapsed_ms) == 0)
+            .collect()
+    }
+
+    /// Returns whether all channels are stable `elapsed_ms` ms after starting the measurement.
+    pub fn is_warm(&self, elapsed_ms: u64) -> bool {
+        self.ready(elapsed_ms) == ChannelSet::ALL
+    }
+
+    /// Returns the values of the `measurement` taken `elapsed_ms` ms after starting the
+    /// measurement, with channels which are not yet stable or available set to `None`.
+    pub fn annotate(&self, measurement: &Measurement, elapsed_ms: u64) -> ValidatedMeasurement {
+        let ready = self.ready(elapsed_ms);
+        let value = |channel: Channel| {
+            Some(channel)
+                .filter(|&channel| ready.contains(channel))
+                .and_then(|channel| channel.value(measurement))
+        };
+        ValidatedMeasurement {
+            pm1_0: value(Channel::Pm1_0),
+            pm2_5: value(Channel::Pm2_5),
+            pm4_0: value(Channel::Pm4_0),
+            pm10_0: value(Channel::Pm10_0),
+            relative_humidity: value(Channel::RelativeHumidity),
+            temperature: value(Channel::Temperature),
+            voc_index: value(Channel::VocIndex),
+            nox_index: value(Channel::NoxIndex),
+            co2: value(Channel::Co2).map(|co2| co2 as u16),
+        }
+    }
+}
+
+impl Default for WarmupTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn channels_become_ready_after_stabilization() {
+        let tracker = WarmupTracker::new().with_stabilization(Channel::NoxIndex, 60_000);
+        assert_eq!(
+            tracker.ready(0),
+            ChannelSet::EMPTY
+                .with(Channel::RelativeHumidity)
+                .with(Channel::Temperature)
+        );
+        assert!(tracker.ready(11_000).contains(Channel::Co2));
+        assert_eq!(tracker.remaining_ms(Channel::Pm2_5, 20_000), 10_000);
+        assert_eq!(tracker.ready(45_000).len(), 8);
+        assert!(!tracker.is_warm(59_999));
+        assert!(tracker.is_warm(60_000));
+    }
+
+    #[test]
+    fn annotate_drops_unstable_values() {
+        let measurement = Measurement {
+            pm2_5: 2.0,
+            pm4_0: 3.0,
+            pm10_0: 4.0,
+            ..Measurement::fixture()
+        };
+        let tracker = WarmupTracker::default();
+        assert_eq!(
+            tracker.annotate(&measurement, 15_000),
+            ValidatedMeasurement {
+                relative_humidity: Some(40.0),
+                temperature: Some(21.0),
+                co2: Some(420),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            tracker.annotate(&measurement, 45_000),
+            measurement.validated()
+        );
+    }
+}
@@ -0,0 +1,208 @@
+#[cfg(feature = "tuning")]
+use crate::configuration::{NoxTuning, VocTuning};
+use crate::{
+    configuration::{
+        AmbientPressure, SensorAltitude, Setting, TemperatureAcceleration, TemperatureOffset,
+    },
+    data::AscState,
+};
+
+/// Number of temperature offset slots of the sensor.
+const TEMPERATURE_OFFSET_SLOTS: usize = 5;
+
+/// Host-side copy of the settings last written to or read from the sensor. Used to skip writing
+/// settings the sensor already holds. The VOC algorithm state changes continuously and is not
+/// cached.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ConfigCache {
+    temperature_offsets: [Option<TemperatureOffset>; TEMPERATURE_OFFSET_SLOTS],
+    temperature_acceleration: Option<TemperatureAcceleration>,
+    #[cfg(feature = "tuning")]
+    voc_tuning: Option<VocTuning>,
+    #[cfg(feature = "tuning")]
+    nox_tuning: Option<NoxTuning>,
+    asc_state: Option<AscState>,
+    ambient_pressure: Option<AmbientPressure>,
+    sensor_altitude: Option<SensorAltitude>,
+}
+
+impl ConfigCache {
+    /// Creates an empty cache.
+    pub const fn new() -> Self {
+        Self {
+            temperature_offsets: [None; TEMPERATURE_OFFSET_SLOTS],
+            temperature_acceleration: None,
+            #[cfg(feature = "tuning")]
+            voc_tuning: None,
+            #[cfg(feature = "tuning")]
+            nox_tuning: None,
+            asc_state: None,
+            ambient_pressure: None,
+            sensor_altitude: None,
+        }
+    }
+
+    /// Returns whether the sensor is known to hold `setting`.
+    pub fn contains(&self, setting: &Setting) -> bool {
+        match setting {
+            Setting::TemperatureOffset(value) => {
+                self.temperature_offsets.get(usize::from(value.slot())) == Some(&Some(*value))
+            }
+            Setting::TemperatureAcceleration(value) => {
+                self.temperature_acceleration == Some(*value)
+            }
+            #[cfg(feature = "tuning")]
+            Setting::VocTuning(value) => self.voc_tuning == Some(*value),
+            #[cfg(feature = "tuning")]
+            Setting::NoxTuning(value) => self.nox_tuning == Some(*value),
+            #[cfg(feature = "tuning")]
+            Setting::VocAlgorithmState(_) => false,
+            Setting::Co2AutomaticSelfCalibration(value) => self.asc_state == Some(*value),
+            Setting::AmbientPressure(value) => self.ambient_pressure == Some(*value),
+            Setting::SensorAltitude(value) => self.sensor_altitude == Some(*value),
+        }
+    }
+
+    /// Records that the sensor holds `setting`.
+    pub fn record(&mut self, setting: Setting) {
+        self.update(&setting, Some(setting));
+    }
+
+    /// Forgets the cached value of the kind of `setting`, e.g. because writing it failed and
+    /// the sensor's value is unknown.
+    pub fn invalidate(&mut self, setting: &Setting) {
+        self.update(setting, None);
+    }
+
+    /// Forgets all cached values, e.g. after a device reset.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns all cached settings.
+    pub fn settings(&self) -> impl Iterator<Item = Setting> {
+        let [offset0, offset1, offset2, offset3, offset4] = self.temperature_offsets;
+        [
+            offset0.map(Setting::TemperatureOffset),
+            offset1.map(Setting::TemperatureOffset),
+            offset2.map(Setting::TemperatureOffset),
+            offset3.map(Setting::TemperatureOffset),
+            offset4.map(Setting::TemperatureOffset),
+            self.temperature_acceleration
+                .map(Setting::TemperatureAcceleration),
+            #[cfg(feature = "tuning")]
+            self.voc_tuning.map(Setting::VocTuning),
+            #[cfg(feature = "tuning")]
+            self.nox_tuning.map(Setting::NoxTuning),
+            self.asc_state.map(Setting::Co2AutomaticSelfCalibration),
+            self.ambient_pressure.map(Setting::AmbientPressure),
+            self.sensor_altitude.map(Setting::SensorAltitude),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    /// Replaces the cached value of the kind of `setting` with `value`.
+    fn update(&mut self, setting: &Setting, value: Option<Setting>) {
+        match setting {
+            Setting::TemperatureOffset(offset) => {
+                if let Some(slot) = self.temperature_offsets.get_mut(usize::from(offset.slot())) {
+                    *slot = match value {
+                        Some(Setting::TemperatureOffset(value)) => Some(value),
+                        _ => None,
+                    }
+                }
+            }
+            Setting::TemperatureAcceleration(_) => {
+                self.temperature_acceleration = match value {
+                    Some(Setting::TemperatureAcceleration(value)) => Some(value),
+                    _ => None,
+                }
+            }
+            #[cfg(feature = "tuning")]
+            Setting::VocTuning(_) => {
+                self.voc_tuning = match value {
+                    Some(Setting::VocTuning(value)) => Some(value),
+                    _ => None,
+                }
+            }
+            #[cfg(feature = "tuning")]
+            Setting::NoxTuning(_) => {
+                self.nox_tuning = match value {
+                    Some(Setting::NoxTuning(value)) => Some(value),
+                    _ => None,
+                }
+            }
+            #[cfg(feature = "tuning")]
+            Setting::VocAlgorithmState(_) => {}
+            Setting::Co2AutomaticSelfCalibration(_) => {
+                self.asc_state = match value {
+                    Some(Setting::Co2AutomaticSelfCalibration(value)) => Some(value),
+                    _ => None,
+                }
+            }
+            Setting::AmbientPressure(_) => {
+                self.ambient_pressure = match value {
+                    Some(Setting::AmbientPressure(value)) => Some(value),
+                    _ => None,
+                }
+            }
+            Setting::SensorAltitude(_) => {
+                self.sensor_altitude = match value {
+                    Some(Setting::SensorAltitude(value)) => Some(value),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_contains_recorded_settings() {
+        let mut cache = ConfigCache::new();
+        let altitude = Setting::SensorAltitude(SensorAltitude::try_from(500).unwrap());
+        assert!(!cache.contains(&altitude));
+        cache.record(altitude);
+        assert!(cache.contains(&altitude));
+        assert!(!cache.contains(&Setting::SensorAltitude(SensorAltitude::default())));
+        cache.invalidate(&altitude);
+        assert!(!cache.contains(&altitude));
+    }
+
+    #[test]
+    fn cache_keeps_temperature_offsets_per_slot() {
+        let mut cache = ConfigCache::new();
+        let slot0 = Setting::TemperatureOffset(TemperatureOffset::new(1, 0, 0, 0).unwrap());
+        let slot1 = Setting::TemperatureOffset(TemperatureOffset::new(2, 0, 0, 1).unwrap());
+        cache.record(slot0);
+        cache.record(slot1);
+        assert!(cache.contains(&slot0));
+        assert!(cache.contains(&slot1));
+        assert_eq!(cache.settings().collect::<Vec<_>>(), [slot0, slot1]);
+        cache.clear();
+        assert_eq!(cache.settings().count(), 0);
+    }
+
+    #[cfg(feature = "tuning")]
+    #[test]
+    fn cache_ignores_voc_algorithm_state() {
+        let mut cache = ConfigCache::new();
+        let state = Setting::VocAlgorithmState(
+            crate::data::VocAlgorithmState::try_from(
+                &[
+                    0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81,
+                ][..],
+            )
+            .unwrap(),
+        );
+        cache.record(state);
+        assert!(!cache.contains(&state));
+        assert_eq!(cache, ConfigCache::new());
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(feature = "tuning")]
+use crate::configuration::{NoxTuning, VocTuning};
+use crate::{
+    configuration::{
+        AmbientPressure, SensorAltitude, Setting, TemperatureAcceleration, TemperatureOffset,
+    },
+    data::AscState,
+};
+
+/// Complete configuration of the sensor, e.g. to persist it and restore it after a power cycle.
+/// Apply it with [`apply_config`](crate::asynch::Sen66::apply_config) and read it back with
+/// [`read_config`](crate::asynch::Sen66::read_config).
+///
+/// The temperature offset and acceleration parameters cannot be read from the sensor. They are
+/// `None` in a configuration read from the sensor and only written if set.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Sen66Config {
+    /// Altitude of the sensor, used for the CO2 compensation.
+    pub sensor_altitude: SensorAltitude,
+    /// Ambient pressure, used for the CO2 compensation.
+    pub ambient_pressure: AmbientPressure,
+    /// State of the CO2 automatic self calibration.
+    pub asc_state: AscState,
+    /// Temperature offset of one slot.
+    pub temperature_offset: Option<TemperatureOffset>,
+    /// Temperature acceleration parameters.
+    pub temperature_acceleration: Option<TemperatureAcceleration>,
+    /// VOC index algorithm tuning parameters.
+    #[cfg(feature = "tuning")]
+    pub voc_tuning: VocTuning,
+    /// NOx index algorithm tuning parameters.
+    #[cfg(feature = "tuning")]
+    pub nox_tuning: NoxTuning,
+}
+
+impl Sen66Config {
+    /// Returns the configuration as [`Setting`]s in the order they are applied.
+    pub fn settings(&self) -> impl Iterator<Item = Setting> {
+        #[cfg(feature = "tuning")]
+        let tuning = [
+            Setting::VocTuning(self.voc_tuning),
+            Setting::NoxTuning(self.nox_tuning),
+        ];
+        #[cfg(not(feature = "tuning"))]
+        let tuning = [];
+        [
+            Some(Setting::SensorAltitude(self.sensor_altitude)),
+            Some(Setting::AmbientPressure(self.ambient_pressure)),
+            Some(Setting::Co2AutomaticSelfCalibration(self.asc_state)),
+            self.temperature_offset.map(Setting::TemperatureOffset),
+            self.temperature_acceleration
+                .map(Setting::TemperatureAcceleration),
+        ]
+        .into_iter()
+        .flatten()
+        .chain(tuning)
+    }
+}
+
+impl Default for Sen66Config {
+    /// Creates the sensor's default configuration, leaving the temperature offset and
+    /// acceleration parameters untouched.
+    fn default() -> Self {
+        Self {
+            sensor_altitude: SensorAltitude::default(),
+            ambient_pressure: AmbientPressure::default(),
+            asc_state: AscState::Enabled,
+            temperature_offset: None,
+            temperature_acceleration: None,
+            #[cfg(feature = "tuning")]
+            voc_tuning: VocTuning::default(),
+            #[cfg(feature = "tuning")]
+            nox_tuning: NoxTuning::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unset_temperature_parameters_are_skipped() {
+        let mut config = Sen66Config::default();
+        assert!(
+            !config
+                .settings()
+                .any(|setting| matches!(setting, Setting::TemperatureOffset(_)))
+        );
+        config.temperature_offset = Some(TemperatureOffset::default());
+        assert_eq!(
+            config.settings().nth(3),
+            Some(Setting::TemperatureOffset(TemperatureOffset::default()))
+        );
+    }
+}
@@ -1,16 +1,33 @@
 //! Data types for configuring the SEN66's operations.
 
+#[cfg(feature = "config-cache")]
+mod cache;
+mod config;
+mod persistence;
+#[cfg(feature = "tuning")]
+mod profile;
+mod setting;
 mod temperature;
 mod tuning;
 
 use crate::{
     error::DataError,
-    util::{check_deserialization, check_range},
+    util::{check_range, decode_words},
 };
+#[cfg(feature = "config-cache")]
+pub use cache::ConfigCache;
+pub use config::Sen66Config;
+pub use persistence::{PersistentState, SuspendState};
+#[cfg(feature = "tuning")]
+pub use profile::Profile;
+pub use setting::Setting;
 pub use temperature::{TemperatureAcceleration, TemperatureOffset};
 pub use tuning::{NoxTuning, VocTuning};
 
 /// Target CO2 concentration after a forced CO2 recalibration in ppm.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "u16", into = "u16"))]
 pub struct TargetCO2Concentration(u16);
 
 impl From<u16> for TargetCO2Concentration {
@@ -26,16 +43,24 @@ impl From<TargetCO2Concentration> for u16 {
 }
 
 /// CO2 correction value determined after forced CO2 recalibration (FRC).
-/// Is set to `0xFFFF` if recalibration has failed.
-pub struct Co2Correction(u16);
+/// The sensor reports `0xFFFF` if recalibration has failed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Co2Correction(Option<i16>);
 
 impl Co2Correction {
-    /// Returns true if recalibration has failed.
+    /// Returns true if recalibration has succeeded.
     pub fn is_valid(&self) -> bool {
-        self.0 != 0xFFFF
+        self.0.is_some()
+    }
+
+    /// Returns the correction in ppm. `None` if recalibration has failed.
+    pub fn ppm(&self) -> Option<i16> {
+        self.0
     }
 }
 
+#[cfg(feature = "co2-calibration")]
 impl TryFrom<&[u8]> for Co2Correction {
     type Error = DataError;
 
@@ -49,18 +74,14 @@ impl TryFrom<&[u8]> for Co2Correction {
     /// - [`ReceivedBufferWrongSize`](crate::error::DataError::ReceivedBufferWrongSize): If the
     ///   received data buffer is not the expected size.
     fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
-        check_deserialization(data, 3)?;
-        let value = u16::from_be_bytes([data[0], data[1]]);
-        let value = if value != 0xFFFF {
-            value - 0x8000
-        } else {
-            value
-        };
-        Ok(Co2Correction(value))
+        let [value] = decode_words(data)?;
+        // The correction is offset by 0x8000, values below represent negative corrections
+        let correction = (value != 0xFFFF).then(|| (i32::from(value) - 0x8000) as i16);
+        Ok(Co2Correction(correction))
     }
 }
 
-impl From<Co2Correction> for u16 {
+impl From<Co2Correction> for Option<i16> {
     fn from(value: Co2Correction) -> Self {
         value.0
     }
@@ -68,7 +89,9 @@ impl From<Co2Correction> for u16 {
 
 /// Ambient pressure value used for CO2 measurement compensation in hPa. Must be between 700hPa and
 /// 1,200 hPa. The default value is 1,013 hPa.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16", into = "u16"))]
 pub struct AmbientPressure(u16);
 
 impl TryFrom<u16> for AmbientPressure {
@@ -98,8 +121,8 @@ impl TryFrom<&[u8]> for AmbientPressure {
     /// - [`ReceivedBufferWrongSize`](crate::error::DataError::ReceivedBufferWrongSize): If the
     ///   received data buffer is not the expected size.
     fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
-        check_deserialization(data, 3)?;
-        Ok(AmbientPressure(u16::from_be_bytes([data[0], data[1]])))
+        let [value] = decode_words(data)?;
+        Ok(AmbientPressure(value))
     }
 }
 
@@ -118,7 +141,9 @@ impl Default for AmbientPressure {
 
 /// Sensor altitude for CO2 measurement compensation in m above sea level. Must be between 0 m and
 /// 3,000 m. The default value is 0 m.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16", into = "u16"))]
 pub struct SensorAltitude(u16);
 
 impl TryFrom<u16> for SensorAltitude {
@@ -148,8 +173,8 @@ impl TryFrom<&[u8]> for SensorAltitude {
     /// - [`ReceivedBufferWrongSize`](crate::error::DataError::ReceivedBufferWrongSize): If the
     ///   received data buffer is not the expected size.
     fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
-        check_deserialization(data, 3)?;
-        Ok(SensorAltitude(u16::from_be_bytes([data[0], data[1]])))
+        let [value] = decode_words(data)?;
+        Ok(SensorAltitude(value))
     }
 }
 
@@ -159,6 +184,37 @@ impl From<SensorAltitude> for u16 {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for TargetCO2Concentration {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{} ppm", self.0)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Co2Correction {
+    fn format(&self, f: defmt::Formatter) {
+        match self.0 {
+            Some(correction) => defmt::write!(f, "{} ppm", correction),
+            None => defmt::write!(f, "Failed"),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for AmbientPressure {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{} hPa", self.0)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for SensorAltitude {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{} m", self.0)
+    }
+}
+
 impl Default for SensorAltitude {
     /// Returns the default ambient pressure of 1,013 hPa.
     fn default() -> Self {
@@ -175,4 +231,19 @@ mod tests {
         let value = 12;
         assert_eq!(u16::from(TargetCO2Concentration::from(value)), value)
     }
+
+    #[cfg(feature = "co2-calibration")]
+    #[test]
+    fn co2_correction_is_signed() {
+        let decode = |data: &[u8]| Co2Correction::try_from(data).unwrap();
+        assert_eq!(decode(&[0x7F, 0xFF, 0x8F]).ppm(), Some(-1));
+        assert_eq!(decode(&[0x80, 0x00, 0xA2]).ppm(), Some(0));
+        assert_eq!(decode(&[0x80, 0x01, 0x93]).ppm(), Some(1));
+        assert_eq!(decode(&[0x00, 0x00, 0x81]).ppm(), Some(i16::MIN));
+        assert!(decode(&[0x7F, 0xFF, 0x8F]).is_valid());
+
+        let failed = decode(&[0xFF, 0xFF, 0xAC]);
+        assert!(!failed.is_valid());
+        assert_eq!(Option::<i16>::from(failed), None);
+    }
 }
@@ -0,0 +1,228 @@
+#[cfg(feature = "tuning")]
+use crate::configuration::{NoxTuning, VocTuning};
+use crate::{
+    configuration::{
+        AmbientPressure, Sen66Config, SensorAltitude, TemperatureAcceleration, TemperatureOffset,
+    },
+    data::{AscState, VocAlgorithmState},
+    error::DataError,
+    util::{ByteReader, compute_crc8},
+};
+
+/// Marker at the start of the persisted representation.
+const MAGIC: [u8; 2] = *b"S6";
+/// Version of the persisted representation.
+const VERSION: u8 = 1;
+
+/// Flags marking the optional fields present in the persisted representation.
+const TEMPERATURE_OFFSET_PRESENT: u8 = 1 << 0;
+const TEMPERATURE_ACCELERATION_PRESENT: u8 = 1 << 1;
+#[cfg(feature = "tuning")]
+const VOC_TUNING_PRESENT: u8 = 1 << 2;
+#[cfg(feature = "tuning")]
+const NOX_TUNING_PRESENT: u8 = 1 << 3;
+const VOC_STATE_PRESENT: u8 = 1 << 4;
+
+/// Configuration and VOC algorithm state persisted across power cycles, e.g. in EEPROM or flash.
+/// The persisted representation is versioned and protected by a CRC, so data written by a
+/// different firmware or corrupted in storage is rejected instead of misconfiguring the sensor.
+///
+/// Restore the configuration with [`apply_config`](crate::asynch::Sen66::apply_config) and the
+/// VOC algorithm state with
+/// [`set_voc_algorithm_state`](crate::asynch::Sen66::set_voc_algorithm_state) to skip the
+/// learning phase.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PersistentState {
+    /// Configuration of the sensor.
+    pub config: Sen66Config,
+    /// VOC algorithm state, read with
+    /// [`get_voc_algorithm_state`](crate::asynch::Sen66::get_voc_algorithm_state).
+    pub voc_state: Option<VocAlgorithmState>,
+}
+
+impl PersistentState {
+    /// Size of the persisted representation in bytes.
+    pub const SERIALIZED_SIZE: usize = 58;
+
+    /// Writes the persisted representation into `buffer` and returns the number of bytes
+    /// written.
+    ///
+    /// # Errors
+    ///
+    /// - [`BufferTooSmall`](crate::error::DataError::BufferTooSmall): If the buffer is smaller
+    ///   than [`SERIALIZED_SIZE`](PersistentState::SERIALIZED_SIZE).
+    pub fn to_persistent_bytes(&self, buffer: &mut [u8]) -> Result<usize, DataError> {
+        let data = buffer
+            .get_mut(..Self::SERIALIZED_SIZE)
+            .ok_or(DataError::BufferTooSmall)?;
+        let config = &self.config;
+        let mut flags = 0;
+        let mut words = [0; 26];
+        words[0] = u16::from(config.sensor_altitude);
+        words[1] = u16::from(config.ambient_pressure);
+        if let Some(offset) = config.temperature_offset {
+            flags |= TEMPERATURE_OFFSET_PRESENT;
+            words[2..6].copy_from_slice(&<[u16; 4]>::from(offset));
+        }
+        if let Some(acceleration) = config.temperature_acceleration {
+            flags |= TEMPERATURE_ACCELERATION_PRESENT;
+            words[6..10].copy_from_slice(&<[u16; 4]>::from(acceleration));
+        }
+        #[cfg(feature = "tuning")]
+        {
+            flags |= VOC_TUNING_PRESENT | NOX_TUNING_PRESENT;
+            words[10..16].copy_from_slice(&<[u16; 6]>::from(config.voc_tuning));
+            words[16..22].copy_from_slice(&<[u16; 6]>::from(config.nox_tuning));
+        }
+        if let Some(state) = self.voc_state {
+            flags |= VOC_STATE_PRESENT;
+            words[22..26].copy_from_slice(&<[u16; 4]>::from(state));
+        }
+
+        data[0..2].copy_from_slice(&MAGIC);
+        data[2] = VERSION;
+        data[3] = flags;
+        data[4] = u8::from(config.asc_state == AscState::Enabled);
+        for (chunk, word) in data[5..57].chunks_exact_mut(2).zip(words) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        data[57] = compute_crc8(&data[..57]);
+        Ok(Self::SERIALIZED_SIZE)
+    }
+
+    /// Restores the state from its persisted representation. Tuning parameters are ignored if
+    /// the `tuning` feature is disabled, missing ones are set to their defaults.
+    ///
+    /// # Errors
+    ///
+    /// - [`ReceivedBufferWrongSize`](crate::error::DataError::ReceivedBufferWrongSize): If the
+    ///   data is not the expected size.
+    /// - [`CrcFailed`](crate::error::DataError::CrcFailed): If the data is corrupted.
+    /// - [`UnexpectedValueReceived`](crate::error::DataError::UnexpectedValueReceived): If the
+    ///   data was written in an unknown format or version.
+    /// - [`ValueOutOfRange`](crate::error::DataError::ValueOutOfRange): If a persisted value is
+    ///   not in range.
+    pub fn from_persistent_bytes(data: &[u8]) -> Result<Self, DataError> {
+        let (crc, content) = data
+            .split_last()
+            .filter(|_| data.len() == Self::SERIALIZED_SIZE)
+            .ok_or(DataError::ReceivedBufferWrongSize)?;
+        if compute_crc8(content) != *crc {
+            return Err(DataError::CrcFailed);
+        }
+        let mut reader = ByteReader::new(content);
+        let [magic @ .., version] = reader.take::<3>()?;
+        if magic != MAGIC || version != VERSION {
+            return Err(DataError::UnexpectedValueReceived {
+                parameter: "Persistence Version",
+                expected: "1",
+                actual: u16::from(version),
+            });
+        }
+        let [flags, asc] = reader.take()?;
+        let mut words = [0; 26];
+        for word in &mut words {
+            *word = u16::from_be_bytes(reader.take()?);
+        }
+        reader.finish()?;
+
+        let present = |flag: u8| flags & flag != 0;
+        Ok(Self {
+            config: Sen66Config {
+                sensor_altitude: SensorAltitude::try_from(words[0])?,
+                ambient_pressure: AmbientPressure::try_from(words[1])?,
+                asc_state: if asc != 0 {
+                    AscState::Enabled
+                } else {
+                    AscState::Disabled
+                },
+                temperature_offset: present(TEMPERATURE_OFFSET_PRESENT)
+                    .then(|| TemperatureOffset::try_from(words_at(&words, 2)))
+                    .transpose()?,
+                temperature_acceleration: present(TEMPERATURE_ACCELERATION_PRESENT)
+                    .then(|| TemperatureAcceleration::from(words_at(&words, 6))),
+                #[cfg(feature = "tuning")]
+                voc_tuning: present(VOC_TUNING_PRESENT)
+                    .then(|| VocTuning::try_from(words_at(&words, 10)))
+                    .transpose()?
+                    .unwrap_or_default(),
+                #[cfg(feature = "tuning")]
+                nox_tuning: present(NOX_TUNING_PRESENT)
+                    .then(|| NoxTuning::try_from(words_at(&words, 16)))
+                    .transpose()?
+                    .unwrap_or_default(),
+            },
+            voc_state: present(VOC_STATE_PRESENT)
+                .then(|| VocAlgorithmState::from(words_at(&words, 22))),
+        })
+    }
+}
+
+/// Configuration, VOC algorithm state and operating state captured by
+/// [`suspend`](crate::asynch::Sen66::suspend) before a deep sleep power cycling the MCU and
+/// possibly the sensor. Restore them with [`resume_from`](crate::asynch::Sen66::resume_from).
+///
+/// Persist [`persistent`](SuspendState::persistent) with its byte representation if the state
+/// has to survive in flash instead of retained memory.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SuspendState {
+    /// Configuration and VOC algorithm state of the sensor.
+    pub persistent: PersistentState,
+    /// Whether the sensor was measuring when it was suspended.
+    pub measuring: bool,
+}
+
+/// Returns the `N` words starting at `start`.
+fn words_at<const N: usize>(words: &[u16], start: usize) -> [u16; N] {
+    core::array::from_fn(|i| words[start + i])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_roundtrips_through_bytes() {
+        let mut state = PersistentState::default();
+        state.config.temperature_offset = Some(TemperatureOffset::new(-2, 0, 0, 1).unwrap());
+        state.voc_state = Some(VocAlgorithmState::from([1, 2, 3, 4]));
+        let mut buffer = [0; 64];
+        let len = state.to_persistent_bytes(&mut buffer).unwrap();
+        assert_eq!(
+            PersistentState::from_persistent_bytes(&buffer[..len]),
+            Ok(state)
+        );
+        assert_eq!(
+            state.to_persistent_bytes(&mut buffer[..57]),
+            Err(DataError::BufferTooSmall)
+        );
+    }
+
+    #[test]
+    fn corrupted_or_unknown_data_is_rejected() {
+        let mut buffer = [0; PersistentState::SERIALIZED_SIZE];
+        PersistentState::default()
+            .to_persistent_bytes(&mut buffer)
+            .unwrap();
+        buffer[10] ^= 0x01;
+        assert_eq!(
+            PersistentState::from_persistent_bytes(&buffer),
+            Err(DataError::CrcFailed)
+        );
+        buffer[10] ^= 0x01;
+        buffer[2] = 2;
+        buffer[57] = compute_crc8(&buffer[..57]);
+        assert!(matches!(
+            PersistentState::from_persistent_bytes(&buffer),
+            Err(DataError::UnexpectedValueReceived { actual: 2, .. })
+        ));
+        assert_eq!(
+            PersistentState::from_persistent_bytes(&buffer[..57]),
+            Err(DataError::ReceivedBufferWrongSize)
+        );
+    }
+}
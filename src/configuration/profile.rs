@@ -0,0 +1,130 @@
+use crate::{
+    analysis::{EpisodeThreshold, EpisodeThresholds},
+    configuration::{NoxTuning, Setting, TemperatureOffset, VocTuning},
+    data::AscState,
+};
+
+/// Curated configurations for typical applications, giving a starting point for integrators.
+/// Apply a profile with [`apply_profile`](crate::asynch::Sen66::apply_profile).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Profile {
+    /// Residential sleeping room, aired regularly. Uses default gas index tunings and stricter
+    /// thresholds for PM2.5 and VOC, following the WHO's 24h PM2.5 guideline.
+    Bedroom,
+    /// Office or classroom with regular occupancy. Uses the sensor's defaults.
+    Office,
+    /// Workshop with recurring dust and solvent emissions. Gas index tunings adapt slower and
+    /// amplify less, thresholds are relaxed to only report unusual episodes.
+    Workshop,
+    /// Greenhouse with CO2 enrichment or depletion by plants. The automatic self calibration is
+    /// disabled, as the CO2 concentration rarely settles at fresh air levels. The CO2 threshold
+    /// follows the occupational exposure limit of 5000 ppm.
+    Greenhouse,
+}
+
+impl Profile {
+    /// All available profiles.
+    pub const ALL: [Profile; 4] = [
+        Profile::Bedroom,
+        Profile::Office,
+        Profile::Workshop,
+        Profile::Greenhouse,
+    ];
+
+    /// Returns the VOC index tuning of the profile.
+    pub fn voc_tuning(&self) -> VocTuning {
+        match self {
+            Profile::Bedroom | Profile::Office => VocTuning::default(),
+            Profile::Workshop => VocTuning::preset(24, 360, 150),
+            Profile::Greenhouse => VocTuning::preset(24, 180, 230),
+        }
+    }
+
+    /// Returns the NOx index tuning of the profile.
+    pub fn nox_tuning(&self) -> NoxTuning {
+        match self {
+            Profile::Bedroom | Profile::Office | Profile::Greenhouse => {
+                NoxTuning::preset(12, 720, 230)
+            }
+            Profile::Workshop => NoxTuning::preset(24, 720, 150),
+        }
+    }
+
+    /// Returns the temperature offset of the profile. All profiles reset slot 0 to the sensor's
+    /// default, as the offset depends on the design-in rather than the application. Use a
+    /// [`TemperatureOffsetFit`](crate::correction::TemperatureOffsetFit) to determine it.
+    pub fn temperature_offset(&self) -> TemperatureOffset {
+        TemperatureOffset::default()
+    }
+
+    /// Returns whether the profile uses the automatic self calibration of the CO2 sensor.
+    pub fn asc_state(&self) -> AscState {
+        match self {
+            Profile::Greenhouse => AscState::Disabled,
+            _ => AscState::Enabled,
+        }
+    }
+
+    /// Returns the recommended alarm thresholds of the profile.
+    pub fn thresholds(&self) -> EpisodeThresholds {
+        match self {
+            Profile::Bedroom => EpisodeThresholds {
+                pm2_5: Some(EpisodeThreshold::new(15.0, 12.0)),
+                voc_index: Some(EpisodeThreshold::new(200.0, 160.0)),
+                ..EpisodeThresholds::default()
+            },
+            Profile::Office => EpisodeThresholds::default(),
+            Profile::Workshop => EpisodeThresholds {
+                pm2_5: Some(EpisodeThreshold::new(50.0, 40.0)),
+                co2: Some(EpisodeThreshold::new(1_400.0, 1_120.0)),
+                voc_index: Some(EpisodeThreshold::new(350.0, 280.0)),
+                nox_index: Some(EpisodeThreshold::new(50.0, 40.0)),
+            },
+            Profile::Greenhouse => EpisodeThresholds {
+                co2: Some(EpisodeThreshold::new(5_000.0, 4_000.0)),
+                ..EpisodeThresholds::default()
+            },
+        }
+    }
+
+    /// Returns the sensor settings of the profile, e.g. to apply them with
+    /// [`apply_settings`](crate::asynch::Sen66::apply_settings).
+    pub fn settings(&self) -> [Setting; 4] {
+        [
+            Setting::VocTuning(self.voc_tuning()),
+            Setting::NoxTuning(self.nox_tuning()),
+            Setting::TemperatureOffset(self.temperature_offset()),
+            Setting::Co2AutomaticSelfCalibration(self.asc_state()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile_tunings_are_in_range() {
+        for profile in Profile::ALL {
+            let voc = <[u16; 6]>::from(profile.voc_tuning()).map(|value| value as i16);
+            assert_eq!(
+                VocTuning::new(voc[0], voc[1], voc[2], voc[3], voc[4], voc[5]),
+                Ok(profile.voc_tuning())
+            );
+            let nox = <[u16; 6]>::from(profile.nox_tuning()).map(|value| value as i16);
+            assert_eq!(
+                NoxTuning::new(nox[0], nox[1], nox[2], nox[3], nox[5]),
+                Ok(profile.nox_tuning())
+            );
+        }
+    }
+
+    #[test]
+    fn greenhouse_disables_asc() {
+        assert_eq!(Profile::Greenhouse.asc_state(), AscState::Disabled);
+        assert_eq!(Profile::Office.asc_state(), AscState::Enabled);
+        assert_eq!(Profile::Office.thresholds(), EpisodeThresholds::default());
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{
+    configuration::{AmbientPressure, SensorAltitude, TemperatureAcceleration, TemperatureOffset},
+    data::AscState,
+};
+#[cfg(feature = "tuning")]
+use crate::{
+    configuration::{NoxTuning, VocTuning},
+    data::VocAlgorithmState,
+};
+
+/// A single configuration value, which can be applied together with others using
+/// [`apply_settings`](crate::asynch::Sen66::apply_settings).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Setting {
+    /// See [`set_temperature_offset`](crate::asynch::Sen66::set_temperature_offset).
+    TemperatureOffset(TemperatureOffset),
+    /// See [`set_temperature_acceleration`](crate::asynch::Sen66::set_temperature_acceleration).
+    TemperatureAcceleration(TemperatureAcceleration),
+    /// See [`set_voc_tuning_parameters`](crate::asynch::Sen66::set_voc_tuning_parameters).
+    #[cfg(feature = "tuning")]
+    VocTuning(VocTuning),
+    /// See [`set_nox_tuning_parameters`](crate::asynch::Sen66::set_nox_tuning_parameters).
+    #[cfg(feature = "tuning")]
+    NoxTuning(NoxTuning),
+    /// See [`set_voc_algorithm_state`](crate::asynch::Sen66::set_voc_algorithm_state).
+    #[cfg(feature = "tuning")]
+    VocAlgorithmState(VocAlgorithmState),
+    /// See [`set_co2_asc_state`](crate::asynch::Sen66::set_co2_asc_state).
+    Co2AutomaticSelfCalibration(AscState),
+    /// See [`set_ambient_pressure`](crate::asynch::Sen66::set_ambient_pressure).
+    AmbientPressure(AmbientPressure),
+    /// See [`set_sensor_altitude`](crate::asynch::Sen66::set_sensor_altitude).
+    SensorAltitude(SensorAltitude),
+}
+
+impl Setting {
+    /// Returns whether the setting can only be applied in idle state.
+    pub fn requires_idle(&self) -> bool {
+        !matches!(
+            self,
+            Setting::TemperatureOffset(_) | Setting::AmbientPressure(_)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_offset_and_pressure_can_be_applied_while_measuring() {
+        assert!(
+            !Setting::TemperatureOffset(TemperatureOffset::new(0, 0, 0, 0).unwrap())
+                .requires_idle()
+        );
+        assert!(!Setting::AmbientPressure(AmbientPressure::default()).requires_idle());
+        assert!(Setting::SensorAltitude(SensorAltitude::default()).requires_idle());
+        #[cfg(feature = "tuning")]
+        assert!(Setting::VocTuning(VocTuning::default()).requires_idle());
+        assert!(Setting::Co2AutomaticSelfCalibration(AscState::Enabled).requires_idle());
+    }
+}
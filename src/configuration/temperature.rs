@@ -4,6 +4,9 @@ use crate::{error::DataError, util::check_scaling};
 /// using:
 /// `T_Ambient_Compensated = T_Ambient + (slope * T_Ambient) + offset`
 /// Up to 5 temperature offsets can be stored.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "[u16; 4]", into = "[u16; 4]"))]
 pub struct TemperatureOffset {
     offset: i16,
     slope: i16,
@@ -23,9 +26,63 @@ impl TemperatureOffset {
     /// - [`ValueOutOfRange`](crate::error::DataError::ValueOutOfRange)`: If the values with scaling
     ///   are not in range.
     pub fn new(offset: i16, slope: i16, time_constant: u16, slot: u16) -> Result<Self, DataError> {
+        Self::from_scaled(
+            check_scaling(offset, 200, "Temperature Offset", "°C")?,
+            check_scaling(slope, 10_000, "Temperature Slope", "")?,
+            time_constant,
+            slot,
+        )
+    }
+
+    /// Creates a new [`TemperatureOffset`](TemperatureOffset) configuration with fractional
+    /// parameters:
+    /// - `offset`: Constant temperature offset in °C. Resolution: 0.005°C
+    /// - `slope`: Normalized temperature offset slope. Resolution: 0.0001
+    /// - `time_constant`: Time constant determining how fast the new slope and offset are applied.
+    /// - `slot`: Temperature offset slot to modify. Available slots range from 0 to 4.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValueOutOfRange`](crate::error::DataError::ValueOutOfRange)`: If the values with scaling
+    ///   are not in range.
+    pub fn from_f32(
+        offset: f32,
+        slope: f32,
+        time_constant: u16,
+        slot: u16,
+    ) -> Result<Self, DataError> {
+        Self::from_scaled(
+            scale_f32(offset, 200, "Temperature Offset", "°C")?,
+            scale_f32(slope, 10_000, "Temperature Slope", "")?,
+            time_constant,
+            slot,
+        )
+    }
+
+    /// Returns the constant temperature offset in °C.
+    pub fn offset(&self) -> f32 {
+        f32::from(self.offset) / 200.
+    }
+
+    /// Returns the normalized temperature offset slope.
+    pub fn slope(&self) -> f32 {
+        f32::from(self.slope) / 10_000.
+    }
+
+    /// Returns the temperature offset slot the parameters are stored in.
+    pub fn slot(&self) -> u16 {
+        self.slot
+    }
+
+    fn from_scaled(
+        offset: i16,
+        slope: i16,
+        time_constant: u16,
+        slot: u16,
+    ) -> Result<Self, DataError> {
         Ok(Self {
-            offset: check_scaling(offset, 200, "Temperature Offset", "°C")?,
-            slope: check_scaling(slope, 10_000, "Temperature Slope", "")?,
+            offset,
+            slope,
             time_constant,
             slot: if (0..=4).contains(&slot) {
                 slot
@@ -41,6 +98,70 @@ impl TemperatureOffset {
     }
 }
 
+impl Default for TemperatureOffset {
+    /// Creates the sensor's default [`TemperatureOffset`](TemperatureOffset) without offset and
+    /// slope in slot 0.
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            slope: 0,
+            time_constant: 0,
+            slot: 0,
+        }
+    }
+}
+
+/// Scales a fractional `value` by `scalar` and rounds it to the closest integer.
+fn scale_f32(
+    value: f32,
+    scalar: i16,
+    name: &'static str,
+    unit: &'static str,
+) -> Result<i16, DataError> {
+    let scaled = value * f32::from(scalar);
+    if scaled.is_nan() || scaled < f32::from(i16::MIN) || scaled > f32::from(i16::MAX) {
+        return Err(DataError::ValueOutOfRange {
+            parameter: name,
+            min: i32::from(i16::MIN / scalar),
+            max: i32::from(i16::MAX / scalar),
+            unit,
+        });
+    }
+    Ok(if scaled < 0. {
+        (scaled - 0.5) as i16
+    } else {
+        (scaled + 0.5) as i16
+    })
+}
+
+impl TryFrom<[u16; 4]> for TemperatureOffset {
+    type Error = DataError;
+
+    /// Creates the temperature offset from its scaled parameters as sent to the sensor.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValueOutOfRange`](crate::error::DataError::ValueOutOfRange)`: If the slot is not in
+    ///   range.
+    fn try_from([offset, slope, time_constant, slot]: [u16; 4]) -> Result<Self, Self::Error> {
+        Self::from_scaled(offset as i16, slope as i16, time_constant, slot)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for TemperatureOffset {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Slot {}: Offset {} °C, Slope {}, Time Constant {}",
+            self.slot,
+            f32::from(self.offset) / 200.,
+            f32::from(self.slope) / 10_000.,
+            self.time_constant
+        )
+    }
+}
+
 impl From<TemperatureOffset> for [u16; 4] {
     fn from(value: TemperatureOffset) -> Self {
         [
@@ -54,6 +175,9 @@ impl From<TemperatureOffset> for [u16; 4] {
 
 /// Temperature acceleration parameters for the RH/T engine. No documentation on these has been
 /// published so far.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "[u16; 4]", into = "[u16; 4]"))]
 pub struct TemperatureAcceleration {
     k: u16,
     p: u16,
@@ -79,6 +203,27 @@ impl TemperatureAcceleration {
     }
 }
 
+impl From<[u16; 4]> for TemperatureAcceleration {
+    /// Creates the temperature acceleration from its scaled parameters as sent to the sensor.
+    fn from([k, p, t1, t2]: [u16; 4]) -> Self {
+        Self { k, p, t1, t2 }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for TemperatureAcceleration {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "K {}, P {}, T1 {}, T2 {}",
+            f32::from(self.k) / 10.,
+            f32::from(self.p) / 10.,
+            f32::from(self.t1) / 10.,
+            f32::from(self.t2) / 10.
+        )
+    }
+}
+
 impl From<TemperatureAcceleration> for [u16; 4] {
     fn from(value: TemperatureAcceleration) -> Self {
         [value.k, value.p, value.t1, value.t2]
@@ -1,10 +1,10 @@
-use crate::{
-    error::DataError,
-    util::{check_deserialization, check_range},
-};
+#[cfg(feature = "tuning")]
+use crate::util::decode_words;
+use crate::{error::DataError, util::check_range};
 
 /// Configuration for the VOC Index algorithm.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VocTuning(Tuning);
 
 impl VocTuning {
@@ -42,6 +42,30 @@ impl VocTuning {
             gain_factor,
         )?))
     }
+
+    #[cfg(feature = "tuning")]
+    /// Creates a preset from parameters known to be in range, keeping the remaining defaults.
+    pub(crate) const fn preset(
+        learning_time_offset: i16,
+        gating_max_durations: i16,
+        gain_factor: i16,
+    ) -> Self {
+        Self(Tuning {
+            index_offset: 100,
+            learning_time_offset,
+            learning_time_gain: 12,
+            gating_max_durations,
+            initial_standard_deviation: 50,
+            gain_factor,
+        })
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for VocTuning {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "VOC {}", self.0)
+    }
 }
 
 impl From<VocTuning> for [u16; 6] {
@@ -50,6 +74,21 @@ impl From<VocTuning> for [u16; 6] {
     }
 }
 
+impl TryFrom<[u16; 6]> for VocTuning {
+    type Error = DataError;
+
+    /// Creates the VOC tuning parameters from the words as sent to the sensor.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValueOutOfRange`](crate::error::DataError::ValueOutOfRange)`: If the values are not
+    ///   in range.
+    fn try_from(words: [u16; 6]) -> Result<Self, Self::Error> {
+        Ok(VocTuning(Tuning::try_from(words)?))
+    }
+}
+
+#[cfg(feature = "tuning")]
 impl TryFrom<&[u8]> for VocTuning {
     type Error = DataError;
 
@@ -87,7 +126,8 @@ impl Default for VocTuning {
 }
 
 /// Configuration for the NOx Index algorithm.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NoxTuning(Tuning);
 
 impl NoxTuning {
@@ -122,6 +162,30 @@ impl NoxTuning {
             gain_factor,
         )?))
     }
+
+    #[cfg(feature = "tuning")]
+    /// Creates a preset from parameters known to be in range, keeping the remaining defaults.
+    pub(crate) const fn preset(
+        learning_time_offset: i16,
+        gating_max_durations: i16,
+        gain_factor: i16,
+    ) -> Self {
+        Self(Tuning {
+            index_offset: 1,
+            learning_time_offset,
+            learning_time_gain: 12,
+            gating_max_durations,
+            initial_standard_deviation: 50,
+            gain_factor,
+        })
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for NoxTuning {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "NOx {}", self.0)
+    }
 }
 
 impl From<NoxTuning> for [u16; 6] {
@@ -130,6 +194,21 @@ impl From<NoxTuning> for [u16; 6] {
     }
 }
 
+impl TryFrom<[u16; 6]> for NoxTuning {
+    type Error = DataError;
+
+    /// Creates the NOx tuning parameters from the words as sent to the sensor.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValueOutOfRange`](crate::error::DataError::ValueOutOfRange)`: If the values are not
+    ///   in range.
+    fn try_from(words: [u16; 6]) -> Result<Self, Self::Error> {
+        Ok(NoxTuning(Tuning::try_from(words)?))
+    }
+}
+
+#[cfg(feature = "tuning")]
 impl TryFrom<&[u8]> for NoxTuning {
     type Error = DataError;
 
@@ -166,7 +245,9 @@ impl Default for NoxTuning {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "[u16; 6]", into = "[u16; 6]"))]
 struct Tuning {
     index_offset: i16,
     learning_time_offset: i16,
@@ -176,6 +257,23 @@ struct Tuning {
     gain_factor: i16,
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for Tuning {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Index Offset: {}, Learning Time Offset: {} h, Learning Time Gain: {} h, \
+             Gating Max Duration: {} min, Initial Standard Deviation: {}, Gain Factor: {}",
+            self.index_offset,
+            self.learning_time_offset,
+            self.learning_time_gain,
+            self.gating_max_durations,
+            self.initial_standard_deviation,
+            self.gain_factor
+        )
+    }
+}
+
 impl Tuning {
     fn new(
         index_offset: i16,
@@ -233,18 +331,34 @@ impl From<Tuning> for [u16; 6] {
     }
 }
 
+#[cfg(feature = "tuning")]
 impl TryFrom<&[u8]> for Tuning {
     type Error = DataError;
 
     fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
-        check_deserialization(data, 18)?;
+        Tuning::try_from(decode_words::<6>(data)?)
+    }
+}
+
+impl TryFrom<[u16; 6]> for Tuning {
+    type Error = DataError;
+
+    fn try_from(words: [u16; 6]) -> Result<Self, Self::Error> {
+        let [
+            index_offset,
+            learning_time_offset,
+            learning_time_gain,
+            gating_max_durations,
+            initial_standard_deviation,
+            gain_factor,
+        ] = words.map(|word| word as i16);
         Tuning::new(
-            i16::from_be_bytes([data[0], data[1]]),
-            i16::from_be_bytes([data[3], data[4]]),
-            i16::from_be_bytes([data[6], data[7]]),
-            i16::from_be_bytes([data[9], data[10]]),
-            i16::from_be_bytes([data[12], data[13]]),
-            i16::from_be_bytes([data[15], data[16]]),
+            index_offset,
+            learning_time_offset,
+            learning_time_gain,
+            gating_max_durations,
+            initial_standard_deviation,
+            gain_factor,
         )
     }
 }
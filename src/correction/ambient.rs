@@ -0,0 +1,104 @@
+use crate::data::Measurement;
+
+/// Temperature and relative humidity measured by an external sensor.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AmbientConditions {
+    /// Temperature in °C.
+    pub temperature: f32,
+    /// Relative Humidity in %.
+    pub relative_humidity: f32,
+}
+
+/// Source of the ambient conditions measured by an external, better placed RH/T sensor. The
+/// SEN66's own RH/T readings are affected by its self-heating. Fusing a measurement with the
+/// source replaces them, so derived values computed from the fused measurement use the external
+/// readings, while PM, CO2, VOC and NOx still come from the SEN66.
+///
+/// Implemented for fixed [`AmbientConditions`] and for closures returning them.
+pub trait AmbientConditionsSource {
+    /// Returns the latest ambient conditions. `None` if none are available, e.g. because the
+    /// external sensor failed.
+    fn ambient_conditions(&mut self) -> Option<AmbientConditions>;
+
+    /// Returns the `measurement` with the relative humidity and temperature of the source. Keeps
+    /// the SEN66's readings if the source provides no finite values.
+    fn fuse(&mut self, measurement: &Measurement) -> Measurement {
+        match self.ambient_conditions() {
+            Some(conditions)
+                if conditions.temperature.is_finite()
+                    && conditions.relative_humidity.is_finite() =>
+            {
+                Measurement {
+                    temperature: conditions.temperature,
+                    relative_humidity: conditions.relative_humidity,
+                    ..*measurement
+                }
+            }
+            _ => *measurement,
+        }
+    }
+}
+
+impl AmbientConditionsSource for AmbientConditions {
+    fn ambient_conditions(&mut self) -> Option<AmbientConditions> {
+        Some(*self)
+    }
+}
+
+impl<F: FnMut() -> Option<AmbientConditions>> AmbientConditionsSource for F {
+    fn ambient_conditions(&mut self) -> Option<AmbientConditions> {
+        self()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement() -> Measurement {
+        Measurement {
+            pm2_5: 2.0,
+            pm4_0: 3.0,
+            pm10_0: 4.0,
+            relative_humidity: 35.0,
+            temperature: 25.0,
+            co2: 600,
+            ..Measurement::fixture()
+        }
+    }
+
+    #[test]
+    fn fusing_replaces_only_rh_and_temperature() {
+        let mut source = AmbientConditions {
+            temperature: 21.0,
+            relative_humidity: 45.0,
+        };
+        let fused = source.fuse(&measurement());
+        assert_eq!(fused.temperature, 21.0);
+        assert_eq!(fused.relative_humidity, 45.0);
+        assert_eq!(
+            Measurement {
+                temperature: 25.0,
+                relative_humidity: 35.0,
+                ..fused
+            },
+            measurement()
+        );
+    }
+
+    #[test]
+    fn fusing_falls_back_to_sensor_readings() {
+        let mut readings = [
+            None,
+            Some(AmbientConditions {
+                temperature: f32::NAN,
+                relative_humidity: 45.0,
+            }),
+        ]
+        .into_iter();
+        let mut source = move || readings.next().flatten();
+        assert_eq!(source.fuse(&measurement()), measurement());
+        assert_eq!(source.fuse(&measurement()), measurement());
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{
+    correction::CorrectionModel,
+    data::{Channel, Measurement},
+    error::DataError,
+    util::ByteReader,
+};
+
+/// Minimum number of reference points required for a correction.
+const MIN_POINTS: usize = 2;
+/// Maximum number of reference points supported for a correction.
+const MAX_POINTS: usize = 3;
+
+/// Host-side CO2 correction derived from two or three reference gas checks, applied on top of
+/// the device's forced recalibration for stricter accuracy requirements using:
+/// `CO2_Corrected = gain * CO2 + offset`
+/// Persist the correction using [`to_bytes`](Co2ReferenceCorrection::to_bytes) and restore it
+/// with [`TryFrom<&[u8]>`](Co2ReferenceCorrection::try_from).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Co2ReferenceCorrection {
+    gain: f32,
+    offset: f32,
+}
+
+impl Co2ReferenceCorrection {
+    /// Size of the persisted representation in bytes.
+    pub const SERIALIZED_SIZE: usize = 8;
+
+    /// Creates a correction from the `(measured, reference)` CO2 concentrations in ppm of two or
+    /// three reference gas checks. Gain and offset are fitted via least squares. If all checks
+    /// measured the same concentration, only an offset is applied.
+    ///
+    /// # Errors
+    ///
+    /// - [`InsufficientData`](crate::error::DataError::InsufficientData): If less than two
+    ///   points are provided.
+    /// - [`ValueOutOfRange`](crate::error::DataError::ValueOutOfRange): If more than three
+    ///   points are provided.
+    pub fn from_points(points: &[(u16, u16)]) -> Result<Self, DataError> {
+        if points.len() < MIN_POINTS {
+            return Err(DataError::InsufficientData);
+        }
+        if points.len() > MAX_POINTS {
+            return Err(DataError::ValueOutOfRange {
+                parameter: "CO2 Reference Points",
+                min: MIN_POINTS as i32,
+                max: MAX_POINTS as i32,
+                unit: "",
+            });
+        }
+        let n = points.len() as f32;
+        let (sum_x, sum_y) = points
+            .iter()
+            .fold((0.0, 0.0), |(x, y), (measured, reference)| {
+                (x + f32::from(*measured), y + f32::from(*reference))
+            });
+        let (mean_x, mean_y) = (sum_x / n, sum_y / n);
+        let (covariance, variance) = points.iter().fold(
+            (0.0, 0.0),
+            |(covariance, variance), (measured, reference)| {
+                let dx = f32::from(*measured) - mean_x;
+                let dy = f32::from(*reference) - mean_y;
+                (covariance + dx * dy, variance + dx * dx)
+            },
+        );
+        let gain = if variance == 0.0 {
+            1.0
+        } else {
+            covariance / variance
+        };
+        Ok(Self::new(gain, mean_y - gain * mean_x))
+    }
+
+    /// Creates a correction from known coefficients:
+    /// - `gain`: Factor applied to the measured concentration.
+    /// - `offset`: Constant offset in ppm.
+    pub const fn new(gain: f32, offset: f32) -> Self {
+        Self { gain, offset }
+    }
+
+    /// Returns the factor applied to the measured concentration.
+    pub fn gain(&self) -> f32 {
+        self.gain
+    }
+
+    /// Returns the constant offset in ppm.
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    /// Returns the corrected `co2` concentration in ppm. Unavailable values are returned as is.
+    pub fn apply(&self, co2: u16) -> u16 {
+        if co2 == Measurement::CO2_UNAVAILABLE {
+            return co2;
+        }
+        (self.correct(Channel::Co2, f32::from(co2)) + 0.5)
+            .clamp(0.0, f32::from(Measurement::CO2_UNAVAILABLE - 1)) as u16
+    }
+
+    /// Returns the persisted representation of the correction.
+    pub fn to_bytes(&self) -> [u8; Self::SERIALIZED_SIZE] {
+        let mut data = [0; Self::SERIALIZED_SIZE];
+        data[0..4].copy_from_slice(&self.gain.to_be_bytes());
+        data[4..8].copy_from_slice(&self.offset.to_be_bytes());
+        data
+    }
+}
+
+impl CorrectionModel for Co2ReferenceCorrection {
+    fn correct(&self, channel: Channel, value: f32) -> f32 {
+        match channel {
+            Channel::Co2 => self.gain * value + self.offset,
+            _ => value,
+        }
+    }
+}
+
+impl Default for Co2ReferenceCorrection {
+    /// Returns a correction leaving the CO2 concentration unchanged.
+    fn default() -> Self {
+        Self::new(1.0, 0.0)
+    }
+}
+
+impl TryFrom<&[u8]> for Co2ReferenceCorrection {
+    type Error = DataError;
+
+    /// Restores the correction from its persisted representation.
+    ///
+    /// # Errors
+    ///
+    /// - [`ReceivedBufferWrongSize`](crate::error::DataError::ReceivedBufferWrongSize): If the
+    ///   data is not the expected size.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let mut reader = ByteReader::new(data);
+        let gain = f32::from_be_bytes(reader.take()?);
+        let offset = f32::from_be_bytes(reader.take()?);
+        reader.finish()?;
+        Ok(Self::new(gain, offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn co2_reference_correction_fits_points() {
+        let correction =
+            Co2ReferenceCorrection::from_points(&[(400, 420), (1_000, 1_050)]).unwrap();
+        assert_eq!(correction.gain(), 1.05);
+        assert_eq!(correction.apply(400), 420);
+        assert_eq!(correction.apply(2_000), 2_100);
+        assert_eq!(
+            correction.apply(Measurement::CO2_UNAVAILABLE),
+            Measurement::CO2_UNAVAILABLE
+        );
+
+        let correction =
+            Co2ReferenceCorrection::from_points(&[(800, 790), (800, 800), (800, 810)]).unwrap();
+        assert_eq!(correction, Co2ReferenceCorrection::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn co2_reference_correction_requires_two_or_three_points() {
+        assert_eq!(
+            Co2ReferenceCorrection::from_points(&[(400, 420)]),
+            Err(DataError::InsufficientData)
+        );
+        assert!(matches!(
+            Co2ReferenceCorrection::from_points(&[(400, 420); 4]),
+            Err(DataError::ValueOutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn co2_reference_correction_roundtrips_through_bytes() {
+        let correction = Co2ReferenceCorrection::new(1.02, -12.5);
+        let data = correction.to_bytes();
+        assert_eq!(
+            Co2ReferenceCorrection::try_from(&data[..]).unwrap(),
+            correction
+        );
+        assert_eq!(
+            Co2ReferenceCorrection::try_from(&data[1..]),
+            Err(DataError::ReceivedBufferWrongSize)
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{
+    correction::CorrectionModel,
+    data::{Channel, Measurement},
+};
+
+/// Host-side correction of the relative humidity, e.g. derived from a comparison against a
+/// reference hygrometer. The sensor offers no humidity offset, so the correction is applied to
+/// read values using:
+/// `RH_Corrected = gain * RH + offset`
+/// Corrected values are limited to 0 - 100 %. Values derived from a corrected measurement, like
+/// the dew point, are thus consistent with the corrected humidity.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HumidityCorrection {
+    offset: f32,
+    gain: f32,
+}
+
+impl HumidityCorrection {
+    /// Creates a new humidity correction:
+    /// - `offset`: Constant humidity offset in %.
+    /// - `gain`: Factor applied to the measured humidity.
+    pub const fn new(offset: f32, gain: f32) -> Self {
+        Self { offset, gain }
+    }
+
+    /// Returns the constant humidity offset in %.
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    /// Returns the factor applied to the measured humidity.
+    pub fn gain(&self) -> f32 {
+        self.gain
+    }
+
+    /// Returns the corrected `relative_humidity` in %. Unavailable values are returned as is.
+    pub fn apply(&self, relative_humidity: f32) -> f32 {
+        if relative_humidity == Measurement::RH_UNAVAILABLE {
+            return relative_humidity;
+        }
+        (self.gain * relative_humidity + self.offset).clamp(0.0, 100.0)
+    }
+}
+
+impl CorrectionModel for HumidityCorrection {
+    fn correct(&self, channel: Channel, value: f32) -> f32 {
+        match channel {
+            Channel::RelativeHumidity => self.apply(value),
+            _ => value,
+        }
+    }
+}
+
+impl Default for HumidityCorrection {
+    /// Returns a correction leaving the humidity unchanged.
+    fn default() -> Self {
+        Self::new(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn humidity_correction_applies_gain_and_offset() {
+        let correction = HumidityCorrection::new(-2.0, 1.1);
+        assert_eq!(correction.apply(50.0), 53.0);
+        assert_eq!(correction.apply(1.0), 0.0);
+        assert_eq!(correction.apply(95.0), 100.0);
+        assert_eq!(
+            correction.apply(Measurement::RH_UNAVAILABLE),
+            Measurement::RH_UNAVAILABLE
+        );
+        assert_eq!(HumidityCorrection::default().apply(42.0), 42.0);
+    }
+}
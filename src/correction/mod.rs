@@ -0,0 +1,14 @@
+//! Host-side corrections of measured values based on reference data.
+
+mod ambient;
+mod co2;
+mod humidity;
+mod model;
+mod temperature;
+
+pub use crate::data::Channel;
+pub use ambient::{AmbientConditions, AmbientConditionsSource};
+pub use co2::Co2ReferenceCorrection;
+pub use humidity::HumidityCorrection;
+pub use model::{ChannelCorrection, CorrectionModel, Polynomial};
+pub use temperature::TemperatureOffsetFit;
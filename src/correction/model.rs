@@ -0,0 +1,221 @@
+use crate::data::{Channel, Measurement, RawMeasurement};
+
+/// Host-side correction of measured values, e.g. derived from a lab calibration. Implementors
+/// provide the correction per [`Channel`], which is then applied uniformly to measurements.
+/// Values which are not yet available are never corrected.
+pub trait CorrectionModel {
+    /// Returns the corrected `value` measured on `channel`.
+    fn correct(&self, channel: Channel, value: f32) -> f32;
+
+    /// Corrects all channels of the `measurement` in place.
+    fn apply(&self, measurement: &mut Measurement) {
+        let pm = |channel, value: &mut f32| {
+            if *value != Measurement::PM_UNAVAILABLE {
+                *value = self.correct(channel, *value);
+            }
+        };
+        pm(Channel::Pm1_0, &mut measurement.pm1_0);
+        pm(Channel::Pm2_5, &mut measurement.pm2_5);
+        pm(Channel::Pm4_0, &mut measurement.pm4_0);
+        pm(Channel::Pm10_0, &mut measurement.pm10_0);
+        correct_available(
+            self,
+            Channel::RelativeHumidity,
+            &mut measurement.relative_humidity,
+            Measurement::RH_UNAVAILABLE,
+        );
+        correct_available(
+            self,
+            Channel::Temperature,
+            &mut measurement.temperature,
+            Measurement::TEMPERATURE_UNAVAILABLE,
+        );
+        correct_available(
+            self,
+            Channel::VocIndex,
+            &mut measurement.voc_index,
+            Measurement::INDEX_UNAVAILABLE,
+        );
+        correct_available(
+            self,
+            Channel::NoxIndex,
+            &mut measurement.nox_index,
+            Measurement::INDEX_UNAVAILABLE,
+        );
+        measurement.co2 = correct_co2(self, measurement.co2);
+    }
+
+    /// Corrects the relative humidity, temperature and CO2 concentration of the raw
+    /// `measurement` in place.
+    fn apply_raw(&self, measurement: &mut RawMeasurement) {
+        correct_available(
+            self,
+            Channel::RelativeHumidity,
+            &mut measurement.relative_humidity,
+            Measurement::RH_UNAVAILABLE,
+        );
+        correct_available(
+            self,
+            Channel::Temperature,
+            &mut measurement.temperature,
+            Measurement::TEMPERATURE_UNAVAILABLE,
+        );
+        measurement.co2 = correct_co2(self, measurement.co2);
+    }
+}
+
+fn correct_available<M: CorrectionModel + ?Sized>(
+    model: &M,
+    channel: Channel,
+    value: &mut f32,
+    unavailable: f32,
+) {
+    if *value != unavailable {
+        *value = model.correct(channel, *value);
+    }
+}
+
+fn correct_co2<M: CorrectionModel + ?Sized>(model: &M, co2: u16) -> u16 {
+    if co2 == Measurement::CO2_UNAVAILABLE {
+        return co2;
+    }
+    let corrected = model.correct(Channel::Co2, f32::from(co2));
+    // Saturates and keeps the result distinguishable from an unavailable value
+    (corrected + 0.5).clamp(0.0, f32::from(Measurement::CO2_UNAVAILABLE - 1)) as u16
+}
+
+/// Polynomial correction `c[0] + c[1] * x + c[2] * x² + ...` with `N` coefficients.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Polynomial<const N: usize> {
+    coefficients: [f32; N],
+}
+
+impl<const N: usize> Polynomial<N> {
+    /// Creates a polynomial from its `coefficients` in ascending order of the power.
+    pub const fn new(coefficients: [f32; N]) -> Self {
+        Self { coefficients }
+    }
+
+    /// Returns the coefficients in ascending order of the power.
+    pub fn coefficients(&self) -> [f32; N] {
+        self.coefficients
+    }
+
+    /// Evaluates the polynomial at `x`.
+    pub fn evaluate(&self, x: f32) -> f32 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |result, coefficient| result * x + coefficient)
+    }
+}
+
+impl Polynomial<2> {
+    /// Creates a linear correction `gain * x + offset`.
+    pub const fn linear(offset: f32, gain: f32) -> Self {
+        Self::new([offset, gain])
+    }
+}
+
+/// [`CorrectionModel`] applying an independent [`Polynomial`] per [`Channel`]. Channels without
+/// a polynomial are left unchanged.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChannelCorrection<const N: usize> {
+    polynomials: [Option<Polynomial<N>>; Channel::COUNT],
+}
+
+impl<const N: usize> ChannelCorrection<N> {
+    /// Creates a model leaving all channels unchanged.
+    pub const fn new() -> Self {
+        Self {
+            polynomials: [None; Channel::COUNT],
+        }
+    }
+
+    /// Returns the model with `polynomial` applied to `channel`.
+    pub const fn with(mut self, channel: Channel, polynomial: Polynomial<N>) -> Self {
+        self.polynomials[channel as usize] = Some(polynomial);
+        self
+    }
+
+    /// Sets the `polynomial` applied to `channel`. `None` leaves the channel unchanged.
+    pub fn set(&mut self, channel: Channel, polynomial: Option<Polynomial<N>>) {
+        self.polynomials[channel as usize] = polynomial;
+    }
+
+    /// Returns the polynomial applied to `channel`.
+    pub fn get(&self, channel: Channel) -> Option<Polynomial<N>> {
+        self.polynomials[channel as usize]
+    }
+}
+
+impl<const N: usize> Default for ChannelCorrection<N> {
+    /// Returns a model leaving all channels unchanged.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> CorrectionModel for ChannelCorrection<N> {
+    fn correct(&self, channel: Channel, value: f32) -> f32 {
+        self.get(channel)
+            .map_or(value, |polynomial| polynomial.evaluate(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement() -> Measurement {
+        Measurement {
+            pm2_5: 2.0,
+            pm4_0: 3.0,
+            pm10_0: 4.0,
+            voc_index: Measurement::INDEX_UNAVAILABLE,
+            ..Measurement::fixture()
+        }
+    }
+
+    #[test]
+    fn polynomial_evaluates_coefficients() {
+        assert_eq!(Polynomial::new([1.0, 2.0, 0.5]).evaluate(2.0), 7.0);
+        assert_eq!(Polynomial::linear(-1.0, 2.0).evaluate(3.0), 5.0);
+        assert_eq!(Polynomial::<0>::new([]).evaluate(3.0), 0.0);
+    }
+
+    #[test]
+    fn channel_correction_applies_per_channel() {
+        let model = ChannelCorrection::new()
+            .with(Channel::Pm2_5, Polynomial::linear(0.0, 2.0))
+            .with(Channel::VocIndex, Polynomial::linear(1.0, 1.0))
+            .with(Channel::Co2, Polynomial::linear(-20.0, 1.0));
+        let mut corrected = measurement();
+        model.apply(&mut corrected);
+        assert_eq!(
+            corrected,
+            Measurement {
+                pm2_5: 4.0,
+                co2: 400,
+                ..measurement()
+            }
+        );
+    }
+
+    #[test]
+    fn co2_correction_saturates() {
+        let model = ChannelCorrection::new().with(Channel::Co2, Polynomial::linear(-500.0, 1.0));
+        let mut raw = RawMeasurement {
+            relative_humidity: 40.0,
+            temperature: 21.0,
+            voc: 0,
+            nox: 0,
+            co2: 420,
+        };
+        model.apply_raw(&mut raw);
+        assert_eq!(raw.co2, 0);
+        raw.co2 = Measurement::CO2_UNAVAILABLE;
+        model.apply_raw(&mut raw);
+        assert_eq!(raw.co2, Measurement::CO2_UNAVAILABLE);
+    }
+}
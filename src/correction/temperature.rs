@@ -0,0 +1,109 @@
+use crate::{configuration::TemperatureOffset, error::DataError};
+
+/// Computes the best-fit [`TemperatureOffset`] from pairs of sensor and reference temperatures
+/// collected during commissioning. The slope and offset minimize the squared error of
+/// `T_Reference = T_Sensor + (slope * T_Sensor) + offset` via least squares.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TemperatureOffsetFit {
+    count: u32,
+    sum_x: f32,
+    sum_y: f32,
+    sum_xx: f32,
+    sum_xy: f32,
+}
+
+impl TemperatureOffsetFit {
+    /// Creates a fit without any samples.
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            sum_x: 0.0,
+            sum_y: 0.0,
+            sum_xx: 0.0,
+            sum_xy: 0.0,
+        }
+    }
+
+    /// Adds a pair of a temperature measured by the sensor and the reference temperature, both
+    /// in °C.
+    pub fn add(&mut self, sensor: f32, reference: f32) {
+        let deviation = reference - sensor;
+        self.count += 1;
+        self.sum_x += sensor;
+        self.sum_y += deviation;
+        self.sum_xx += sensor * sensor;
+        self.sum_xy += sensor * deviation;
+    }
+
+    /// Returns the number of samples added.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Returns the fitted `(slope, offset)` with the offset in °C. If all samples were taken at
+    /// the same sensor temperature, only a constant offset is fitted. `None` if no samples were
+    /// added.
+    pub fn coefficients(&self) -> Option<(f32, f32)> {
+        if self.count == 0 {
+            return None;
+        }
+        let n = self.count as f32;
+        let mean_x = self.sum_x / n;
+        let mean_y = self.sum_y / n;
+        let variance = self.sum_xx / n - mean_x * mean_x;
+        if variance <= f32::EPSILON * self.sum_xx / n {
+            return Some((0.0, mean_y));
+        }
+        let slope = (self.sum_xy / n - mean_x * mean_y) / variance;
+        Some((slope, mean_y - slope * mean_x))
+    }
+
+    /// Returns the fitted [`TemperatureOffset`] for the given `time_constant` and `slot`.
+    ///
+    /// # Errors
+    ///
+    /// - [`InsufficientData`](crate::error::DataError::InsufficientData): If no samples were
+    ///   added.
+    /// - [`ValueOutOfRange`](crate::error::DataError::ValueOutOfRange): If the fitted values or
+    ///   the slot are not in range.
+    pub fn fit(&self, time_constant: u16, slot: u16) -> Result<TemperatureOffset, DataError> {
+        let (slope, offset) = self.coefficients().ok_or(DataError::InsufficientData)?;
+        TemperatureOffset::from_f32(offset, slope, time_constant, slot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fit_recovers_slope_and_offset() {
+        let mut fit = TemperatureOffsetFit::new();
+        for sensor in [18.0, 21.0, 24.0, 27.0] {
+            fit.add(sensor, sensor + 0.01 * sensor - 1.5);
+        }
+        let (slope, offset) = fit.coefficients().unwrap();
+        assert!((slope - 0.01).abs() < 1e-4);
+        assert!((offset + 1.5).abs() < 1e-3);
+        assert_eq!(
+            fit.fit(0, 1),
+            Ok(TemperatureOffset::from_f32(-1.5, 0.01, 0, 1).unwrap())
+        );
+    }
+
+    #[test]
+    fn fit_with_constant_sensor_temperature_only_fits_offset() {
+        let mut fit = TemperatureOffsetFit::new();
+        fit.add(25.0, 23.0);
+        fit.add(25.0, 23.5);
+        assert_eq!(fit.coefficients(), Some((0.0, -1.75)));
+    }
+
+    #[test]
+    fn fit_without_samples_fails() {
+        assert_eq!(
+            TemperatureOffsetFit::new().fit(0, 0),
+            Err(DataError::InsufficientData)
+        );
+    }
+}
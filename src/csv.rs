@@ -0,0 +1,193 @@
+//! CSV rows of measurements in a fixed column order, e.g. for SD card loggers.
+//!
+//! Each row is terminated with `\n` and starts with the columns listed in the header of its
+//! type, so a log is written by writing the header once and appending rows. Values of a
+//! [`Measurement`] which are not yet available are written as empty fields.
+
+use core::fmt::{Result, Write};
+
+use crate::{
+    data::{Channel, Concentrations, Measurement, RawMeasurement, Timestamped},
+    sink::MeasurementSink,
+};
+
+impl Measurement {
+    /// Returns the header line of the rows written by
+    /// [`write_csv_row`](Measurement::write_csv_row).
+    pub const fn csv_header() -> &'static str {
+        "pm1_0_ug_m3,pm2_5_ug_m3,pm4_0_ug_m3,pm10_0_ug_m3,relative_humidity_pct,temperature_c,\
+         voc_index,nox_index,co2_ppm\n"
+    }
+
+    /// Writes the measurement as one CSV row into `writer`.
+    ///
+    /// # Errors
+    ///
+    /// - [`core::fmt::Error`]: If the writer fails, e.g. because it is full.
+    pub fn write_csv_row<W: Write>(&self, writer: &mut W) -> Result {
+        // The CO2 concentration is the last channel and written as integer.
+        for channel in &Channel::ALL[..Channel::COUNT - 1] {
+            if let Some(value) = channel.value(self) {
+                write!(writer, "{value}")?;
+            }
+            writer.write_char(',')?;
+        }
+        if self.co2 != Measurement::CO2_UNAVAILABLE {
+            write!(writer, "{}", self.co2)?;
+        }
+        writer.write_char('\n')
+    }
+}
+
+impl RawMeasurement {
+    /// Returns the header line of the rows written by
+    /// [`write_csv_row`](RawMeasurement::write_csv_row).
+    pub const fn csv_header() -> &'static str {
+        "relative_humidity_pct,temperature_c,voc_ticks,nox_ticks,co2_ppm\n"
+    }
+
+    /// Writes the raw measurement as one CSV row into `writer`. The CO2 concentration is written
+    /// as empty field during the warm-up after power-on.
+    ///
+    /// # Errors
+    ///
+    /// - [`core::fmt::Error`]: If the writer fails, e.g. because it is full.
+    pub fn write_csv_row<W: Write>(&self, writer: &mut W) -> Result {
+        write!(
+            writer,
+            "{},{},{},{},",
+            self.relative_humidity, self.temperature, self.voc, self.nox
+        )?;
+        if self.co2_valid() {
+            write!(writer, "{}", self.co2)?;
+        }
+        writer.write_char('\n')
+    }
+}
+
+impl Concentrations {
+    /// Returns the header line of the rows written by
+    /// [`write_csv_row`](Concentrations::write_csv_row).
+    pub const fn csv_header() -> &'static str {
+        "pm0_5_p_cm3,pm1_0_p_cm3,pm2_5_p_cm3,pm4_0_p_cm3,pm10_0_p_cm3\n"
+    }
+
+    /// Writes the concentrations as one CSV row into `writer`.
+    ///
+    /// # Errors
+    ///
+    /// - [`core::fmt::Error`]: If the writer fails, e.g. because it is full.
+    pub fn write_csv_row<W: Write>(&self, writer: &mut W) -> Result {
+        writeln!(
+            writer,
+            "{},{},{},{},{}",
+            self.pm0_5, self.pm1_0, self.pm2_5, self.pm4_0, self.pm10_0
+        )
+    }
+}
+
+/// [`MeasurementSink`] appending each measurement as CSV row to `writer`, e.g. a file on an SD
+/// card. Each row starts with the monotonic timestamp of the measurement in ms.
+#[derive(Debug)]
+pub struct CsvSink<W> {
+    writer: W,
+}
+
+impl<W: Write> CsvSink<W> {
+    /// Creates a sink appending rows to `writer`.
+    pub const fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Writes the header line of the rows, e.g. when starting a new log.
+    ///
+    /// # Errors
+    ///
+    /// - [`core::fmt::Error`]: If the writer fails, e.g. because it is full.
+    pub fn write_header(&mut self) -> Result {
+        write!(self.writer, "timestamp_ms,{}", Measurement::csv_header())
+    }
+
+    /// Releases the writer.
+    pub fn release(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> MeasurementSink for CsvSink<W> {
+    type Error = core::fmt::Error;
+
+    fn publish(&mut self, measurement: &Timestamped<Measurement>) -> Result {
+        write!(self.writer, "{},", measurement.timestamp_ms)?;
+        measurement.value.write_csv_row(&mut self.writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement() -> Measurement {
+        Measurement {
+            pm2_5: 2.5,
+            pm4_0: 3.0,
+            pm10_0: 4.0,
+            temperature: 21.5,
+            nox_index: Measurement::INDEX_UNAVAILABLE,
+            ..Measurement::fixture()
+        }
+    }
+
+    #[test]
+    fn rows_match_header_columns() {
+        let mut row = String::new();
+        measurement().write_csv_row(&mut row).unwrap();
+        assert_eq!(row, "1,2.5,3,4,40,21.5,100,,420\n");
+        assert_eq!(
+            Measurement::csv_header().matches(',').count(),
+            row.matches(',').count()
+        );
+
+        let mut row = String::new();
+        RawMeasurement {
+            relative_humidity: 40.0,
+            temperature: 21.5,
+            voc: 30_000,
+            nox: 15_000,
+            co2: 600,
+        }
+        .write_csv_row(&mut row)
+        .unwrap();
+        assert_eq!(row, "40,21.5,30000,15000,600\n");
+        assert_eq!(
+            RawMeasurement::csv_header().matches(',').count(),
+            row.matches(',').count()
+        );
+
+        let mut row = String::new();
+        RawMeasurement {
+            relative_humidity: 40.0,
+            temperature: 21.5,
+            voc: 30_000,
+            nox: 15_000,
+            co2: 0xFFFF,
+        }
+        .write_csv_row(&mut row)
+        .unwrap();
+        assert_eq!(row, "40,21.5,30000,15000,\n");
+    }
+
+    #[test]
+    fn csv_sink_appends_timestamped_rows() {
+        let mut sink = CsvSink::new(String::new());
+        sink.write_header().unwrap();
+        sink.publish(&Timestamped::new(1_000, measurement()))
+            .unwrap();
+        assert_eq!(
+            sink.release(),
+            "timestamp_ms,pm1_0_ug_m3,pm2_5_ug_m3,pm4_0_ug_m3,pm10_0_ug_m3,\
+             relative_humidity_pct,temperature_c,voc_index,nox_index,co2_ppm\n\
+             1000,1,2.5,3,4,40,21.5,100,,420\n"
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{
+    configuration::{AmbientPressure, Co2Correction, SensorAltitude, TargetCO2Concentration},
+    data::{AscState, SerialNumber},
+};
+
+/// Record of a forced CO2 recalibration (FRC) and the CO2 compensation settings in effect, e.g.
+/// to be stored by quality assurance after factory calibration.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CalibrationReport {
+    /// Serial number of the calibrated sensor.
+    pub serial_number: SerialNumber,
+    /// CO2 concentration the sensor was recalibrated to.
+    pub target: TargetCO2Concentration,
+    /// Correction returned by the sensor for the recalibration.
+    pub correction: Co2Correction,
+    /// State of the automatic self calibration (ASC).
+    pub asc_state: AscState,
+    /// Sensor altitude configured for CO2 compensation.
+    pub sensor_altitude: SensorAltitude,
+    /// Ambient pressure configured for CO2 compensation.
+    pub ambient_pressure: AmbientPressure,
+    /// Time of the calibration. Its unit and epoch are chosen by the user, e.g. seconds since the
+    /// Unix epoch.
+    pub timestamp: u64,
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calibration_report_roundtrips_through_postcard() {
+        let mut data = vec![0x31, 0x32, 0x60, 0x33, 0x00, 0x1E];
+        data.extend([0x00, 0x00, 0x81].repeat(14));
+        let report = CalibrationReport {
+            serial_number: SerialNumber::try_from(&data[..]).unwrap(),
+            target: TargetCO2Concentration::from(420),
+            correction: Co2Correction::try_from(&[0x80, 0x0A, 0x79][..]).unwrap(),
+            asc_state: AscState::Disabled,
+            sensor_altitude: SensorAltitude::try_from(350).unwrap(),
+            ambient_pressure: AmbientPressure::default(),
+            timestamp: 1_700_000_000,
+        };
+
+        let mut buffer = [0; 64];
+        let encoded = postcard::to_slice(&report, &mut buffer).unwrap();
+        assert_eq!(
+            postcard::from_bytes::<CalibrationReport>(encoded).unwrap(),
+            report
+        );
+    }
+
+    #[test]
+    fn calibration_report_rejects_out_of_range_settings() {
+        let mut buffer = [0; 8];
+        let encoded = postcard::to_slice(&5_000u16, &mut buffer).unwrap();
+        assert!(postcard::from_bytes::<SensorAltitude>(encoded).is_err());
+    }
+}
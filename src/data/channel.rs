@@ -0,0 +1,149 @@
+use crate::data::Measurement;
+
+/// Measured quantity of a [`Measurement`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Channel {
+    /// Mass concentration for PM1.0 in ug/m³.
+    Pm1_0,
+    /// Mass concentration for PM2.5 in ug/m³.
+    Pm2_5,
+    /// Mass concentration for PM4.0 in ug/m³.
+    Pm4_0,
+    /// Mass concentration for PM10.0 in ug/m³.
+    Pm10_0,
+    /// Relative Humidity in %.
+    RelativeHumidity,
+    /// Temperature in °C.
+    Temperature,
+    /// VOC Index.
+    VocIndex,
+    /// NOx Index.
+    NoxIndex,
+    /// CO2 concentration in ppm.
+    Co2,
+}
+
+impl Channel {
+    /// Number of channels.
+    pub const COUNT: usize = 9;
+    /// All channels in the order of the measurement.
+    pub const ALL: [Channel; Channel::COUNT] = [
+        Channel::Pm1_0,
+        Channel::Pm2_5,
+        Channel::Pm4_0,
+        Channel::Pm10_0,
+        Channel::RelativeHumidity,
+        Channel::Temperature,
+        Channel::VocIndex,
+        Channel::NoxIndex,
+        Channel::Co2,
+    ];
+
+    /// Returns the value of the channel in the `measurement`. `None` if the value is not yet
+    /// available.
+    pub fn value(self, measurement: &Measurement) -> Option<f32> {
+        let (value, unavailable) = match self {
+            Channel::Pm1_0 => (measurement.pm1_0, Measurement::PM_UNAVAILABLE),
+            Channel::Pm2_5 => (measurement.pm2_5, Measurement::PM_UNAVAILABLE),
+            Channel::Pm4_0 => (measurement.pm4_0, Measurement::PM_UNAVAILABLE),
+            Channel::Pm10_0 => (measurement.pm10_0, Measurement::PM_UNAVAILABLE),
+            Channel::RelativeHumidity => {
+                (measurement.relative_humidity, Measurement::RH_UNAVAILABLE)
+            }
+            Channel::Temperature => (
+                measurement.temperature,
+                Measurement::TEMPERATURE_UNAVAILABLE,
+            ),
+            Channel::VocIndex => (measurement.voc_index, Measurement::INDEX_UNAVAILABLE),
+            Channel::NoxIndex => (measurement.nox_index, Measurement::INDEX_UNAVAILABLE),
+            Channel::Co2 => {
+                return (measurement.co2 != Measurement::CO2_UNAVAILABLE)
+                    .then_some(f32::from(measurement.co2));
+            }
+        };
+        (value != unavailable).then_some(value)
+    }
+}
+
+/// Set of [`Channel`]s, e.g. the channels of a measurement holding valid values as returned by
+/// [`valid_fields`](Measurement::valid_fields).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ChannelSet(u16);
+
+impl ChannelSet {
+    /// Set of no channels.
+    pub const EMPTY: ChannelSet = ChannelSet(0);
+    /// Set of all channels.
+    pub const ALL: ChannelSet = ChannelSet((1 << Channel::COUNT) - 1);
+
+    /// Returns the set with `channel` added.
+    pub const fn with(self, channel: Channel) -> Self {
+        Self(self.0 | 1 << channel as u16)
+    }
+
+    /// Returns whether `channel` is in the set.
+    pub const fn contains(self, channel: Channel) -> bool {
+        self.0 & 1 << channel as u16 != 0
+    }
+
+    /// Returns the number of channels in the set.
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns whether the set contains no channels.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the channels in the set in the order of the measurement.
+    pub fn iter(self) -> impl Iterator<Item = Channel> {
+        Channel::ALL
+            .into_iter()
+            .filter(move |&channel| self.contains(channel))
+    }
+}
+
+impl FromIterator<Channel> for ChannelSet {
+    fn from_iter<T: IntoIterator<Item = Channel>>(iter: T) -> Self {
+        iter.into_iter().fold(Self::EMPTY, Self::with)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn channel_value_skips_unavailable_values() {
+        let measurement = Measurement {
+            pm2_5: Measurement::PM_UNAVAILABLE,
+            nox_index: Measurement::INDEX_UNAVAILABLE,
+            ..Measurement::fixture()
+        };
+        assert_eq!(Channel::Pm1_0.value(&measurement), Some(1.0));
+        assert_eq!(Channel::Pm2_5.value(&measurement), None);
+        assert_eq!(Channel::NoxIndex.value(&measurement), None);
+        assert_eq!(Channel::Co2.value(&measurement), Some(420.0));
+    }
+
+    #[test]
+    fn channel_set_tracks_contained_channels() {
+        let set = ChannelSet::EMPTY
+            .with(Channel::Temperature)
+            .with(Channel::Co2);
+        assert!(set.contains(Channel::Co2));
+        assert!(!set.contains(Channel::VocIndex));
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.iter().collect::<ChannelSet>(), set);
+        assert_eq!(
+            Channel::ALL.into_iter().collect::<ChannelSet>(),
+            ChannelSet::ALL
+        );
+        assert!(ChannelSet::EMPTY.is_empty());
+    }
+}
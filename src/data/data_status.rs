@@ -1,4 +1,4 @@
-use crate::{error::DataError, util::check_deserialization};
+use crate::{error::DataError, util::decode_words};
 
 const DATA_STATUS_VALUE: &str = "Data ready status";
 const DATA_STATUS_EXPECTED: &str = "0 or 1";
@@ -36,14 +36,14 @@ impl TryFrom<&[u8]> for DataStatus {
     /// - [UnexpectedValueReceived](crate::error::DataError::UnexpectedValueReceived) if the
     ///   received value is not `0` or `1`.
     fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
-        check_deserialization(data, 3)?;
-        match data[1] {
-            0x00 => Ok(Self::NotReady),
-            0x01 => Ok(Self::Ready),
-            val => Err(DataError::UnexpectedValueReceived {
+        let [value] = decode_words(data)?;
+        match value.to_be_bytes() {
+            [_, 0x00] => Ok(Self::NotReady),
+            [_, 0x01] => Ok(Self::Ready),
+            [_, val] => Err(DataError::UnexpectedValueReceived {
                 parameter: DATA_STATUS_VALUE,
                 expected: DATA_STATUS_EXPECTED,
-                actual: val as u16,
+                actual: u16::from(val),
             }),
         }
     }
@@ -1,8 +1,18 @@
-use crate::{error::DataError, util::check_deserialization};
+use core::fmt;
+
+use crate::{
+    configuration::AmbientPressure,
+    data::{Channel, ChannelSet},
+    error::DataError,
+    util::decode_words,
+};
 
 /// One measurement taken from the SEN66. Use
 /// [`read_measured_values`](crate::asynch::Sen66::read_measured_values) to retrieve it.
-#[derive(Debug, PartialEq)]
+/// With the `serde` feature, recorded measurements can be loaded back, e.g. to replay them through
+/// the analytics on the host.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Measurement {
     /// Mass concentration for PM1.0 in ug/m³.
     pub pm1_0: f32,
@@ -36,21 +46,141 @@ impl TryFrom<&[u8]> for Measurement {
     /// - [`ReceivedBufferWrongSize`](crate::error::DataError::ReceivedBufferWrongSize): If the
     ///   received data buffer is not the expected size.
     fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
-        check_deserialization(data, 27)?;
+        let [
+            pm1_0,
+            pm2_5,
+            pm4_0,
+            pm10_0,
+            rh,
+            temperature,
+            voc_index,
+            nox_index,
+            co2,
+        ] = decode_words(data)?;
         Ok(Self {
-            pm1_0: u16::from_be_bytes([data[0], data[1]]) as f32 / 10.,
-            pm2_5: u16::from_be_bytes([data[3], data[4]]) as f32 / 10.,
-            pm4_0: u16::from_be_bytes([data[6], data[7]]) as f32 / 10.,
-            pm10_0: u16::from_be_bytes([data[9], data[10]]) as f32 / 10.,
-            relative_humidity: i16::from_be_bytes([data[12], data[13]]) as f32 / 100.,
-            temperature: i16::from_be_bytes([data[15], data[16]]) as f32 / 200.,
-            voc_index: i16::from_be_bytes([data[18], data[19]]) as f32 / 10.,
-            nox_index: i16::from_be_bytes([data[21], data[22]]) as f32 / 10.,
-            co2: u16::from_be_bytes([data[24], data[25]]),
+            pm1_0: pm1_0 as f32 / 10.,
+            pm2_5: pm2_5 as f32 / 10.,
+            pm4_0: pm4_0 as f32 / 10.,
+            pm10_0: pm10_0 as f32 / 10.,
+            relative_humidity: rh as i16 as f32 / 100.,
+            temperature: temperature as i16 as f32 / 200.,
+            voc_index: voc_index as i16 as f32 / 10.,
+            nox_index: nox_index as i16 as f32 / 10.,
+            co2,
         })
     }
 }
 
+impl Measurement {
+    /// Raw value reported for the mass concentrations while they are not yet available.
+    pub(crate) const PM_UNAVAILABLE: f32 = u16::MAX as f32 / 10.;
+    /// Raw value reported for the relative humidity while it is not yet available.
+    pub(crate) const RH_UNAVAILABLE: f32 = i16::MAX as f32 / 100.;
+    /// Raw value reported for the temperature while it is not yet available.
+    pub(crate) const TEMPERATURE_UNAVAILABLE: f32 = i16::MAX as f32 / 200.;
+    /// Raw value reported for the VOC and NOx index while it is not yet available.
+    pub(crate) const INDEX_UNAVAILABLE: f32 = i16::MAX as f32 / 10.;
+    /// Raw value reported for the CO2 concentration while it is not yet available.
+    pub(crate) const CO2_UNAVAILABLE: u16 = 0xFFFF;
+
+    /// Returns whether all values are within the sensor's specified output ranges. The VOC index,
+    /// NOx index and CO2 concentration are considered plausible while not yet available, as they
+    /// take longer to settle after starting a measurement. Unavailable mass concentrations,
+    /// humidity or temperature are out of range, e.g. failing a
+    /// [`SelfTestReport`](crate::data::SelfTestReport) taken before the first data point.
+    pub fn is_plausible(&self) -> bool {
+        let index_plausible =
+            |index: f32| (1.0..=500.0).contains(&index) || index == Self::INDEX_UNAVAILABLE;
+        [self.pm1_0, self.pm2_5, self.pm4_0, self.pm10_0]
+            .iter()
+            .all(|pm| (0.0..=1_000.0).contains(pm))
+            && (0.0..=100.0).contains(&self.relative_humidity)
+            && (-10.0..=50.0).contains(&self.temperature)
+            && index_plausible(self.voc_index)
+            && index_plausible(self.nox_index)
+            && (self.co2 <= 40_000 || self.co2 == Self::CO2_UNAVAILABLE)
+    }
+
+    /// Returns the CO2 concentration in ppm compensated on the host for the ambient
+    /// `pressure_hpa` in hPa, e.g. if the barometer is attached to a different node. Assumes the
+    /// sensor uses its default ambient pressure of 1,013 hPa and altitude of 0 m. The measured
+    /// concentration scales with the gas density, so it is scaled by the ratio of the assumed to
+    /// the actual pressure. `None` if the CO2 concentration is not available or the pressure is
+    /// not positive.
+    pub fn co2_corrected(&self, pressure_hpa: f32) -> Option<u16> {
+        let assumed_hpa = f32::from(u16::from(AmbientPressure::default()));
+        (self.co2 != Self::CO2_UNAVAILABLE && pressure_hpa > 0.0).then(|| {
+            let co2 = f32::from(self.co2) * assumed_hpa / pressure_hpa;
+            (co2 + 0.5).clamp(0.0, f32::from(Self::CO2_UNAVAILABLE - 1)) as u16
+        })
+    }
+
+    /// Returns the channels holding valid values. A channel is invalid while the sensor reports
+    /// the maximum raw value for it, e.g. while it is still conditioning the NOx channel after
+    /// starting a measurement.
+    pub fn valid_fields(&self) -> ChannelSet {
+        Channel::ALL
+            .into_iter()
+            .filter(|channel| channel.value(self).is_some())
+            .collect()
+    }
+
+    /// Returns whether all channels hold valid values.
+    pub fn is_complete(&self) -> bool {
+        self.valid_fields() == ChannelSet::ALL
+    }
+
+    /// Returns the measurement with the values which are not yet available set to `None`. The
+    /// sensor reports the maximum raw value for them, e.g. 6553.5 ug/m³ for the mass
+    /// concentrations, until it produced the first data point after starting a measurement.
+    pub fn validated(&self) -> ValidatedMeasurement {
+        ValidatedMeasurement {
+            pm1_0: Channel::Pm1_0.value(self),
+            pm2_5: Channel::Pm2_5.value(self),
+            pm4_0: Channel::Pm4_0.value(self),
+            pm10_0: Channel::Pm10_0.value(self),
+            relative_humidity: Channel::RelativeHumidity.value(self),
+            temperature: Channel::Temperature.value(self),
+            voc_index: Channel::VocIndex.value(self),
+            nox_index: Channel::NoxIndex.value(self),
+            co2: (self.co2 != Self::CO2_UNAVAILABLE).then_some(self.co2),
+        }
+    }
+}
+
+/// One measurement taken from the SEN66 with the values which are not yet available set to
+/// `None`, instead of the maximum raw value reported by the sensor. Obtained with
+/// [`validated`](Measurement::validated).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ValidatedMeasurement {
+    /// Mass concentration for PM1.0 in ug/m³.
+    pub pm1_0: Option<f32>,
+    /// Mass concentration for PM2.5 in ug/m³.
+    pub pm2_5: Option<f32>,
+    /// Mass concentration for PM4.0 in ug/m³.
+    pub pm4_0: Option<f32>,
+    /// Mass concentration for PM10.0 in ug/m³.
+    pub pm10_0: Option<f32>,
+    /// Relative Humidity in %.
+    pub relative_humidity: Option<f32>,
+    /// Temperature in °C.
+    pub temperature: Option<f32>,
+    /// VOC Index.
+    pub voc_index: Option<f32>,
+    /// NOx Index.
+    pub nox_index: Option<f32>,
+    /// CO2 concentration in ppm.
+    pub co2: Option<u16>,
+}
+
+impl From<Measurement> for ValidatedMeasurement {
+    fn from(measurement: Measurement) -> Self {
+        measurement.validated()
+    }
+}
+
 #[cfg(feature = "defmt")]
 impl defmt::Format for Measurement {
     fn format(&self, f: defmt::Formatter) {
@@ -78,9 +208,387 @@ CO2:       {} ppm",
     }
 }
 
+impl fmt::Display for Measurement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "PM1.0:     {} ug/m³
+PM2.5:     {} ug/m³
+PM4.0:     {} ug/m³
+PM10.0:    {} ug/m³
+RH:        {} %
+Temp:      {} °C
+VOC Index: {} / 1
+NOx Index: {} / 100
+CO2:       {} ppm",
+            self.pm1_0,
+            self.pm2_5,
+            self.pm4_0,
+            self.pm10_0,
+            self.relative_humidity,
+            self.temperature,
+            self.voc_index,
+            self.nox_index,
+            self.co2
+        )
+    }
+}
+
+/// One measurement taken from the SEN66, keeping the sensor's scaled integers instead of
+/// converting them to `f32`, e.g. to avoid soft-float routines on targets without FPU. Use
+/// [`read_measured_values_fixed`](crate::asynch::Sen66::read_measured_values_fixed) to retrieve
+/// it. Values which are not yet available are set to their maximum value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MeasurementFixed {
+    /// Mass concentration for PM1.0 in ug/m³. Scale factor: 10
+    pub pm1_0: u16,
+    /// Mass concentration for PM2.5 in ug/m³. Scale factor: 10
+    pub pm2_5: u16,
+    /// Mass concentration for PM4.0 in ug/m³. Scale factor: 10
+    pub pm4_0: u16,
+    /// Mass concentration for PM10.0 in ug/m³. Scale factor: 10
+    pub pm10_0: u16,
+    /// Relative Humidity in %. Scale factor: 100
+    pub relative_humidity: i16,
+    /// Temperature in °C. Scale factor: 200
+    pub temperature: i16,
+    /// VOC Index. Scale factor: 10
+    pub voc_index: i16,
+    /// NOx Index. Scale factor: 10
+    pub nox_index: i16,
+    /// CO2 concentration in ppm.
+    pub co2: u16,
+}
+
+impl MeasurementFixed {
+    /// Scale factor of the mass concentrations.
+    pub const PM_SCALE: u16 = 10;
+    /// Scale factor of the relative humidity.
+    pub const RH_SCALE: i16 = 100;
+    /// Scale factor of the temperature.
+    pub const TEMPERATURE_SCALE: i16 = 200;
+    /// Scale factor of the VOC and NOx index.
+    pub const INDEX_SCALE: i16 = 10;
+}
+
+impl TryFrom<&[u8]> for MeasurementFixed {
+    type Error = DataError;
+
+    /// Parse the measurement from the received data.
+    ///
+    /// # Errors
+    ///
+    /// - [`CrcFailed`](crate::error::DataError::CrcFailed): If the received data CRC indicates
+    ///   corruption.
+    /// - [`ReceivedBufferWrongSize`](crate::error::DataError::ReceivedBufferWrongSize): If the
+    ///   received data buffer is not the expected size.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let [
+            pm1_0,
+            pm2_5,
+            pm4_0,
+            pm10_0,
+            rh,
+            temperature,
+            voc_index,
+            nox_index,
+            co2,
+        ] = decode_words(data)?;
+        Ok(Self {
+            pm1_0,
+            pm2_5,
+            pm4_0,
+            pm10_0,
+            relative_humidity: rh as i16,
+            temperature: temperature as i16,
+            voc_index: voc_index as i16,
+            nox_index: nox_index as i16,
+            co2,
+        })
+    }
+}
+
+impl From<MeasurementFixed> for Measurement {
+    /// Converts the scaled integers to `f32`, equal to parsing the received data directly.
+    fn from(fixed: MeasurementFixed) -> Self {
+        let pm = |value: u16| value as f32 / f32::from(MeasurementFixed::PM_SCALE);
+        let index = |value: i16| value as f32 / f32::from(MeasurementFixed::INDEX_SCALE);
+        Self {
+            pm1_0: pm(fixed.pm1_0),
+            pm2_5: pm(fixed.pm2_5),
+            pm4_0: pm(fixed.pm4_0),
+            pm10_0: pm(fixed.pm10_0),
+            relative_humidity: fixed.relative_humidity as f32
+                / f32::from(MeasurementFixed::RH_SCALE),
+            temperature: fixed.temperature as f32 / f32::from(MeasurementFixed::TEMPERATURE_SCALE),
+            voc_index: index(fixed.voc_index),
+            nox_index: index(fixed.nox_index),
+            co2: fixed.co2,
+        }
+    }
+}
+
+/// One measurement taken from the SEN63C. Use
+/// [`read_measured_values`](crate::asynch::Sen6x::read_measured_values) to retrieve it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sen63cMeasurement {
+    /// Mass concentration for PM1.0 in ug/m³.
+    pub pm1_0: f32,
+    /// Mass concentration for PM2.5 in ug/m³.
+    pub pm2_5: f32,
+    /// Mass concentration for PM4.0 in ug/m³.
+    pub pm4_0: f32,
+    /// Mass concentration for PM10.0 in ug/m³.
+    pub pm10_0: f32,
+    /// Relative Humidity in %.
+    pub relative_humidity: f32,
+    /// Temperature in °C.
+    pub temperature: f32,
+    /// CO2 concentration in ppm.
+    pub co2: u16,
+}
+
+impl TryFrom<&[u8]> for Sen63cMeasurement {
+    type Error = DataError;
+
+    /// Parse the measurement from the received data.
+    ///
+    /// # Errors
+    ///
+    /// - [`CrcFailed`](crate::error::DataError::CrcFailed): If the received data CRC indicates
+    ///   corruption.
+    /// - [`ReceivedBufferWrongSize`](crate::error::DataError::ReceivedBufferWrongSize): If the
+    ///   received data buffer is not the expected size.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let [pm1_0, pm2_5, pm4_0, pm10_0, rh, temperature, co2] = decode_words(data)?;
+        Ok(Self {
+            pm1_0: pm1_0 as f32 / 10.,
+            pm2_5: pm2_5 as f32 / 10.,
+            pm4_0: pm4_0 as f32 / 10.,
+            pm10_0: pm10_0 as f32 / 10.,
+            relative_humidity: rh as i16 as f32 / 100.,
+            temperature: temperature as i16 as f32 / 200.,
+            co2,
+        })
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Sen63cMeasurement {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "PM1.0:     {} ug/m³
+PM2.5:     {} ug/m³
+PM4.0:     {} ug/m³
+PM10.0:    {} ug/m³
+RH:        {} %
+Temp:      {} °C
+CO2:       {} ppm",
+            self.pm1_0,
+            self.pm2_5,
+            self.pm4_0,
+            self.pm10_0,
+            self.relative_humidity,
+            self.temperature,
+            self.co2
+        )
+    }
+}
+
+/// One measurement taken from the SEN65. Use
+/// [`read_measured_values`](crate::asynch::Sen6x::read_measured_values) to retrieve it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sen65Measurement {
+    /// Mass concentration for PM1.0 in ug/m³.
+    pub pm1_0: f32,
+    /// Mass concentration for PM2.5 in ug/m³.
+    pub pm2_5: f32,
+    /// Mass concentration for PM4.0 in ug/m³.
+    pub pm4_0: f32,
+    /// Mass concentration for PM10.0 in ug/m³.
+    pub pm10_0: f32,
+    /// Relative Humidity in %.
+    pub relative_humidity: f32,
+    /// Temperature in °C.
+    pub temperature: f32,
+    /// VOC Index.
+    pub voc_index: f32,
+    /// NOx Index.
+    pub nox_index: f32,
+}
+
+impl TryFrom<&[u8]> for Sen65Measurement {
+    type Error = DataError;
+
+    /// Parse the measurement from the received data.
+    ///
+    /// # Errors
+    ///
+    /// - [`CrcFailed`](crate::error::DataError::CrcFailed): If the received data CRC indicates
+    ///   corruption.
+    /// - [`ReceivedBufferWrongSize`](crate::error::DataError::ReceivedBufferWrongSize): If the
+    ///   received data buffer is not the expected size.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let [
+            pm1_0,
+            pm2_5,
+            pm4_0,
+            pm10_0,
+            rh,
+            temperature,
+            voc_index,
+            nox_index,
+        ] = decode_words(data)?;
+        Ok(Self {
+            pm1_0: pm1_0 as f32 / 10.,
+            pm2_5: pm2_5 as f32 / 10.,
+            pm4_0: pm4_0 as f32 / 10.,
+            pm10_0: pm10_0 as f32 / 10.,
+            relative_humidity: rh as i16 as f32 / 100.,
+            temperature: temperature as i16 as f32 / 200.,
+            voc_index: voc_index as i16 as f32 / 10.,
+            nox_index: nox_index as i16 as f32 / 10.,
+        })
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Sen65Measurement {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "PM1.0:     {} ug/m³
+PM2.5:     {} ug/m³
+PM4.0:     {} ug/m³
+PM10.0:    {} ug/m³
+RH:        {} %
+Temp:      {} °C
+VOC Index: {} / 1
+NOx Index: {} / 100",
+            self.pm1_0,
+            self.pm2_5,
+            self.pm4_0,
+            self.pm10_0,
+            self.relative_humidity,
+            self.temperature,
+            self.voc_index,
+            self.nox_index
+        )
+    }
+}
+
+/// One measurement taken from the SEN68, which reports the formaldehyde concentration instead of
+/// the CO2 concentration. Use [`read_measured_values`](crate::asynch::Sen6x::read_measured_values)
+/// to retrieve it.
+#[cfg(feature = "sen68")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sen68Measurement {
+    /// Mass concentration for PM1.0 in ug/m³.
+    pub pm1_0: f32,
+    /// Mass concentration for PM2.5 in ug/m³.
+    pub pm2_5: f32,
+    /// Mass concentration for PM4.0 in ug/m³.
+    pub pm4_0: f32,
+    /// Mass concentration for PM10.0 in ug/m³.
+    pub pm10_0: f32,
+    /// Relative Humidity in %.
+    pub relative_humidity: f32,
+    /// Temperature in °C.
+    pub temperature: f32,
+    /// VOC Index.
+    pub voc_index: f32,
+    /// NOx Index.
+    pub nox_index: f32,
+    /// Formaldehyde (HCHO) concentration in ppb.
+    pub hcho: f32,
+}
+
+#[cfg(feature = "sen68")]
+impl Sen68Measurement {
+    /// Raw value reported for the formaldehyde concentration while it is not yet available.
+    pub(crate) const HCHO_UNAVAILABLE: f32 = u16::MAX as f32 / 10.;
+
+    /// Returns the formaldehyde concentration in ppb. `None` if it is not yet available.
+    pub fn hcho(&self) -> Option<f32> {
+        (self.hcho != Self::HCHO_UNAVAILABLE).then_some(self.hcho)
+    }
+}
+
+#[cfg(feature = "sen68")]
+impl TryFrom<&[u8]> for Sen68Measurement {
+    type Error = DataError;
+
+    /// Parse the measurement from the received data.
+    ///
+    /// # Errors
+    ///
+    /// - [`CrcFailed`](crate::error::DataError::CrcFailed): If the received data CRC indicates
+    ///   corruption.
+    /// - [`ReceivedBufferWrongSize`](crate::error::DataError::ReceivedBufferWrongSize): If the
+    ///   received data buffer is not the expected size.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let [
+            pm1_0,
+            pm2_5,
+            pm4_0,
+            pm10_0,
+            rh,
+            temperature,
+            voc_index,
+            nox_index,
+            hcho,
+        ] = decode_words(data)?;
+        Ok(Self {
+            pm1_0: pm1_0 as f32 / 10.,
+            pm2_5: pm2_5 as f32 / 10.,
+            pm4_0: pm4_0 as f32 / 10.,
+            pm10_0: pm10_0 as f32 / 10.,
+            relative_humidity: rh as i16 as f32 / 100.,
+            temperature: temperature as i16 as f32 / 200.,
+            voc_index: voc_index as i16 as f32 / 10.,
+            nox_index: nox_index as i16 as f32 / 10.,
+            hcho: hcho as f32 / 10.,
+        })
+    }
+}
+
+#[cfg(all(feature = "sen68", feature = "defmt"))]
+impl defmt::Format for Sen68Measurement {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "PM1.0:     {} ug/m³
+PM2.5:     {} ug/m³
+PM4.0:     {} ug/m³
+PM10.0:    {} ug/m³
+RH:        {} %
+Temp:      {} °C
+VOC Index: {} / 1
+NOx Index: {} / 100
+HCHO:      {} ppb",
+            self.pm1_0,
+            self.pm2_5,
+            self.pm4_0,
+            self.pm10_0,
+            self.relative_humidity,
+            self.temperature,
+            self.voc_index,
+            self.nox_index,
+            self.hcho
+        )
+    }
+}
+
 /// One raw measurement taken from the SEN66. Use
 /// [`read_measured_raw_values`](crate::asynch::Sen66::read_measured_raw_values) to retrieve it.
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RawMeasurement {
     /// Relative Humidity in %.
     pub relative_humidity: f32,
@@ -90,10 +598,20 @@ pub struct RawMeasurement {
     pub voc: u16,
     /// NOx ticks without scale facot
     pub nox: u16,
-    /// Uninterpolated CO2 concentration in ppm, updated every 5 seconds.
+    /// Uninterpolated CO2 concentration in ppm, updated every 5 seconds. Set to 0xFFFF during
+    /// the first 10 to 11 s after power-on, see [`co2_valid`](RawMeasurement::co2_valid).
     pub co2: u16,
 }
 
+impl RawMeasurement {
+    /// Returns whether the CO2 concentration is valid, i.e. not the 0xFFFF reported during the
+    /// first 10 to 11 s after power-on.
+    pub fn co2_valid(&self) -> bool {
+        self.co2 != Measurement::CO2_UNAVAILABLE
+    }
+}
+
+#[cfg(feature = "raw-measurements")]
 impl TryFrom<&[u8]> for RawMeasurement {
     type Error = DataError;
 
@@ -106,13 +624,13 @@ impl TryFrom<&[u8]> for RawMeasurement {
     /// - [`ReceivedBufferWrongSize`](crate::error::DataError::ReceivedBufferWrongSize): If the
     ///   received data buffer is not the expected size.
     fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
-        check_deserialization(data, 15)?;
+        let [rh, temperature, voc, nox, co2] = decode_words(data)?;
         Ok(Self {
-            relative_humidity: i16::from_be_bytes([data[0], data[1]]) as f32 / 100.,
-            temperature: i16::from_be_bytes([data[3], data[4]]) as f32 / 200.,
-            voc: u16::from_be_bytes([data[6], data[7]]),
-            nox: u16::from_be_bytes([data[9], data[10]]),
-            co2: u16::from_be_bytes([data[12], data[13]]),
+            relative_humidity: rh as i16 as f32 / 100.,
+            temperature: temperature as i16 as f32 / 200.,
+            voc,
+            nox,
+            co2,
         })
     }
 }
@@ -136,9 +654,24 @@ CO2:    {} ppm",
     }
 }
 
+impl fmt::Display for RawMeasurement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "RH:     {} %
+Temp:   {} °C
+VOC:    {} ticks
+NOx:    {} ticks
+CO2:    {} ppm",
+            self.relative_humidity, self.temperature, self.voc, self.nox, self.co2
+        )
+    }
+}
+
 /// One concentration measurement taken from the SEN66. Use
 /// [`read_number_concentrations`](crate::asynch::Sen66::read_number_concentrations) to retrieve it.
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Concentrations {
     /// PM0.5 concentration in particles/cm³
     pub pm0_5: f32,
@@ -152,6 +685,7 @@ pub struct Concentrations {
     pub pm10_0: f32,
 }
 
+#[cfg(feature = "raw-measurements")]
 impl TryFrom<&[u8]> for Concentrations {
     type Error = DataError;
 
@@ -164,13 +698,13 @@ impl TryFrom<&[u8]> for Concentrations {
     /// - [`ReceivedBufferWrongSize`](crate::error::DataError::ReceivedBufferWrongSize): If the
     ///   received data buffer is not the expected size.
     fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
-        check_deserialization(data, 15)?;
+        let [pm0_5, pm1_0, pm2_5, pm4_0, pm10_0] = decode_words(data)?;
         Ok(Self {
-            pm0_5: u16::from_be_bytes([data[0], data[1]]) as f32 / 10.,
-            pm1_0: u16::from_be_bytes([data[3], data[4]]) as f32 / 10.,
-            pm2_5: u16::from_be_bytes([data[6], data[7]]) as f32 / 10.,
-            pm4_0: u16::from_be_bytes([data[9], data[10]]) as f32 / 10.,
-            pm10_0: u16::from_be_bytes([data[12], data[13]]) as f32 / 10.,
+            pm0_5: pm0_5 as f32 / 10.,
+            pm1_0: pm1_0 as f32 / 10.,
+            pm2_5: pm2_5 as f32 / 10.,
+            pm4_0: pm4_0 as f32 / 10.,
+            pm10_0: pm10_0 as f32 / 10.,
         })
     }
 }
@@ -193,3 +727,154 @@ PM10.0: {} p/cm³",
         )
     }
 }
+
+impl fmt::Display for Concentrations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "PM0.5:  {} p/cm³
+PM1.0:  {} p/cm³
+PM2.5:  {} p/cm³
+PM4.0:  {} p/cm³
+PM10.0: {} p/cm³",
+            self.pm0_5, self.pm1_0, self.pm2_5, self.pm4_0, self.pm10_0
+        )
+    }
+}
+
+#[cfg(test)]
+impl Measurement {
+    /// Returns a measurement of typical indoor air for tests. Adjust single values with the
+    /// struct update syntax, e.g. `Measurement { co2: 800, ..Measurement::fixture() }`.
+    pub(crate) const fn fixture() -> Self {
+        Self {
+            pm1_0: 1.0,
+            pm2_5: 1.0,
+            pm4_0: 1.0,
+            pm10_0: 1.0,
+            relative_humidity: 40.0,
+            temperature: 21.0,
+            voc_index: 100.0,
+            nox_index: 1.0,
+            co2: 420,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(co2: u16) -> Measurement {
+        Measurement {
+            co2,
+            ..Measurement::fixture()
+        }
+    }
+
+    #[test]
+    fn co2_is_corrected_for_ambient_pressure() {
+        assert_eq!(measurement(800).co2_corrected(1_013.0), Some(800));
+        assert_eq!(measurement(800).co2_corrected(900.0), Some(900));
+        assert_eq!(measurement(800).co2_corrected(1_100.0), Some(737));
+        assert_eq!(measurement(800).co2_corrected(0.0), None);
+        assert_eq!(measurement(800).co2_corrected(f32::NAN), None);
+        assert_eq!(
+            measurement(Measurement::CO2_UNAVAILABLE).co2_corrected(1_013.0),
+            None
+        );
+    }
+
+    #[test]
+    fn unavailable_values_are_validated_to_none() {
+        let data = [
+            0xFF, 0xFF, 0xAC, 0xFF, 0xFF, 0xAC, 0xFF, 0xFF, 0xAC, 0xFF, 0xFF, 0xAC, 0x7F, 0xFF,
+            0x8F, 0x7F, 0xFF, 0x8F, 0x7F, 0xFF, 0x8F, 0x7F, 0xFF, 0x8F, 0xFF, 0xFF, 0xAC,
+        ];
+        let unavailable = Measurement::try_from(&data[..]).unwrap();
+        assert_eq!(unavailable.pm2_5, 6553.5);
+        assert_eq!(unavailable.validated(), ValidatedMeasurement::default());
+
+        assert!(unavailable.valid_fields().is_empty());
+        assert!(!unavailable.is_complete());
+        assert!(measurement(600).is_complete());
+        let conditioning = Measurement {
+            nox_index: Measurement::INDEX_UNAVAILABLE,
+            ..measurement(600)
+        };
+        assert_eq!(conditioning.valid_fields().len(), Channel::COUNT - 1);
+        assert!(!conditioning.valid_fields().contains(Channel::NoxIndex));
+
+        let validated = ValidatedMeasurement::from(measurement(600));
+        assert_eq!(validated.pm1_0, Some(1.0));
+        assert_eq!(validated.co2, Some(600));
+    }
+
+    #[test]
+    fn unavailable_values_are_plausible_only_for_gas_channels() {
+        let data = [
+            0xFF, 0xFF, 0xAC, 0xFF, 0xFF, 0xAC, 0xFF, 0xFF, 0xAC, 0xFF, 0xFF, 0xAC, 0x7F, 0xFF,
+            0x8F, 0x7F, 0xFF, 0x8F, 0x7F, 0xFF, 0x8F, 0x7F, 0xFF, 0x8F, 0xFF, 0xFF, 0xAC,
+        ];
+        assert!(!Measurement::try_from(&data[..]).unwrap().is_plausible());
+        assert!(measurement(600).is_plausible());
+        let settling = Measurement {
+            voc_index: Measurement::INDEX_UNAVAILABLE,
+            nox_index: Measurement::INDEX_UNAVAILABLE,
+            ..measurement(Measurement::CO2_UNAVAILABLE)
+        };
+        assert!(settling.is_plausible());
+    }
+
+    #[test]
+    fn fixed_measurement_keeps_scaled_integers() {
+        let data = [
+            0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x0F, 0xA0,
+            0xE4, 0xFF, 0x38, 0x7C, 0x03, 0xE8, 0xD4, 0x7F, 0xFF, 0x8F, 0x01, 0xA4, 0x4D,
+        ];
+        let fixed = MeasurementFixed::try_from(&data[..]).unwrap();
+        assert_eq!(fixed.relative_humidity, 4_000);
+        assert_eq!(fixed.temperature, -200);
+        assert_eq!(fixed.nox_index, i16::MAX);
+        assert_eq!(
+            Measurement::from(fixed),
+            Measurement::try_from(&data[..]).unwrap()
+        );
+    }
+
+    #[test]
+    fn measurement_displays_like_defmt() {
+        let text = format!("{}", measurement(600));
+        assert!(text.starts_with("PM1.0:     1 ug/m³\n"));
+        assert!(text.ends_with("CO2:       600 ppm"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn recorded_measurements_roundtrip_through_postcard() {
+        let mut buffer = [0; 64];
+        let recorded = crate::data::Timestamped::new(1_000, measurement(600));
+        let encoded = postcard::to_slice(&recorded, &mut buffer).unwrap();
+        assert_eq!(postcard::from_bytes(encoded), Ok(recorded));
+
+        let raw = RawMeasurement {
+            relative_humidity: 40.0,
+            temperature: 21.0,
+            voc: 30_000,
+            nox: 15_000,
+            co2: 600,
+        };
+        let encoded = postcard::to_slice(&raw, &mut buffer).unwrap();
+        assert_eq!(postcard::from_bytes(encoded), Ok(raw));
+
+        let concentrations = Concentrations {
+            pm0_5: 1.0,
+            pm1_0: 2.0,
+            pm2_5: 3.0,
+            pm4_0: 4.0,
+            pm10_0: 5.0,
+        };
+        let encoded = postcard::to_slice(&concentrations, &mut buffer).unwrap();
+        assert_eq!(postcard::from_bytes(encoded), Ok(concentrations));
+    }
+}
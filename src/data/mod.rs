@@ -1,11 +1,34 @@
 //! Data types for configuring the SEN66's operations.
 
+#[cfg(feature = "co2-calibration")]
+mod calibration;
+mod channel;
 mod data_status;
 mod measurement;
 mod product_data;
+#[cfg(feature = "uom")]
+mod quantities;
+mod self_test;
 mod state;
+mod timestamped;
 
+#[cfg(feature = "co2-calibration")]
+pub use calibration::CalibrationReport;
+pub use channel::{Channel, ChannelSet};
 pub use data_status::DataStatus;
-pub use measurement::{Concentrations, Measurement, RawMeasurement};
+#[cfg(feature = "sen68")]
+pub use measurement::Sen68Measurement;
+pub use measurement::{
+    Concentrations, Measurement, MeasurementFixed, RawMeasurement, Sen63cMeasurement,
+    Sen65Measurement, ValidatedMeasurement,
+};
 pub use product_data::{ProductName, SerialNumber};
-pub use state::{AscState, DeviceStatusRegister, SensorState, VocAlgorithmState};
+#[cfg(feature = "uom")]
+pub use quantities::Quantities;
+pub use self_test::SelfTestReport;
+pub use state::{
+    AscState, DeviceStatusRegister, Idle, Measuring, OperatingState, SensorState, VocAlgorithmState,
+};
+pub use timestamped::Timestamped;
+#[cfg(feature = "std")]
+pub use timestamped::{UtcTimestamped, unix_time_ms};
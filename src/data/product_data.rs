@@ -1,7 +1,7 @@
-use crate::{error::DataError, util::check_deserialization};
+use crate::{error::DataError, util::decode_words};
 
 /// Name of the sensor in ASCII
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct ProductName(SmallString);
 
 impl TryFrom<&[u8]> for ProductName {
@@ -17,6 +17,11 @@ impl ProductName {
     pub fn get_name_buffer(&self) -> &[u8] {
         self.0.get_buffer()
     }
+
+    /// Returns the product name as string without the null-terminator.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
 }
 
 #[cfg(feature = "defmt")]
@@ -28,7 +33,7 @@ impl defmt::Format for ProductName {
 }
 
 /// Name of the sensor in ASCII
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct SerialNumber(SmallString);
 
 impl SerialNumber {
@@ -36,6 +41,11 @@ impl SerialNumber {
     pub fn get_serial_buffer(&self) -> &[u8] {
         self.0.get_buffer()
     }
+
+    /// Returns the serial number as string without the null-terminator.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
 }
 
 impl TryFrom<&[u8]> for SerialNumber {
@@ -46,6 +56,22 @@ impl TryFrom<&[u8]> for SerialNumber {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for SerialNumber {
+    /// Serializes the serial number as string.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SerialNumber {
+    /// Deserializes the serial number from an ASCII string of at most 32 characters.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(SerialNumber(SmallString::deserialize(deserializer)?))
+    }
+}
+
 #[cfg(feature = "defmt")]
 impl defmt::Format for SerialNumber {
     /// Writes the defmt representation to the Formatter.
@@ -54,7 +80,7 @@ impl defmt::Format for SerialNumber {
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 struct SmallString {
     name: [u8; 32],
     len: usize,
@@ -62,7 +88,16 @@ struct SmallString {
 
 impl SmallString {
     fn get_buffer(&self) -> &[u8] {
-        &self.name[0..self.len]
+        self.name.get(..self.len).unwrap_or(&self.name)
+    }
+
+    fn as_str(&self) -> &str {
+        let text = self
+            .get_buffer()
+            .strip_suffix(&[0x00])
+            .unwrap_or(self.get_buffer());
+        // TryFrom and Deserialize ensure that the data contained is ASCII
+        core::str::from_utf8(text).unwrap_or_default()
     }
 }
 
@@ -70,18 +105,15 @@ impl TryFrom<&[u8]> for SmallString {
     type Error = DataError;
 
     fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
-        check_deserialization(data, 48)?;
+        let words = decode_words::<16>(data)?;
         let mut name = [0; 32];
         let mut len = 0;
-        for (i, &c) in data.iter().enumerate() {
-            // Skip CRC bytes
-            if (i + 1) % 3 == 0 {
-                continue;
-            }
+        let characters = words.iter().flat_map(|word| word.to_be_bytes());
+        for (target, c) in name.iter_mut().zip(characters) {
             if !c.is_ascii() {
                 return Err(Self::Error::NotASCIIString);
             }
-            name[len] = c;
+            *target = c;
             len += 1;
             if c == 0x00 {
                 break;
@@ -92,13 +124,47 @@ impl TryFrom<&[u8]> for SmallString {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for SmallString {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SmallString {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = SmallString;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.write_str("an ASCII string of at most 32 characters")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, text: &str) -> Result<Self::Value, E> {
+                let mut name = [0; 32];
+                if !text.is_ascii() || text.len() > name.len() {
+                    return Err(E::invalid_value(serde::de::Unexpected::Str(text), &self));
+                }
+                name.iter_mut()
+                    .zip(text.bytes())
+                    .for_each(|(target, c)| *target = c);
+                // Keep the null-terminator, as received from the sensor
+                let len = (text.len() + 1).min(name.len());
+                Ok(SmallString { name, len })
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
 #[cfg(feature = "defmt")]
 impl defmt::Format for SmallString {
     /// Writes the defmt representation to the Formatter.
     fn format(&self, f: defmt::Formatter) {
-        // ProductName::TryFrom ensures that the data contained is ASCII and not longer than the
-        // first null-terminator
-        let output = unsafe { core::str::from_utf8_unchecked(&self.name[0..self.len]) };
-        defmt::write!(f, "{}", output)
+        defmt::write!(f, "{}", self.as_str())
     }
 }
@@ -0,0 +1,94 @@
+use uom::si::{
+    f32::{MassConcentration, Ratio, ThermodynamicTemperature},
+    mass_concentration::microgram_per_cubic_meter,
+    ratio::{part_per_million, percent},
+    thermodynamic_temperature::degree_celsius,
+};
+
+use crate::data::{Channel, Measurement};
+
+/// Values of a [`Measurement`] as typed `uom` quantities, preventing unit mix-ups in downstream
+/// code. Obtained with [`quantities`](Measurement::quantities). Values which are not yet
+/// available are `None`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quantities {
+    /// Mass concentration for PM1.0.
+    pub pm1_0: Option<MassConcentration>,
+    /// Mass concentration for PM2.5.
+    pub pm2_5: Option<MassConcentration>,
+    /// Mass concentration for PM4.0.
+    pub pm4_0: Option<MassConcentration>,
+    /// Mass concentration for PM10.0.
+    pub pm10_0: Option<MassConcentration>,
+    /// Relative humidity.
+    pub relative_humidity: Option<Ratio>,
+    /// Temperature.
+    pub temperature: Option<ThermodynamicTemperature>,
+    /// VOC index, which has no unit.
+    pub voc_index: Option<f32>,
+    /// NOx index, which has no unit.
+    pub nox_index: Option<f32>,
+    /// CO2 concentration as volume fraction.
+    pub co2: Option<Ratio>,
+}
+
+impl Measurement {
+    /// Returns the values of the measurement as typed `uom` quantities.
+    pub fn quantities(&self) -> Quantities {
+        let pm = |channel: Channel| {
+            channel
+                .value(self)
+                .map(MassConcentration::new::<microgram_per_cubic_meter>)
+        };
+        Quantities {
+            pm1_0: pm(Channel::Pm1_0),
+            pm2_5: pm(Channel::Pm2_5),
+            pm4_0: pm(Channel::Pm4_0),
+            pm10_0: pm(Channel::Pm10_0),
+            relative_humidity: Channel::RelativeHumidity
+                .value(self)
+                .map(Ratio::new::<percent>),
+            temperature: Channel::Temperature
+                .value(self)
+                .map(ThermodynamicTemperature::new::<degree_celsius>),
+            voc_index: Channel::VocIndex.value(self),
+            nox_index: Channel::NoxIndex.value(self),
+            co2: Channel::Co2.value(self).map(Ratio::new::<part_per_million>),
+        }
+    }
+}
+
+impl From<&Measurement> for Quantities {
+    fn from(measurement: &Measurement) -> Self {
+        measurement.quantities()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uom::si::{
+        mass_concentration::milligram_per_cubic_meter, thermodynamic_temperature::kelvin,
+    };
+
+    use super::*;
+
+    #[test]
+    fn measurement_converts_to_quantities() {
+        let quantities = Measurement {
+            pm2_5: 2.5,
+            pm4_0: 3.0,
+            pm10_0: 4.0,
+            temperature: 21.5,
+            nox_index: Measurement::INDEX_UNAVAILABLE,
+            ..Measurement::fixture()
+        }
+        .quantities();
+        let pm2_5 = quantities.pm2_5.unwrap().get::<milligram_per_cubic_meter>();
+        assert!((pm2_5 - 0.0025).abs() < 1e-6);
+        let temperature = quantities.temperature.unwrap().get::<kelvin>();
+        assert!((temperature - 294.65).abs() < 1e-3);
+        assert!((quantities.relative_humidity.unwrap().value - 0.4).abs() < 1e-6);
+        assert!((quantities.co2.unwrap().value - 420e-6).abs() < 1e-9);
+        assert_eq!(quantities.nox_index, None);
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{data::Measurement, error::DeviceError};
+
+/// Result of a [`self_test`](crate::asynch::Sen66::self_test), e.g. for end-of-line testing.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SelfTestReport {
+    /// Data-ready was reported as not ready after starting the measurement and toggled to ready
+    /// once the first measurement was available.
+    pub data_ready_toggled: bool,
+    /// First measurement taken during the test. `None` if no measurement became available.
+    pub measurement: Option<Measurement>,
+    /// Error flags set in the device status register after measuring. `None` if no error is
+    /// present.
+    pub device_error: Option<DeviceError>,
+    /// Fan speed warning set in the device status register after measuring.
+    pub fan_speed_warning: bool,
+}
+
+impl SelfTestReport {
+    /// Returns whether the test passed: data-ready toggled, the measurement is plausible and
+    /// neither errors nor warnings are present.
+    pub fn passed(&self) -> bool {
+        self.data_ready_toggled
+            && self
+                .measurement
+                .as_ref()
+                .is_some_and(Measurement::is_plausible)
+            && self.device_error.is_none()
+            && !self.fan_speed_warning
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn self_test_report_passes_without_findings() {
+        let report = SelfTestReport {
+            data_ready_toggled: true,
+            measurement: Some(Measurement::fixture()),
+            device_error: None,
+            fan_speed_warning: false,
+        };
+        assert!(report.passed());
+    }
+
+    #[test]
+    fn self_test_report_fails_on_findings() {
+        let report = SelfTestReport {
+            data_ready_toggled: true,
+            measurement: Some(Measurement::fixture()),
+            device_error: None,
+            fan_speed_warning: true,
+        };
+        assert!(!report.passed());
+        let report = SelfTestReport {
+            data_ready_toggled: true,
+            measurement: Some(Measurement {
+                relative_humidity: 120.0,
+                ..Measurement::fixture()
+            }),
+            device_error: None,
+            fan_speed_warning: false,
+        };
+        assert!(!report.passed());
+        let report = SelfTestReport {
+            data_ready_toggled: false,
+            measurement: None,
+            device_error: None,
+            fan_speed_warning: false,
+        };
+        assert!(!report.passed());
+    }
+}
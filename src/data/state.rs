@@ -1,10 +1,13 @@
+use core::fmt;
+
 use crate::{
     error::{DataError, DeviceError},
-    util::{check_deserialization, is_set},
+    util::{decode_words, is_set},
 };
 
 /// Represents the state of the sensor.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SensorState {
     /// Sensor is in idle state. Either after power-on, a reset or when calling
     /// [`stop_measurement`](crate::asynch::Sen66::stop_measurement).
@@ -12,15 +15,63 @@ pub enum SensorState {
     /// Sensor is in measuring state. Entered by calling
     /// [`start_measurement`](crate::asynch::Sen66::start_measurement).
     Measuring,
+    /// The sensor's state cannot be determined, as a state changing command was interrupted.
+    /// Leave it by calling [`stop_measurement`](crate::asynch::Sen66::stop_measurement) or
+    /// [`reset_device`](crate::asynch::Sen66::reset_device).
+    Unknown,
+}
+
+impl SensorState {
+    /// Returns the name of the state.
+    pub(crate) const fn name(&self) -> &'static str {
+        match self {
+            SensorState::Idle => "Idle",
+            SensorState::Measuring => "Measuring",
+            SensorState::Unknown => "Unknown",
+        }
+    }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for SensorState {
     fn format(&self, f: defmt::Formatter) {
-        defmt::write!(f, "{}", self)
+        defmt::write!(f, "{}", self.name())
     }
 }
 
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Operating state of a typestate driver, see [`Sen66Typed`](crate::asynch::Sen66Typed).
+/// Implemented by [`Idle`] and [`Measuring`].
+pub trait OperatingState: sealed::Sealed {
+    /// Runtime state corresponding to the typestate.
+    const STATE: SensorState;
+}
+
+/// Typestate of a driver whose sensor is in idle state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Idle;
+
+impl sealed::Sealed for Idle {}
+
+impl OperatingState for Idle {
+    const STATE: SensorState = SensorState::Idle;
+}
+
+/// Typestate of a driver whose sensor is in measuring state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Measuring;
+
+impl sealed::Sealed for Measuring {}
+
+impl OperatingState for Measuring {
+    const STATE: SensorState = SensorState::Measuring;
+}
+
 /// Sensor status register.
 #[derive(Debug, PartialEq)]
 pub struct DeviceStatusRegister(u32);
@@ -94,6 +145,51 @@ impl DeviceStatusRegister {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for DeviceStatusRegister {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Device Status: {=u32:#010x}
+    Fan Speed Warning: {}
+    PM Error:          {}
+    CO2 Error:         {}
+    Gas Error:         {}
+    RH/T Error:        {}
+    Fan Error:         {}",
+            self.0,
+            self.fan_speed_warning(),
+            self.pm_sensor_error(),
+            self.co2_sensor_error(),
+            self.gas_sensor_error(),
+            self.rht_sensor_error(),
+            self.fan_error()
+        )
+    }
+}
+
+impl fmt::Display for DeviceStatusRegister {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Device Status: {:#010x}
+    Fan Speed Warning: {}
+    PM Error:          {}
+    CO2 Error:         {}
+    Gas Error:         {}
+    RH/T Error:        {}
+    Fan Error:         {}",
+            self.0,
+            self.fan_speed_warning(),
+            self.pm_sensor_error(),
+            self.co2_sensor_error(),
+            self.gas_sensor_error(),
+            self.rht_sensor_error(),
+            self.fan_error()
+        )
+    }
+}
+
 impl TryFrom<&[u8]> for DeviceStatusRegister {
     type Error = DataError;
 
@@ -106,15 +202,16 @@ impl TryFrom<&[u8]> for DeviceStatusRegister {
     /// - [`ReceivedBufferWrongSize`](crate::error::DataError::ReceivedBufferWrongSize): If the
     ///   received data buffer is not the expected size.
     fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
-        check_deserialization(data, 6)?;
-        Ok(DeviceStatusRegister(u32::from_be_bytes([
-            data[0], data[1], data[3], data[4],
-        ])))
+        let [high, low] = decode_words(data)?;
+        Ok(DeviceStatusRegister(
+            (u32::from(high) << 16) | u32::from(low),
+        ))
     }
 }
 
 /// Indicates whether automatic self calibration (ASC) is enabled.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AscState {
     /// ASC is enabled.
     Enabled,
@@ -136,19 +233,29 @@ impl TryFrom<&[u8]> for AscState {
     /// - [UnexpectedValueReceived](crate::error::DataError::UnexpectedValueReceived) if the
     ///   received value is not `0` or `1`.
     fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
-        check_deserialization(data, 3)?;
-        match data[1] {
-            0x00 => Ok(Self::Disabled),
-            0x01 => Ok(Self::Enabled),
-            val => Err(DataError::UnexpectedValueReceived {
+        let [value] = decode_words(data)?;
+        match value.to_be_bytes() {
+            [_, 0x00] => Ok(Self::Disabled),
+            [_, 0x01] => Ok(Self::Enabled),
+            [_, val] => Err(DataError::UnexpectedValueReceived {
                 parameter: "ASC State",
                 expected: "0 or 1",
-                actual: val as u16,
+                actual: u16::from(val),
             }),
         }
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for AscState {
+    fn format(&self, f: defmt::Formatter) {
+        match self {
+            AscState::Enabled => defmt::write!(f, "Enabled"),
+            AscState::Disabled => defmt::write!(f, "Disabled"),
+        }
+    }
+}
+
 impl From<AscState> for u16 {
     fn from(value: AscState) -> Self {
         match value {
@@ -160,9 +267,11 @@ impl From<AscState> for u16 {
 
 /// Stores the VOC algorithm state, which can be used to skip the learning phase after a power
 /// cycle.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VocAlgorithmState([u8; 8]);
 
+#[cfg(feature = "tuning")]
 impl TryFrom<&[u8]> for VocAlgorithmState {
     type Error = DataError;
 
@@ -175,20 +284,35 @@ impl TryFrom<&[u8]> for VocAlgorithmState {
     /// - [`ReceivedBufferWrongSize`](crate::error::DataError::ReceivedBufferWrongSize): If the
     ///   received data buffer is not the expected size.
     fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
-        check_deserialization(data, 12)?;
-        Ok(VocAlgorithmState([
-            data[0], data[1], data[3], data[4], data[6], data[7], data[9], data[10],
-        ]))
+        let [[b0, b1], [b2, b3], [b4, b5], [b6, b7]] =
+            decode_words::<4>(data)?.map(u16::to_be_bytes);
+        Ok(VocAlgorithmState([b0, b1, b2, b3, b4, b5, b6, b7]))
+    }
+}
+
+impl From<[u16; 4]> for VocAlgorithmState {
+    /// Creates the VOC algorithm state from the words as sent to the sensor.
+    fn from(words: [u16; 4]) -> Self {
+        let [[b0, b1], [b2, b3], [b4, b5], [b6, b7]] = words.map(u16::to_be_bytes);
+        VocAlgorithmState([b0, b1, b2, b3, b4, b5, b6, b7])
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for VocAlgorithmState {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "VOC Algorithm State: {=[u8]:02x}", self.0)
     }
 }
 
 impl From<VocAlgorithmState> for [u16; 4] {
     fn from(value: VocAlgorithmState) -> Self {
+        let [b0, b1, b2, b3, b4, b5, b6, b7] = value.0;
         [
-            u16::from_be_bytes([value.0[0], value.0[1]]),
-            u16::from_be_bytes([value.0[2], value.0[3]]),
-            u16::from_be_bytes([value.0[4], value.0[5]]),
-            u16::from_be_bytes([value.0[6], value.0[7]]),
+            u16::from_be_bytes([b0, b1]),
+            u16::from_be_bytes([b2, b3]),
+            u16::from_be_bytes([b4, b5]),
+            u16::from_be_bytes([b6, b7]),
         ]
     }
 }
@@ -204,6 +328,14 @@ mod tests {
         assert!(state.has_error().is_ok());
     }
 
+    #[test]
+    fn status_displays_raw_value_and_flags() {
+        let text = format!("{}", DeviceStatusRegister(0x0020_0010));
+        assert!(text.starts_with("Device Status: 0x00200010\n"));
+        assert!(text.contains("Fan Speed Warning: true"));
+        assert!(text.ends_with("Fan Error:         true"));
+    }
+
     #[test]
     fn set_fan_speed_warning_reported() {
         let state = DeviceStatusRegister(0b0000_0000_0010_0000_0000_0000_0000_0000);
@@ -298,6 +430,7 @@ mod tests {
         assert_eq!(u16::from(AscState::Disabled), 0x0000);
     }
 
+    #[cfg(feature = "tuning")]
     #[test]
     fn deserialize_voc_algorithm_state_yields_same_state() {
         let data = [
@@ -309,6 +442,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "tuning")]
     #[test]
     fn serialize_voc_algorithm_state_yields_same_state() {
         assert_eq!(
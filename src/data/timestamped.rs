@@ -0,0 +1,84 @@
+/// Value annotated with the time it was taken, e.g. a [`Measurement`](crate::data::Measurement)
+/// read at irregular intervals.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Timestamped<T> {
+    /// Time the value was taken at in ms, based on a monotonic clock.
+    pub timestamp_ms: u64,
+    /// The value taken.
+    pub value: T,
+}
+
+impl<T> Timestamped<T> {
+    /// Annotates `value` with `timestamp_ms`.
+    pub const fn new(timestamp_ms: u64, value: T) -> Self {
+        Self {
+            timestamp_ms,
+            value,
+        }
+    }
+}
+
+/// Value annotated with the wall-clock time it was taken at, e.g. a
+/// [`Measurement`](crate::data::Measurement) recorded on a Linux gateway.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UtcTimestamped<T> {
+    /// Time the value was taken at.
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    /// The value taken.
+    pub value: T,
+}
+
+#[cfg(feature = "std")]
+impl<T> UtcTimestamped<T> {
+    /// Annotates `value` with `timestamp`.
+    pub const fn new(timestamp: chrono::DateTime<chrono::Utc>, value: T) -> Self {
+        Self { timestamp, value }
+    }
+
+    /// Annotates `value` with the current system time.
+    pub fn now(value: T) -> Self {
+        Self::new(chrono::Utc::now(), value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> From<UtcTimestamped<T>> for Timestamped<T> {
+    /// Converts the wall-clock time into ms since the UNIX epoch. Times before the epoch are
+    /// mapped to 0.
+    fn from(value: UtcTimestamped<T>) -> Self {
+        let timestamp_ms = u64::try_from(value.timestamp.timestamp_millis()).unwrap_or_default();
+        Timestamped::new(timestamp_ms, value.value)
+    }
+}
+
+/// Returns the current system time in ms since the UNIX epoch, e.g. to use it as time source of
+/// the driver.
+#[cfg(feature = "std")]
+pub fn unix_time_ms() -> u64 {
+    u64::try_from(chrono::Utc::now().timestamp_millis()).unwrap_or_default()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utc_timestamp_converts_to_unix_ms() {
+        let timestamp = chrono::DateTime::from_timestamp_millis(1_700_000_000_123).unwrap();
+        let stamped = UtcTimestamped::new(timestamp, 42);
+        assert_eq!(
+            Timestamped::from(stamped),
+            Timestamped::new(1_700_000_000_123, 42)
+        );
+        let before_epoch = chrono::DateTime::from_timestamp_millis(-1).unwrap();
+        assert_eq!(
+            Timestamped::from(UtcTimestamped::new(before_epoch, 42)).timestamp_ms,
+            0
+        );
+        assert!(unix_time_ms() >= 1_700_000_000_123);
+    }
+}
@@ -0,0 +1,163 @@
+//! Values derived from the SEN66's RH/T readings, e.g. for HVAC or greenhouse applications.
+//!
+//! The saturation vapor pressure is approximated with the Magnus formula with the coefficients of
+//! Sonntag (1990), valid from -45 °C to 60 °C over water.
+//!
+//! The comfort indices are only meaningful in warm conditions. The heat index is defined from
+//! 27 °C and 40 % relative humidity, the humidex is commonly reported from 25 °C on.
+
+use libm::{fabsf, logf, sqrtf};
+
+use crate::{
+    data::Measurement,
+    util::{MAGNUS_BETA, MAGNUS_LAMBDA, magnus_vapor_pressure, rh_t},
+};
+
+/// Ratio of the molar mass to the gas constant of water vapor in g·K/J, scaled for hPa.
+const WATER_VAPOR_FACTOR: f32 = 216.7;
+/// Offset between °C and K.
+const ZERO_CELSIUS_K: f32 = 273.15;
+
+/// Returns the dew point in °C.
+/// - `temperature`: Air temperature in °C.
+/// - `relative_humidity`: Relative humidity in %, greater than 0. Values above 100 % are clamped.
+pub fn dew_point(temperature: f32, relative_humidity: f32) -> f32 {
+    let gamma = logf(relative_humidity.min(100.0) / 100.0)
+        + MAGNUS_BETA * temperature / (MAGNUS_LAMBDA + temperature);
+    MAGNUS_LAMBDA * gamma / (MAGNUS_BETA - gamma)
+}
+
+/// Returns the absolute humidity in g/m³.
+/// - `temperature`: Air temperature in °C.
+/// - `relative_humidity`: Relative humidity in %. Values outside 0 to 100 % are clamped.
+pub fn absolute_humidity(temperature: f32, relative_humidity: f32) -> f32 {
+    let vapor_pressure =
+        relative_humidity.clamp(0.0, 100.0) / 100.0 * magnus_vapor_pressure(temperature);
+    WATER_VAPOR_FACTOR * vapor_pressure / (temperature + ZERO_CELSIUS_K)
+}
+
+/// Returns the heat index, the apparent temperature perceived by humans, in °C, using the
+/// algorithm of the US National Weather Service. Below a heat index of 27 °C, Steadman's
+/// simplified formula is used, which approximates the air temperature in mild conditions.
+/// - `temperature`: Air temperature in °C.
+/// - `relative_humidity`: Relative humidity in %. Values outside 0 to 100 % are clamped.
+pub fn heat_index(temperature: f32, relative_humidity: f32) -> f32 {
+    let t = temperature * 1.8 + 32.0;
+    let rh = relative_humidity.clamp(0.0, 100.0);
+    let simple = 0.5 * (t + 61.0 + (t - 68.0) * 1.2 + rh * 0.094);
+    let heat_index = if (simple + t) / 2.0 < 80.0 {
+        simple
+    } else {
+        let regression = -42.379 + 2.049_015_2 * t + 10.143_332 * rh
+            - 0.224_755_4 * t * rh
+            - 6.837_83e-3 * t * t
+            - 5.481_717e-2 * rh * rh
+            + 1.228_74e-3 * t * t * rh
+            + 8.528_2e-4 * t * rh * rh
+            - 1.99e-6 * t * t * rh * rh;
+        if rh < 13.0 && (80.0..=112.0).contains(&t) {
+            regression - (13.0 - rh) / 4.0 * sqrtf((17.0 - fabsf(t - 95.0)) / 17.0)
+        } else if rh > 85.0 && (80.0..=87.0).contains(&t) {
+            regression + (rh - 85.0) / 10.0 * (87.0 - t) / 5.0
+        } else {
+            regression
+        }
+    };
+    (heat_index - 32.0) / 1.8
+}
+
+/// Returns the humidex, the perceived temperature defined by Environment Canada, in °C.
+/// - `temperature`: Air temperature in °C.
+/// - `relative_humidity`: Relative humidity in %. Values outside 0 to 100 % are clamped.
+pub fn humidex(temperature: f32, relative_humidity: f32) -> f32 {
+    let vapor_pressure =
+        relative_humidity.clamp(0.0, 100.0) / 100.0 * magnus_vapor_pressure(temperature);
+    temperature + 0.5555 * (vapor_pressure - 10.0)
+}
+
+/// Returns the dew point at the conditions of the `measurement` in °C. `None` if the temperature
+/// or relative humidity is not available or the relative humidity is 0 %.
+pub fn measured_dew_point(measurement: &Measurement) -> Option<f32> {
+    rh_t(measurement)
+        .filter(|&(_, relative_humidity)| relative_humidity > 0.0)
+        .map(|(temperature, relative_humidity)| dew_point(temperature, relative_humidity))
+}
+
+/// Returns the absolute humidity at the conditions of the `measurement` in g/m³. `None` if the
+/// temperature or relative humidity is not available.
+pub fn measured_absolute_humidity(measurement: &Measurement) -> Option<f32> {
+    rh_t(measurement)
+        .map(|(temperature, relative_humidity)| absolute_humidity(temperature, relative_humidity))
+}
+
+/// Returns the heat index at the conditions of the `measurement` in °C. `None` if the
+/// temperature or relative humidity is not available.
+pub fn measured_heat_index(measurement: &Measurement) -> Option<f32> {
+    rh_t(measurement)
+        .map(|(temperature, relative_humidity)| heat_index(temperature, relative_humidity))
+}
+
+/// Returns the humidex at the conditions of the `measurement` in °C. `None` if the temperature
+/// or relative humidity is not available.
+pub fn measured_humidex(measurement: &Measurement) -> Option<f32> {
+    rh_t(measurement)
+        .map(|(temperature, relative_humidity)| humidex(temperature, relative_humidity))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-2, "{actual} != {expected}");
+    }
+
+    fn measurement(temperature: f32, relative_humidity: f32) -> Measurement {
+        Measurement {
+            relative_humidity,
+            temperature,
+            ..Measurement::fixture()
+        }
+    }
+
+    #[test]
+    fn dew_point_matches_reference_values() {
+        assert_close(dew_point(20.0, 50.0), 9.26);
+        assert_close(dew_point(20.0, 100.0), 20.0);
+        assert_close(dew_point(-10.0, 80.0), -12.80);
+        assert_close(measured_dew_point(&measurement(20.0, 50.0)).unwrap(), 9.26);
+        assert_eq!(measured_dew_point(&measurement(20.0, 0.0)), None);
+        assert_eq!(
+            measured_dew_point(&measurement(Measurement::TEMPERATURE_UNAVAILABLE, 50.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn absolute_humidity_matches_reference_values() {
+        assert_close(absolute_humidity(20.0, 50.0), 8.62);
+        assert_close(absolute_humidity(30.0, 100.0), 30.26);
+        assert_close(absolute_humidity(20.0, 0.0), 0.0);
+        assert_eq!(
+            measured_absolute_humidity(&measurement(20.0, Measurement::RH_UNAVAILABLE)),
+            None
+        );
+    }
+
+    #[test]
+    fn comfort_indices_match_reference_values() {
+        // NWS heat index table: 90 °F at 60 % RH feels like 100 °F.
+        assert!((heat_index(32.22, 60.0) - 37.78).abs() < 0.3);
+        assert!((heat_index(20.0, 50.0) - 19.5).abs() < 0.6);
+        // Environment Canada: 30 °C at a dew point of 15 °C yields a humidex of 34.
+        assert!((humidex(30.0, 39.9) - 34.0).abs() < 0.5);
+        assert_eq!(
+            measured_humidex(&measurement(30.0, Measurement::RH_UNAVAILABLE)),
+            None
+        );
+        assert_close(
+            measured_heat_index(&measurement(32.22, 60.0)).unwrap(),
+            heat_index(32.22, 60.0),
+        );
+    }
+}
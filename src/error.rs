@@ -3,6 +3,8 @@
 use embedded_hal::i2c;
 use thiserror::Error;
 
+use crate::data::ProductName;
+
 /// Error variants emitted when interacting with the sensor.
 #[derive(Debug, Error, PartialEq)]
 pub enum Sen66Error<I2C: i2c::Error> {
@@ -23,6 +25,49 @@ pub enum Sen66Error<I2C: i2c::Error> {
     /// enter the measuring State, use [stop_measurement](crate::asynch::Sen66::stop_measurement) to enter the idle state.
     #[error("Command called in invalid state: {0}")]
     WrongState(&'static str),
+    /// Emitted when an operation did not complete before its deadline.
+    #[cfg(feature = "deadline")]
+    #[error("The operation did not complete before its deadline.")]
+    Timeout,
+    /// Emitted when write verification is enabled and the value read back after writing a
+    /// setting differs from the written value.
+    #[error("Verification of written {0} failed.")]
+    VerificationFailed(&'static str),
+    /// Emitted when the sensor did not acknowledge a split-phase transfer, as it is still
+    /// executing the previous command. Retry the transfer later.
+    #[error("The sensor is still executing the previous command.")]
+    WouldBlock,
+    /// Emitted when the sensor did not report new data within the timeout of
+    /// [`wait_for_data_ready`](crate::asynch::Sen66::wait_for_data_ready).
+    #[error("No data became ready within {timeout_ms} ms.")]
+    DataReadyTimeout {
+        /// Timeout that passed in ms
+        timeout_ms: u32,
+    },
+    /// Emitted when the device responding reports a different product name than expected, e.g.
+    /// because a different sensor is connected.
+    #[error("Expected a {expected}, found a {}.", .found.as_str())]
+    WrongDevice {
+        /// Product name expected
+        expected: &'static str,
+        /// Product name reported by the device
+        found: ProductName,
+    },
+    /// Emitted when a measurement is started during the cooldown after stopping a measurement,
+    /// cleaning the fan or activating the SHT heater and the
+    /// [`CooldownPolicy`](crate::maintenance::CooldownPolicy) rejects it.
+    #[cfg(feature = "cooldown")]
+    #[error("Cooldown active for another {remaining_ms} ms.")]
+    CooldownActive {
+        /// Remaining time of the cooldown in ms
+        remaining_ms: u32,
+    },
+    /// Emitted when the data words sent with a command do not fit into the transmit buffer.
+    #[error("{words} data words do not fit into the transmit buffer.")]
+    InvalidLength {
+        /// Number of data words passed
+        words: usize,
+    },
 }
 
 #[cfg(feature = "defmt")]
@@ -56,6 +101,15 @@ pub enum DataError {
         /// Actual value received
         actual: u16,
     },
+    /// Emitted when an output buffer is too small to hold the encoded data.
+    #[error("Buffer too small for the encoded data.")]
+    BufferTooSmall,
+    /// Emitted when decoded data is not a valid encoding of the expected value.
+    #[error("Data is not a valid encoding of the expected value.")]
+    InvalidEncoding,
+    /// Emitted when a computation requires more samples than have been provided.
+    #[error("Not enough data for the computation.")]
+    InsufficientData,
     /// Emitted when a value is used to construct data send to the sensor, but the value is not in
     /// the specified value's range. Adjust the argument to a value within the specified bounds.
     #[error("{parameter} must be between {min} and {max} {unit}.")]
@@ -100,3 +154,80 @@ pub struct DeviceError {
     /// Fan error present
     pub fan: bool,
 }
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for DeviceError {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Sensor has errors set:
+    PM:  {}
+    CO2: {}
+    Gas: {}
+    RHT: {}
+    Fan: {}",
+            self.pm,
+            self.co2,
+            self.gas,
+            self.rht,
+            self.fan
+        )
+    }
+}
+
+/// Error variants emitted when publishing a measurement to an encoding
+/// [`MeasurementSink`](crate::sink::MeasurementSink).
+#[derive(Debug, Error, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SinkError<E> {
+    /// Emitted when the measurement cannot be encoded, e.g. because the buffer is too small.
+    #[error(transparent)]
+    Encoding(#[from] DataError),
+    /// Emitted when handing the encoded measurement to its transport fails.
+    #[error("Publishing the encoded measurement failed.")]
+    Transport(E),
+}
+
+/// Error variants emitted when checkpointing the VOC algorithm state with
+/// [`checkpoint_voc_state`](crate::asynch::Sen66::checkpoint_voc_state).
+#[cfg(feature = "tuning")]
+#[derive(Debug, Error, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CheckpointError<I2C: i2c::Error, S> {
+    /// Emitted when reading the state from the sensor fails.
+    #[error(transparent)]
+    Sensor(#[from] Sen66Error<I2C>),
+    /// Emitted when saving the state to the store fails.
+    #[error("Saving the VOC algorithm state failed.")]
+    Store(S),
+}
+
+/// Error variants emitted when checkpointing to a NOR flash with
+/// [`FlashCheckpoint`](crate::storage::FlashCheckpoint) or saving a tracker to storage.
+#[cfg(feature = "storage")]
+#[derive(Debug, Error, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum StorageError<E> {
+    /// Emitted when the checkpoint cannot be encoded or the stored data is corrupted.
+    #[error(transparent)]
+    Data(#[from] DataError),
+    /// Emitted when reading, erasing or writing the flash or storage fails.
+    #[error("Accessing the flash failed.")]
+    Flash(E),
+    /// Emitted when the flash region is not aligned to the erase sectors or too small.
+    #[error("The flash region must be aligned to erase sectors and span at least two of them.")]
+    InvalidRegion,
+}
+
+/// Error variants emitted when publishing a measurement to a pair of sinks. The measurement is
+/// published to both sinks even if the first one fails.
+#[derive(Debug, Error, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FanoutError<A, B> {
+    /// Emitted when the first sink failed.
+    #[error("Publishing to the first sink failed.")]
+    First(A),
+    /// Emitted when only the second sink failed.
+    #[error("Publishing to the second sink failed.")]
+    Second(B),
+}
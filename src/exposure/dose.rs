@@ -0,0 +1,291 @@
+use crate::{
+    data::{Measurement, Timestamped},
+    error::DataError,
+    util::ByteReader,
+};
+
+/// Duration of one day in ms.
+const DAY_MS: i64 = 24 * 3_600_000;
+/// Default longest gap between samples which is still integrated in ms.
+const DEFAULT_MAX_GAP_MS: u64 = 5 * 60_000;
+/// Duration of one hour in ms.
+const HOUR_MS: f32 = 3_600_000.0;
+
+/// Dose accumulated over one completed day, reported by [`DailyDose::update`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompletedDay {
+    /// Day since the UNIX epoch, in the configured time zone.
+    pub day: u32,
+    /// Accumulated dose in ug·h/m³.
+    pub dose: f32,
+}
+
+/// Cumulative dose of a concentration (concentration × time in ug·h/m³) with daily rollover.
+/// Multiplying the dose by a breathing rate in m³/h yields a proxy for the inhaled mass in ug.
+/// Timestamps are expected in ms since the UNIX epoch to determine the day. Persist the tracker
+/// using [`to_bytes`](DailyDose::to_bytes) and restore it with
+/// [`TryFrom<&[u8]>`](DailyDose::try_from), e.g. whenever a day is completed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DailyDose {
+    utc_offset_s: i32,
+    max_gap_ms: u64,
+    day: Option<u32>,
+    today: f32,
+    previous_day: Option<f32>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last: Option<Timestamped<f32>>,
+}
+
+impl DailyDose {
+    /// Size of the persisted representation in bytes.
+    pub const SERIALIZED_SIZE: usize = 14;
+
+    /// Creates a tracker with days starting at midnight UTC.
+    pub const fn new() -> Self {
+        Self {
+            utc_offset_s: 0,
+            max_gap_ms: DEFAULT_MAX_GAP_MS,
+            day: None,
+            today: 0.0,
+            previous_day: None,
+            last: None,
+        }
+    }
+
+    /// Returns the tracker with days starting at midnight in the time zone `utc_offset_s`
+    /// seconds ahead of UTC.
+    pub const fn with_utc_offset(mut self, utc_offset_s: i32) -> Self {
+        self.utc_offset_s = utc_offset_s;
+        self
+    }
+
+    /// Returns the tracker without integrating across gaps between samples longer than
+    /// `max_gap_ms` ms. Defaults to 5 min.
+    pub const fn with_max_gap(mut self, max_gap_ms: u64) -> Self {
+        self.max_gap_ms = max_gap_ms;
+        self
+    }
+
+    /// Adds the concentration `sample` in ug/m³ and returns the completed day if it rolled over.
+    /// The concentration of each sample is applied until the next sample.
+    pub fn update(&mut self, sample: Timestamped<f32>) -> Option<CompletedDay> {
+        if !sample.value.is_finite() {
+            return None;
+        }
+        let day = self.day_of(sample.timestamp_ms);
+        let completed = if let Some(last) = self.last.filter(|last| {
+            sample.timestamp_ms > last.timestamp_ms
+                && sample.timestamp_ms - last.timestamp_ms <= self.max_gap_ms
+        }) {
+            let midnight_ms = (self.local_ms(sample.timestamp_ms) / DAY_MS * DAY_MS
+                - i64::from(self.utc_offset_s) * 1_000)
+                .max(0) as u64;
+            let split_ms = midnight_ms.clamp(last.timestamp_ms, sample.timestamp_ms);
+            self.today += last.value * (split_ms - last.timestamp_ms) as f32 / HOUR_MS;
+            let completed = self.roll_over(day);
+            self.today += last.value * (sample.timestamp_ms - split_ms) as f32 / HOUR_MS;
+            completed
+        } else if self
+            .last
+            .is_none_or(|last| sample.timestamp_ms > last.timestamp_ms)
+        {
+            self.roll_over(day)
+        } else {
+            return None;
+        };
+        self.last = Some(sample);
+        completed
+    }
+
+    /// Returns the dose accumulated today in ug·h/m³.
+    pub fn today(&self) -> f32 {
+        self.today
+    }
+
+    /// Returns the dose accumulated on the previous day in ug·h/m³.
+    pub fn previous_day(&self) -> Option<f32> {
+        self.previous_day
+    }
+
+    /// Returns the persisted representation of the tracker. The time zone, maximum gap and the
+    /// last sample are not persisted.
+    pub fn to_bytes(&self) -> [u8; Self::SERIALIZED_SIZE] {
+        let mut data = [0; Self::SERIALIZED_SIZE];
+        data[0] = u8::from(self.day.is_some());
+        data[1..5].copy_from_slice(&self.day.unwrap_or_default().to_be_bytes());
+        data[5..9].copy_from_slice(&self.today.to_be_bytes());
+        data[9] = u8::from(self.previous_day.is_some());
+        data[10..14].copy_from_slice(&self.previous_day.unwrap_or_default().to_be_bytes());
+        data
+    }
+
+    fn local_ms(&self, timestamp_ms: u64) -> i64 {
+        timestamp_ms as i64 + i64::from(self.utc_offset_s) * 1_000
+    }
+
+    fn day_of(&self, timestamp_ms: u64) -> u32 {
+        self.local_ms(timestamp_ms).div_euclid(DAY_MS).max(0) as u32
+    }
+
+    fn roll_over(&mut self, day: u32) -> Option<CompletedDay> {
+        match self.day {
+            Some(current) if current == day => None,
+            Some(current) => {
+                let completed = CompletedDay {
+                    day: current,
+                    dose: self.today,
+                };
+                // Only the directly preceding day is reported as previous day
+                self.previous_day = (current + 1 == day).then_some(self.today);
+                self.today = 0.0;
+                self.day = Some(day);
+                Some(completed)
+            }
+            None => {
+                self.day = Some(day);
+                None
+            }
+        }
+    }
+}
+
+impl Default for DailyDose {
+    /// Returns a tracker with days starting at midnight UTC.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&[u8]> for DailyDose {
+    type Error = DataError;
+
+    /// Restores the tracker from its persisted representation, with days starting at midnight
+    /// UTC.
+    ///
+    /// # Errors
+    ///
+    /// - [`ReceivedBufferWrongSize`](crate::error::DataError::ReceivedBufferWrongSize): If the
+    ///   data is not the expected size.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let mut reader = ByteReader::new(data);
+        let [has_day] = reader.take()?;
+        let day = u32::from_be_bytes(reader.take()?);
+        let today = f32::from_be_bytes(reader.take()?);
+        let [has_previous_day] = reader.take()?;
+        let previous_day = f32::from_be_bytes(reader.take()?);
+        reader.finish()?;
+        Ok(Self {
+            day: (has_day != 0).then_some(day),
+            today,
+            previous_day: (has_previous_day != 0).then_some(previous_day),
+            ..Self::new()
+        })
+    }
+}
+
+/// Tracks the cumulative dose of PM2.5 and PM10. Unavailable values are skipped.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PmDose {
+    /// Dose of PM2.5.
+    pub pm2_5: DailyDose,
+    /// Dose of PM10.
+    pub pm10_0: DailyDose,
+}
+
+impl PmDose {
+    /// Creates a tracker with days starting at midnight in the time zone `utc_offset_s` seconds
+    /// ahead of UTC.
+    pub const fn new(utc_offset_s: i32) -> Self {
+        Self {
+            pm2_5: DailyDose::new().with_utc_offset(utc_offset_s),
+            pm10_0: DailyDose::new().with_utc_offset(utc_offset_s),
+        }
+    }
+
+    /// Adds the `measurement` and returns the completed day of PM2.5 and PM10 if it rolled over.
+    pub fn update(
+        &mut self,
+        measurement: &Timestamped<Measurement>,
+    ) -> (Option<CompletedDay>, Option<CompletedDay>) {
+        let Timestamped {
+            timestamp_ms,
+            value,
+        } = *measurement;
+        let update = |dose: &mut DailyDose, value: f32| {
+            (value != Measurement::PM_UNAVAILABLE)
+                .then(|| dose.update(Timestamped::new(timestamp_ms, value)))
+                .flatten()
+        };
+        (
+            update(&mut self.pm2_5, value.pm2_5),
+            update(&mut self.pm10_0, value.pm10_0),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINUTE_MS: u64 = 60_000;
+
+    #[test]
+    fn daily_dose_integrates_concentration() {
+        let mut dose = DailyDose::new();
+        assert_eq!(dose.update(Timestamped::new(0, 10.0)), None);
+        dose.update(Timestamped::new(3 * MINUTE_MS, 20.0));
+        dose.update(Timestamped::new(6 * MINUTE_MS, 0.0));
+        // 10 ug/m³ and 20 ug/m³ for 3 min each
+        assert_eq!(dose.today(), 1.5);
+        // Gaps are not integrated
+        dose.update(Timestamped::new(60 * MINUTE_MS, 100.0));
+        dose.update(Timestamped::new(120 * MINUTE_MS, 100.0));
+        assert_eq!(dose.today(), 1.5);
+    }
+
+    #[test]
+    fn daily_dose_rolls_over_at_local_midnight() {
+        let mut dose = DailyDose::new()
+            .with_utc_offset(3_600)
+            .with_max_gap(10 * MINUTE_MS);
+        let midnight_ms = 23 * 60 * MINUTE_MS;
+        dose.update(Timestamped::new(midnight_ms - 3 * MINUTE_MS, 10.0));
+        let completed = dose.update(Timestamped::new(midnight_ms + 3 * MINUTE_MS, 10.0));
+        assert_eq!(completed, Some(CompletedDay { day: 0, dose: 0.5 }));
+        assert_eq!(dose.previous_day(), Some(0.5));
+        assert_eq!(dose.today(), 0.5);
+    }
+
+    #[test]
+    fn daily_dose_roundtrips_through_bytes() {
+        let mut dose = DailyDose::new();
+        dose.update(Timestamped::new(0, 10.0));
+        dose.update(Timestamped::new(3 * MINUTE_MS, 10.0));
+        dose.update(Timestamped::new(6 * MINUTE_MS, 10.0));
+        let data = dose.to_bytes();
+        let restored = DailyDose::try_from(&data[..]).unwrap();
+        assert_eq!(restored.today(), 1.0);
+        assert_eq!(restored.to_bytes(), data);
+        assert_eq!(
+            DailyDose::try_from(&data[1..]),
+            Err(DataError::ReceivedBufferWrongSize)
+        );
+    }
+
+    #[test]
+    fn pm_dose_skips_unavailable_values() {
+        let measurement = Measurement {
+            pm2_5: 12.0,
+            pm10_0: Measurement::PM_UNAVAILABLE,
+            ..Measurement::fixture()
+        };
+        let mut dose = PmDose::new(0);
+        dose.update(&Timestamped::new(0, measurement));
+        dose.update(&Timestamped::new(5 * MINUTE_MS, measurement));
+        assert_eq!(dose.pm2_5.today(), 1.0);
+        assert_eq!(dose.pm10_0.today(), 0.0);
+    }
+}
@@ -0,0 +1,7 @@
+//! Exposure metrics computed from measurement series.
+
+mod dose;
+mod twa;
+
+pub use dose::{CompletedDay, DailyDose, PmDose};
+pub use twa::{ExposureTwa, GUIDELINE_WINDOW_MS, OCCUPATIONAL_WINDOW_MS, TimeWeightedAverage};
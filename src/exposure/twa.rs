@@ -0,0 +1,194 @@
+use crate::data::{Measurement, Timestamped};
+
+/// Averaging window for occupational exposure in ms.
+pub const OCCUPATIONAL_WINDOW_MS: u64 = 8 * 3_600_000;
+/// Averaging window for air quality guidelines in ms.
+pub const GUIDELINE_WINDOW_MS: u64 = 24 * 3_600_000;
+
+/// Samples accumulated within one bucket of the window.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Bucket {
+    sum: f32,
+    count: u32,
+}
+
+/// Time-weighted average of a value over a sliding window, maintained incrementally. The window
+/// is split into `BUCKETS` buckets, so memory is bounded and the window slides in steps of one
+/// bucket. Feed values at a fixed interval, e.g. using a
+/// [`Resampler`](crate::interpolation::Resampler), so each value carries the same weight.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TimeWeightedAverage<const BUCKETS: usize = 48> {
+    bucket_ms: u64,
+    buckets: [Bucket; BUCKETS],
+    current: Option<u64>,
+}
+
+impl<const BUCKETS: usize> TimeWeightedAverage<BUCKETS> {
+    /// Creates an average over a window of `window_ms` ms, e.g.
+    /// [`OCCUPATIONAL_WINDOW_MS`](crate::exposure::OCCUPATIONAL_WINDOW_MS).
+    pub const fn new(window_ms: u64) -> Self {
+        let bucket_ms = window_ms / BUCKETS as u64;
+        Self {
+            bucket_ms: if bucket_ms == 0 { 1 } else { bucket_ms },
+            buckets: [Bucket { sum: 0.0, count: 0 }; BUCKETS],
+            current: None,
+        }
+    }
+
+    /// Returns the covered window in ms.
+    pub fn window_ms(&self) -> u64 {
+        self.bucket_ms * BUCKETS as u64
+    }
+
+    /// Adds the `sample`. Samples older than the latest bucket are ignored.
+    pub fn add(&mut self, sample: Timestamped<f32>) {
+        if BUCKETS == 0 || !sample.value.is_finite() {
+            return;
+        }
+        let bucket = sample.timestamp_ms / self.bucket_ms;
+        match self.current {
+            Some(current) if bucket < current => return,
+            Some(current) => {
+                // Clear all buckets which dropped out of the window
+                for skipped in (current + 1..=bucket).take(BUCKETS) {
+                    self.buckets[(skipped % BUCKETS as u64) as usize] = Bucket::default();
+                }
+            }
+            None => {}
+        }
+        self.current = Some(bucket);
+        let slot = &mut self.buckets[(bucket % BUCKETS as u64) as usize];
+        slot.sum += sample.value;
+        slot.count += 1;
+    }
+
+    /// Returns the average over all samples within the window. `None` if no samples are
+    /// available.
+    pub fn average(&self) -> Option<f32> {
+        let (sum, count) = self.buckets.iter().fold((0.0, 0), |(sum, count), bucket| {
+            (sum + bucket.sum, count + bucket.count)
+        });
+        (count > 0).then(|| sum / count as f32)
+    }
+
+    /// Returns the fraction of buckets within the window containing samples, indicating whether
+    /// the average covers the full window.
+    pub fn coverage(&self) -> f32 {
+        if BUCKETS == 0 {
+            return 0.0;
+        }
+        let filled = self
+            .buckets
+            .iter()
+            .filter(|bucket| bucket.count > 0)
+            .count();
+        filled as f32 / BUCKETS as f32
+    }
+
+    /// Clears all samples.
+    pub fn reset(&mut self) {
+        *self = Self::new(self.window_ms());
+    }
+}
+
+/// Tracks the time-weighted average exposure to PM2.5 and CO2. Unavailable values are skipped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExposureTwa<const BUCKETS: usize = 48> {
+    pm2_5: TimeWeightedAverage<BUCKETS>,
+    co2: TimeWeightedAverage<BUCKETS>,
+}
+
+impl<const BUCKETS: usize> ExposureTwa<BUCKETS> {
+    /// Creates a tracker averaging over a window of `window_ms` ms.
+    pub const fn new(window_ms: u64) -> Self {
+        Self {
+            pm2_5: TimeWeightedAverage::new(window_ms),
+            co2: TimeWeightedAverage::new(window_ms),
+        }
+    }
+
+    /// Creates a tracker for the 8 h occupational window.
+    pub const fn occupational() -> Self {
+        Self::new(OCCUPATIONAL_WINDOW_MS)
+    }
+
+    /// Creates a tracker for the 24 h guideline window.
+    pub const fn guideline() -> Self {
+        Self::new(GUIDELINE_WINDOW_MS)
+    }
+
+    /// Adds the `measurement`.
+    pub fn update(&mut self, measurement: &Timestamped<Measurement>) {
+        let Timestamped {
+            timestamp_ms,
+            value,
+        } = *measurement;
+        if value.pm2_5 != Measurement::PM_UNAVAILABLE {
+            self.pm2_5.add(Timestamped::new(timestamp_ms, value.pm2_5));
+        }
+        if value.co2 != Measurement::CO2_UNAVAILABLE {
+            self.co2
+                .add(Timestamped::new(timestamp_ms, f32::from(value.co2)));
+        }
+    }
+
+    /// Returns the time-weighted average of PM2.5 in ug/m³.
+    pub fn pm2_5(&self) -> &TimeWeightedAverage<BUCKETS> {
+        &self.pm2_5
+    }
+
+    /// Returns the time-weighted average of the CO2 concentration in ppm.
+    pub fn co2(&self) -> &TimeWeightedAverage<BUCKETS> {
+        &self.co2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn twa_slides_window_by_bucket() {
+        let mut twa = TimeWeightedAverage::<4>::new(4_000);
+        assert_eq!(twa.average(), None);
+        for (timestamp_ms, value) in [(0, 1.0), (500, 3.0), (1_000, 5.0), (3_000, 7.0)] {
+            twa.add(Timestamped::new(timestamp_ms, value));
+        }
+        assert_eq!(twa.average(), Some(4.0));
+        assert_eq!(twa.coverage(), 0.75);
+
+        // Evicts the first bucket
+        twa.add(Timestamped::new(4_000, 9.0));
+        assert_eq!(twa.average(), Some(7.0));
+        // Ignores samples older than the latest bucket
+        twa.add(Timestamped::new(100, 100.0));
+        assert_eq!(twa.average(), Some(7.0));
+        // Evicts everything
+        twa.add(Timestamped::new(100_000, 2.0));
+        assert_eq!(twa.average(), Some(2.0));
+        twa.reset();
+        assert_eq!(twa.average(), None);
+    }
+
+    #[test]
+    fn exposure_twa_skips_unavailable_values() {
+        let measurement = Measurement {
+            pm2_5: 10.0,
+            co2: Measurement::CO2_UNAVAILABLE,
+            ..Measurement::fixture()
+        };
+        let mut exposure = ExposureTwa::<48>::occupational();
+        exposure.update(&Timestamped::new(0, measurement));
+        exposure.update(&Timestamped::new(
+            60_000,
+            Measurement {
+                pm2_5: 20.0,
+                co2: 800,
+                ..measurement
+            },
+        ));
+        assert_eq!(exposure.pm2_5().average(), Some(15.0));
+        assert_eq!(exposure.co2().average(), Some(800.0));
+        assert_eq!(exposure.co2().window_ms(), OCCUPATIONAL_WINDOW_MS);
+    }
+}
@@ -0,0 +1,70 @@
+use super::Filter;
+
+/// Exponential moving average, weighting each new value with a smoothing factor `alpha` and
+/// the previous average with `1 - alpha`. Smaller factors result in a smoother, but more delayed
+/// signal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ema {
+    alpha: f32,
+    value: Option<f32>,
+}
+
+impl Ema {
+    /// Creates a filter with the smoothing factor `alpha`, clamped to 0 to 1.
+    pub fn new(alpha: f32) -> Self {
+        Self {
+            alpha: alpha.clamp(0.0, 1.0),
+            value: None,
+        }
+    }
+
+    /// Creates a filter for samples taken every `interval_s` seconds with a time constant of
+    /// `time_constant_s` seconds.
+    pub fn with_time_constant(time_constant_s: f32, interval_s: f32) -> Self {
+        Self::new(interval_s / (time_constant_s + interval_s))
+    }
+
+    /// Returns the smoothing factor.
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+}
+
+impl Filter for Ema {
+    /// Processes the next `value`. The first value initializes the average.
+    fn process(&mut self, value: f32) -> f32 {
+        let value = match self.value {
+            Some(average) => average + self.alpha * (value - average),
+            None => value,
+        };
+        self.value = Some(value);
+        value
+    }
+
+    fn value(&self) -> Option<f32> {
+        self.value
+    }
+
+    fn reset(&mut self) {
+        self.value = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ema_weights_new_values() {
+        let mut ema = Ema::new(0.25);
+        assert_eq!(ema.value(), None);
+        assert_eq!(ema.process(10.0), 10.0);
+        assert_eq!(ema.process(18.0), 12.0);
+        assert_eq!(ema.process(16.0), 13.0);
+        ema.reset();
+        assert_eq!(ema.process(2.0), 2.0);
+        assert_eq!(Ema::with_time_constant(3.0, 1.0).alpha(), 0.25);
+        assert_eq!(Ema::new(2.0).alpha(), 1.0);
+    }
+}
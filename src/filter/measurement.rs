@@ -0,0 +1,102 @@
+use super::Filter;
+use crate::data::{Channel, ChannelSet, Measurement};
+
+/// Applies a [`Filter`] to each selected channel of a measurement series.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MeasurementFilter<F> {
+    filters: [F; Channel::COUNT],
+    channels: ChannelSet,
+}
+
+impl<F: Filter + Clone> MeasurementFilter<F> {
+    /// Creates a filter applying a copy of `filter` to every channel.
+    pub fn new(filter: F) -> Self {
+        Self {
+            filters: core::array::from_fn(|_| filter.clone()),
+            channels: ChannelSet::ALL,
+        }
+    }
+
+    /// Returns the filter only applied to the `channels`, e.g. the PM mass concentrations.
+    /// Other channels are passed through.
+    pub fn with_channels(mut self, channels: ChannelSet) -> Self {
+        self.channels = channels;
+        self
+    }
+}
+
+impl<F: Filter> MeasurementFilter<F> {
+    /// Processes the next `measurement` and returns it with the selected channels filtered.
+    /// Unavailable values are passed through without affecting the filter of their channel.
+    pub fn process(&mut self, measurement: &Measurement) -> Measurement {
+        let mut filtered = *measurement;
+        for channel in self.channels.iter() {
+            let Some(value) = channel.value(measurement) else {
+                continue;
+            };
+            let value = self.filters[channel as usize].process(value);
+            match channel {
+                Channel::Pm1_0 => filtered.pm1_0 = value,
+                Channel::Pm2_5 => filtered.pm2_5 = value,
+                Channel::Pm4_0 => filtered.pm4_0 = value,
+                Channel::Pm10_0 => filtered.pm10_0 = value,
+                Channel::RelativeHumidity => filtered.relative_humidity = value,
+                Channel::Temperature => filtered.temperature = value,
+                Channel::VocIndex => filtered.voc_index = value,
+                Channel::NoxIndex => filtered.nox_index = value,
+                Channel::Co2 => filtered.co2 = (value + 0.5) as u16,
+            }
+        }
+        filtered
+    }
+
+    /// Returns the filter of `channel`, e.g. to read its current value.
+    pub fn channel(&self, channel: Channel) -> &F {
+        &self.filters[channel as usize]
+    }
+
+    /// Discards all processed values, e.g. after restarting the measurement.
+    pub fn reset(&mut self) {
+        self.filters.iter_mut().for_each(Filter::reset);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::{Ema, MovingAverage};
+
+    fn measurement(pm2_5: f32, co2: u16) -> Measurement {
+        Measurement {
+            pm2_5,
+            pm4_0: pm2_5,
+            pm10_0: pm2_5,
+            co2,
+            ..Measurement::fixture()
+        }
+    }
+
+    #[test]
+    fn measurement_filter_smooths_selected_channels() {
+        let mut filter = MeasurementFilter::new(Ema::new(0.5))
+            .with_channels(ChannelSet::EMPTY.with(Channel::Pm2_5).with(Channel::Co2));
+        filter.process(&measurement(10.0, 600));
+        let filtered = filter.process(&measurement(20.0, 701));
+        assert_eq!(filtered.pm2_5, 15.0);
+        assert_eq!(filtered.pm10_0, 20.0);
+        assert_eq!(filtered.co2, 651);
+        assert_eq!(filter.channel(Channel::Pm2_5).value(), Some(15.0));
+    }
+
+    #[test]
+    fn measurement_filter_passes_unavailable_values() {
+        let mut filter = MeasurementFilter::new(MovingAverage::<4>::new());
+        filter.process(&measurement(10.0, 600));
+        let filtered = filter.process(&measurement(20.0, Measurement::CO2_UNAVAILABLE));
+        assert_eq!(filtered.pm2_5, 15.0);
+        assert_eq!(filtered.co2, Measurement::CO2_UNAVAILABLE);
+        assert_eq!(filter.process(&measurement(30.0, 800)).co2, 700);
+        filter.reset();
+        assert_eq!(filter.channel(Channel::Co2).value(), None);
+    }
+}
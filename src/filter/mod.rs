@@ -0,0 +1,25 @@
+//! Smoothing filters for noisy measurement values, e.g. the PM mass concentrations which vary
+//! notably from sample to sample.
+//!
+//! Apply an [`Ema`] or a [`MovingAverage`] to a single value, or a [`MeasurementFilter`] to
+//! whole measurements.
+
+mod ema;
+mod measurement;
+mod moving_average;
+
+pub use ema::Ema;
+pub use measurement::MeasurementFilter;
+pub use moving_average::MovingAverage;
+
+/// Filter smoothing a series of values.
+pub trait Filter {
+    /// Processes the next `value` of the series and returns the filtered value.
+    fn process(&mut self, value: f32) -> f32;
+
+    /// Returns the filtered value. `None` before the first value was processed.
+    fn value(&self) -> Option<f32>;
+
+    /// Discards all processed values.
+    fn reset(&mut self);
+}
@@ -0,0 +1,75 @@
+use super::Filter;
+
+/// Mean of the last `N` values. Until `N` values were processed, the mean of the processed
+/// values is returned.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MovingAverage<const N: usize> {
+    values: [f32; N],
+    next: usize,
+    len: usize,
+}
+
+impl<const N: usize> MovingAverage<N> {
+    /// Creates a filter without values.
+    pub const fn new() -> Self {
+        Self {
+            values: [0.0; N],
+            next: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns whether the window is filled with `N` values.
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+}
+
+impl<const N: usize> Filter for MovingAverage<N> {
+    /// Processes the next `value`, replacing the oldest one once the window is full. A window of
+    /// zero values passes values through.
+    fn process(&mut self, value: f32) -> f32 {
+        if N == 0 {
+            return value;
+        }
+        self.values[self.next] = value;
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+        self.value().unwrap_or(value)
+    }
+
+    fn value(&self) -> Option<f32> {
+        (self.len > 0).then(|| self.values[..self.len].iter().sum::<f32>() / self.len as f32)
+    }
+
+    fn reset(&mut self) {
+        self.next = 0;
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> Default for MovingAverage<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moving_average_drops_oldest_value() {
+        let mut average = MovingAverage::<3>::new();
+        assert_eq!(average.value(), None);
+        assert_eq!(average.process(3.0), 3.0);
+        assert_eq!(average.process(6.0), 4.5);
+        assert!(!average.is_full());
+        assert_eq!(average.process(9.0), 6.0);
+        assert_eq!(average.process(12.0), 9.0);
+        assert!(average.is_full());
+        average.reset();
+        assert_eq!(average.process(1.0), 1.0);
+        assert_eq!(MovingAverage::<0>::new().process(5.0), 5.0);
+    }
+}
@@ -0,0 +1,700 @@
+//! Host-side port of Sensirion's gas index algorithm.
+//!
+//! The SEN66 computes the VOC and NOx index on the device. The algorithms provided here compute
+//! the indices from the raw VOC and NOx ticks of a
+//! [`RawMeasurement`](crate::data::RawMeasurement) instead, e.g. to reprocess logged raw data or
+//! to experiment with tuning parameters without reconfiguring the sensor.
+
+use libm::{expf, sqrtf};
+
+use crate::configuration::{NoxTuning, VocTuning};
+
+const DEFAULT_SAMPLING_INTERVAL: f32 = 1.;
+const INITIAL_BLACKOUT: f32 = 45.;
+const INDEX_GAIN: f32 = 230.;
+const SRAW_STD_INITIAL: f32 = 50.;
+const SRAW_STD_BONUS_VOC: f32 = 220.;
+const SRAW_STD_NOX: f32 = 2_000.;
+const TAU_MEAN_HOURS: f32 = 12.;
+const TAU_VARIANCE_HOURS: f32 = 12.;
+const TAU_INITIAL_MEAN_VOC: f32 = 20.;
+const TAU_INITIAL_MEAN_NOX: f32 = 1_200.;
+const INIT_DURATION_MEAN_VOC: f32 = 3_600. * 0.75;
+const INIT_DURATION_MEAN_NOX: f32 = 3_600. * 4.75;
+const INIT_TRANSITION_MEAN: f32 = 0.01;
+const TAU_INITIAL_VARIANCE: f32 = 2_500.;
+const INIT_DURATION_VARIANCE_VOC: f32 = 3_600. * 1.45;
+const INIT_DURATION_VARIANCE_NOX: f32 = 3_600. * 5.70;
+const INIT_TRANSITION_VARIANCE: f32 = 0.01;
+const GATING_THRESHOLD_VOC: f32 = 340.;
+const GATING_THRESHOLD_NOX: f32 = 30.;
+const GATING_THRESHOLD_INITIAL: f32 = 510.;
+const GATING_THRESHOLD_TRANSITION: f32 = 0.09;
+const GATING_VOC_MAX_DURATION_MINUTES: f32 = 60. * 3.;
+const GATING_NOX_MAX_DURATION_MINUTES: f32 = 60. * 12.;
+const GATING_MAX_RATIO: f32 = 0.3;
+const SIGMOID_L: f32 = 500.;
+const SIGMOID_K_VOC: f32 = -0.0065;
+const SIGMOID_X0_VOC: f32 = 213.;
+const SIGMOID_K_NOX: f32 = -0.0101;
+const SIGMOID_X0_NOX: f32 = 614.;
+const VOC_INDEX_OFFSET_DEFAULT: f32 = 100.;
+const NOX_INDEX_OFFSET_DEFAULT: f32 = 1.;
+const LP_TAU_FAST: f32 = 20.;
+const LP_TAU_SLOW: f32 = 500.;
+const LP_ALPHA: f32 = -0.2;
+const VOC_SRAW_MINIMUM: i32 = 20_000;
+const NOX_SRAW_MINIMUM: i32 = 10_000;
+const PERSISTENCE_UPTIME_GAMMA: f32 = 3. * 3_600.;
+const GAMMA_SCALING: f32 = 64.;
+const ADDITIONAL_GAMMA_MEAN_SCALING: f32 = 8.;
+const FIX16_MAX: f32 = 32_767.;
+
+/// Learned state of a gas index algorithm. Restore it after a power cycle to skip the initial
+/// learning phase.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GasIndexState {
+    /// Estimated mean of the raw signal.
+    pub mean: f32,
+    /// Estimated standard deviation of the raw signal.
+    pub std: f32,
+}
+
+/// Computes the VOC index from raw VOC ticks, as the SEN66 does on the device.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VocGasIndex(GasIndexAlgorithm);
+
+impl VocGasIndex {
+    /// Creates the algorithm for samples taken every second, the SEN66's measurement interval.
+    pub fn new() -> Self {
+        Self::with_sampling_interval(DEFAULT_SAMPLING_INTERVAL)
+    }
+
+    /// Creates the algorithm for samples taken every `sampling_interval_s` seconds.
+    pub fn with_sampling_interval(sampling_interval_s: f32) -> Self {
+        Self(GasIndexAlgorithm::new(Kind::Voc, sampling_interval_s))
+    }
+
+    /// Applies the `tuning` parameters and restarts the learning of the algorithm.
+    pub fn set_tuning(&mut self, tuning: VocTuning) {
+        self.0.set_tuning(<[u16; 6]>::from(tuning));
+    }
+
+    /// Processes the raw VOC ticks of a sample and returns the VOC index. Returns 0 during the
+    /// initial blackout of 45s.
+    pub fn process(&mut self, sraw: u16) -> u16 {
+        self.0.process(sraw)
+    }
+
+    /// Returns the learned state of the algorithm.
+    pub fn state(&self) -> GasIndexState {
+        self.0.state()
+    }
+
+    /// Restores a previously learned `state` of the algorithm.
+    pub fn set_state(&mut self, state: GasIndexState) {
+        self.0.set_state(state);
+    }
+
+    /// Resets the algorithm, discarding the learned state while keeping the tuning parameters.
+    pub fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl Default for VocGasIndex {
+    /// Creates the algorithm for samples taken every second.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Computes the NOx index from raw NOx ticks, as the SEN66 does on the device.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NoxGasIndex(GasIndexAlgorithm);
+
+impl NoxGasIndex {
+    /// Creates the algorithm for samples taken every second, the SEN66's measurement interval.
+    pub fn new() -> Self {
+        Self::with_sampling_interval(DEFAULT_SAMPLING_INTERVAL)
+    }
+
+    /// Creates the algorithm for samples taken every `sampling_interval_s` seconds.
+    pub fn with_sampling_interval(sampling_interval_s: f32) -> Self {
+        Self(GasIndexAlgorithm::new(Kind::Nox, sampling_interval_s))
+    }
+
+    /// Applies the `tuning` parameters and restarts the learning of the algorithm.
+    pub fn set_tuning(&mut self, tuning: NoxTuning) {
+        self.0.set_tuning(<[u16; 6]>::from(tuning));
+    }
+
+    /// Processes the raw NOx ticks of a sample and returns the NOx index. Returns 0 during the
+    /// initial blackout of 45s.
+    pub fn process(&mut self, sraw: u16) -> u16 {
+        self.0.process(sraw)
+    }
+
+    /// Returns the learned state of the algorithm.
+    pub fn state(&self) -> GasIndexState {
+        self.0.state()
+    }
+
+    /// Restores a previously learned `state` of the algorithm.
+    pub fn set_state(&mut self, state: GasIndexState) {
+        self.0.set_state(state);
+    }
+
+    /// Resets the algorithm, discarding the learned state while keeping the tuning parameters.
+    pub fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl Default for NoxGasIndex {
+    /// Creates the algorithm for samples taken every second.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Gas the algorithm is processing.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Kind {
+    Voc,
+    Nox,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct GasIndexAlgorithm {
+    kind: Kind,
+    sampling_interval: f32,
+    index_offset: f32,
+    sraw_minimum: i32,
+    gating_max_duration_minutes: f32,
+    init_duration_mean: f32,
+    init_duration_variance: f32,
+    gating_threshold: f32,
+    index_gain: f32,
+    tau_mean_hours: f32,
+    tau_variance_hours: f32,
+    sraw_std_initial: f32,
+    uptime: f32,
+    sraw: f32,
+    gas_index: f32,
+    estimator: MeanVarianceEstimator,
+    mox_model: MoxModel,
+    sigmoid_scaled: SigmoidScaled,
+    lowpass: AdaptiveLowpass,
+}
+
+impl GasIndexAlgorithm {
+    fn new(kind: Kind, sampling_interval: f32) -> Self {
+        let mut algorithm = Self {
+            kind,
+            sampling_interval,
+            index_offset: VOC_INDEX_OFFSET_DEFAULT,
+            sraw_minimum: VOC_SRAW_MINIMUM,
+            gating_max_duration_minutes: GATING_VOC_MAX_DURATION_MINUTES,
+            init_duration_mean: INIT_DURATION_MEAN_VOC,
+            init_duration_variance: INIT_DURATION_VARIANCE_VOC,
+            gating_threshold: GATING_THRESHOLD_VOC,
+            index_gain: INDEX_GAIN,
+            tau_mean_hours: TAU_MEAN_HOURS,
+            tau_variance_hours: TAU_VARIANCE_HOURS,
+            sraw_std_initial: SRAW_STD_INITIAL,
+            uptime: 0.,
+            sraw: 0.,
+            gas_index: 0.,
+            estimator: MeanVarianceEstimator::default(),
+            mox_model: MoxModel::default(),
+            sigmoid_scaled: SigmoidScaled::default(),
+            lowpass: AdaptiveLowpass::default(),
+        };
+        if kind == Kind::Nox {
+            algorithm.index_offset = NOX_INDEX_OFFSET_DEFAULT;
+            algorithm.sraw_minimum = NOX_SRAW_MINIMUM;
+            algorithm.gating_max_duration_minutes = GATING_NOX_MAX_DURATION_MINUTES;
+            algorithm.init_duration_mean = INIT_DURATION_MEAN_NOX;
+            algorithm.init_duration_variance = INIT_DURATION_VARIANCE_NOX;
+            algorithm.gating_threshold = GATING_THRESHOLD_NOX;
+        }
+        algorithm.reset();
+        algorithm
+    }
+
+    fn reset(&mut self) {
+        self.uptime = 0.;
+        self.sraw = 0.;
+        self.gas_index = 0.;
+        self.init_instances();
+    }
+
+    fn init_instances(&mut self) {
+        self.estimator.set_parameters(
+            self.sampling_interval,
+            self.sraw_std_initial,
+            self.tau_mean_hours,
+            self.tau_variance_hours,
+            match self.kind {
+                Kind::Voc => TAU_INITIAL_MEAN_VOC,
+                Kind::Nox => TAU_INITIAL_MEAN_NOX,
+            },
+        );
+        self.mox_model = MoxModel {
+            sraw_std: self.estimator.std,
+            sraw_mean: self.estimator.mean(),
+        };
+        self.sigmoid_scaled = match self.kind {
+            Kind::Voc => SigmoidScaled {
+                k: SIGMOID_K_VOC,
+                x0: SIGMOID_X0_VOC,
+                offset_default: VOC_INDEX_OFFSET_DEFAULT,
+            },
+            Kind::Nox => SigmoidScaled {
+                k: SIGMOID_K_NOX,
+                x0: SIGMOID_X0_NOX,
+                offset_default: NOX_INDEX_OFFSET_DEFAULT,
+            },
+        };
+        self.lowpass.set_parameters(self.sampling_interval);
+    }
+
+    fn set_tuning(&mut self, tuning: [u16; 6]) {
+        let [
+            index_offset,
+            learning_time_offset_hours,
+            learning_time_gain_hours,
+            gating_max_duration_minutes,
+            std_initial,
+            gain_factor,
+        ] = tuning.map(f32::from);
+        self.index_offset = index_offset;
+        self.tau_mean_hours = learning_time_offset_hours;
+        self.tau_variance_hours = learning_time_gain_hours;
+        self.gating_max_duration_minutes = gating_max_duration_minutes;
+        self.sraw_std_initial = std_initial;
+        self.index_gain = gain_factor;
+        self.init_instances();
+    }
+
+    fn state(&self) -> GasIndexState {
+        GasIndexState {
+            mean: self.estimator.mean(),
+            std: self.estimator.std,
+        }
+    }
+
+    fn set_state(&mut self, state: GasIndexState) {
+        self.estimator
+            .set_states(state.mean, state.std, PERSISTENCE_UPTIME_GAMMA);
+        self.mox_model = MoxModel {
+            sraw_std: self.estimator.std,
+            sraw_mean: self.estimator.mean(),
+        };
+        self.sraw = state.mean;
+    }
+
+    fn process(&mut self, sraw: u16) -> u16 {
+        if self.uptime <= INITIAL_BLACKOUT {
+            self.uptime += self.sampling_interval;
+        } else {
+            if sraw > 0 && sraw < 65_000 {
+                let sraw = i32::from(sraw).clamp(self.sraw_minimum + 1, self.sraw_minimum + 32_767);
+                self.sraw = (sraw - self.sraw_minimum) as f32;
+            }
+            self.gas_index = if self.kind == Kind::Voc || self.estimator.initialized {
+                let gas_index = self
+                    .mox_model
+                    .process(self.kind, self.sraw, self.index_gain);
+                self.sigmoid_scaled.process(gas_index, self.index_offset)
+            } else {
+                self.index_offset
+            };
+            self.gas_index = self.lowpass.process(self.gas_index, self.sampling_interval);
+            if self.gas_index < 0.5 {
+                self.gas_index = 0.5;
+            }
+            if self.sraw > 0. {
+                self.estimator.process(
+                    self.sraw,
+                    self.sampling_interval,
+                    self.gas_index,
+                    self.gating_threshold,
+                    self.gating_max_duration_minutes,
+                    self.init_duration_mean,
+                    self.init_duration_variance,
+                );
+                self.mox_model = MoxModel {
+                    sraw_std: self.estimator.std,
+                    sraw_mean: self.estimator.mean(),
+                };
+            }
+        }
+        (self.gas_index + 0.5) as u16
+    }
+}
+
+/// Estimates mean and standard deviation of the raw signal.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct MeanVarianceEstimator {
+    initialized: bool,
+    mean: f32,
+    sraw_offset: f32,
+    std: f32,
+    gamma_mean: f32,
+    gamma_variance: f32,
+    gamma_initial_mean: f32,
+    gamma_initial_variance: f32,
+    current_gamma_mean: f32,
+    current_gamma_variance: f32,
+    uptime_gamma: f32,
+    uptime_gating: f32,
+    gating_duration_minutes: f32,
+}
+
+impl MeanVarianceEstimator {
+    fn set_parameters(
+        &mut self,
+        sampling_interval: f32,
+        std_initial: f32,
+        tau_mean_hours: f32,
+        tau_variance_hours: f32,
+        tau_initial_mean: f32,
+    ) {
+        let interval_hours = sampling_interval / 3_600.;
+        *self = Self {
+            std: std_initial,
+            gamma_mean: ADDITIONAL_GAMMA_MEAN_SCALING * GAMMA_SCALING * interval_hours
+                / (tau_mean_hours + interval_hours),
+            gamma_variance: GAMMA_SCALING * interval_hours / (tau_variance_hours + interval_hours),
+            gamma_initial_mean: ADDITIONAL_GAMMA_MEAN_SCALING * GAMMA_SCALING * sampling_interval
+                / (tau_initial_mean + sampling_interval),
+            gamma_initial_variance: GAMMA_SCALING * sampling_interval
+                / (TAU_INITIAL_VARIANCE + sampling_interval),
+            ..Self::default()
+        };
+    }
+
+    fn set_states(&mut self, mean: f32, std: f32, uptime_gamma: f32) {
+        self.mean = mean;
+        self.std = std;
+        self.uptime_gamma = uptime_gamma;
+        self.initialized = true;
+    }
+
+    fn mean(&self) -> f32 {
+        self.mean + self.sraw_offset
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn calculate_gamma(
+        &mut self,
+        sampling_interval: f32,
+        gas_index: f32,
+        gating_threshold: f32,
+        gating_max_duration_minutes: f32,
+        init_duration_mean: f32,
+        init_duration_variance: f32,
+    ) {
+        let uptime_limit = FIX16_MAX - sampling_interval;
+        if self.uptime_gamma < uptime_limit {
+            self.uptime_gamma += sampling_interval;
+        }
+        if self.uptime_gating < uptime_limit {
+            self.uptime_gating += sampling_interval;
+        }
+
+        let sigmoid = Sigmoid::new(init_duration_mean, INIT_TRANSITION_MEAN);
+        let sigmoid_gamma_mean = sigmoid.process(self.uptime_gamma);
+        let gamma_mean =
+            self.gamma_mean + (self.gamma_initial_mean - self.gamma_mean) * sigmoid_gamma_mean;
+        let gating_threshold_mean = gating_threshold
+            + (GATING_THRESHOLD_INITIAL - gating_threshold) * sigmoid.process(self.uptime_gating);
+        let sigmoid_gating_mean =
+            Sigmoid::new(gating_threshold_mean, GATING_THRESHOLD_TRANSITION).process(gas_index);
+        self.current_gamma_mean = sigmoid_gating_mean * gamma_mean;
+
+        let sigmoid = Sigmoid::new(init_duration_variance, INIT_TRANSITION_VARIANCE);
+        let sigmoid_gamma_variance = sigmoid.process(self.uptime_gamma);
+        let gamma_variance = self.gamma_variance
+            + (self.gamma_initial_variance - self.gamma_variance)
+                * (sigmoid_gamma_variance - sigmoid_gamma_mean);
+        let gating_threshold_variance = gating_threshold
+            + (GATING_THRESHOLD_INITIAL - gating_threshold) * sigmoid.process(self.uptime_gating);
+        let sigmoid_gating_variance =
+            Sigmoid::new(gating_threshold_variance, GATING_THRESHOLD_TRANSITION).process(gas_index);
+        self.current_gamma_variance = sigmoid_gating_variance * gamma_variance;
+
+        self.gating_duration_minutes += sampling_interval / 60.
+            * ((1. - sigmoid_gating_mean) * (1. + GATING_MAX_RATIO) - GATING_MAX_RATIO);
+        if self.gating_duration_minutes < 0. {
+            self.gating_duration_minutes = 0.;
+        }
+        if self.gating_duration_minutes > gating_max_duration_minutes {
+            self.uptime_gating = 0.;
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process(
+        &mut self,
+        sraw: f32,
+        sampling_interval: f32,
+        gas_index: f32,
+        gating_threshold: f32,
+        gating_max_duration_minutes: f32,
+        init_duration_mean: f32,
+        init_duration_variance: f32,
+    ) {
+        if !self.initialized {
+            self.initialized = true;
+            self.sraw_offset = sraw;
+            self.mean = 0.;
+            return;
+        }
+        if self.mean >= 100. || self.mean <= -100. {
+            self.sraw_offset += self.mean;
+            self.mean = 0.;
+        }
+        let sraw = sraw - self.sraw_offset;
+        self.calculate_gamma(
+            sampling_interval,
+            gas_index,
+            gating_threshold,
+            gating_max_duration_minutes,
+            init_duration_mean,
+            init_duration_variance,
+        );
+        let delta_sgp = (sraw - self.mean) / GAMMA_SCALING;
+        let c = if delta_sgp < 0. {
+            self.std - delta_sgp
+        } else {
+            self.std + delta_sgp
+        };
+        let additional_scaling = if c > 1_440. {
+            (c / 1_440.) * (c / 1_440.)
+        } else {
+            1.
+        };
+        self.std = sqrtf(additional_scaling * (GAMMA_SCALING - self.current_gamma_variance))
+            * sqrtf(
+                self.std * (self.std / (GAMMA_SCALING * additional_scaling))
+                    + self.current_gamma_variance * delta_sgp / additional_scaling * delta_sgp,
+            );
+        self.mean += self.current_gamma_mean * delta_sgp / ADDITIONAL_GAMMA_MEAN_SCALING;
+    }
+}
+
+/// Logistic function used to blend between the estimator's phases.
+struct Sigmoid {
+    x0: f32,
+    k: f32,
+}
+
+impl Sigmoid {
+    fn new(x0: f32, k: f32) -> Self {
+        Self { x0, k }
+    }
+
+    fn process(&self, sample: f32) -> f32 {
+        let x = self.k * (sample - self.x0);
+        if x < -50. {
+            1.
+        } else if x > 50. {
+            0.
+        } else {
+            1. / (1. + expf(x))
+        }
+    }
+}
+
+/// Normalizes the raw signal by the estimated mean and standard deviation.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct MoxModel {
+    sraw_std: f32,
+    sraw_mean: f32,
+}
+
+impl MoxModel {
+    fn process(&self, kind: Kind, sraw: f32, index_gain: f32) -> f32 {
+        match kind {
+            Kind::Voc => {
+                (sraw - self.sraw_mean) / -(self.sraw_std + SRAW_STD_BONUS_VOC) * index_gain
+            }
+            Kind::Nox => (sraw - self.sraw_mean) / SRAW_STD_NOX * index_gain,
+        }
+    }
+}
+
+/// Maps the normalized signal onto the index range.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct SigmoidScaled {
+    k: f32,
+    x0: f32,
+    offset_default: f32,
+}
+
+impl SigmoidScaled {
+    fn process(&self, sample: f32, index_offset: f32) -> f32 {
+        let x = self.k * (sample - self.x0);
+        if x < -50. {
+            SIGMOID_L
+        } else if x > 50. {
+            0.
+        } else if sample >= 0. {
+            let shift = if self.offset_default == 1. {
+                (500. / 499.) * (1. - index_offset)
+            } else {
+                (SIGMOID_L - 5. * index_offset) / 4.
+            };
+            (SIGMOID_L + shift) / (1. + expf(x)) - shift
+        } else {
+            index_offset / self.offset_default * (SIGMOID_L / (1. + expf(x)))
+        }
+    }
+}
+
+/// Low-pass filter whose time constant adapts to the signal's dynamics.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct AdaptiveLowpass {
+    a1: f32,
+    a2: f32,
+    x1: f32,
+    x2: f32,
+    x3: f32,
+    initialized: bool,
+}
+
+impl AdaptiveLowpass {
+    fn set_parameters(&mut self, sampling_interval: f32) {
+        self.a1 = sampling_interval / (LP_TAU_FAST + sampling_interval);
+        self.a2 = sampling_interval / (LP_TAU_SLOW + sampling_interval);
+        self.initialized = false;
+    }
+
+    fn process(&mut self, sample: f32, sampling_interval: f32) -> f32 {
+        if !self.initialized {
+            self.x1 = sample;
+            self.x2 = sample;
+            self.x3 = sample;
+            self.initialized = true;
+        }
+        self.x1 = (1. - self.a1) * self.x1 + self.a1 * sample;
+        self.x2 = (1. - self.a2) * self.x2 + self.a2 * sample;
+        let abs_delta = (self.x1 - self.x2).abs();
+        let f1 = expf(LP_ALPHA * abs_delta);
+        let tau_a = (LP_TAU_SLOW - LP_TAU_FAST) * f1 + LP_TAU_FAST;
+        let a3 = sampling_interval / (sampling_interval + tau_a);
+        self.x3 = (1. - a3) * self.x3 + a3 * sample;
+        self.x3
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn voc_gas_index_is_zero_during_blackout() {
+        let mut algorithm = VocGasIndex::new();
+        for _ in 0..45 {
+            assert_eq!(algorithm.process(30_000), 0);
+        }
+    }
+
+    #[test]
+    fn voc_gas_index_settles_at_offset() {
+        let mut algorithm = VocGasIndex::new();
+        let mut index = 0;
+        for _ in 0..3_600 {
+            index = algorithm.process(30_000);
+        }
+        assert_eq!(index, 100);
+    }
+
+    #[test]
+    fn voc_gas_index_rises_on_voc_event() {
+        let mut algorithm = VocGasIndex::new();
+        for _ in 0..3_600 {
+            algorithm.process(30_000);
+        }
+        let mut index = 0;
+        for _ in 0..60 {
+            index = algorithm.process(29_000);
+        }
+        assert!(index > 150, "index {index}");
+    }
+
+    #[test]
+    fn voc_gas_index_restores_state() {
+        let mut learned = VocGasIndex::new();
+        for _ in 0..3_600 {
+            learned.process(30_000);
+        }
+        let state = learned.state();
+        assert!((state.mean - 10_000.).abs() < 1.);
+
+        let mut restored = VocGasIndex::new();
+        restored.set_state(state);
+        assert_eq!(restored.state(), state);
+        let mut index = 0;
+        for _ in 0..60 {
+            index = restored.process(30_000);
+        }
+        assert_eq!(index, 100);
+    }
+
+    #[test]
+    fn nox_gas_index_settles_at_offset() {
+        let mut algorithm = NoxGasIndex::new();
+        let mut index = 0;
+        for _ in 0..45 {
+            assert_eq!(algorithm.process(16_000), 0);
+        }
+        for _ in 0..3_600 {
+            index = algorithm.process(16_000);
+        }
+        assert_eq!(index, 1);
+    }
+
+    #[test]
+    fn nox_gas_index_rises_on_nox_event() {
+        let mut algorithm = NoxGasIndex::new();
+        for _ in 0..3_600 {
+            algorithm.process(16_000);
+        }
+        let mut index = 0;
+        for _ in 0..60 {
+            index = algorithm.process(20_000);
+        }
+        assert!(index > 50, "index {index}");
+    }
+
+    #[test]
+    fn nox_gas_index_restores_state() {
+        let mut learned = NoxGasIndex::new();
+        for _ in 0..3_600 {
+            learned.process(16_000);
+        }
+        let mut restored = NoxGasIndex::new();
+        restored.set_state(learned.state());
+        let mut index = 0;
+        for _ in 0..60 {
+            index = restored.process(16_000);
+        }
+        assert_eq!(index, 1);
+    }
+
+    #[test]
+    fn voc_gas_index_applies_tuning() {
+        let mut algorithm = VocGasIndex::new();
+        algorithm.set_tuning(VocTuning::new(250, 12, 12, 180, 50, 230).unwrap());
+        let mut index = 0;
+        for _ in 0..3_600 {
+            index = algorithm.process(30_000);
+        }
+        assert_eq!(index, 250);
+    }
+}
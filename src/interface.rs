@@ -1,8 +1,53 @@
 use duplicate::duplicate_item;
 
+#[cfg(feature = "async")]
+mod asynch_only;
+#[cfg(feature = "blocking")]
+mod blocking_only;
+
 const ADDRESS: u8 = 0x6B;
 const WRITE_FLAG: u8 = 0x00;
 const READ_FLAG: u8 = 0x01;
+/// Interval in which data-ready is polled while waiting for a measurement.
+const DATA_READY_POLL_INTERVAL_MS: u32 = 100;
+/// Interval in which new measurements become available in ms.
+const MEASUREMENT_INTERVAL_MS: u64 = 1_000;
+/// Interval in which data-ready is polled around the expected availability of a measurement.
+const ADAPTIVE_POLL_INTERVAL_MS: u32 = 10;
+/// Time around the expected availability of a measurement in which data-ready is polled rapidly.
+const ADAPTIVE_POLL_WINDOW_MS: u64 = 50;
+/// Number of data-ready polls during a self-test before the measurement is considered missing.
+const SELF_TEST_POLL_LIMIT: u32 = 20;
+/// Time the sensor needs to boot after a reset before accepting commands in ms.
+const BOOT_TIME_MS: u32 = 100;
+
+/// Size of the largest frame written to the sensor in bytes.
+pub const TX_BUFFER_SIZE: usize = 20;
+/// Size of the largest frame read from the sensor in bytes.
+pub const RX_BUFFER_SIZE: usize = 48;
+
+/// Caller-provided transfer buffers with stable addresses. Some HALs require the memory passed
+/// to an (async) I2C transfer to be DMA-capable and `'static`, which rules out the driver's default
+/// stack buffers. Place the buffers in a suitable memory region, e.g. via
+/// [`static_cell`](https://docs.rs/static_cell), and hand them to
+/// [`new_with_buffers`](crate::asynch::Sen66::new_with_buffers). The buffers are handed back by
+/// [`kill`](crate::asynch::Sen66::kill) and [`shutdown`](crate::asynch::Sen66::shutdown).
+pub struct StaticBuffers {
+    tx: &'static mut [u8; TX_BUFFER_SIZE],
+    rx: &'static mut [u8; RX_BUFFER_SIZE],
+}
+
+impl StaticBuffers {
+    /// Creates the transfer buffers from the provided memory.
+    /// - `tx`: Buffer all writes to the sensor are staged in.
+    /// - `rx`: Buffer all reads from the sensor are received in.
+    pub fn new(
+        tx: &'static mut [u8; TX_BUFFER_SIZE],
+        rx: &'static mut [u8; RX_BUFFER_SIZE],
+    ) -> Self {
+        Self { tx, rx }
+    }
+}
 
 // `await` replacement needs to be a callable due to the dot notation. This tricks enables that
 // use case.
@@ -15,39 +60,222 @@ trait Identity: Sized {
 
 impl<T: Sized> Identity for T {}
 
+/// Encodes the `command` followed by the `data` words and their CRCs into `sent`. Returns the
+/// length of the frame in bytes, `None` if the frame does not fit into `sent`.
+fn encode_frame(
+    sent: &mut [u8],
+    command: crate::command::Command,
+    data: Option<&[u16]>,
+) -> Option<usize> {
+    let data = data.unwrap_or_default();
+    let len = 2 + data.len() * 3;
+    let frame = sent.get_mut(..len)?;
+    frame[..2].copy_from_slice(&command.to_be_bytes());
+    for (chunk, datum) in frame[2..].chunks_exact_mut(3).zip(data) {
+        let bytes = datum.to_be_bytes();
+        chunk[..2].copy_from_slice(&bytes);
+        chunk[2] = crate::util::compute_crc8(&bytes);
+    }
+    Some(len)
+}
+
+/// Driver state carried over when converting between the async and blocking interface.
+#[cfg(all(feature = "async", feature = "blocking"))]
+struct DriverState {
+    state: crate::data::SensorState,
+    bus_gap_us: u32,
+    buffers: Option<StaticBuffers>,
+    pending_stop: bool,
+    counters: crate::maintenance::RuntimeCounters,
+    time_source: Option<fn() -> u64>,
+    measuring_since_ms: Option<u64>,
+    humidity_correction: Option<crate::correction::HumidityCorrection>,
+    correction_model: Option<&'static (dyn crate::correction::CorrectionModel + Sync)>,
+    verify_writes: bool,
+    #[cfg(feature = "hot-plug")]
+    hot_plug: crate::maintenance::HotPlugMonitor,
+    #[cfg(feature = "reset-recovery")]
+    reset_monitor: crate::maintenance::ResetMonitor,
+    #[cfg(feature = "rate-limit")]
+    rate_limiter: crate::maintenance::RateLimiter,
+    #[cfg(feature = "cooldown")]
+    cooldown: crate::maintenance::Cooldown,
+    #[cfg(feature = "maintenance-schedule")]
+    epoch_source: Option<fn() -> u64>,
+    #[cfg(feature = "maintenance-schedule")]
+    schedule: crate::maintenance::MaintenanceSchedule,
+    #[cfg(feature = "tuning")]
+    voc_state: Option<crate::data::VocAlgorithmState>,
+    #[cfg(feature = "config-cache")]
+    config_cache: crate::configuration::ConfigCache,
+    #[cfg(feature = "config-cache")]
+    bypass_config_cache: bool,
+}
+
 #[duplicate_item(
-    feature_        module      async   await               delay_trait                             i2c_trait                                       test_macro;
-    ["async"]       [asynch]    [async] [await.identity()]  [embedded_hal_async::delay::DelayNs]    [embedded_hal_async::i2c::I2c<Error = ERR>]  [tokio::test];
-    ["blocking"]    [blocking]  []      [identity()]        [embedded_hal::delay::DelayNs]          [embedded_hal::i2c::I2c<Error = ERR>]        [test];
+    feature_        module      async   await               delay_trait                             i2c_trait                                       i2c_base                            flavor          test_macro;
+    ["async"]       [asynch]    [async] [await.identity()]  [embedded_hal_async::delay::DelayNs]    [embedded_hal_async::i2c::I2c<Error = ERR>]  [embedded_hal_async::i2c::I2c]   [asynch_only]   [tokio::test];
+    ["blocking"]    [blocking]  []      [identity()]        [embedded_hal::delay::DelayNs]          [embedded_hal::i2c::I2c<Error = ERR>]        [embedded_hal::i2c::I2c]         [blocking_only] [test];
 )]
 pub mod module {
     //! Implementation of the SCD30's interface
     #[cfg(feature=feature_)]
     mod inner {
         use crate::{
-            command::Command,
+            command::{Command, PendingRead, Response},
+            conditioning::{BurnIn, BurnInStatus},
             configuration::{
-                AmbientPressure, Co2Correction, NoxTuning, SensorAltitude, TargetCO2Concentration,
-                TemperatureAcceleration, TemperatureOffset, VocTuning,
+                AmbientPressure, PersistentState, Sen66Config, SensorAltitude, Setting,
+                SuspendState, TemperatureAcceleration, TemperatureOffset,
             },
+            correction::{CorrectionModel, HumidityCorrection, TemperatureOffsetFit},
             data::{
-                AscState, Concentrations, DataStatus, DeviceStatusRegister, Measurement,
-                ProductName, RawMeasurement, SensorState, SerialNumber, VocAlgorithmState,
+                AscState, DataStatus, DeviceStatusRegister, Idle, Measurement, MeasurementFixed,
+                Measuring, OperatingState, ProductName, SelfTestReport, SensorState, SerialNumber,
             },
-            error::Sen66Error,
-            interface::{ADDRESS, Identity, READ_FLAG, WRITE_FLAG},
-            util::compute_crc8,
+            error::{DataError, Sen66Error},
+            interface::{
+                ADAPTIVE_POLL_INTERVAL_MS, ADAPTIVE_POLL_WINDOW_MS, ADDRESS, BOOT_TIME_MS,
+                DATA_READY_POLL_INTERVAL_MS, Identity, MEASUREMENT_INTERVAL_MS, READ_FLAG,
+                SELF_TEST_POLL_LIMIT, WRITE_FLAG, encode_frame,
+            },
+            maintenance::{
+                DriverSnapshot, DriverSummary, DutyCycle, PmAverage, RuntimeCounters,
+                STOP_COOLDOWN_MS,
+            },
+            model::{Co2Model, GasIndexModel, Sen6xModel},
+        };
+        use core::marker::PhantomData;
+        use embedded_hal::i2c::ErrorKind;
+
+        #[cfg(feature = "config-cache")]
+        use crate::configuration::ConfigCache;
+        #[cfg(feature = "raw-measurements")]
+        use crate::data::{Concentrations, RawMeasurement};
+        #[cfg(feature = "rate-limit")]
+        use crate::maintenance::RateLimiter;
+        #[cfg(feature = "cooldown")]
+        use crate::maintenance::{Cooldown, CooldownPolicy};
+        #[cfg(feature = "maintenance")]
+        use crate::maintenance::{FAN_CLEANING_COOLDOWN_MS, HEATER_COOLDOWN_MS};
+        #[cfg(feature = "hot-plug")]
+        use crate::maintenance::{HotPlugEvent, HotPlugMonitor};
+        #[cfg(feature = "maintenance-schedule")]
+        use crate::maintenance::{MaintenanceSchedule, MaintenanceTask};
+        #[cfg(feature = "reset-recovery")]
+        use crate::maintenance::{ResetCause, ResetMonitor};
+        #[cfg(feature = "scan")]
+        use crate::scan::{DiscoveredDevice, SCAN_ADDRESSES};
+        #[cfg(feature = "tuning")]
+        use crate::{
+            conditioning::{VocCheckpointScheduler, VocStateStore},
+            configuration::{NoxTuning, Profile, VocTuning},
+            data::VocAlgorithmState,
+            error::CheckpointError,
+        };
+        #[cfg(feature = "co2-calibration")]
+        use crate::{
+            configuration::{Co2Correction, TargetCO2Concentration},
+            data::CalibrationReport,
         };
+        #[cfg(feature = "scan")]
+        use alloc::vec::Vec;
+        #[cfg(feature = "hot-plug")]
+        use embedded_hal::i2c::NoAcknowledgeSource;
+
+        pub use crate::interface::{RX_BUFFER_SIZE, StaticBuffers, TX_BUFFER_SIZE};
 
         /// Interface for the SEN66.
+        ///
+        /// # Cancellation safety
+        ///
+        /// Futures of the async interface may be dropped at any await point without the driver
+        /// believing in a wrong operating state. Commands changing the operating state move the
+        /// driver to [`Unknown`](crate::data::SensorState::Unknown) and only commit the new
+        /// state once the command completed. If such a future is cancelled, the state remains
+        /// unknown and [`stop_measurement`](Sen66::stop_measurement) or
+        /// [`reset_device`](Sen66::reset_device) must be used to return to a known state.
         pub struct Sen66<DELAY, I2C> {
             delay: DELAY,
             i2c: I2C,
-            state: SensorState,
+            pub(in crate::interface) state: SensorState,
+            bus_gap_us: u32,
+            buffers: Option<StaticBuffers>,
+            pub(in crate::interface) pending_stop: bool,
+            counters: RuntimeCounters,
+            time_source: Option<fn() -> u64>,
+            measuring_since_ms: Option<u64>,
+            humidity_correction: Option<HumidityCorrection>,
+            correction_model: Option<&'static (dyn CorrectionModel + Sync)>,
+            #[cfg(feature = "deadline")]
+            deadline_ms: Option<u64>,
+            #[cfg(feature = "adaptive-polling")]
+            last_ready_ms: Option<u64>,
+            verify_writes: bool,
+            #[cfg(feature = "hot-plug")]
+            hot_plug: HotPlugMonitor,
+            #[cfg(feature = "reset-recovery")]
+            reset_monitor: ResetMonitor,
+            #[cfg(feature = "rate-limit")]
+            rate_limiter: RateLimiter,
+            #[cfg(feature = "cooldown")]
+            cooldown: Cooldown,
+            #[cfg(feature = "maintenance-schedule")]
+            epoch_source: Option<fn() -> u64>,
+            #[cfg(feature = "maintenance-schedule")]
+            schedule: MaintenanceSchedule,
+            #[cfg(feature = "tuning")]
+            voc_state: Option<VocAlgorithmState>,
+            #[cfg(feature = "config-cache")]
+            config_cache: ConfigCache,
+            #[cfg(feature = "config-cache")]
+            bypass_config_cache: bool,
         }
 
         impl<DELAY: delay_trait, I2C: i2c_trait, ERR: embedded_hal::i2c::Error> Sen66<DELAY, I2C> {
-            /// Creates a new SEN66 interface.
+            /// Creates a new SEN66 interface, verifying that the device responding is a SEN66, see
+            /// [`probe`](Sen66::probe).
+            /// - `delay`: Delay provider, implementing embedded_hal's `DelayNs` trait.
+            /// - `i2c`: I2C peripheral implementing embedded_hal's `I2c` trait.
+            /// Execution Time: 20ms
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            /// - [`WrongDevice`](crate::error::Sen66Error::WrongDevice): If the device reports a
+            /// product name other than SEN66.
+            pub async fn try_new(delay: DELAY, i2c: I2C) -> Result<Self, Sen66Error<ERR>> {
+                let mut sensor = Self::new(delay, i2c);
+                sensor.probe().await?;
+                Ok(sensor)
+            }
+
+            /// Creates a [`Sen66Builder`] queueing the configuration applied when creating the
+            /// driver.
+            /// - `delay`: Delay provider, implementing embedded_hal's `DelayNs` trait.
+            /// - `i2c`: I2C peripheral implementing embedded_hal's `I2c` trait.
+            pub fn builder(delay: DELAY, i2c: I2C) -> Sen66Builder<DELAY, I2C> {
+                Sen66Builder {
+                    delay,
+                    i2c,
+                    reset: false,
+                    sensor_altitude: None,
+                    ambient_pressure: None,
+                    asc_state: None,
+                    temperature_offset: None,
+                    temperature_acceleration: None,
+                    #[cfg(feature = "tuning")]
+                    voc_tuning: None,
+                    #[cfg(feature = "tuning")]
+                    nox_tuning: None,
+                }
+            }
+
+            /// Creates a new SEN66 interface. The peripherals can also be borrowed, i.e. `&mut
+            /// DELAY` and `&mut I2C`, to use them with other drivers after this one is dropped.
             /// - `delay`: Delay provider, implementing embedded_hal's `DelayNs` trait.
             /// - `i2c`: I2C peripheral implementing embedded_hal's `I2c` trait.
             pub fn new(delay: DELAY, i2c: I2C) -> Self {
@@ -55,12 +283,313 @@ pub mod module {
                     delay,
                     i2c,
                     state: SensorState::Idle,
+                    bus_gap_us: 0,
+                    buffers: None,
+                    pending_stop: false,
+                    counters: RuntimeCounters::default(),
+                    time_source: None,
+                    measuring_since_ms: None,
+                    humidity_correction: None,
+                    correction_model: None,
+                    #[cfg(feature = "deadline")]
+                    deadline_ms: None,
+                    #[cfg(feature = "adaptive-polling")]
+                    last_ready_ms: None,
+                    verify_writes: false,
+                    #[cfg(feature = "hot-plug")]
+                    hot_plug: HotPlugMonitor::new(),
+                    #[cfg(feature = "reset-recovery")]
+                    reset_monitor: ResetMonitor::new(),
+                    #[cfg(feature = "rate-limit")]
+                    rate_limiter: RateLimiter::new(),
+                    #[cfg(feature = "cooldown")]
+                    cooldown: Cooldown::new(),
+                    #[cfg(feature = "maintenance-schedule")]
+                    epoch_source: None,
+                    #[cfg(feature = "maintenance-schedule")]
+                    schedule: MaintenanceSchedule::default(),
+                    #[cfg(feature = "tuning")]
+                    voc_state: None,
+                    #[cfg(feature = "config-cache")]
+                    config_cache: ConfigCache::new(),
+                    #[cfg(feature = "config-cache")]
+                    bypass_config_cache: false,
+                }
+            }
+
+            /// Creates a new SEN66 interface, which stages all bus transfers in the provided
+            /// [`StaticBuffers`] instead of buffers on the stack.
+            /// - `delay`: Delay provider, implementing embedded_hal's `DelayNs` trait.
+            /// - `i2c`: I2C peripheral implementing embedded_hal's `I2c` trait.
+            /// - `buffers`: DMA-capable transfer buffers.
+            pub fn new_with_buffers(delay: DELAY, i2c: I2C, buffers: StaticBuffers) -> Self {
+                Self {
+                    buffers: Some(buffers),
+                    ..Self::new(delay, i2c)
+                }
+            }
+
+            /// Creates a new SEN66 interface assuming the sensor is in the given `state`, e.g. to
+            /// attach to a sensor which kept measuring while the MCU was reset. No bus traffic is
+            /// caused. With [`SensorState::Unknown`](crate::data::SensorState::Unknown) only
+            /// commands available in any state are accepted until the sensor is stopped or reset.
+            /// - `delay`: Delay provider, implementing embedded_hal's `DelayNs` trait.
+            /// - `i2c`: I2C peripheral implementing embedded_hal's `I2c` trait.
+            /// - `state`: Operating state the sensor is in.
+            pub fn from_state(delay: DELAY, i2c: I2C, state: SensorState) -> Self {
+                Self {
+                    state,
+                    ..Self::new(delay, i2c)
+                }
+            }
+
+            /// Creates a new SEN66 interface attaching to a sensor which is already measuring,
+            /// see [`from_state`](Sen66::from_state).
+            /// - `delay`: Delay provider, implementing embedded_hal's `DelayNs` trait.
+            /// - `i2c`: I2C peripheral implementing embedded_hal's `I2c` trait.
+            pub fn assume_measuring(delay: DELAY, i2c: I2C) -> Self {
+                Self::from_state(delay, i2c, SensorState::Measuring)
+            }
+
+            /// Returns the operating state the driver assumes the sensor to be in.
+            pub fn state(&self) -> SensorState {
+                self.state
+            }
+
+            /// Overrides the operating state the driver assumes the sensor to be in, for recovery
+            /// when it diverged from the sensor's, e.g. after the sensor was reset via its supply
+            /// or an external reset line. No bus traffic is caused and a deferred stop is
+            /// discarded. Commands called in the wrong state are then rejected by the sensor,
+            /// which is reported as an [`I2cError`](crate::error::Sen66Error::I2cError).
+            /// - `state`: Operating state the sensor is in.
+            pub fn force_state(&mut self, state: SensorState) {
+                match (self.state, state) {
+                    (SensorState::Measuring, SensorState::Measuring) => (),
+                    (SensorState::Measuring, _) => self.commit_measuring_time(),
+                    (_, SensorState::Measuring) => {
+                        self.measuring_since_ms = self.time_source.map(|now_ms| now_ms());
+                    }
+                    _ => (),
+                }
+                self.pending_stop = false;
+                self.state = state;
+            }
+
+            /// Configures a minimum gap between consecutive bus transactions, including the gap
+            /// between writing a command and reading its response. Useful when sharing a slow bus
+            /// with timing-sensitive devices. A gap of `0` disables pacing, which is the default.
+            /// - `gap_us`: Minimum gap between two transactions in µs.
+            pub fn set_bus_pacing(&mut self, gap_us: u32) {
+                self.bus_gap_us = gap_us;
+            }
+
+            /// Returns the configured minimum gap between consecutive bus transactions in µs.
+            pub fn bus_pacing(&self) -> u32 {
+                self.bus_gap_us
+            }
+
+            /// Caps the frequency of data-ready polls, protecting a shared bus against
+            /// application code polling [`is_data_ready`](Sen66::is_data_ready) in a tight loop.
+            /// Polls issued earlier are delayed. Without a time source every poll but the first
+            /// is delayed by the full interval. An interval of `0` disables the cap, which is the
+            /// default.
+            /// - `interval_ms`: Minimum interval between two data-ready polls in ms.
+            #[cfg(feature = "rate-limit")]
+            pub fn set_poll_rate_limit(&mut self, interval_ms: u32) {
+                self.rate_limiter.set_poll_interval(interval_ms);
+            }
+
+            /// Returns the configured minimum interval between two data-ready polls in ms.
+            #[cfg(feature = "rate-limit")]
+            pub fn poll_rate_limit(&self) -> u32 {
+                self.rate_limiter.poll_interval()
+            }
+
+            /// Configures a host-side correction applied to the relative humidity of all read
+            /// measurements. `None` disables the correction.
+            /// - `correction`: See [`HumidityCorrection`](crate::correction::HumidityCorrection)
+            pub fn set_humidity_correction(&mut self, correction: Option<HumidityCorrection>) {
+                self.humidity_correction = correction;
+            }
+
+            /// Returns the configured host-side humidity correction.
+            pub fn humidity_correction(&self) -> Option<HumidityCorrection> {
+                self.humidity_correction
+            }
+
+            /// Attaches a [`CorrectionModel`](crate::correction::CorrectionModel) applied to all
+            /// read measurements after the humidity correction. `None` detaches the model.
+            /// - `model`: Correction model, e.g. a
+            /// [`ChannelCorrection`](crate::correction::ChannelCorrection) holding lab-calibrated
+            /// coefficients.
+            pub fn set_correction_model(
+                &mut self,
+                model: Option<&'static (dyn CorrectionModel + Sync)>,
+            ) {
+                self.correction_model = model;
+            }
+
+            /// Enables reading back settings after writing them. Setters with a read-back
+            /// counterpart then fail with
+            /// [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed) if the value
+            /// read back differs from the written value, e.g. due to corruption on the bus.
+            /// Settings held in the configuration cache are written and verified regardless.
+            /// Disabled by default.
+            /// - `verify`: Whether written settings are verified.
+            pub fn set_write_verification(&mut self, verify: bool) {
+                self.verify_writes = verify;
+            }
+
+            /// Returns whether written settings are read back and verified.
+            pub fn write_verification(&self) -> bool {
+                self.verify_writes
+            }
+
+            /// Returns the settings the sensor is known to hold.
+            #[cfg(feature = "config-cache")]
+            pub fn config_cache(&self) -> &ConfigCache {
+                &self.config_cache
+            }
+
+            /// Forgets all cached settings, e.g. after the sensor has been power cycled. All
+            /// settings are written again afterwards.
+            #[cfg(feature = "config-cache")]
+            pub fn invalidate_config_cache(&mut self) {
+                self.config_cache.clear();
+            }
+
+            /// Configures whether setters write settings the sensor is known to hold. By default
+            /// such writes are skipped unless write verification is enabled. Cached values are
+            /// still updated while bypassing the cache.
+            /// - `bypass`: Whether all settings are written regardless of the cache.
+            #[cfg(feature = "config-cache")]
+            pub fn set_config_cache_bypass(&mut self, bypass: bool) {
+                self.bypass_config_cache = bypass;
+            }
+
+            /// Configures the hot-plug supervision, detecting the sensor being unplugged by
+            /// consecutive address NACKs. Call [`supervise`](Sen66::supervise) periodically to
+            /// re-initialize the sensor once it reappears. Disabled by default.
+            /// - `nack_limit`: Number of consecutive address NACKs after which the sensor is
+            ///   considered absent. `0` disables the supervision.
+            #[cfg(feature = "hot-plug")]
+            pub fn set_hot_plug_supervision(&mut self, nack_limit: u8) {
+                self.hot_plug.set_nack_limit(nack_limit);
+            }
+
+            /// Returns the number of consecutive address NACKs after which the sensor is
+            /// considered absent. `0` if the hot-plug supervision is disabled.
+            #[cfg(feature = "hot-plug")]
+            pub fn hot_plug_supervision(&self) -> u8 {
+                self.hot_plug.nack_limit()
+            }
+
+            /// Configures a monotonic time source, used to accumulate the time spent in
+            /// measuring state. Without a time source the measuring time is not tracked. If the
+            /// sensor is measuring, its measuring time is tracked from now on.
+            /// - `now_ms`: Returns the current time in ms.
+            pub fn set_time_source(&mut self, now_ms: fn() -> u64) {
+                self.time_source = Some(now_ms);
+                if self.state == SensorState::Measuring && self.measuring_since_ms.is_none() {
+                    self.measuring_since_ms = Some(now_ms());
+                }
+            }
+
+            /// Configures a wall-clock time source, e.g. an RTC, used to track the
+            /// [`MaintenanceSchedule`] across deep sleep and resets. Without an epoch source
+            /// performed maintenance is not recorded and no maintenance is reported as due.
+            /// - `now_s`: Returns the current time in s since the UNIX epoch.
+            #[cfg(feature = "maintenance-schedule")]
+            pub fn set_epoch_source(&mut self, now_s: fn() -> u64) {
+                self.epoch_source = Some(now_s);
+            }
+
+            /// Configures how [`start_measurement`](Sen66::start_measurement) handles the
+            /// cooldown after stopping a measurement, cleaning the fan or activating the SHT
+            /// heater. Cooldowns are only tracked with a
+            /// [`set_time_source`](Sen66::set_time_source). Defaults to
+            /// [`Wait`](CooldownPolicy::Wait).
+            /// - `policy`: Whether to wait for the cooldown or reject the command.
+            #[cfg(feature = "cooldown")]
+            pub fn set_cooldown_policy(&mut self, policy: CooldownPolicy) {
+                self.cooldown.set_policy(policy);
+            }
+
+            /// Returns the configured [`CooldownPolicy`].
+            #[cfg(feature = "cooldown")]
+            pub fn cooldown_policy(&self) -> CooldownPolicy {
+                self.cooldown.policy()
+            }
+
+            /// Returns the remaining cooldown before a measurement may be started in ms. `0`
+            /// without a time source.
+            #[cfg(feature = "cooldown")]
+            pub fn cooldown_remaining(&self) -> u32 {
+                self.cooldown
+                    .remaining(self.time_source.map(|now_ms| now_ms()))
+            }
+
+            /// Returns the [`MaintenanceSchedule`], e.g. to persist it before powering down.
+            #[cfg(feature = "maintenance-schedule")]
+            pub fn maintenance_schedule(&self) -> MaintenanceSchedule {
+                self.schedule
+            }
+
+            /// Replaces the [`MaintenanceSchedule`], e.g. to restore a persisted schedule or to
+            /// change the maintenance intervals.
+            /// - `schedule`: Schedule to track the maintenance with.
+            #[cfg(feature = "maintenance-schedule")]
+            pub fn set_maintenance_schedule(&mut self, schedule: MaintenanceSchedule) {
+                self.schedule = schedule;
+            }
+
+            /// Returns the first [`MaintenanceTask`] due according to the configured
+            /// [`set_epoch_source`](Sen66::set_epoch_source). `None` if nothing is due or no epoch
+            /// source is configured.
+            #[cfg(feature = "maintenance-schedule")]
+            pub fn maintenance_due(&self) -> Option<MaintenanceTask> {
+                self.epoch_source
+                    .and_then(|now_s| self.schedule.due(now_s()))
+            }
+
+            /// Returns the accumulated [`RuntimeCounters`], including an ongoing measurement.
+            pub fn runtime_counters(&self) -> RuntimeCounters {
+                let mut counters = self.counters;
+                counters.measuring_time_ms += self.ongoing_measuring_time_ms();
+                counters
+            }
+
+            /// Returns a compact [`DriverSummary`] of the driver's health, e.g. to log it during
+            /// fault analysis.
+            pub fn summary(&self) -> DriverSummary {
+                DriverSummary {
+                    state: self.state,
+                    counters: self.runtime_counters(),
+                    measuring_for_ms: self
+                        .time_source
+                        .and(self.measuring_since_ms)
+                        .map(|_| self.ongoing_measuring_time_ms()),
+                    pending_stop: self.pending_stop,
+                    verify_writes: self.verify_writes,
+                    corrected: self.humidity_correction.is_some()
+                        || self.correction_model.is_some(),
+                    #[cfg(feature = "config-cache")]
+                    cached_settings: self.config_cache.settings().count(),
                 }
             }
 
+            /// Restores previously persisted [`RuntimeCounters`], e.g. after a power cycle.
+            /// Time of an ongoing measurement is added to the restored counters.
+            pub fn restore_runtime_counters(&mut self, counters: RuntimeCounters) {
+                self.counters = counters;
+            }
+
             /// Starts a continous measurement. The first result is available after roughly 1.1s
             /// use [`is_data_ready`](Sen66::is_data_ready) to poll for available measurements.
-            /// Changes sensors state to [`Measuring`](crate::data::SensorState).
+            /// Changes sensors state to [`Measuring`](crate::data::SensorState). With the
+            /// `cooldown` feature and a time source, the cooldown after stopping a measurement,
+            /// cleaning the fan or activating the SHT heater is enforced according to the
+            /// cooldown policy.
             /// Execution Time: 50ms
             /// <div class="warning">Only available in idle state</div>
             ///
@@ -70,20 +599,65 @@ pub mod module {
             /// I2C bus occurs.
             /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
             /// Measuring state.
+            #[cfg_attr(
+                feature = "cooldown",
+                doc = "- [`CooldownActive`](crate::error::Sen66Error::CooldownActive): If a cooldown is"
+            )]
+            #[cfg_attr(
+                feature = "cooldown",
+                doc = "active and the cooldown policy rejects waiting for it."
+            )]
             pub async fn start_measurement(&mut self) -> Result<(), Sen66Error<ERR>> {
-                if self.state != SensorState::Idle {
-                    return Err(Sen66Error::WrongState("Measuring"));
+                self.ensure_state(SensorState::Idle)?;
+                // A deferred stop starts a cooldown, so it is sent before awaiting the cooldown
+                self.flush_pending_stop().await?;
+                #[cfg(feature = "cooldown")]
+                self.await_cooldown().await?;
+                self.state = SensorState::Unknown;
+                let result = self
+                    .write::<2>(Command::StartContinuousMeasurement, None)
+                    .await;
+                #[cfg(feature = "adaptive-polling")]
+                {
+                    self.last_ready_ms = None;
                 }
-                self.write::<2>(Command::StartContinuousMeasurement, None)
-                    .await?;
-                self.state = SensorState::Measuring;
-                Ok(())
+                self.state = if result.is_ok() {
+                    self.measuring_since_ms = self.time_source.map(|now_ms| now_ms());
+                    #[cfg(feature = "reset-recovery")]
+                    self.reset_monitor.started(self.measuring_since_ms);
+                    SensorState::Measuring
+                } else {
+                    SensorState::Idle
+                };
+                result
+            }
+
+            /// Starts a continous measurement and returns a [`MeasurementSession`] guard
+            /// providing access to the measurement's data. The measurement is stopped once the
+            /// guard is finished or dropped, so early returns cannot leave the sensor running.
+            /// <div class="warning">Only available in idle state</div>
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Measuring state.
+            pub async fn start_session(
+                &mut self,
+            ) -> Result<MeasurementSession<'_, DELAY, I2C>, Sen66Error<ERR>> {
+                self.start_measurement().await?;
+                Ok(MeasurementSession {
+                    sensor: self,
+                    finished: false,
+                })
             }
 
             /// Stops continous measurements.
-            /// Changes sensors state to [`Idle`](crate::data::SensorState).
+            /// Changes sensors state to [`Idle`](crate::data::SensorState). Can also be used to
+            /// recover from the [`Unknown`](crate::data::SensorState) state.
             /// Execution Time: 1000ms
-            /// <div class="warning">Only available in measuring state</div>
+            /// <div class="warning">Only available in measuring or unknown state</div>
             ///
             /// # Errors
             ///
@@ -92,15 +666,27 @@ pub mod module {
             /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
             /// Idle state.
             pub async fn stop_measurement(&mut self) -> Result<(), Sen66Error<ERR>> {
-                if self.state != SensorState::Measuring {
-                    return Err(Sen66Error::WrongState("Idle"));
+                if self.state != SensorState::Unknown {
+                    self.ensure_state(SensorState::Measuring)?;
                 }
-                self.write::<2>(Command::StopMeasurement, None).await?;
-                self.state = SensorState::Idle;
-                Ok(())
+                let previous = self.state;
+                self.state = SensorState::Unknown;
+                let result = self
+                    .write_with_cooldown(Command::StopMeasurement, STOP_COOLDOWN_MS)
+                    .await;
+                self.state = if result.is_ok() {
+                    self.commit_measuring_time();
+                    #[cfg(feature = "reset-recovery")]
+                    self.reset_monitor.stopped();
+                    SensorState::Idle
+                } else {
+                    previous
+                };
+                result
             }
 
-            /// Queries whether new data is available.
+            /// Queries whether new data is available. With the `rate-limit` feature, delayed if it
+            /// would exceed the configured poll rate limit.
             /// Execution Time: 20ms
             /// <div class="warning">Only available in measuring state</div>
             ///
@@ -113,17 +699,71 @@ pub mod module {
             /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
             /// corrupted or wrong.
             pub async fn is_data_ready(&mut self) -> Result<DataStatus, Sen66Error<ERR>> {
-                if self.state != SensorState::Measuring {
-                    return Err(Sen66Error::WrongState("Idle"));
+                self.ensure_state(SensorState::Measuring)?;
+                #[cfg(feature = "rate-limit")]
+                {
+                    let wait_ms = self
+                        .rate_limiter
+                        .poll(self.time_source.map(|now_ms| now_ms()));
+                    if wait_ms > 0 {
+                        self.delay_within_deadline(wait_ms).await?;
+                    }
                 }
                 let received = self.write_read::<2, 3>(Command::GetDataReady, None).await?;
-                Ok(DataStatus::try_from(&received[..])?)
+                let status = DataStatus::try_from(&received[..])?;
+                #[cfg(feature = "reset-recovery")]
+                self.reset_monitor.record_data_ready(
+                    status == DataStatus::Ready,
+                    self.time_source.map(|now_ms| now_ms()),
+                );
+                Ok(status)
+            }
+
+            /// Polls data-ready every `poll_interval_ms` until new data is available.
+            /// - `poll_interval_ms`: Time to sleep between two polls in ms.
+            /// - `timeout_ms`: Time after which waiting is given up in ms. Accounted from the
+            ///   execution time of the polls and the sleeps in between, so no time source is
+            ///   required. The last poll happens once the timeout is reached.
+            /// Execution Time: Until data is ready, at most the timeout
+            /// <div class="warning">Only available in measuring state</div>
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Idle state.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            /// - [`DataReadyTimeout`](crate::error::Sen66Error::DataReadyTimeout): If no data
+            /// became ready within the timeout.
+            pub async fn wait_for_data_ready(
+                &mut self,
+                poll_interval_ms: u32,
+                timeout_ms: u32,
+            ) -> Result<(), Sen66Error<ERR>> {
+                let mut waited_ms = 0u32;
+                loop {
+                    if self.is_data_ready().await? == DataStatus::Ready {
+                        self.record_data_ready();
+                        return Ok(());
+                    }
+                    waited_ms = waited_ms.saturating_add(Command::GetDataReady.execution_time_ms());
+                    if waited_ms >= timeout_ms {
+                        return Err(Sen66Error::DataReadyTimeout { timeout_ms });
+                    }
+                    let sleep_ms = poll_interval_ms.min(timeout_ms - waited_ms);
+                    self.delay_within_deadline(sleep_ms).await?;
+                    waited_ms += sleep_ms;
+                }
             }
 
             /// Read a [`Measurement`](crate::data::Measurement) value from the sensor.
             /// If new data is available clears the data ready flag. If no new data is available
             /// the previous data point is returned. If no data at all is available all values are
-            /// set to their maximum value.
+            /// set to their maximum value. A configured
+            /// [`HumidityCorrection`](crate::correction::HumidityCorrection) and
+            /// [`CorrectionModel`](crate::correction::CorrectionModel) are applied.
             /// Execution Time: 20ms
             /// <div class="warning">Only available in measuring state</div>
             ///
@@ -136,19 +776,63 @@ pub mod module {
             /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
             /// corrupted or wrong.
             pub async fn read_measured_values(&mut self) -> Result<Measurement, Sen66Error<ERR>> {
-                if self.state != SensorState::Measuring {
-                    return Err(Sen66Error::WrongState("Idle"));
+                self.ensure_state(SensorState::Measuring)?;
+                let received = self
+                    .write_read::<2, 27>(Command::ReadMeasurement, None)
+                    .await?;
+                let mut measurement = Measurement::try_from(&received[..])?;
+                #[cfg(feature = "reset-recovery")]
+                self.reset_monitor.record_co2(measurement.co2);
+                if let Some(correction) = self.humidity_correction {
+                    measurement.relative_humidity = correction.apply(measurement.relative_humidity);
+                }
+                if let Some(model) = self.correction_model {
+                    model.apply(&mut measurement);
                 }
+                self.counters.samples += 1;
+                Ok(measurement)
+            }
+
+            /// Read a [`MeasurementFixed`](crate::data::MeasurementFixed) value from the sensor,
+            /// keeping the scaled integers, e.g. to avoid soft-float routines on targets without
+            /// FPU. If new data is available clears the data ready flag. If no new data is
+            /// available the previous data point is returned. If no data at all is available all
+            /// values are set to their maximum value. A configured
+            /// [`HumidityCorrection`](crate::correction::HumidityCorrection) and
+            /// [`CorrectionModel`](crate::correction::CorrectionModel) are not applied, as they
+            /// operate on `f32`.
+            /// Execution Time: 20ms
+            /// <div class="warning">Only available in measuring state</div>
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Idle state.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn read_measured_values_fixed(
+                &mut self,
+            ) -> Result<MeasurementFixed, Sen66Error<ERR>> {
+                self.ensure_state(SensorState::Measuring)?;
                 let received = self
                     .write_read::<2, 27>(Command::ReadMeasurement, None)
                     .await?;
-                Ok(Measurement::try_from(&received[..])?)
+                let measurement = MeasurementFixed::try_from(&received[..])?;
+                #[cfg(feature = "reset-recovery")]
+                self.reset_monitor.record_co2(measurement.co2);
+                self.counters.samples += 1;
+                Ok(measurement)
             }
 
+            #[cfg(feature = "raw-measurements")]
             /// Read a [`RawMeasurement`](crate::data::RawMeasurement) value from the sensor.
             /// If new data is available clears the data ready flag. If no new data is available
             /// the previous data point is returned. If no data at all is available all values are
-            /// set to their maximum value.
+            /// set to their maximum value. A configured
+            /// [`HumidityCorrection`](crate::correction::HumidityCorrection) and
+            /// [`CorrectionModel`](crate::correction::CorrectionModel) are applied.
             /// Execution Time: 20ms
             /// <div class="warning">Only available in measuring state</div>
             ///
@@ -163,15 +847,21 @@ pub mod module {
             pub async fn read_measured_raw_values(
                 &mut self,
             ) -> Result<RawMeasurement, Sen66Error<ERR>> {
-                if self.state != SensorState::Measuring {
-                    return Err(Sen66Error::WrongState("Idle"));
-                }
+                self.ensure_state(SensorState::Measuring)?;
                 let received = self
                     .write_read::<2, 15>(Command::ReadRawMeasurement, None)
                     .await?;
-                Ok(RawMeasurement::try_from(&received[..])?)
+                let mut measurement = RawMeasurement::try_from(&received[..])?;
+                if let Some(correction) = self.humidity_correction {
+                    measurement.relative_humidity = correction.apply(measurement.relative_humidity);
+                }
+                if let Some(model) = self.correction_model {
+                    model.apply_raw(&mut measurement);
+                }
+                Ok(measurement)
             }
 
+            #[cfg(feature = "raw-measurements")]
             /// Read a [`Concentrations`](crate::data::Concentrations) value from the sensor.
             /// If new data is available clears the data ready flag. If no new data is available
             /// the previous data point is returned. If no data at all is available all values are
@@ -190,9 +880,7 @@ pub mod module {
             pub async fn read_number_concentrations(
                 &mut self,
             ) -> Result<Concentrations, Sen66Error<ERR>> {
-                if self.state != SensorState::Measuring {
-                    return Err(Sen66Error::WrongState("Idle"));
-                }
+                self.ensure_state(SensorState::Measuring)?;
                 let received = self
                     .write_read::<2, 15>(Command::ReadNumberConcentrationValues, None)
                     .await?;
@@ -211,12 +899,43 @@ pub mod module {
                 &mut self,
                 parameter: TemperatureOffset,
             ) -> Result<(), Sen66Error<ERR>> {
-                Ok(self
-                    .write::<14>(
-                        Command::SetTemperatureOffsetParameters,
-                        Some(&(<[u16; 4]>::from(parameter))),
-                    )
-                    .await?)
+                let setting = Setting::TemperatureOffset(parameter);
+                if self.is_cached(&setting) {
+                    return Ok(());
+                }
+                self.invalidate_cached(&setting);
+                self.write::<14>(
+                    Command::SetTemperatureOffsetParameters,
+                    Some(&(<[u16; 4]>::from(parameter))),
+                )
+                .await?;
+                self.record_cached(setting);
+                Ok(())
+            }
+
+            /// Computes the best-fit temperature offset from the reference data collected in `fit`
+            /// and writes it to the sensor.
+            /// - `fit`: See [`TemperatureOffsetFit`](crate::correction::TemperatureOffsetFit)
+            /// - `time_constant`: Time constant determining how fast the new slope and offset are
+            /// applied.
+            /// - `slot`: Temperature offset slot to modify. Available slots range from 0 to 4.
+            /// Execution Time: 20ms
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If `fit` contains no samples
+            /// or the fitted values are not in range.
+            pub async fn apply_temperature_offset_fit(
+                &mut self,
+                fit: &TemperatureOffsetFit,
+                time_constant: u16,
+                slot: u16,
+            ) -> Result<TemperatureOffset, Sen66Error<ERR>> {
+                let parameter = fit.fit(time_constant, slot)?;
+                self.set_temperature_offset(parameter).await?;
+                Ok(parameter)
             }
 
             /// Set the temperature acceleration parameters.
@@ -234,15 +953,19 @@ pub mod module {
                 &mut self,
                 parameter: TemperatureAcceleration,
             ) -> Result<(), Sen66Error<ERR>> {
-                if self.state != SensorState::Idle {
-                    return Err(Sen66Error::WrongState("Measuring"));
+                self.ensure_state(SensorState::Idle)?;
+                let setting = Setting::TemperatureAcceleration(parameter);
+                if self.is_cached(&setting) {
+                    return Ok(());
                 }
-                Ok(self
-                    .write::<14>(
-                        Command::SetTemperatureAccelerationParameters,
-                        Some(&(<[u16; 4]>::from(parameter))),
-                    )
-                    .await?)
+                self.invalidate_cached(&setting);
+                self.write::<14>(
+                    Command::SetTemperatureAccelerationParameters,
+                    Some(&(<[u16; 4]>::from(parameter))),
+                )
+                .await?;
+                self.record_cached(setting);
+                Ok(())
             }
 
             /// Read out the sensor's product name
@@ -261,6 +984,36 @@ pub mod module {
                 Ok(ProductName::try_from(&received[..])?)
             }
 
+            /// Verifies that the device responding is a SEN66 by reading its product name, e.g. to
+            /// catch wiring mistakes at start-up instead of reading garbage measurements later.
+            /// Execution Time: 20ms
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            /// - [`WrongDevice`](crate::error::Sen66Error::WrongDevice): If the device reports a
+            /// product name other than SEN66.
+            pub async fn probe(&mut self) -> Result<ProductName, Sen66Error<ERR>> {
+                self.expect_product_name(crate::model::Sen66::PRODUCT_NAME)
+                    .await
+            }
+
+            /// Reads the product name and checks it equals `expected`.
+            async fn expect_product_name(
+                &mut self,
+                expected: &'static str,
+            ) -> Result<ProductName, Sen66Error<ERR>> {
+                let found = self.get_product_name().await?;
+                if found.as_str() == expected {
+                    Ok(found)
+                } else {
+                    Err(Sen66Error::WrongDevice { expected, found })
+                }
+            }
+
             /// Read out the sensor's serial number
             /// Execution Time: 20ms
             ///
@@ -293,7 +1046,11 @@ pub mod module {
                 let received = self
                     .write_read::<2, 6>(Command::GetDeviceStatus, None)
                     .await?;
-                Ok(DeviceStatusRegister::try_from(&received[..])?)
+                let status = DeviceStatusRegister::try_from(&received[..])?;
+                #[cfg(feature = "reset-recovery")]
+                self.reset_monitor
+                    .record_status(status.has_error().is_err());
+                Ok(status)
             }
 
             /// Read out the sensor's [`DeviceStatusRegister`](crate::data::DeviceStatusRegister) and
@@ -312,12 +1069,15 @@ pub mod module {
                 let received = self
                     .write_read::<2, 6>(Command::ReadAndClearDeviceStatus, None)
                     .await?;
+                #[cfg(feature = "reset-recovery")]
+                self.reset_monitor.status_cleared();
                 Ok(DeviceStatusRegister::try_from(&received[..])?)
             }
 
-            /// Reset the sensor, akin to a power cycle.
+            /// Reset the sensor, akin to a power cycle. Can also be used to recover from the
+            /// [`Unknown`](crate::data::SensorState) state.
             /// Execution Time: 20ms
-            /// <div class="warning">Only available in idle state</div>
+            /// <div class="warning">Only available in idle or unknown state</div>
             ///
             /// # Errors
             ///
@@ -326,15 +1086,33 @@ pub mod module {
             /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
             /// Measuring state.
             pub async fn reset_device(&mut self) -> Result<(), Sen66Error<ERR>> {
-                if self.state != SensorState::Idle {
-                    return Err(Sen66Error::WrongState("Measuring"));
+                if self.state != SensorState::Unknown {
+                    self.ensure_state(SensorState::Idle)?;
                 }
-                self.write::<2>(Command::ResetDevice, None).await
+                let previous = self.state;
+                self.state = SensorState::Unknown;
+                #[cfg(feature = "config-cache")]
+                self.config_cache.clear();
+                let result = self.write::<2>(Command::ResetDevice, None).await;
+                self.state = if result.is_ok() {
+                    self.commit_measuring_time();
+                    #[cfg(feature = "reset-recovery")]
+                    {
+                        self.reset_monitor = ResetMonitor::new();
+                    }
+                    SensorState::Idle
+                } else {
+                    previous
+                };
+                result
             }
 
+            #[cfg(feature = "maintenance")]
             /// Start the fan cleaning procedure.
             /// The fan is set to maximum speed for 10s and then stopped. After issuing this
-            /// command wait at least 10s before starting a measurement.
+            /// command wait at least 10s before starting a measurement. With the `cooldown`
+            /// feature and a time source, [`start_measurement`](Sen66::start_measurement)
+            /// enforces this cooldown.
             /// Execution Time: 20ms
             /// <div class="warning">Only available in idle state</div>
             ///
@@ -345,15 +1123,20 @@ pub mod module {
             /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
             /// Measuring state.
             pub async fn start_fan_cleaning(&mut self) -> Result<(), Sen66Error<ERR>> {
-                if self.state != SensorState::Idle {
-                    return Err(Sen66Error::WrongState("Measuring"));
-                }
-                self.write::<2>(Command::StartFanCleaning, None).await
+                self.ensure_state(SensorState::Idle)?;
+                self.write_with_cooldown(Command::StartFanCleaning, FAN_CLEANING_COOLDOWN_MS)
+                    .await?;
+                #[cfg(feature = "maintenance-schedule")]
+                self.record_maintenance(MaintenanceTask::FanCleaning);
+                Ok(())
             }
 
+            #[cfg(feature = "maintenance")]
             /// Activate the SHT heater.
             /// The heater runs with 200mW for 1s. Wait at least 20s after the command for the heat
-            /// to disapper, before taking the next measurement.
+            /// to disapper, before taking the next measurement. With the `cooldown`
+            /// feature and a time source, [`start_measurement`](Sen66::start_measurement)
+            /// enforces this cooldown.
             /// Execution Time: 1300ms
             /// <div class="warning">Only available in idle state</div>
             ///
@@ -364,12 +1147,12 @@ pub mod module {
             /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
             /// Measuring state.
             pub async fn activate_sht_heater(&mut self) -> Result<(), Sen66Error<ERR>> {
-                if self.state != SensorState::Idle {
-                    return Err(Sen66Error::WrongState("Measuring"));
-                }
-                self.write::<2>(Command::ActivateShtHeater, None).await
+                self.ensure_state(SensorState::Idle)?;
+                self.write_with_cooldown(Command::ActivateShtHeater, HEATER_COOLDOWN_MS)
+                    .await
             }
 
+            #[cfg(feature = "tuning")]
             /// Read the [`VocTuning`](crate::configuration::VocTuning) parameters from the sensor.
             /// Execution Time: 20ms
             /// <div class="warning">Only available in idle state</div>
@@ -385,17 +1168,18 @@ pub mod module {
             pub async fn get_voc_tuning_parameters(
                 &mut self,
             ) -> Result<VocTuning, Sen66Error<ERR>> {
-                if self.state != SensorState::Idle {
-                    return Err(Sen66Error::WrongState("Measuring"));
-                }
+                self.ensure_state(SensorState::Idle)?;
                 let received = self
                     .write_read::<2, 18>(Command::SetReadVocTuningParameters, None)
                     .await?;
-                Ok(VocTuning::try_from(&received[..])?)
+                let value = VocTuning::try_from(&received[..])?;
+                self.record_cached(Setting::VocTuning(value));
+                Ok(value)
             }
 
+            #[cfg(feature = "tuning")]
             /// Set the [`VocTuning`](crate::configuration::VocTuning) parameters for the sensor.
-            /// Execution Time: 20ms
+            /// Execution Time: 20ms, 40ms with [write verification](Sen66::set_write_verification)
             /// <div class="warning">Only available in idle state</div>
             ///
             /// # Errors
@@ -404,20 +1188,33 @@ pub mod module {
             /// I2C bus occurs.
             /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
             /// Idle state.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+            /// enabled and the data read back is corrupted or wrong.
+            /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+            /// verification is enabled and the VOC tuning parameters read back differs.
             pub async fn set_voc_tuning_parameters(
                 &mut self,
                 parameter: VocTuning,
             ) -> Result<(), Sen66Error<ERR>> {
-                if self.state != SensorState::Idle {
-                    return Err(Sen66Error::WrongState("Measuring"));
+                self.ensure_state(SensorState::Idle)?;
+                let setting = Setting::VocTuning(parameter);
+                if self.is_cached(&setting) {
+                    return Ok(());
                 }
+                self.invalidate_cached(&setting);
                 self.write::<20>(
                     Command::SetReadVocTuningParameters,
                     Some(&(<[u16; 6]>::from(parameter))),
                 )
-                .await
+                .await?;
+                if self.verify_writes && self.get_voc_tuning_parameters().await? != parameter {
+                    return Err(Sen66Error::VerificationFailed("VOC tuning parameters"));
+                }
+                self.record_cached(setting);
+                Ok(())
             }
 
+            #[cfg(feature = "tuning")]
             /// Read the [`VocAlgorithmState`](crate::data::VocAlgorithmState) parameters
             /// from the sensor.
             /// The VOC algorithm state is lost after a device reset or power cycle, this enables
@@ -438,9 +1235,39 @@ pub mod module {
                 let received = self
                     .write_read::<2, 12>(Command::SetReadVocAlgorithmState, None)
                     .await?;
-                Ok(VocAlgorithmState::try_from(&received[..])?)
+                let state = VocAlgorithmState::try_from(&received[..])?;
+                self.voc_state = Some(state);
+                Ok(state)
+            }
+
+            #[cfg(feature = "tuning")]
+            /// Reads the [`VocAlgorithmState`](crate::data::VocAlgorithmState) and saves it to the
+            /// store of the `scheduler` if a checkpoint is due. Call it regularly, e.g. after each
+            /// measurement. Returns the saved state, `None` if no checkpoint was due.
+            /// - `scheduler`: Scheduler of the checkpoints.
+            /// Execution Time: 20ms if a checkpoint is due
+            ///
+            /// # Errors
+            ///
+            /// - [`Sensor`](crate::error::CheckpointError::Sensor): If reading the state from the
+            /// sensor fails.
+            /// - [`Store`](crate::error::CheckpointError::Store): If saving the state to the store
+            /// fails.
+            pub async fn checkpoint_voc_state<S: VocStateStore>(
+                &mut self,
+                scheduler: &mut VocCheckpointScheduler<S>,
+            ) -> Result<Option<VocAlgorithmState>, CheckpointError<ERR, S::Error>> {
+                if !scheduler.is_due() {
+                    return Ok(None);
+                }
+                let state = self.get_voc_algorithm_state().await?;
+                scheduler
+                    .checkpoint(&state)
+                    .map_err(CheckpointError::Store)?;
+                Ok(Some(state))
             }
 
+            #[cfg(feature = "tuning")]
             /// Set the [`VocAlgorithmState`](crate::data::VocAlgorithmState) parameters
             /// for the sensor.
             /// Use [`get_voc_algorithm_state`](Sen66::get_voc_algorithm_state) to retrive it.
@@ -457,16 +1284,17 @@ pub mod module {
                 &mut self,
                 parameter: VocAlgorithmState,
             ) -> Result<(), Sen66Error<ERR>> {
-                if self.state != SensorState::Idle {
-                    return Err(Sen66Error::WrongState("Measuring"));
-                }
+                self.ensure_state(SensorState::Idle)?;
                 self.write::<14>(
                     Command::SetReadVocAlgorithmState,
                     Some(&(<[u16; 4]>::from(parameter))),
                 )
-                .await
+                .await?;
+                self.voc_state = Some(parameter);
+                Ok(())
             }
 
+            #[cfg(feature = "tuning")]
             /// Read the [`NoxTuning`](crate::configuration::NoxTuning) parameters from the sensor.
             /// Execution Time: 20ms
             /// <div class="warning">Only available in idle state</div>
@@ -482,17 +1310,18 @@ pub mod module {
             pub async fn get_nox_tuning_parameters(
                 &mut self,
             ) -> Result<NoxTuning, Sen66Error<ERR>> {
-                if self.state != SensorState::Idle {
-                    return Err(Sen66Error::WrongState("Measuring"));
-                }
+                self.ensure_state(SensorState::Idle)?;
                 let received = self
                     .write_read::<2, 18>(Command::SetReadNoxTuningParameters, None)
                     .await?;
-                Ok(NoxTuning::try_from(&received[..])?)
+                let value = NoxTuning::try_from(&received[..])?;
+                self.record_cached(Setting::NoxTuning(value));
+                Ok(value)
             }
 
+            #[cfg(feature = "tuning")]
             /// Set the [`NoxTuning`](crate::configuration::NoxTuning) parameters for the sensor.
-            /// Execution Time: 20ms
+            /// Execution Time: 20ms, 40ms with [write verification](Sen66::set_write_verification)
             /// <div class="warning">Only available in idle state</div>
             ///
             /// # Errors
@@ -501,20 +1330,33 @@ pub mod module {
             /// I2C bus occurs.
             /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
             /// Idle state.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+            /// enabled and the data read back is corrupted or wrong.
+            /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+            /// verification is enabled and the NOx tuning parameters read back differs.
             pub async fn set_nox_tuning_parameters(
                 &mut self,
                 parameter: NoxTuning,
             ) -> Result<(), Sen66Error<ERR>> {
-                if self.state != SensorState::Idle {
-                    return Err(Sen66Error::WrongState("Measuring"));
+                self.ensure_state(SensorState::Idle)?;
+                let setting = Setting::NoxTuning(parameter);
+                if self.is_cached(&setting) {
+                    return Ok(());
                 }
+                self.invalidate_cached(&setting);
                 self.write::<20>(
                     Command::SetReadNoxTuningParameters,
                     Some(&(<[u16; 6]>::from(parameter))),
                 )
-                .await
+                .await?;
+                if self.verify_writes && self.get_nox_tuning_parameters().await? != parameter {
+                    return Err(Sen66Error::VerificationFailed("NOx tuning parameters"));
+                }
+                self.record_cached(setting);
+                Ok(())
             }
 
+            #[cfg(feature = "co2-calibration")]
             /// Execute the forced recalibration (FRC) for the CO2 sensor.
             /// Wait at least 1000ms after power-on or 600ms after stopping the measurement before
             /// issuing this command.
@@ -533,9 +1375,7 @@ pub mod module {
                 &mut self,
                 parameter: TargetCO2Concentration,
             ) -> Result<Co2Correction, Sen66Error<ERR>> {
-                if self.state != SensorState::Idle {
-                    return Err(Sen66Error::WrongState("Measuring"));
-                }
+                self.ensure_state(SensorState::Idle)?;
                 let received = self
                     .write_read::<5, 3>(
                         Command::ForcedRecalibration,
@@ -546,10 +1386,50 @@ pub mod module {
                 if !value.is_valid() {
                     Err(Sen66Error::FailedCo2Recalibration)
                 } else {
+                    #[cfg(feature = "maintenance-schedule")]
+                    self.record_maintenance(MaintenanceTask::Co2Recalibration);
                     Ok(value)
                 }
             }
 
+            #[cfg(feature = "co2-calibration")]
+            /// Executes the forced recalibration (FRC) for the CO2 sensor like
+            /// [`perform_forced_co2_recalibration`](Sen66::perform_forced_co2_recalibration) and
+            /// records it together with the sensor's CO2 compensation settings.
+            /// - `target`: CO2 concentration the sensor is recalibrated to.
+            /// - `timestamp`: User-supplied time of the calibration.
+            /// Execution Time: 600ms
+            /// <div class="warning">Only available in idle state</div>
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Measuring state.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            /// - [`FailedCo2Recalibration`](crate::error::Sen66Error::FailedCo2Recalibration): If
+            /// the sensor reports the recalibration as failed.
+            pub async fn perform_calibration(
+                &mut self,
+                target: TargetCO2Concentration,
+                timestamp: u64,
+            ) -> Result<CalibrationReport, Sen66Error<ERR>> {
+                self.ensure_state(SensorState::Idle)?;
+                let serial_number = self.get_serial_number().await?;
+                let correction = self.perform_forced_co2_recalibration(target).await?;
+                Ok(CalibrationReport {
+                    serial_number,
+                    target,
+                    correction,
+                    asc_state: self.get_co2_asc_state().await?,
+                    sensor_altitude: self.get_sensor_altitude().await?,
+                    ambient_pressure: self.get_ambient_pressure().await?,
+                    timestamp,
+                })
+            }
+
             /// Read out whether the automatic self calibration (ASC) for the CO2 sensor is
             /// enabled or disabled.
             /// Execution Time: 20ms
@@ -564,18 +1444,18 @@ pub mod module {
             /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
             /// corrupted or wrong.
             pub async fn get_co2_asc_state(&mut self) -> Result<AscState, Sen66Error<ERR>> {
-                if self.state != SensorState::Idle {
-                    return Err(Sen66Error::WrongState("Measuring"));
-                }
+                self.ensure_state(SensorState::Idle)?;
                 let received = self
                     .write_read::<2, 3>(Command::SetReadCo2AutomaticSelfCalibration, None)
                     .await?;
-                Ok(AscState::try_from(&received[..])?)
+                let value = AscState::try_from(&received[..])?;
+                self.record_cached(Setting::Co2AutomaticSelfCalibration(value));
+                Ok(value)
             }
 
             /// Set whether the automatic self calibration (ASC) for the CO2 sensor is
             /// enabled or disabled.
-            /// Execution Time: 20ms
+            /// Execution Time: 20ms, 40ms with [write verification](Sen66::set_write_verification)
             /// <div class="warning">Only available in idle state</div>
             ///
             /// # Errors
@@ -584,18 +1464,30 @@ pub mod module {
             /// I2C bus occurs.
             /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
             /// Idle state.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+            /// enabled and the data read back is corrupted or wrong.
+            /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+            /// verification is enabled and the ASC state read back differs.
             pub async fn set_co2_asc_state(
                 &mut self,
                 new_state: AscState,
             ) -> Result<(), Sen66Error<ERR>> {
-                if self.state != SensorState::Idle {
-                    return Err(Sen66Error::WrongState("Measuring"));
+                self.ensure_state(SensorState::Idle)?;
+                let setting = Setting::Co2AutomaticSelfCalibration(new_state);
+                if self.is_cached(&setting) {
+                    return Ok(());
                 }
+                self.invalidate_cached(&setting);
                 self.write::<5>(
                     Command::SetReadCo2AutomaticSelfCalibration,
                     Some(&([u16::from(new_state)])),
                 )
-                .await
+                .await?;
+                if self.verify_writes && self.get_co2_asc_state().await? != new_state {
+                    return Err(Sen66Error::VerificationFailed("CO2 ASC state"));
+                }
+                self.record_cached(setting);
+                Ok(())
             }
 
             /// Read the configured ambient pressure for CO2 sensor compensation from the sensor.
@@ -613,25 +1505,41 @@ pub mod module {
                 let received = self
                     .write_read::<2, 3>(Command::SetReadAmbientPreassure, None)
                     .await?;
-                Ok(AmbientPressure::try_from(&received[..])?)
+                let value = AmbientPressure::try_from(&received[..])?;
+                self.record_cached(Setting::AmbientPressure(value));
+                Ok(value)
             }
 
             /// Configure the ambient pressure for CO2 sensor compensation for the sensor.
-            /// Execution Time: 20ms
+            /// Execution Time: 20ms, 40ms with [write verification](Sen66::set_write_verification)
             ///
             /// # Errors
             ///
             /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
             /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+            /// enabled and the data read back is corrupted or wrong.
+            /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+            /// verification is enabled and the ambient pressure read back differs.
             pub async fn set_ambient_pressure(
                 &mut self,
                 parameter: AmbientPressure,
             ) -> Result<(), Sen66Error<ERR>> {
+                let setting = Setting::AmbientPressure(parameter);
+                if self.is_cached(&setting) {
+                    return Ok(());
+                }
+                self.invalidate_cached(&setting);
                 self.write::<5>(
                     Command::SetReadAmbientPreassure,
                     Some(&([u16::from(parameter)])),
                 )
-                .await
+                .await?;
+                if self.verify_writes && self.get_ambient_pressure().await? != parameter {
+                    return Err(Sen66Error::VerificationFailed("ambient pressure"));
+                }
+                self.record_cached(setting);
+                Ok(())
             }
 
             /// Read the configured sensor altitude for CO2 sensor compensation from the sensor.
@@ -647,17 +1555,17 @@ pub mod module {
             /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
             /// corrupted or wrong.
             pub async fn get_sensor_altitude(&mut self) -> Result<SensorAltitude, Sen66Error<ERR>> {
-                if self.state != SensorState::Idle {
-                    return Err(Sen66Error::WrongState("Measuring"));
-                }
+                self.ensure_state(SensorState::Idle)?;
                 let received = self
                     .write_read::<2, 3>(Command::SetReadSensorAltitude, None)
                     .await?;
-                Ok(SensorAltitude::try_from(&received[..])?)
+                let value = SensorAltitude::try_from(&received[..])?;
+                self.record_cached(Setting::SensorAltitude(value));
+                Ok(value)
             }
 
             /// Configure the sensor altitude for CO2 sensor compensation for the sensor.
-            /// Execution Time: 20ms
+            /// Execution Time: 20ms, 40ms with [write verification](Sen66::set_write_verification)
             /// <div class="warning">Only available in idle state</div>
             ///
             /// # Errors
@@ -666,707 +1574,5189 @@ pub mod module {
             /// I2C bus occurs.
             /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
             /// Idle state.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+            /// enabled and the data read back is corrupted or wrong.
+            /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+            /// verification is enabled and the sensor altitude read back differs.
             pub async fn set_sensor_altitude(
                 &mut self,
                 parameter: SensorAltitude,
             ) -> Result<(), Sen66Error<ERR>> {
-                if self.state != SensorState::Idle {
-                    return Err(Sen66Error::WrongState("Measuring"));
+                self.ensure_state(SensorState::Idle)?;
+                let setting = Setting::SensorAltitude(parameter);
+                if self.is_cached(&setting) {
+                    return Ok(());
                 }
+                self.invalidate_cached(&setting);
                 self.write::<5>(
                     Command::SetReadSensorAltitude,
                     Some(&([u16::from(parameter)])),
                 )
-                .await
+                .await?;
+                if self.verify_writes && self.get_sensor_altitude().await? != parameter {
+                    return Err(Sen66Error::VerificationFailed("sensor altitude"));
+                }
+                self.record_cached(setting);
+                Ok(())
             }
 
-            /// Closes the sensor interface, stops active measuring if active and returns the
-            /// contained peripherals.
+            /// Exercises the device for end-of-line testing: Clears the device status, measures
+            /// until the first measurement is available and checks the device status for errors
+            /// afterwards. The sensor is returned to its previous state, the device status is
+            /// left cleared.
+            /// Execution Time: up to 4200ms
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs. The test is aborted and the sensor may remain measuring.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the state is unknown.
+            pub async fn self_test(&mut self) -> Result<SelfTestReport, Sen66Error<ERR>> {
+                let previous = self.state;
+                match previous {
+                    SensorState::Measuring => self.stop_measurement().await?,
+                    SensorState::Idle => (),
+                    SensorState::Unknown => self.ensure_state(SensorState::Idle)?,
+                }
+                self.read_and_clear_device_status().await?;
+                self.start_measurement().await?;
+
+                let polls = self.poll_data_ready(SELF_TEST_POLL_LIMIT).await?;
+                // Ready on the first poll indicates a stuck flag
+                let data_ready_toggled = polls.is_some_and(|polls| polls > 0);
+                let measurement = match polls {
+                    Some(_) => Some(self.read_measured_values().await?),
+                    None => None,
+                };
+
+                let status = self.read_device_status().await?;
+                self.stop_measurement().await?;
+                if previous == SensorState::Measuring {
+                    self.start_measurement().await?;
+                }
+                Ok(SelfTestReport {
+                    data_ready_toggled,
+                    measurement,
+                    device_error: status.has_error().err(),
+                    fan_speed_warning: status.fan_speed_warning(),
+                })
+            }
+
+            /// Runs the sensor until the conditioning period tracked by `burn_in` has elapsed.
+            /// Each measurement accounts for one second of operation, so an interrupted burn-in
+            /// can be resumed with the same tracker. Starts a measurement if the sensor is idle
+            /// and leaves it measuring afterwards.
+            /// Execution Time: The remaining conditioning period
             ///
             /// # Errors
             ///
             /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
             /// I2C bus occurs.
-            pub async fn shutdown(mut self) -> Result<(DELAY, I2C), Sen66Error<ERR>> {
-                if self.state == SensorState::Measuring {
-                    self.stop_measurement().await?;
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the state is unknown.
+            pub async fn burn_in(
+                &mut self,
+                burn_in: &mut BurnIn,
+            ) -> Result<BurnInStatus, Sen66Error<ERR>> {
+                if self.state == SensorState::Idle {
+                    self.start_measurement().await?;
                 }
-                Ok((self.delay, self.i2c))
+                self.ensure_state(SensorState::Measuring)?;
+                while burn_in.status().remaining_s > 0 {
+                    self.poll_data_ready(u32::MAX).await?;
+                    let measurement = self.read_measured_values().await?;
+                    burn_in.update(&measurement, 1);
+                }
+                Ok(burn_in.status())
+            }
+
+            /// Runs one measurement cycle of the duty `cycle`: Starts a measurement, discards
+            /// the readings of the stabilization period, averages the PM readings of the
+            /// remaining measurement, stops the measurement and idles for the off period. Each
+            /// measurement accounts for one second. Call repeatedly to operate the sensor in the
+            /// duty cycle. The measurement is stopped even if waiting for or reading a
+            /// measurement fails, the original error is returned.
+            /// Execution Time: The cycle's duration
+            /// <div class="warning">Only available in idle state</div>
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Measuring state.
+            pub async fn run_duty_cycle(
+                &mut self,
+                cycle: &DutyCycle,
+            ) -> Result<PmAverage, Sen66Error<ERR>> {
+                self.ensure_state(SensorState::Idle)?;
+                self.start_measurement().await?;
+                let result = self.average_duty_cycle(cycle).await;
+                let stopped = self.stop_measurement().await;
+                let average = result?;
+                stopped?;
+                self.delay_within_deadline(u32::from(cycle.off_s()) * 1000)
+                    .await?;
+                Ok(average)
             }
 
-            /// Closes the sensor interface, does not change sensor state.
-            pub async fn kill(self) -> (DELAY, I2C) {
-                (self.delay, self.i2c)
+            /// Takes a single measurement: Starts a measurement, waits until the first
+            /// measurement is available, reads it and stops the measurement again. Useful for
+            /// battery powered devices sampling every few minutes. The measurement is stopped
+            /// even if waiting for or reading the measurement fails. Mind that the VOC and NOx
+            /// index as well as the CO2 concentration require several samples to settle.
+            /// Execution Time: ~2.2s
+            /// <div class="warning">Only available in idle state</div>
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Measuring state.
+            #[cfg_attr(
+                feature = "cooldown",
+                doc = "- [`CooldownActive`](crate::error::Sen66Error::CooldownActive): If a cooldown is"
+            )]
+            #[cfg_attr(
+                feature = "cooldown",
+                doc = "active and the cooldown policy rejects waiting for it."
+            )]
+            pub async fn measure_once(&mut self) -> Result<Measurement, Sen66Error<ERR>> {
+                self.ensure_state(SensorState::Idle)?;
+                self.start_measurement().await?;
+                let result = self.read_first_measurement().await;
+                let stopped = self.stop_measurement().await;
+                let measurement = result?;
+                stopped?;
+                Ok(measurement)
             }
 
-            /// Writes the command and optional data to the sensor, waits for the execution time of
-            /// the command and reads the values returned.
-            async fn write_read<const TX_SIZE: usize, const RX_SIZE: usize>(
+            #[cfg(all(feature = "co2-calibration", feature = "deadline"))]
+            /// Runs [`perform_calibration`](Sen66::perform_calibration), aborting once
+            /// `deadline_ms` has passed. The deadline is compared against the time source
+            /// configured via [`set_time_source`](Sen66::set_time_source); without a time source
+            /// it never passes. The sensor remains idle.
+            /// Execution Time: 600ms, at most until the deadline
+            /// <div class="warning">Only available in idle state</div>
+            ///
+            /// # Errors
+            ///
+            /// - [`Timeout`](crate::error::Sen66Error::Timeout): If the deadline passed before
+            /// the calibration completed.
+            /// - See [`perform_calibration`](Sen66::perform_calibration).
+            pub async fn perform_calibration_with_deadline(
                 &mut self,
-                command: Command,
-                data: Option<&[u16]>,
-            ) -> Result<[u8; RX_SIZE], Sen66Error<ERR>> {
-                self.write::<TX_SIZE>(command, data).await?;
-                Ok(self.read().await?)
+                target: TargetCO2Concentration,
+                timestamp: u64,
+                deadline_ms: u64,
+            ) -> Result<CalibrationReport, Sen66Error<ERR>> {
+                self.deadline_ms = Some(deadline_ms);
+                let result = self.perform_calibration(target, timestamp).await;
+                self.end_deadline(result, false).await
             }
 
-            /// Writes the command and optional data to the sensor and waits for the execution time
-            /// of the command.
-            async fn write<const TX_SIZE: usize>(
+            /// Runs [`self_test`](Sen66::self_test), aborting once `deadline_ms` has passed. The
+            /// deadline is compared against the time source configured via
+            /// [`set_time_source`](Sen66::set_time_source); without a time source it never
+            /// passes. On timeout the measurement is stopped and the sensor left idle.
+            /// Execution Time: up to 4200ms, at most until the deadline
+            ///
+            /// # Errors
+            ///
+            /// - [`Timeout`](crate::error::Sen66Error::Timeout): If the deadline passed before
+            /// the test completed.
+            /// - See [`self_test`](Sen66::self_test).
+            #[cfg(feature = "deadline")]
+            pub async fn self_test_with_deadline(
                 &mut self,
-                command: Command,
-                data: Option<&[u16]>,
+                deadline_ms: u64,
+            ) -> Result<SelfTestReport, Sen66Error<ERR>> {
+                self.deadline_ms = Some(deadline_ms);
+                let result = self.self_test().await;
+                self.end_deadline(result, true).await
+            }
+
+            /// Runs [`burn_in`](Sen66::burn_in), aborting once `deadline_ms` has passed. The
+            /// deadline is compared against the time source configured via
+            /// [`set_time_source`](Sen66::set_time_source); without a time source it never
+            /// passes. On timeout the sensor is left measuring and `burn_in` holds the progress,
+            /// so the burn-in can be resumed.
+            /// Execution Time: The remaining conditioning period, at most until the deadline
+            ///
+            /// # Errors
+            ///
+            /// - [`Timeout`](crate::error::Sen66Error::Timeout): If the deadline passed before
+            /// the conditioning period elapsed.
+            /// - See [`burn_in`](Sen66::burn_in).
+            #[cfg(feature = "deadline")]
+            pub async fn burn_in_with_deadline(
+                &mut self,
+                burn_in: &mut BurnIn,
+                deadline_ms: u64,
+            ) -> Result<BurnInStatus, Sen66Error<ERR>> {
+                self.deadline_ms = Some(deadline_ms);
+                let result = self.burn_in(burn_in).await;
+                self.end_deadline(result, false).await
+            }
+
+            /// Runs [`run_duty_cycle`](Sen66::run_duty_cycle), aborting once `deadline_ms` has
+            /// passed. The deadline is compared against the time source configured via
+            /// [`set_time_source`](Sen66::set_time_source); without a time source it never
+            /// passes. On timeout the measurement is stopped and the sensor left idle.
+            /// Execution Time: The cycle's duration, at most until the deadline
+            /// <div class="warning">Only available in idle state</div>
+            ///
+            /// # Errors
+            ///
+            /// - [`Timeout`](crate::error::Sen66Error::Timeout): If the deadline passed before
+            /// the cycle completed.
+            /// - See [`run_duty_cycle`](Sen66::run_duty_cycle).
+            #[cfg(feature = "deadline")]
+            pub async fn run_duty_cycle_with_deadline(
+                &mut self,
+                cycle: &DutyCycle,
+                deadline_ms: u64,
+            ) -> Result<PmAverage, Sen66Error<ERR>> {
+                self.deadline_ms = Some(deadline_ms);
+                let result = self.run_duty_cycle(cycle).await;
+                self.end_deadline(result, true).await
+            }
+
+            /// Applies multiple [`Setting`](crate::configuration::Setting)s in one sequence.
+            /// Settings only available in idle state are applied first. If the sensor is measuring
+            /// and any of them is given, the measurement is stopped once before and restarted
+            /// right after them, instead of once per setting. The remaining settings are applied
+            /// while measuring, so their execution time does not extend the interruption. Each
+            /// setting's result is reported at its index in `results`.
+            /// - `settings`: Settings to apply.
+            /// - `results`: Receives the result of each setting. Settings without a corresponding
+            ///   entry are not applied.
+            /// Execution Time: 20ms per setting, plus 1050ms if the measurement is interrupted
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs while stopping or restarting the measurement. If stopping fails, no
+            /// setting is applied. If restarting fails, the settings have been applied but the
+            /// sensor remains idle.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the state is unknown.
+            pub async fn apply_settings(
+                &mut self,
+                settings: &[Setting],
+                results: &mut [Result<(), Sen66Error<ERR>>],
             ) -> Result<(), Sen66Error<ERR>> {
-                let mut sent = [0; TX_SIZE];
-                let command_data = command.to_be_bytes();
-                sent[0] = command_data[0];
-                sent[1] = command_data[1];
-
-                let len = if let Some(data) = data {
-                    for (i, datum) in data.iter().enumerate() {
-                        let bytes = datum.to_be_bytes();
-                        sent[2 + i * 3] = bytes[0];
-                        sent[3 + i * 3] = bytes[1];
-                        sent[4 + i * 3] = compute_crc8(&bytes);
+                let settings = &settings[..settings.len().min(results.len())];
+                let interrupt = self.state == SensorState::Measuring
+                    && settings.iter().any(Setting::requires_idle);
+                if interrupt {
+                    self.stop_measurement().await?;
+                } else if self.state == SensorState::Unknown {
+                    return Err(Sen66Error::WrongState(self.state.name()));
+                }
+                for (setting, result) in settings.iter().zip(results.iter_mut()) {
+                    *result = Ok(());
+                    if setting.requires_idle() {
+                        *result = self.apply_setting(*setting).await;
                     }
-                    2 + data.len() * 3
+                }
+                let restarted = if interrupt {
+                    self.start_measurement().await
                 } else {
-                    2
+                    Ok(())
                 };
-                self.i2c.write(ADDRESS | WRITE_FLAG, &sent[..len]).await?;
-                self.delay.delay_ms(command.execution_time_ms()).await;
-                Ok(())
+                for (setting, result) in settings.iter().zip(results.iter_mut()) {
+                    if !setting.requires_idle() {
+                        *result = self.apply_setting(*setting).await;
+                    }
+                }
+                restarted
             }
 
-            /// Reads data from the I2C bus.
-            async fn read<const RX_SIZE: usize>(
+            #[cfg(feature = "tuning")]
+            /// Applies the sensor settings of a curated [`Profile`](crate::configuration::Profile)
+            /// using [`apply_settings`](Sen66::apply_settings). The profile's alarm thresholds are
+            /// available with [`Profile::thresholds`](crate::configuration::Profile::thresholds).
+            /// - `profile`: Application profile to apply.
+            /// Execution Time: 80ms, plus 1050ms if the measurement is interrupted
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs. All settings are attempted, the first error is reported.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the state is unknown.
+            pub async fn apply_profile(&mut self, profile: Profile) -> Result<(), Sen66Error<ERR>> {
+                let settings = profile.settings();
+                let mut results = settings.map(|_| Ok(()));
+                self.apply_settings(&settings, &mut results).await?;
+                results.into_iter().collect()
+            }
+
+            /// Applies a complete [`Sen66Config`](crate::configuration::Sen66Config). If the
+            /// sensor is measuring, the measurement is stopped before and restarted after applying
+            /// the configuration.
+            /// - `config`: Configuration to apply.
+            /// Execution Time: 20ms per setting, plus 1050ms if the measurement is interrupted
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs. All settings are attempted, the first error is reported.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the state is unknown.
+            pub async fn apply_config(
                 &mut self,
-            ) -> Result<[u8; RX_SIZE], Sen66Error<ERR>> {
-                let mut received = [0; RX_SIZE];
-                self.i2c.read(ADDRESS | READ_FLAG, &mut received).await?;
-                Ok(received)
+                config: &Sen66Config,
+            ) -> Result<(), Sen66Error<ERR>> {
+                let interrupt = self.state == SensorState::Measuring;
+                if interrupt {
+                    self.stop_measurement().await?;
+                } else if self.state == SensorState::Unknown {
+                    return Err(Sen66Error::WrongState(self.state.name()));
+                }
+                let mut result = Ok(());
+                for setting in config.settings() {
+                    result = result.and(self.apply_setting(setting).await);
+                }
+                if interrupt {
+                    self.start_measurement().await?;
+                }
+                result
             }
-        }
 
-        #[cfg(test)]
-        mod tests {
-            use super::*;
-            use embedded_hal_mock::eh1::{
-                delay::NoopDelay,
-                i2c::{Mock as I2cMock, Transaction as I2cTransaction},
-            };
+            /// Reads the [`Sen66Config`](crate::configuration::Sen66Config) from the sensor. The
+            /// temperature offset and acceleration parameters cannot be read and are `None`.
+            /// Execution Time: 100ms
+            /// <div class="warning">Only available in idle state</div>
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Measuring state.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn read_config(&mut self) -> Result<Sen66Config, Sen66Error<ERR>> {
+                self.ensure_state(SensorState::Idle)?;
+                Ok(Sen66Config {
+                    sensor_altitude: self.get_sensor_altitude().await?,
+                    ambient_pressure: self.get_ambient_pressure().await?,
+                    asc_state: self.get_co2_asc_state().await?,
+                    temperature_offset: None,
+                    temperature_acceleration: None,
+                    #[cfg(feature = "tuning")]
+                    voc_tuning: self.get_voc_tuning_parameters().await?,
+                    #[cfg(feature = "tuning")]
+                    nox_tuning: self.get_nox_tuning_parameters().await?,
+                })
+            }
 
-            #[test_macro]
-            async fn start_measurements_works() {
-                let expected_transaction = [I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21])];
-                let i2c = I2cMock::new(&expected_transaction);
-                let delay = NoopDelay::new();
-                let mut sensor = Sen66::new(delay, i2c);
+            /// Stops a running measurement and captures the applied configuration, the VOC
+            /// algorithm state and whether the sensor was measuring in a
+            /// [`SuspendState`](crate::configuration::SuspendState), e.g. before a deep sleep
+            /// power cycling the sensor. Restore it with [`resume_from`](Sen66::resume_from).
+            /// The temperature offset and acceleration parameters cannot be read from the sensor
+            /// and are only captured from the `config-cache`.
+            /// Execution Time: 120ms, plus 1000ms if the measurement is stopped
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the state is unknown.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn suspend(&mut self) -> Result<SuspendState, Sen66Error<ERR>> {
+                let measuring = match self.state {
+                    SensorState::Measuring => {
+                        self.stop_measurement().await?;
+                        true
+                    }
+                    SensorState::Idle => {
+                        self.flush_pending_stop().await?;
+                        false
+                    }
+                    SensorState::Unknown => {
+                        return Err(Sen66Error::WrongState("Suspending requires a known state"));
+                    }
+                };
+                #[cfg_attr(not(feature = "config-cache"), allow(unused_mut))]
+                let mut config = self.read_config().await?;
+                #[cfg(feature = "config-cache")]
+                for setting in self.config_cache.settings() {
+                    match setting {
+                        Setting::TemperatureOffset(offset) => {
+                            config.temperature_offset = Some(offset)
+                        }
+                        Setting::TemperatureAcceleration(acceleration) => {
+                            config.temperature_acceleration = Some(acceleration)
+                        }
+                        _ => (),
+                    }
+                }
+                Ok(SuspendState {
+                    persistent: PersistentState {
+                        config,
+                        #[cfg(feature = "tuning")]
+                        voc_state: Some(self.get_voc_algorithm_state().await?),
+                        #[cfg(not(feature = "tuning"))]
+                        voc_state: None,
+                    },
+                    measuring,
+                })
+            }
 
-                sensor.start_measurement().await.unwrap();
-                sensor.kill().await.1.done();
+            /// Restores a [`SuspendState`](crate::configuration::SuspendState) captured by
+            /// [`suspend`](Sen66::suspend), e.g. after waking up from a deep sleep which power
+            /// cycled the sensor. Works in any state: Resets the sensor, as it may have kept
+            /// running or lost its configuration while suspended, applies the configuration and
+            /// VOC algorithm state and restarts the measurement if the sensor was measuring.
+            /// - `suspended`: State captured before suspending.
+            /// Execution Time: 120ms, plus 20ms per setting and 50ms if the measurement is
+            /// restarted
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs. All settings are attempted, the first error is reported and the
+            /// measurement is not restarted.
+            pub async fn resume_from(
+                &mut self,
+                suspended: &SuspendState,
+            ) -> Result<(), Sen66Error<ERR>> {
+                // Whether the sensor kept running while suspended is unknown
+                self.pending_stop = false;
+                self.state = SensorState::Unknown;
+                self.reset_device().await?;
+                self.delay.delay_ms(BOOT_TIME_MS).await;
+                let mut result = Ok(());
+                for setting in suspended.persistent.config.settings() {
+                    result = result.and(self.apply_setting(setting).await);
+                }
+                #[cfg(feature = "tuning")]
+                if let Some(state) = suspended.persistent.voc_state {
+                    result = result.and(self.set_voc_algorithm_state(state).await);
+                }
+                result?;
+                if suspended.measuring {
+                    self.start_measurement().await?;
+                }
+                Ok(())
             }
 
-            #[test_macro]
-            async fn stop_measurement_in_idle_yields_error() {
-                let expected_transaction = [];
-                let i2c = I2cMock::new(&expected_transaction);
-                let delay = NoopDelay::new();
-                let mut sensor = Sen66::new(delay, i2c);
+            /// Brings the sensor into a known state: Resets the device, waits for it to boot,
+            /// clears the device status register and applies the `settings` in order. The sensor
+            /// is left in idle state. Returns the device status read before clearing it, e.g. to
+            /// log errors which occurred before the reset. Works in any state.
+            /// - `settings`: Configuration applied after the reset, e.g. the ambient pressure,
+            /// sensor altitude, temperature offsets or tuning parameters.
+            /// Execution Time: 140ms, plus 20ms per setting
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs. The remaining steps are skipped.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn init(
+                &mut self,
+                settings: &[Setting],
+            ) -> Result<DeviceStatusRegister, Sen66Error<ERR>> {
+                self.pending_stop = false;
+                self.state = SensorState::Unknown;
+                self.reset_device().await?;
+                self.delay.delay_ms(BOOT_TIME_MS).await;
+                let status = self.read_and_clear_device_status().await?;
+                for setting in settings {
+                    self.apply_setting(*setting).await?;
+                }
+                Ok(status)
+            }
 
-                assert!(sensor.stop_measurement().await.is_err());
-                sensor.kill().await.1.done();
+            /// Applies a single setting using its setter.
+            async fn apply_setting(&mut self, setting: Setting) -> Result<(), Sen66Error<ERR>> {
+                match setting {
+                    Setting::TemperatureOffset(value) => self.set_temperature_offset(value).await,
+                    Setting::TemperatureAcceleration(value) => {
+                        self.set_temperature_acceleration(value).await
+                    }
+                    #[cfg(feature = "tuning")]
+                    Setting::VocTuning(value) => self.set_voc_tuning_parameters(value).await,
+                    #[cfg(feature = "tuning")]
+                    Setting::NoxTuning(value) => self.set_nox_tuning_parameters(value).await,
+                    #[cfg(feature = "tuning")]
+                    Setting::VocAlgorithmState(value) => self.set_voc_algorithm_state(value).await,
+                    Setting::Co2AutomaticSelfCalibration(value) => {
+                        self.set_co2_asc_state(value).await
+                    }
+                    Setting::AmbientPressure(value) => self.set_ambient_pressure(value).await,
+                    Setting::SensorAltitude(value) => self.set_sensor_altitude(value).await,
+                }
             }
 
-            #[test_macro]
-            async fn stop_measurement_works() {
-                let expected_transaction = [I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04])];
-                let i2c = I2cMock::new(&expected_transaction);
-                let delay = NoopDelay::new();
-                let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Measuring;
+            /// Checks the presence of a sensor considered absent by the hot-plug supervision, see
+            /// [`set_hot_plug_supervision`](Sen66::set_hot_plug_supervision). Once it reappears, it
+            /// is re-initialized: the cached configuration is written again and an interrupted
+            /// measurement is resumed. Without the `config-cache` feature only the measurement is
+            /// resumed.
+            /// Execution Time: 20ms if absent, plus the time to re-initialize the sensor
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs while re-initializing the sensor.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the data received while
+            /// re-initializing the sensor is corrupted or wrong.
+            #[cfg(feature = "hot-plug")]
+            pub async fn supervise(&mut self) -> Result<HotPlugEvent, Sen66Error<ERR>> {
+                if !self.hot_plug.is_absent() {
+                    return Ok(HotPlugEvent::Present);
+                }
+                // An unplugged sensor restarts in idle state, a deferred stop is obsolete
+                self.pending_stop = false;
+                match self.read_device_status().await {
+                    Ok(_) => {}
+                    Err(Sen66Error::I2cError(_) | Sen66Error::DataError(_)) => {
+                        return Ok(HotPlugEvent::Absent);
+                    }
+                    Err(error) => return Err(error),
+                }
+                self.commit_measuring_time();
+                self.state = SensorState::Idle;
+                let resume = self.hot_plug.reconnect();
+                self.reinitialize(resume).await?;
+                Ok(HotPlugEvent::Reconnected)
+            }
 
-                sensor.stop_measurement().await.unwrap();
-                sensor.kill().await.1.done();
+            /// Checks whether the sensor reset itself unexpectedly, e.g. due to a brown-out, and
+            /// re-initializes it if so. A reset is suspected if the CO2 concentration becomes
+            /// unavailable again during a measurement, if no new data is available for five
+            /// measurement intervals, which requires a time source, or if error flags of the
+            /// device status register disappear without being cleared. The volatile settings are
+            /// written again, which are the cached configuration and the last read or written
+            /// VOC algorithm state, and a running measurement is restarted. Without the
+            /// `config-cache` feature only the VOC algorithm state is restored.
+            /// Returns the symptom of the detected reset, `None` if no reset was detected.
+            /// Execution Time: 0ms if no reset was detected, otherwise up to 1000ms plus the time
+            /// to re-initialize the sensor
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs while re-initializing the sensor.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the data received while
+            /// re-initializing the sensor is corrupted or wrong.
+            #[cfg(feature = "reset-recovery")]
+            pub async fn recover_from_reset(
+                &mut self,
+            ) -> Result<Option<ResetCause>, Sen66Error<ERR>> {
+                let Some(cause) = self.reset_monitor.take() else {
+                    return Ok(None);
+                };
+                let resume = self.state == SensorState::Measuring;
+                // The symptoms are heuristic, stop a measurement the sensor might still run
+                self.state = SensorState::Unknown;
+                let _ = self.stop_measurement().await;
+                self.commit_measuring_time();
+                self.pending_stop = false;
+                self.state = SensorState::Idle;
+                self.reinitialize(resume).await?;
+                Ok(Some(cause))
             }
 
-            #[test_macro]
-            async fn if_data_ready_is_data_ready_yields_ready() {
-                let expected_transaction = [
-                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
-                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
-                ];
-                let i2c = I2cMock::new(&expected_transaction);
-                let delay = NoopDelay::new();
-                let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Measuring;
+            /// Writes the volatile settings to a sensor which restarted and resumes the
+            /// measurement if `resume` is set.
+            #[cfg(any(feature = "hot-plug", feature = "reset-recovery"))]
+            async fn reinitialize(&mut self, resume: bool) -> Result<(), Sen66Error<ERR>> {
+                #[cfg(feature = "config-cache")]
+                {
+                    let cache = self.config_cache;
+                    self.config_cache.clear();
+                    for setting in cache.settings() {
+                        self.apply_setting(setting).await?;
+                    }
+                }
+                #[cfg(feature = "tuning")]
+                if let Some(state) = self.voc_state {
+                    self.set_voc_algorithm_state(state).await?;
+                }
+                if resume {
+                    self.start_measurement().await?;
+                }
+                Ok(())
+            }
 
-                assert_eq!(sensor.is_data_ready().await.unwrap(), DataStatus::Ready);
-                sensor.kill().await.1.done();
+            /// Closes the sensor interface, stops active measuring if active and returns the
+            /// contained peripherals and the [`StaticBuffers`] if any were provided. If the state
+            /// is unknown a stop is attempted.
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            pub async fn shutdown(
+                mut self,
+            ) -> Result<(DELAY, I2C, Option<StaticBuffers>), Sen66Error<ERR>> {
+                match self.state {
+                    SensorState::Measuring => self.stop_measurement().await?,
+                    // The sensor might already be idle and reject the command
+                    SensorState::Unknown => {
+                        let _ = self.stop_measurement().await;
+                    }
+                    SensorState::Idle => self.flush_pending_stop().await?,
+                }
+                Ok((self.delay, self.i2c, self.buffers))
             }
 
-            #[test_macro]
-            async fn if_data_not_ready_is_data_ready_yields_not_ready() {
-                let expected_transaction = [
-                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
-                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81]),
-                ];
-                let i2c = I2cMock::new(&expected_transaction);
-                let delay = NoopDelay::new();
-                let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Measuring;
+            /// Closes the sensor interface, does not change sensor state. Returns the contained
+            /// peripherals and the [`StaticBuffers`] if any were provided. A stop deferred by
+            /// dropping a [`MeasurementSession`] is discarded, leaving the sensor measuring; use
+            /// [`shutdown`](Sen66::shutdown) to send it.
+            pub async fn kill(self) -> (DELAY, I2C, Option<StaticBuffers>) {
+                (self.delay, self.i2c, self.buffers)
+            }
 
-                assert_eq!(sensor.is_data_ready().await.unwrap(), DataStatus::NotReady);
-                sensor.kill().await.1.done();
+            /// Closes the sensor interface without changing the sensor's state and captures the
+            /// driver's state in a [`DriverSnapshot`](crate::maintenance::DriverSnapshot), e.g.
+            /// before the MCU enters deep sleep while the sensor keeps running. No bus traffic is
+            /// caused. The time of an ongoing measurement is added to the snapshot's runtime
+            /// counters. Read the [`VocAlgorithmState`](crate::data::VocAlgorithmState) before
+            /// if the sensor may be power cycled while hibernating. The [`StaticBuffers`] are
+            /// handed back if any were provided, pass them to
+            /// [`resume_with_buffers`](Sen66::resume_with_buffers) to reuse them.
+            pub fn hibernate(mut self) -> (DELAY, I2C, Option<StaticBuffers>, DriverSnapshot) {
+                self.commit_measuring_time();
+                let snapshot = DriverSnapshot {
+                    state: self.state,
+                    pending_stop: self.pending_stop,
+                    counters: self.counters,
+                    bus_gap_us: self.bus_gap_us,
+                    #[cfg(feature = "rate-limit")]
+                    poll_rate_limit_ms: self.rate_limiter.poll_interval(),
+                    #[cfg(feature = "hot-plug")]
+                    hot_plug_nack_limit: self.hot_plug.nack_limit(),
+                    verify_writes: self.verify_writes,
+                    #[cfg(feature = "cooldown")]
+                    cooldown_policy: self.cooldown.policy(),
+                    humidity_correction: self.humidity_correction,
+                    #[cfg(feature = "maintenance-schedule")]
+                    schedule: self.schedule,
+                    #[cfg(feature = "tuning")]
+                    voc_state: self.voc_state,
+                    #[cfg(feature = "config-cache")]
+                    config_cache: self.config_cache,
+                    #[cfg(feature = "config-cache")]
+                    bypass_config_cache: self.bypass_config_cache,
+                };
+                (self.delay, self.i2c, self.buffers, snapshot)
             }
 
-            #[test_macro]
-            async fn read_measured_values_works() {
+            /// Restores an interface from a [`DriverSnapshot`](crate::maintenance::DriverSnapshot)
+            /// taken by [`hibernate`](Sen66::hibernate), e.g. after the MCU woke up from deep
+            /// sleep. No bus traffic is caused, the sensor is assumed to have kept its state and
+            /// configuration. Configure the time source, epoch source and correction model again.
+            /// - `snapshot`: Driver state captured before hibernating.
+            /// - `delay`: Delay provider, implementing embedded_hal's `DelayNs` trait.
+            /// - `i2c`: I2C peripheral implementing embedded_hal's `I2c` trait.
+            pub fn resume(snapshot: DriverSnapshot, delay: DELAY, i2c: I2C) -> Self {
+                let mut sensor = Self::new(delay, i2c);
+                sensor.state = snapshot.state;
+                sensor.pending_stop = snapshot.pending_stop;
+                sensor.counters = snapshot.counters;
+                sensor.bus_gap_us = snapshot.bus_gap_us;
+                #[cfg(feature = "rate-limit")]
+                sensor
+                    .rate_limiter
+                    .set_poll_interval(snapshot.poll_rate_limit_ms);
+                #[cfg(feature = "hot-plug")]
+                sensor.hot_plug.set_nack_limit(snapshot.hot_plug_nack_limit);
+                sensor.verify_writes = snapshot.verify_writes;
+                #[cfg(feature = "cooldown")]
+                sensor.cooldown.set_policy(snapshot.cooldown_policy);
+                sensor.humidity_correction = snapshot.humidity_correction;
+                #[cfg(feature = "maintenance-schedule")]
+                {
+                    sensor.schedule = snapshot.schedule;
+                }
+                #[cfg(feature = "tuning")]
+                {
+                    sensor.voc_state = snapshot.voc_state;
+                }
+                #[cfg(feature = "config-cache")]
+                {
+                    sensor.config_cache = snapshot.config_cache;
+                    sensor.bypass_config_cache = snapshot.bypass_config_cache;
+                }
+                sensor
+            }
+
+            /// Restores an interface from a [`DriverSnapshot`](crate::maintenance::DriverSnapshot)
+            /// like [`resume`](Sen66::resume), using the [`StaticBuffers`] handed back by
+            /// [`hibernate`](Sen66::hibernate) for I2C transfers.
+            /// - `snapshot`: Driver state captured before hibernating.
+            /// - `delay`: Delay provider, implementing embedded_hal's `DelayNs` trait.
+            /// - `i2c`: I2C peripheral implementing embedded_hal's `I2c` trait.
+            /// - `buffers`: Statically allocated transfer buffers.
+            pub fn resume_with_buffers(
+                snapshot: DriverSnapshot,
+                delay: DELAY,
+                i2c: I2C,
+                buffers: StaticBuffers,
+            ) -> Self {
+                let mut sensor = Self::resume(snapshot, delay, i2c);
+                sensor.buffers = Some(buffers);
+                sensor
+            }
+
+            #[cfg(all(feature = "async", feature = "blocking"))]
+            pub(in crate::interface) fn into_driver_state(
+                self,
+            ) -> (DELAY, I2C, crate::interface::DriverState) {
+                let state = crate::interface::DriverState {
+                    state: self.state,
+                    bus_gap_us: self.bus_gap_us,
+                    buffers: self.buffers,
+                    pending_stop: self.pending_stop,
+                    counters: self.counters,
+                    time_source: self.time_source,
+                    measuring_since_ms: self.measuring_since_ms,
+                    humidity_correction: self.humidity_correction,
+                    correction_model: self.correction_model,
+                    verify_writes: self.verify_writes,
+                    #[cfg(feature = "hot-plug")]
+                    hot_plug: self.hot_plug,
+                    #[cfg(feature = "reset-recovery")]
+                    reset_monitor: self.reset_monitor,
+                    #[cfg(feature = "rate-limit")]
+                    rate_limiter: self.rate_limiter,
+                    #[cfg(feature = "cooldown")]
+                    cooldown: self.cooldown,
+                    #[cfg(feature = "maintenance-schedule")]
+                    epoch_source: self.epoch_source,
+                    #[cfg(feature = "maintenance-schedule")]
+                    schedule: self.schedule,
+                    #[cfg(feature = "tuning")]
+                    voc_state: self.voc_state,
+                    #[cfg(feature = "config-cache")]
+                    config_cache: self.config_cache,
+                    #[cfg(feature = "config-cache")]
+                    bypass_config_cache: self.bypass_config_cache,
+                };
+                (self.delay, self.i2c, state)
+            }
+
+            #[cfg(all(feature = "async", feature = "blocking"))]
+            pub(in crate::interface) fn from_driver_state(
+                delay: DELAY,
+                i2c: I2C,
+                state: crate::interface::DriverState,
+            ) -> Self {
+                Self {
+                    delay,
+                    i2c,
+                    state: state.state,
+                    bus_gap_us: state.bus_gap_us,
+                    buffers: state.buffers,
+                    pending_stop: state.pending_stop,
+                    counters: state.counters,
+                    time_source: state.time_source,
+                    measuring_since_ms: state.measuring_since_ms,
+                    humidity_correction: state.humidity_correction,
+                    correction_model: state.correction_model,
+                    #[cfg(feature = "deadline")]
+                    deadline_ms: None,
+                    #[cfg(feature = "adaptive-polling")]
+                    last_ready_ms: None,
+                    verify_writes: state.verify_writes,
+                    #[cfg(feature = "hot-plug")]
+                    hot_plug: state.hot_plug,
+                    #[cfg(feature = "reset-recovery")]
+                    reset_monitor: state.reset_monitor,
+                    #[cfg(feature = "rate-limit")]
+                    rate_limiter: state.rate_limiter,
+                    #[cfg(feature = "cooldown")]
+                    cooldown: state.cooldown,
+                    #[cfg(feature = "maintenance-schedule")]
+                    epoch_source: state.epoch_source,
+                    #[cfg(feature = "maintenance-schedule")]
+                    schedule: state.schedule,
+                    #[cfg(feature = "tuning")]
+                    voc_state: state.voc_state,
+                    #[cfg(feature = "config-cache")]
+                    config_cache: state.config_cache,
+                    #[cfg(feature = "config-cache")]
+                    bypass_config_cache: state.bypass_config_cache,
+                }
+            }
+
+            /// Records `task` as performed now in the maintenance schedule, if an epoch source is
+            /// configured.
+            #[cfg(all(
+                feature = "maintenance-schedule",
+                any(feature = "maintenance", feature = "co2-calibration")
+            ))]
+            fn record_maintenance(&mut self, task: MaintenanceTask) {
+                if let Some(now_s) = self.epoch_source {
+                    self.schedule.record(task, now_s());
+                }
+            }
+
+            /// Returns whether writing `setting` can be skipped, as the sensor already holds it.
+            #[cfg(feature = "config-cache")]
+            fn is_cached(&self, setting: &Setting) -> bool {
+                !self.bypass_config_cache
+                    && !self.verify_writes
+                    && self.config_cache.contains(setting)
+            }
+
+            #[cfg(not(feature = "config-cache"))]
+            fn is_cached(&self, _setting: &Setting) -> bool {
+                false
+            }
+
+            /// Forgets the cached value of `setting`, as writing it may fail.
+            #[cfg(feature = "config-cache")]
+            fn invalidate_cached(&mut self, setting: &Setting) {
+                self.config_cache.invalidate(setting);
+            }
+
+            #[cfg(not(feature = "config-cache"))]
+            fn invalidate_cached(&mut self, _setting: &Setting) {}
+
+            /// Records that the sensor holds `setting`.
+            #[cfg(feature = "config-cache")]
+            fn record_cached(&mut self, setting: Setting) {
+                self.config_cache.record(setting);
+            }
+
+            #[cfg(not(feature = "config-cache"))]
+            fn record_cached(&mut self, _setting: Setting) {}
+
+            /// Returns the time spent in the ongoing measurement in ms.
+            fn ongoing_measuring_time_ms(&self) -> u64 {
+                match (self.time_source, self.measuring_since_ms) {
+                    (Some(now_ms), Some(since_ms)) => now_ms().saturating_sub(since_ms),
+                    _ => 0,
+                }
+            }
+
+            /// Adds the time spent in the ending measurement to the runtime counters.
+            pub(in crate::interface) fn commit_measuring_time(&mut self) {
+                self.counters.measuring_time_ms += self.ongoing_measuring_time_ms();
+                self.measuring_since_ms = None;
+                #[cfg(feature = "adaptive-polling")]
+                {
+                    self.last_ready_ms = None;
+                }
+            }
+
+            /// Sends a deferred stop command, if one is pending, like
+            /// [`stop_measurement`](Sen66::stop_measurement). The stop stays pending if it fails.
+            async fn flush_pending_stop(&mut self) -> Result<(), Sen66Error<ERR>> {
+                if !self.pending_stop {
+                    return Ok(());
+                }
+                self.pending_stop = false;
+                // Sent without `write`, which flushes the pending stop itself
+                let result = self.send::<2>(Command::StopMeasurement, None).await;
+                if result.is_ok() {
+                    self.start_cooldown(STOP_COOLDOWN_MS);
+                    self.commit_measuring_time();
+                    #[cfg(feature = "reset-recovery")]
+                    self.reset_monitor.stopped();
+                } else {
+                    self.pending_stop = true;
+                }
+                result
+            }
+
+            /// Polls data-ready until a measurement is available, at most `max_polls` times.
+            /// Returns the number of polls reporting no data or `None` if no data became
+            /// available. With a time source, the polling adapts to the time the previous
+            /// measurement became available: It sleeps until shortly before the next
+            /// measurement is expected and polls rapidly around that time.
+            pub(in crate::interface) async fn poll_data_ready(
+                &mut self,
+                max_polls: u32,
+            ) -> Result<Option<u32>, Sen66Error<ERR>> {
+                let expected_ms = self.expected_ready_ms();
+                if let (Some(expected_ms), Some(now_ms)) = (expected_ms, self.time_source) {
+                    let sleep_ms = expected_ms
+                        .saturating_sub(ADAPTIVE_POLL_WINDOW_MS)
+                        .saturating_sub(now_ms());
+                    if sleep_ms > 0 {
+                        self.delay_within_deadline(sleep_ms.min(MEASUREMENT_INTERVAL_MS) as u32)
+                            .await?;
+                    }
+                }
+                for poll in 0..max_polls {
+                    match self.is_data_ready().await? {
+                        DataStatus::Ready => {
+                            self.record_data_ready();
+                            return Ok(Some(poll));
+                        }
+                        DataStatus::NotReady => {
+                            let rapid = match (expected_ms, self.time_source) {
+                                (Some(expected_ms), Some(now_ms)) => {
+                                    now_ms() < expected_ms + ADAPTIVE_POLL_WINDOW_MS
+                                }
+                                _ => false,
+                            };
+                            let interval_ms = if rapid {
+                                ADAPTIVE_POLL_INTERVAL_MS
+                            } else {
+                                DATA_READY_POLL_INTERVAL_MS
+                            };
+                            self.delay_within_deadline(interval_ms).await?
+                        }
+                    }
+                }
+                Ok(None)
+            }
+
+            /// Records the time the latest measurement became available for the adaptive polling.
+            #[cfg(feature = "adaptive-polling")]
+            fn record_data_ready(&mut self) {
+                self.last_ready_ms = self.time_source.map(|now_ms| now_ms());
+            }
+
+            #[cfg(not(feature = "adaptive-polling"))]
+            fn record_data_ready(&mut self) {}
+
+            /// Returns the time the next measurement is expected to become available in ms.
+            #[cfg(feature = "adaptive-polling")]
+            fn expected_ready_ms(&self) -> Option<u64> {
+                self.last_ready_ms
+                    .map(|last_ready_ms| last_ready_ms + MEASUREMENT_INTERVAL_MS)
+            }
+
+            #[cfg(not(feature = "adaptive-polling"))]
+            fn expected_ready_ms(&self) -> Option<u64> {
+                None
+            }
+
+            /// Waits for the first measurement after starting a measurement and reads it.
+            async fn read_first_measurement(&mut self) -> Result<Measurement, Sen66Error<ERR>> {
+                // The first measurement becomes available after roughly 1.1s
+                self.delay_within_deadline(MEASUREMENT_INTERVAL_MS as u32)
+                    .await?;
+                self.poll_data_ready(u32::MAX).await?;
+                self.read_measured_values().await
+            }
+
+            /// Averages the PM readings of the on period of `cycle`, skipping the stabilization
+            /// period.
+            async fn average_duty_cycle(
+                &mut self,
+                cycle: &DutyCycle,
+            ) -> Result<PmAverage, Sen66Error<ERR>> {
+                let mut average = PmAverage::new();
+                for sample in 0..cycle.on_s() {
+                    self.poll_data_ready(u32::MAX).await?;
+                    let measurement = self.read_measured_values().await?;
+                    if sample >= cycle.discard_s() {
+                        average.add(&measurement);
+                    }
+                }
+                Ok(average)
+            }
+
+            /// Fails with [`Timeout`](crate::error::Sen66Error::Timeout) if a deadline is set and
+            /// has passed.
+            #[cfg(feature = "deadline")]
+            fn check_deadline(&self) -> Result<(), Sen66Error<ERR>> {
+                match (self.deadline_ms, self.time_source) {
+                    (Some(deadline_ms), Some(now_ms)) if now_ms() >= deadline_ms => {
+                        Err(Sen66Error::Timeout)
+                    }
+                    _ => Ok(()),
+                }
+            }
+
+            #[cfg(not(feature = "deadline"))]
+            fn check_deadline(&self) -> Result<(), Sen66Error<ERR>> {
+                Ok(())
+            }
+
+            /// Waits for or rejects an active cooldown, depending on the cooldown policy.
+            #[cfg(feature = "cooldown")]
+            async fn await_cooldown(&mut self) -> Result<(), Sen66Error<ERR>> {
+                let remaining_ms = self.cooldown_remaining();
+                match self.cooldown.policy() {
+                    _ if remaining_ms == 0 => Ok(()),
+                    CooldownPolicy::Wait => self.delay_within_deadline(remaining_ms).await,
+                    CooldownPolicy::Reject => Err(Sen66Error::CooldownActive { remaining_ms }),
+                }
+            }
+
+            /// Sends `command` and starts a cooldown of `cooldown_ms` once it completed, before the
+            /// next measurement may be started.
+            #[cfg(feature = "cooldown")]
+            async fn write_with_cooldown(
+                &mut self,
+                command: Command,
+                cooldown_ms: u32,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.write::<2>(command, None).await?;
+                self.start_cooldown(cooldown_ms);
+                Ok(())
+            }
+
+            #[cfg(not(feature = "cooldown"))]
+            async fn write_with_cooldown(
+                &mut self,
+                command: Command,
+                _cooldown_ms: u32,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.write::<2>(command, None).await
+            }
+
+            /// Starts a cooldown of `cooldown_ms` before the next measurement may be started.
+            #[cfg(feature = "cooldown")]
+            fn start_cooldown(&mut self, cooldown_ms: u32) {
+                self.cooldown
+                    .start(self.time_source.map(|now_ms| now_ms()), cooldown_ms);
+            }
+
+            #[cfg(not(feature = "cooldown"))]
+            fn start_cooldown(&mut self, _cooldown_ms: u32) {}
+
+            /// Delays for `ms` milliseconds, but at most until a set deadline, failing with
+            /// [`Timeout`](crate::error::Sen66Error::Timeout) if the deadline is reached.
+            #[cfg(feature = "deadline")]
+            async fn delay_within_deadline(&mut self, ms: u32) -> Result<(), Sen66Error<ERR>> {
+                let remaining_ms = match (self.deadline_ms, self.time_source) {
+                    (Some(deadline_ms), Some(now_ms)) => deadline_ms.saturating_sub(now_ms()),
+                    _ => u64::MAX,
+                };
+                if remaining_ms < u64::from(ms) {
+                    self.delay.delay_ms(remaining_ms as u32).await;
+                    return Err(Sen66Error::Timeout);
+                }
+                self.delay.delay_ms(ms).await;
+                Ok(())
+            }
+
+            #[cfg(not(feature = "deadline"))]
+            async fn delay_within_deadline(&mut self, ms: u32) -> Result<(), Sen66Error<ERR>> {
+                self.delay.delay_ms(ms).await;
+                Ok(())
+            }
+
+            /// Clears the deadline of a deadline-aware operation. On timeout, the measurement is
+            /// stopped if `stop` is set, leaving the sensor idle.
+            #[cfg(feature = "deadline")]
+            async fn end_deadline<T>(
+                &mut self,
+                result: Result<T, Sen66Error<ERR>>,
+                stop: bool,
+            ) -> Result<T, Sen66Error<ERR>> {
+                self.deadline_ms = None;
+                if stop
+                    && matches!(result, Err(Sen66Error::Timeout))
+                    && self.state != SensorState::Idle
+                {
+                    self.stop_measurement().await?;
+                }
+                result
+            }
+
+            /// Checks that the driver is in the `expected` state. Always passes with the
+            /// `lenient-state` feature, leaving it to the sensor to reject the command.
+            pub(in crate::interface) fn ensure_state(
+                &self,
+                expected: SensorState,
+            ) -> Result<(), Sen66Error<ERR>> {
+                if cfg!(feature = "lenient-state") || self.state == expected {
+                    Ok(())
+                } else {
+                    Err(Sen66Error::WrongState(self.state.name()))
+                }
+            }
+
+            /// Records the outcome of a bus transfer for the hot-plug supervision.
+            #[cfg(feature = "hot-plug")]
+            fn track_presence(&mut self, result: Result<(), ERR>) -> Result<(), Sen66Error<ERR>> {
+                let address_nack = result.as_ref().is_err_and(|error| {
+                    matches!(
+                        error.kind(),
+                        ErrorKind::NoAcknowledge(
+                            NoAcknowledgeSource::Address | NoAcknowledgeSource::Unknown
+                        )
+                    )
+                });
+                self.hot_plug
+                    .record(address_nack, self.state == SensorState::Measuring);
+                Ok(result?)
+            }
+
+            #[cfg(not(feature = "hot-plug"))]
+            fn track_presence(&mut self, result: Result<(), ERR>) -> Result<(), Sen66Error<ERR>> {
+                Ok(result?)
+            }
+
+            /// Writes the command and optional data to the sensor, waits for the execution time of
+            /// the command and reads the values returned.
+            async fn write_read<const TX_SIZE: usize, const RX_SIZE: usize>(
+                &mut self,
+                command: Command,
+                data: Option<&[u16]>,
+            ) -> Result<[u8; RX_SIZE], Sen66Error<ERR>> {
+                self.write::<TX_SIZE>(command, data).await?;
+                Ok(self.read().await?)
+            }
+
+            /// Writes the command and optional data to the sensor and waits for the execution time
+            /// of the command or the configured bus gap, whichever is longer.
+            async fn write<const TX_SIZE: usize>(
+                &mut self,
+                command: Command,
+                data: Option<&[u16]>,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.check_deadline()?;
+                // A cancelled future may have skipped waiting for the previous command
+                #[cfg(feature = "rate-limit")]
+                {
+                    let busy_ms = self
+                        .rate_limiter
+                        .busy(self.time_source.map(|now_ms| now_ms()));
+                    if busy_ms > 0 {
+                        self.delay.delay_ms(busy_ms).await;
+                    }
+                }
+                if matches!(command, Command::StopMeasurement) {
+                    self.pending_stop = false;
+                } else {
+                    self.flush_pending_stop().await?;
+                }
+                self.send::<TX_SIZE>(command, data).await
+            }
+
+            /// Sends the command and optional data to the sensor and waits for the execution time
+            /// of the command or the configured bus gap, whichever is longer.
+            async fn send<const TX_SIZE: usize>(
+                &mut self,
+                command: Command,
+                data: Option<&[u16]>,
+            ) -> Result<(), Sen66Error<ERR>> {
+                let mut stack_buffer = [0; TX_SIZE];
+                let sent = match self.buffers.as_mut() {
+                    Some(buffers) => &mut buffers.tx[..TX_SIZE],
+                    None => &mut stack_buffer[..],
+                };
+                let len = encode_frame(sent, command, data).ok_or(Sen66Error::InvalidLength {
+                    words: data.map_or(0, <[u16]>::len),
+                })?;
+                let result = self.i2c.write(ADDRESS | WRITE_FLAG, &sent[..len]).await;
+                self.track_presence(result)?;
+                #[cfg(feature = "rate-limit")]
+                self.rate_limiter.command_sent(
+                    self.time_source.map(|now_ms| now_ms()),
+                    command.execution_time_ms(),
+                );
+                let execution_time_us = command.execution_time_ms() * 1_000;
+                self.delay
+                    .delay_us(execution_time_us.max(self.bus_gap_us))
+                    .await;
+                #[cfg(feature = "rate-limit")]
+                self.rate_limiter.command_completed();
+                Ok(())
+            }
+
+            /// Reads data from the I2C bus and waits for the configured bus gap afterwards.
+            async fn read<const RX_SIZE: usize>(
+                &mut self,
+            ) -> Result<[u8; RX_SIZE], Sen66Error<ERR>> {
+                let mut received = [0; RX_SIZE];
+                self.read_into(&mut received).await?;
+                Ok(received)
+            }
+
+            /// Reads data filling `received` from the I2C bus and waits for the configured bus gap
+            /// afterwards.
+            async fn read_into(&mut self, received: &mut [u8]) -> Result<(), Sen66Error<ERR>> {
+                let result = match self.buffers.as_mut() {
+                    Some(buffers) => {
+                        let buffer = &mut buffers.rx[..received.len()];
+                        let result = self.i2c.read(ADDRESS | READ_FLAG, buffer).await;
+                        received.copy_from_slice(buffer);
+                        result
+                    }
+                    None => self.i2c.read(ADDRESS | READ_FLAG, received).await,
+                };
+                self.track_presence(result)?;
+                if self.bus_gap_us > 0 {
+                    self.delay.delay_us(self.bus_gap_us).await;
+                }
+                Ok(())
+            }
+        }
+
+        impl<DELAY: delay_trait, I2C: i2c_trait, ERR: embedded_hal::i2c::Error> core::fmt::Debug
+            for Sen66<DELAY, I2C>
+        {
+            /// Shows the driver's state, cached configuration and statistics. The peripherals are
+            /// omitted.
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut debug = f.debug_struct("Sen66");
+                debug
+                    .field("state", &self.state)
+                    .field("counters", &self.runtime_counters())
+                    .field("measuring_since_ms", &self.measuring_since_ms)
+                    .field("pending_stop", &self.pending_stop)
+                    .field("bus_gap_us", &self.bus_gap_us)
+                    .field("static_buffers", &self.buffers.is_some())
+                    .field("verify_writes", &self.verify_writes)
+                    .field("humidity_correction", &self.humidity_correction)
+                    .field("correction_model", &self.correction_model.is_some());
+                #[cfg(feature = "adaptive-polling")]
+                debug.field("last_ready_ms", &self.last_ready_ms);
+                #[cfg(feature = "deadline")]
+                debug.field("deadline_ms", &self.deadline_ms);
+                #[cfg(feature = "config-cache")]
+                debug
+                    .field("config_cache", &self.config_cache)
+                    .field("bypass_config_cache", &self.bypass_config_cache);
+                debug.finish_non_exhaustive()
+            }
+        }
+
+        #[cfg(feature = "defmt")]
+        impl<DELAY: delay_trait, I2C: i2c_trait, ERR: embedded_hal::i2c::Error> defmt::Format
+            for Sen66<DELAY, I2C>
+        {
+            fn format(&self, f: defmt::Formatter) {
+                defmt::write!(f, "Sen66 {{ {} }}", self.summary())
+            }
+        }
+
+        /// Guard for a running measurement, created by
+        /// [`start_session`](Sen66::start_session). Provides the commands available in measuring
+        /// state and stops the measurement when finished.
+        ///
+        /// Use [`finish`](MeasurementSession::finish) to stop the measurement and observe
+        /// errors. When dropped, the blocking interface stops the measurement immediately. The
+        /// async interface cannot access the bus while dropping, instead the stop is sent before
+        /// the next command issued through the driver.
+        pub struct MeasurementSession<'a, DELAY: delay_trait, I2C: i2c_base> {
+            sensor: &'a mut Sen66<DELAY, I2C>,
+            finished: bool,
+        }
+
+        impl<DELAY: delay_trait, I2C: i2c_trait, ERR: embedded_hal::i2c::Error>
+            MeasurementSession<'_, DELAY, I2C>
+        {
+            /// See [`is_data_ready`](Sen66::is_data_ready).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn is_data_ready(&mut self) -> Result<DataStatus, Sen66Error<ERR>> {
+                self.sensor.is_data_ready().await
+            }
+
+            /// See [`wait_for_data_ready`](Sen66::wait_for_data_ready).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            /// - [`DataReadyTimeout`](crate::error::Sen66Error::DataReadyTimeout): If no data
+            /// became ready within the timeout.
+            pub async fn wait_for_data_ready(
+                &mut self,
+                poll_interval_ms: u32,
+                timeout_ms: u32,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.sensor
+                    .wait_for_data_ready(poll_interval_ms, timeout_ms)
+                    .await
+            }
+
+            /// See [`read_measured_values`](Sen66::read_measured_values).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn read_measured_values(&mut self) -> Result<Measurement, Sen66Error<ERR>> {
+                self.sensor.read_measured_values().await
+            }
+
+            #[cfg(feature = "raw-measurements")]
+            /// See [`read_measured_raw_values`](Sen66::read_measured_raw_values).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn read_measured_raw_values(
+                &mut self,
+            ) -> Result<RawMeasurement, Sen66Error<ERR>> {
+                self.sensor.read_measured_raw_values().await
+            }
+
+            #[cfg(feature = "raw-measurements")]
+            /// See [`read_number_concentrations`](Sen66::read_number_concentrations).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn read_number_concentrations(
+                &mut self,
+            ) -> Result<Concentrations, Sen66Error<ERR>> {
+                self.sensor.read_number_concentrations().await
+            }
+
+            /// Stops the measurement and ends the session.
+            /// Execution Time: 1000ms
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs. The stop is retried when the session is dropped.
+            pub async fn finish(mut self) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.stop_measurement().await?;
+                self.finished = true;
+                Ok(())
+            }
+        }
+
+        impl<DELAY: delay_trait, I2C: i2c_base> Drop for MeasurementSession<'_, DELAY, I2C> {
+            fn drop(&mut self) {
+                if self.finished || self.sensor.state != SensorState::Measuring {
+                    return;
+                }
+                self.sensor.abandon_session();
+            }
+        }
+
+        /// Builder creating a [`Sen66`] with its configuration applied, created by
+        /// [`builder`](Sen66::builder). Settings are queued and applied by
+        /// [`build`](Sen66Builder::build) in idle state and in a fixed order, regardless of the
+        /// order they are queued in.
+        pub struct Sen66Builder<DELAY, I2C> {
+            delay: DELAY,
+            i2c: I2C,
+            reset: bool,
+            sensor_altitude: Option<SensorAltitude>,
+            ambient_pressure: Option<AmbientPressure>,
+            asc_state: Option<AscState>,
+            temperature_offset: Option<TemperatureOffset>,
+            temperature_acceleration: Option<TemperatureAcceleration>,
+            #[cfg(feature = "tuning")]
+            voc_tuning: Option<VocTuning>,
+            #[cfg(feature = "tuning")]
+            nox_tuning: Option<NoxTuning>,
+        }
+
+        impl<DELAY: delay_trait, I2C: i2c_trait, ERR: embedded_hal::i2c::Error> Sen66Builder<DELAY, I2C> {
+            /// Resets the sensor before applying the settings, see [`init`](Sen66::init).
+            pub fn reset(mut self) -> Self {
+                self.reset = true;
+                self
+            }
+
+            /// Queues the sensor altitude.
+            /// - `altitude`: Altitude in m.
+            ///
+            /// # Errors
+            ///
+            /// - [`ValueOutOfRange`](crate::error::DataError::ValueOutOfRange): If the altitude is
+            ///   not in the sensor's range.
+            pub fn altitude(mut self, altitude: u16) -> Result<Self, DataError> {
+                self.sensor_altitude = Some(SensorAltitude::try_from(altitude)?);
+                Ok(self)
+            }
+
+            /// Queues the ambient pressure.
+            /// - `pressure`: Ambient pressure in hPa.
+            ///
+            /// # Errors
+            ///
+            /// - [`ValueOutOfRange`](crate::error::DataError::ValueOutOfRange): If the pressure is
+            ///   not in the sensor's range.
+            pub fn ambient_pressure(mut self, pressure: u16) -> Result<Self, DataError> {
+                self.ambient_pressure = Some(AmbientPressure::try_from(pressure)?);
+                Ok(self)
+            }
+
+            /// Queues enabling or disabling the CO2 automatic self calibration.
+            pub fn asc(mut self, enabled: bool) -> Self {
+                self.asc_state = Some(if enabled {
+                    AscState::Enabled
+                } else {
+                    AscState::Disabled
+                });
+                self
+            }
+
+            /// Queues the temperature offset of one slot.
+            pub fn temperature_offset(mut self, offset: TemperatureOffset) -> Self {
+                self.temperature_offset = Some(offset);
+                self
+            }
+
+            /// Queues the temperature acceleration parameters.
+            pub fn temperature_acceleration(
+                mut self,
+                acceleration: TemperatureAcceleration,
+            ) -> Self {
+                self.temperature_acceleration = Some(acceleration);
+                self
+            }
+
+            /// Queues the VOC index algorithm tuning parameters.
+            #[cfg(feature = "tuning")]
+            pub fn voc_tuning(mut self, tuning: VocTuning) -> Self {
+                self.voc_tuning = Some(tuning);
+                self
+            }
+
+            /// Queues the NOx index algorithm tuning parameters.
+            #[cfg(feature = "tuning")]
+            pub fn nox_tuning(mut self, tuning: NoxTuning) -> Self {
+                self.nox_tuning = Some(tuning);
+                self
+            }
+
+            /// Creates the driver, optionally resets the sensor and applies the queued settings.
+            /// The sensor is left in idle state.
+            /// Execution Time: 20ms per setting, plus 140ms if the sensor is reset
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs. The remaining settings are skipped.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn build(self) -> Result<Sen66<DELAY, I2C>, Sen66Error<ERR>> {
+                #[cfg(feature = "tuning")]
+                let tuning = [
+                    self.voc_tuning.map(Setting::VocTuning),
+                    self.nox_tuning.map(Setting::NoxTuning),
+                ];
+                #[cfg(not(feature = "tuning"))]
+                let tuning = [];
+                let settings = [
+                    self.sensor_altitude.map(Setting::SensorAltitude),
+                    self.ambient_pressure.map(Setting::AmbientPressure),
+                    self.asc_state.map(Setting::Co2AutomaticSelfCalibration),
+                    self.temperature_offset.map(Setting::TemperatureOffset),
+                    self.temperature_acceleration
+                        .map(Setting::TemperatureAcceleration),
+                ];
+                let mut sensor = Sen66::new(self.delay, self.i2c);
+                if self.reset {
+                    sensor.init(&[]).await?;
+                }
+                for setting in settings.into_iter().chain(tuning).flatten() {
+                    sensor.apply_setting(setting).await?;
+                }
+                Ok(sensor)
+            }
+        }
+
+        /// Typestate variant of the driver, tracking the sensor's operating state in the type
+        /// parameter `STATE`, either [`Idle`] or [`Measuring`]. Only the commands available in
+        /// the state are provided, so calling a command in the wrong state is rejected at
+        /// compile time instead of failing with
+        /// [`WrongState`](crate::error::Sen66Error::WrongState). State changing commands consume
+        /// the driver and return it in the new state.
+        ///
+        /// Create it in idle state with [`new`](Sen66Typed::new) or convert a [`Sen66`] in the
+        /// matching state with [`TryFrom`]. Use [`into_inner`](Sen66Typed::into_inner) to access
+        /// the remaining functionality of the driver.
+        pub struct Sen66Typed<DELAY, I2C, STATE> {
+            sensor: Sen66<DELAY, I2C>,
+            state: PhantomData<STATE>,
+        }
+
+        impl<
+            DELAY: delay_trait,
+            I2C: i2c_trait,
+            ERR: embedded_hal::i2c::Error,
+            STATE: OperatingState,
+        > Sen66Typed<DELAY, I2C, STATE>
+        {
+            fn from_driver(sensor: Sen66<DELAY, I2C>) -> Self {
+                Self {
+                    sensor,
+                    state: PhantomData,
+                }
+            }
+
+            /// Returns the underlying driver, e.g. to read its runtime counters.
+            pub fn driver(&self) -> &Sen66<DELAY, I2C> {
+                &self.sensor
+            }
+
+            /// Converts the driver back into the runtime checked [`Sen66`].
+            pub fn into_inner(self) -> Sen66<DELAY, I2C> {
+                self.sensor
+            }
+
+            /// See [`kill`](Sen66::kill).
+            pub async fn kill(self) -> (DELAY, I2C, Option<StaticBuffers>) {
+                self.sensor.kill().await
+            }
+        }
+
+        impl<
+            DELAY: delay_trait,
+            I2C: i2c_trait,
+            ERR: embedded_hal::i2c::Error,
+            STATE: OperatingState,
+        > core::fmt::Debug for Sen66Typed<DELAY, I2C, STATE>
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.sensor.fmt(f)
+            }
+        }
+
+        impl<
+            DELAY: delay_trait,
+            I2C: i2c_trait,
+            ERR: embedded_hal::i2c::Error,
+            STATE: OperatingState,
+        > TryFrom<Sen66<DELAY, I2C>> for Sen66Typed<DELAY, I2C, STATE>
+        {
+            type Error = Sen66<DELAY, I2C>;
+
+            /// Converts the driver into the typestate driver, if the sensor is in the state
+            /// `STATE`. Returns the driver unchanged otherwise.
+            fn try_from(sensor: Sen66<DELAY, I2C>) -> Result<Self, Self::Error> {
+                if sensor.state == STATE::STATE {
+                    Ok(Self::from_driver(sensor))
+                } else {
+                    Err(sensor)
+                }
+            }
+        }
+
+        impl<DELAY: delay_trait, I2C: i2c_trait, ERR: embedded_hal::i2c::Error>
+            Sen66Typed<DELAY, I2C, Idle>
+        {
+            /// Creates a new SEN66 interface in idle state.
+            /// - `delay`: Delay provider, implementing embedded_hal's `DelayNs` trait.
+            /// - `i2c`: I2C peripheral implementing embedded_hal's `I2c` trait.
+            pub fn new(delay: DELAY, i2c: I2C) -> Self {
+                Self::from_driver(Sen66::new(delay, i2c))
+            }
+
+            /// Starts a continous measurement, see
+            /// [`start_measurement`](Sen66::start_measurement).
+            /// Execution Time: 50ms
+            ///
+            /// # Errors
+            ///
+            /// Returns the driver in idle state together with the error:
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            #[cfg_attr(
+                feature = "cooldown",
+                doc = "- [`CooldownActive`](crate::error::Sen66Error::CooldownActive): If a cooldown is"
+            )]
+            #[cfg_attr(
+                feature = "cooldown",
+                doc = "active and the cooldown policy rejects waiting for it."
+            )]
+            // The driver is returned on failure, so it is not lost.
+            #[allow(clippy::result_large_err)]
+            pub async fn start_measurement(
+                mut self,
+            ) -> Result<Sen66Typed<DELAY, I2C, Measuring>, (Self, Sen66Error<ERR>)> {
+                match self.sensor.start_measurement().await {
+                    Ok(()) => Ok(Sen66Typed::from_driver(self.sensor)),
+                    Err(error) => Err((self, error)),
+                }
+            }
+
+            /// See [`measure_once`](Sen66::measure_once).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            #[cfg_attr(
+                feature = "cooldown",
+                doc = "- [`CooldownActive`](crate::error::Sen66Error::CooldownActive): If a cooldown is"
+            )]
+            #[cfg_attr(
+                feature = "cooldown",
+                doc = "active and the cooldown policy rejects waiting for it."
+            )]
+            pub async fn measure_once(&mut self) -> Result<Measurement, Sen66Error<ERR>> {
+                self.sensor.measure_once().await
+            }
+
+            /// See [`get_product_name`](Sen66::get_product_name).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn get_product_name(&mut self) -> Result<ProductName, Sen66Error<ERR>> {
+                self.sensor.get_product_name().await
+            }
+
+            /// See [`get_serial_number`](Sen66::get_serial_number).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn get_serial_number(&mut self) -> Result<SerialNumber, Sen66Error<ERR>> {
+                self.sensor.get_serial_number().await
+            }
+
+            /// See [`read_device_status`](Sen66::read_device_status).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn read_device_status(
+                &mut self,
+            ) -> Result<DeviceStatusRegister, Sen66Error<ERR>> {
+                self.sensor.read_device_status().await
+            }
+
+            /// See [`read_and_clear_device_status`](Sen66::read_and_clear_device_status).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn read_and_clear_device_status(
+                &mut self,
+            ) -> Result<DeviceStatusRegister, Sen66Error<ERR>> {
+                self.sensor.read_and_clear_device_status().await
+            }
+
+            /// See [`reset_device`](Sen66::reset_device).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            pub async fn reset_device(&mut self) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.reset_device().await
+            }
+
+            /// See [`set_temperature_offset`](Sen66::set_temperature_offset).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            pub async fn set_temperature_offset(
+                &mut self,
+                parameter: TemperatureOffset,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.set_temperature_offset(parameter).await
+            }
+
+            /// See [`set_temperature_acceleration`](Sen66::set_temperature_acceleration).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            pub async fn set_temperature_acceleration(
+                &mut self,
+                parameter: TemperatureAcceleration,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.set_temperature_acceleration(parameter).await
+            }
+
+            /// See [`get_ambient_pressure`](Sen66::get_ambient_pressure).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn get_ambient_pressure(
+                &mut self,
+            ) -> Result<AmbientPressure, Sen66Error<ERR>> {
+                self.sensor.get_ambient_pressure().await
+            }
+
+            /// See [`set_ambient_pressure`](Sen66::set_ambient_pressure).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+            /// enabled and the data read back is corrupted or wrong.
+            /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+            /// verification is enabled and the ambient pressure read back differs.
+            pub async fn set_ambient_pressure(
+                &mut self,
+                parameter: AmbientPressure,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.set_ambient_pressure(parameter).await
+            }
+
+            /// See [`get_sensor_altitude`](Sen66::get_sensor_altitude).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn get_sensor_altitude(&mut self) -> Result<SensorAltitude, Sen66Error<ERR>> {
+                self.sensor.get_sensor_altitude().await
+            }
+
+            /// See [`set_sensor_altitude`](Sen66::set_sensor_altitude).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+            /// enabled and the data read back is corrupted or wrong.
+            /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+            /// verification is enabled and the sensor altitude read back differs.
+            pub async fn set_sensor_altitude(
+                &mut self,
+                parameter: SensorAltitude,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.set_sensor_altitude(parameter).await
+            }
+
+            /// See [`get_co2_asc_state`](Sen66::get_co2_asc_state).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn get_co2_asc_state(&mut self) -> Result<AscState, Sen66Error<ERR>> {
+                self.sensor.get_co2_asc_state().await
+            }
+
+            /// See [`set_co2_asc_state`](Sen66::set_co2_asc_state).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+            /// enabled and the data read back is corrupted or wrong.
+            /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+            /// verification is enabled and the ASC state read back differs.
+            pub async fn set_co2_asc_state(
+                &mut self,
+                new_state: AscState,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.set_co2_asc_state(new_state).await
+            }
+
+            #[cfg(feature = "maintenance")]
+            /// See [`start_fan_cleaning`](Sen66::start_fan_cleaning).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            pub async fn start_fan_cleaning(&mut self) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.start_fan_cleaning().await
+            }
+
+            #[cfg(feature = "maintenance")]
+            /// See [`activate_sht_heater`](Sen66::activate_sht_heater).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            pub async fn activate_sht_heater(&mut self) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.activate_sht_heater().await
+            }
+
+            #[cfg(feature = "co2-calibration")]
+            /// See [`perform_forced_co2_recalibration`](Sen66::perform_forced_co2_recalibration).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn perform_forced_co2_recalibration(
+                &mut self,
+                parameter: TargetCO2Concentration,
+            ) -> Result<Co2Correction, Sen66Error<ERR>> {
+                self.sensor
+                    .perform_forced_co2_recalibration(parameter)
+                    .await
+            }
+
+            #[cfg(feature = "tuning")]
+            /// See [`get_voc_tuning_parameters`](Sen66::get_voc_tuning_parameters).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn get_voc_tuning_parameters(
+                &mut self,
+            ) -> Result<VocTuning, Sen66Error<ERR>> {
+                self.sensor.get_voc_tuning_parameters().await
+            }
+
+            #[cfg(feature = "tuning")]
+            /// See [`set_voc_tuning_parameters`](Sen66::set_voc_tuning_parameters).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+            /// enabled and the data read back is corrupted or wrong.
+            /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+            /// verification is enabled and the VOC tuning parameters read back differs.
+            pub async fn set_voc_tuning_parameters(
+                &mut self,
+                parameter: VocTuning,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.set_voc_tuning_parameters(parameter).await
+            }
+
+            #[cfg(feature = "tuning")]
+            /// See [`get_voc_algorithm_state`](Sen66::get_voc_algorithm_state).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn get_voc_algorithm_state(
+                &mut self,
+            ) -> Result<VocAlgorithmState, Sen66Error<ERR>> {
+                self.sensor.get_voc_algorithm_state().await
+            }
+
+            #[cfg(feature = "tuning")]
+            /// See [`set_voc_algorithm_state`](Sen66::set_voc_algorithm_state).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            pub async fn set_voc_algorithm_state(
+                &mut self,
+                parameter: VocAlgorithmState,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.set_voc_algorithm_state(parameter).await
+            }
+
+            #[cfg(feature = "tuning")]
+            /// See [`get_nox_tuning_parameters`](Sen66::get_nox_tuning_parameters).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn get_nox_tuning_parameters(
+                &mut self,
+            ) -> Result<NoxTuning, Sen66Error<ERR>> {
+                self.sensor.get_nox_tuning_parameters().await
+            }
+
+            #[cfg(feature = "tuning")]
+            /// See [`set_nox_tuning_parameters`](Sen66::set_nox_tuning_parameters).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+            /// enabled and the data read back is corrupted or wrong.
+            /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+            /// verification is enabled and the NOx tuning parameters read back differs.
+            pub async fn set_nox_tuning_parameters(
+                &mut self,
+                parameter: NoxTuning,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.set_nox_tuning_parameters(parameter).await
+            }
+        }
+
+        impl<DELAY: delay_trait, I2C: i2c_trait, ERR: embedded_hal::i2c::Error>
+            Sen66Typed<DELAY, I2C, Measuring>
+        {
+            /// Stops continous measurements, see [`stop_measurement`](Sen66::stop_measurement).
+            /// Execution Time: 1000ms
+            ///
+            /// # Errors
+            ///
+            /// Returns the driver in measuring state together with the error:
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            // The driver is returned on failure, so it is not lost.
+            #[allow(clippy::result_large_err)]
+            pub async fn stop_measurement(
+                mut self,
+            ) -> Result<Sen66Typed<DELAY, I2C, Idle>, (Self, Sen66Error<ERR>)> {
+                match self.sensor.stop_measurement().await {
+                    Ok(()) => Ok(Sen66Typed::from_driver(self.sensor)),
+                    Err(error) => Err((self, error)),
+                }
+            }
+
+            /// See [`is_data_ready`](Sen66::is_data_ready).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn is_data_ready(&mut self) -> Result<DataStatus, Sen66Error<ERR>> {
+                self.sensor.is_data_ready().await
+            }
+
+            /// See [`wait_for_data_ready`](Sen66::wait_for_data_ready).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            /// - [`DataReadyTimeout`](crate::error::Sen66Error::DataReadyTimeout): If no data
+            /// became ready within the timeout.
+            pub async fn wait_for_data_ready(
+                &mut self,
+                poll_interval_ms: u32,
+                timeout_ms: u32,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.sensor
+                    .wait_for_data_ready(poll_interval_ms, timeout_ms)
+                    .await
+            }
+
+            /// See [`read_measured_values`](Sen66::read_measured_values).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn read_measured_values(&mut self) -> Result<Measurement, Sen66Error<ERR>> {
+                self.sensor.read_measured_values().await
+            }
+
+            /// See [`read_measured_values_fixed`](Sen66::read_measured_values_fixed).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn read_measured_values_fixed(
+                &mut self,
+            ) -> Result<MeasurementFixed, Sen66Error<ERR>> {
+                self.sensor.read_measured_values_fixed().await
+            }
+
+            #[cfg(feature = "raw-measurements")]
+            /// See [`read_measured_raw_values`](Sen66::read_measured_raw_values).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn read_measured_raw_values(
+                &mut self,
+            ) -> Result<RawMeasurement, Sen66Error<ERR>> {
+                self.sensor.read_measured_raw_values().await
+            }
+
+            #[cfg(feature = "raw-measurements")]
+            /// See [`read_number_concentrations`](Sen66::read_number_concentrations).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn read_number_concentrations(
+                &mut self,
+            ) -> Result<Concentrations, Sen66Error<ERR>> {
+                self.sensor.read_number_concentrations().await
+            }
+
+            /// See [`read_device_status`](Sen66::read_device_status).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn read_device_status(
+                &mut self,
+            ) -> Result<DeviceStatusRegister, Sen66Error<ERR>> {
+                self.sensor.read_device_status().await
+            }
+
+            /// See [`get_ambient_pressure`](Sen66::get_ambient_pressure).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn get_ambient_pressure(
+                &mut self,
+            ) -> Result<AmbientPressure, Sen66Error<ERR>> {
+                self.sensor.get_ambient_pressure().await
+            }
+
+            /// See [`set_ambient_pressure`](Sen66::set_ambient_pressure).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+            /// enabled and the data read back is corrupted or wrong.
+            /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+            /// verification is enabled and the ambient pressure read back differs.
+            pub async fn set_ambient_pressure(
+                &mut self,
+                parameter: AmbientPressure,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.set_ambient_pressure(parameter).await
+            }
+
+            #[cfg(feature = "tuning")]
+            /// See [`get_voc_algorithm_state`](Sen66::get_voc_algorithm_state).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn get_voc_algorithm_state(
+                &mut self,
+            ) -> Result<VocAlgorithmState, Sen66Error<ERR>> {
+                self.sensor.get_voc_algorithm_state().await
+            }
+        }
+
+        /// Split-phase interface for the SEN66, requiring no delay provider, e.g. for cooperative
+        /// superloops. [`issue`](Sen66SplitPhase::issue) sends a command and returns right after
+        /// the transfer. Once the command's execution time has passed, e.g. in a later iteration
+        /// of the superloop, its response is read with
+        /// [`try_fetch`](Sen66SplitPhase::try_fetch). While the sensor is still executing a
+        /// command it does not acknowledge transfers, which is reported as
+        /// [`WouldBlock`](crate::error::Sen66Error::WouldBlock).
+        ///
+        /// The split-phase interface neither tracks the sensor's state nor applies corrections,
+        /// use [`Sen66`] for that.
+        pub struct Sen66SplitPhase<I2C> {
+            i2c: I2C,
+        }
+
+        impl<I2C: i2c_trait, ERR: embedded_hal::i2c::Error> Sen66SplitPhase<I2C> {
+            /// Creates a new split-phase SEN66 interface.
+            /// - `i2c`: I2C peripheral implementing embedded_hal's `I2c` trait.
+            pub fn new(i2c: I2C) -> Self {
+                Self { i2c }
+            }
+
+            /// Sends the `command` without waiting for its execution. Returns the command's
+            /// execution time in ms, after which the sensor accepts the next transfer.
+            /// - `command`: Command to send, mind the state it is available in.
+            /// - `data`: Words sent with the command, CRCs are added.
+            ///
+            /// # Errors
+            ///
+            /// - [`WouldBlock`](crate::error::Sen66Error::WouldBlock): If the sensor did not
+            /// acknowledge the command, as it is still executing the previous one.
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If another error on the
+            /// underlying I2C bus occurs.
+            /// - [`InvalidLength`](crate::error::Sen66Error::InvalidLength): If more than 6 data
+            /// words are passed, exceeding the transmit buffer.
+            pub async fn issue(
+                &mut self,
+                command: Command,
+                data: Option<&[u16]>,
+            ) -> Result<u32, Sen66Error<ERR>> {
+                let mut sent = [0; TX_BUFFER_SIZE];
+                let len =
+                    encode_frame(&mut sent, command, data).ok_or(Sen66Error::InvalidLength {
+                        words: data.map_or(0, <[u16]>::len),
+                    })?;
+                let result = self.i2c.write(ADDRESS | WRITE_FLAG, &sent[..len]).await;
+                Self::would_block(result)?;
+                Ok(command.execution_time_ms())
+            }
+
+            /// Sends the `command` reading a response of type `T`, without waiting for its
+            /// execution. Wait for the returned [`PendingRead`]'s
+            /// [`wait_ms`](PendingRead::wait_ms), e.g. with the application's scheduler, before
+            /// completing it with [`complete`](Sen66SplitPhase::complete).
+            /// - `command`: Command to send, mind the state it is available in.
+            /// - `data`: Words sent with the command, CRCs are added.
+            ///
+            /// # Errors
+            ///
+            /// - [`WouldBlock`](crate::error::Sen66Error::WouldBlock): If the sensor did not
+            /// acknowledge the command, as it is still executing the previous one.
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If another error on the
+            /// underlying I2C bus occurs.
+            /// - [`InvalidLength`](crate::error::Sen66Error::InvalidLength): If more than 6 data
+            /// words are passed, exceeding the transmit buffer.
+            pub async fn request<T: Response>(
+                &mut self,
+                command: Command,
+                data: Option<&[u16]>,
+            ) -> Result<PendingRead<T>, Sen66Error<ERR>> {
+                self.issue(command, data).await?;
+                Ok(PendingRead::new(command))
+            }
+
+            /// Reads the response of the `pending` read, once its wait time has passed.
+            ///
+            /// # Errors
+            ///
+            /// - [`WouldBlock`](crate::error::Sen66Error::WouldBlock): If the sensor did not
+            /// acknowledge the read, as the wait time has not passed yet.
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If another error on the
+            /// underlying I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn complete<T: Response>(
+                &mut self,
+                pending: PendingRead<T>,
+            ) -> Result<T, Sen66Error<ERR>> {
+                let PendingRead { .. } = pending;
+                self.try_fetch().await
+            }
+
+            /// Attempts to read the response to the previously issued command.
+            ///
+            /// # Errors
+            ///
+            /// - [`WouldBlock`](crate::error::Sen66Error::WouldBlock): If the sensor did not
+            /// acknowledge the read, as it is still executing the command.
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If another error on the
+            /// underlying I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn try_fetch<T: Response>(&mut self) -> Result<T, Sen66Error<ERR>> {
+                let mut received = [0; RX_BUFFER_SIZE];
+                let result = self
+                    .i2c
+                    .read(ADDRESS | READ_FLAG, &mut received[..T::SIZE])
+                    .await;
+                Self::would_block(result)?;
+                Ok(T::try_from(&received[..T::SIZE])?)
+            }
+
+            /// Releases the I2C peripheral.
+            pub fn release(self) -> I2C {
+                self.i2c
+            }
+
+            /// Reports a missing acknowledgement as the sensor being busy.
+            fn would_block(result: Result<(), ERR>) -> Result<(), Sen66Error<ERR>> {
+                result.map_err(|error| match error.kind() {
+                    ErrorKind::NoAcknowledge(_) => Sen66Error::WouldBlock,
+                    _ => Sen66Error::I2cError(error),
+                })
+            }
+        }
+
+        /// Generic driver for the [`Sen6xModel`]s of the SEN6x family. Only the commands
+        /// supported by `MODEL` are provided, e.g. the CO2 configuration only for
+        /// [`Co2Model`]s, and [`read_measured_values`](Sen6x::read_measured_values) returns the
+        /// model's measurement layout. Use [`Sen66`] for the full functionality of the SEN66.
+        pub struct Sen6x<MODEL, DELAY, I2C> {
+            sensor: Sen66<DELAY, I2C>,
+            model: PhantomData<MODEL>,
+        }
+
+        /// Driver for the SEN68, which reports the formaldehyde (HCHO) concentration instead of
+        /// the CO2 concentration.
+        #[cfg(feature = "sen68")]
+        pub type Sen68<DELAY, I2C> = Sen6x<crate::model::Sen68, DELAY, I2C>;
+
+        impl<MODEL: Sen6xModel, DELAY: delay_trait, I2C: i2c_trait, ERR: embedded_hal::i2c::Error>
+            Sen6x<MODEL, DELAY, I2C>
+        {
+            /// Creates a new interface for a sensor of the `MODEL`.
+            /// - `delay`: Delay provider, implementing embedded_hal's `DelayNs` trait.
+            /// - `i2c`: I2C peripheral implementing embedded_hal's `I2c` trait.
+            pub fn new(delay: DELAY, i2c: I2C) -> Self {
+                Self {
+                    sensor: Sen66::new(delay, i2c),
+                    model: PhantomData,
+                }
+            }
+
+            /// Creates a new interface for a sensor of the `MODEL`, verifying that the device
+            /// responding is of the `MODEL`, see [`probe`](Sen6x::probe).
+            /// - `delay`: Delay provider, implementing embedded_hal's `DelayNs` trait.
+            /// - `i2c`: I2C peripheral implementing embedded_hal's `I2c` trait.
+            /// Execution Time: 20ms
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            /// - [`WrongDevice`](crate::error::Sen66Error::WrongDevice): If the device reports a
+            /// product name other than the `MODEL`'s.
+            pub async fn try_new(delay: DELAY, i2c: I2C) -> Result<Self, Sen66Error<ERR>> {
+                let mut sensor = Self::new(delay, i2c);
+                sensor.probe().await?;
+                Ok(sensor)
+            }
+
+            /// Returns the underlying driver, e.g. to read its runtime counters.
+            pub fn driver(&self) -> &Sen66<DELAY, I2C> {
+                &self.sensor
+            }
+
+            /// Verifies that the device responding is of the `MODEL` by reading its product name.
+            /// Execution Time: 20ms
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            /// - [`WrongDevice`](crate::error::Sen66Error::WrongDevice): If the device reports a
+            /// product name other than the `MODEL`'s.
+            pub async fn probe(&mut self) -> Result<ProductName, Sen66Error<ERR>> {
+                self.sensor.expect_product_name(MODEL::PRODUCT_NAME).await
+            }
+
+            /// Read a measurement in the layout of the `MODEL` from the sensor.
+            /// If new data is available clears the data ready flag. If no new data is available
+            /// the previous data point is returned. If no data at all is available all values are
+            /// set to their maximum value.
+            /// Execution Time: 20ms
+            /// <div class="warning">Only available in measuring state</div>
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Idle state.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn read_measured_values(
+                &mut self,
+            ) -> Result<MODEL::Measurement, Sen66Error<ERR>> {
+                self.sensor.ensure_state(SensorState::Measuring)?;
+                self.sensor
+                    .write::<2>(MODEL::READ_MEASUREMENT, None)
+                    .await?;
+                let mut buffer = [0; RX_BUFFER_SIZE];
+                let received = &mut buffer[..MODEL::Measurement::SIZE];
+                self.sensor.read_into(received).await?;
+                let measurement = MODEL::Measurement::try_from(received)?;
+                self.sensor.counters.samples += 1;
+                Ok(measurement)
+            }
+
+            /// See [`start_measurement`](Sen66::start_measurement).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Measuring state.
+            #[cfg_attr(
+                feature = "cooldown",
+                doc = "- [`CooldownActive`](crate::error::Sen66Error::CooldownActive): If a cooldown is"
+            )]
+            #[cfg_attr(
+                feature = "cooldown",
+                doc = "active and the cooldown policy rejects waiting for it."
+            )]
+            pub async fn start_measurement(&mut self) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.start_measurement().await
+            }
+
+            /// See [`stop_measurement`](Sen66::stop_measurement).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Idle state.
+            pub async fn stop_measurement(&mut self) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.stop_measurement().await
+            }
+
+            /// See [`is_data_ready`](Sen66::is_data_ready).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Idle state.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn is_data_ready(&mut self) -> Result<DataStatus, Sen66Error<ERR>> {
+                self.sensor.is_data_ready().await
+            }
+
+            #[cfg(feature = "raw-measurements")]
+            /// See [`read_number_concentrations`](Sen66::read_number_concentrations).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Idle state.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn read_number_concentrations(
+                &mut self,
+            ) -> Result<Concentrations, Sen66Error<ERR>> {
+                self.sensor.read_number_concentrations().await
+            }
+
+            /// See [`get_product_name`](Sen66::get_product_name).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn get_product_name(&mut self) -> Result<ProductName, Sen66Error<ERR>> {
+                self.sensor.get_product_name().await
+            }
+
+            /// See [`get_serial_number`](Sen66::get_serial_number).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn get_serial_number(&mut self) -> Result<SerialNumber, Sen66Error<ERR>> {
+                self.sensor.get_serial_number().await
+            }
+
+            /// See [`read_device_status`](Sen66::read_device_status).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn read_device_status(
+                &mut self,
+            ) -> Result<DeviceStatusRegister, Sen66Error<ERR>> {
+                self.sensor.read_device_status().await
+            }
+
+            /// See [`read_and_clear_device_status`](Sen66::read_and_clear_device_status).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn read_and_clear_device_status(
+                &mut self,
+            ) -> Result<DeviceStatusRegister, Sen66Error<ERR>> {
+                self.sensor.read_and_clear_device_status().await
+            }
+
+            /// See [`reset_device`](Sen66::reset_device).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Measuring state.
+            pub async fn reset_device(&mut self) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.reset_device().await
+            }
+
+            #[cfg(feature = "maintenance")]
+            /// See [`start_fan_cleaning`](Sen66::start_fan_cleaning).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Measuring state.
+            pub async fn start_fan_cleaning(&mut self) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.start_fan_cleaning().await
+            }
+
+            #[cfg(feature = "maintenance")]
+            /// See [`activate_sht_heater`](Sen66::activate_sht_heater).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Measuring state.
+            pub async fn activate_sht_heater(&mut self) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.activate_sht_heater().await
+            }
+
+            /// See [`set_temperature_offset`](Sen66::set_temperature_offset).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            pub async fn set_temperature_offset(
+                &mut self,
+                parameter: TemperatureOffset,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.set_temperature_offset(parameter).await
+            }
+
+            /// See [`set_temperature_acceleration`](Sen66::set_temperature_acceleration).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Measuring state.
+            pub async fn set_temperature_acceleration(
+                &mut self,
+                parameter: TemperatureAcceleration,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.set_temperature_acceleration(parameter).await
+            }
+
+            /// See [`kill`](Sen66::kill).
+            pub async fn kill(self) -> (DELAY, I2C, Option<StaticBuffers>) {
+                self.sensor.kill().await
+            }
+        }
+
+        impl<
+            MODEL: GasIndexModel,
+            DELAY: delay_trait,
+            I2C: i2c_trait,
+            ERR: embedded_hal::i2c::Error,
+        > Sen6x<MODEL, DELAY, I2C>
+        {
+            #[cfg(feature = "tuning")]
+            /// See [`get_voc_tuning_parameters`](Sen66::get_voc_tuning_parameters).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Idle state.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn get_voc_tuning_parameters(
+                &mut self,
+            ) -> Result<VocTuning, Sen66Error<ERR>> {
+                self.sensor.get_voc_tuning_parameters().await
+            }
+
+            #[cfg(feature = "tuning")]
+            /// See [`set_voc_tuning_parameters`](Sen66::set_voc_tuning_parameters).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Idle state.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+            /// enabled and the data read back is corrupted or wrong.
+            /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+            /// verification is enabled and the VOC tuning parameters read back differs.
+            pub async fn set_voc_tuning_parameters(
+                &mut self,
+                parameter: VocTuning,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.set_voc_tuning_parameters(parameter).await
+            }
+
+            #[cfg(feature = "tuning")]
+            /// See [`get_voc_algorithm_state`](Sen66::get_voc_algorithm_state).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn get_voc_algorithm_state(
+                &mut self,
+            ) -> Result<VocAlgorithmState, Sen66Error<ERR>> {
+                self.sensor.get_voc_algorithm_state().await
+            }
+
+            #[cfg(feature = "tuning")]
+            /// See [`checkpoint_voc_state`](Sen66::checkpoint_voc_state).
+            ///
+            /// # Errors
+            ///
+            /// - [`Sensor`](crate::error::CheckpointError::Sensor): If reading the state from the
+            /// sensor fails.
+            /// - [`Store`](crate::error::CheckpointError::Store): If saving the state to the store
+            /// fails.
+            pub async fn checkpoint_voc_state<S: VocStateStore>(
+                &mut self,
+                scheduler: &mut VocCheckpointScheduler<S>,
+            ) -> Result<Option<VocAlgorithmState>, CheckpointError<ERR, S::Error>> {
+                self.sensor.checkpoint_voc_state(scheduler).await
+            }
+
+            #[cfg(feature = "tuning")]
+            /// See [`set_voc_algorithm_state`](Sen66::set_voc_algorithm_state).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Measuring state.
+            pub async fn set_voc_algorithm_state(
+                &mut self,
+                parameter: VocAlgorithmState,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.set_voc_algorithm_state(parameter).await
+            }
+
+            #[cfg(feature = "tuning")]
+            /// See [`get_nox_tuning_parameters`](Sen66::get_nox_tuning_parameters).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Idle state.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn get_nox_tuning_parameters(
+                &mut self,
+            ) -> Result<NoxTuning, Sen66Error<ERR>> {
+                self.sensor.get_nox_tuning_parameters().await
+            }
+
+            #[cfg(feature = "tuning")]
+            /// See [`set_nox_tuning_parameters`](Sen66::set_nox_tuning_parameters).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Idle state.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+            /// enabled and the data read back is corrupted or wrong.
+            /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+            /// verification is enabled and the NOx tuning parameters read back differs.
+            pub async fn set_nox_tuning_parameters(
+                &mut self,
+                parameter: NoxTuning,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.set_nox_tuning_parameters(parameter).await
+            }
+        }
+
+        impl<MODEL: Co2Model, DELAY: delay_trait, I2C: i2c_trait, ERR: embedded_hal::i2c::Error>
+            Sen6x<MODEL, DELAY, I2C>
+        {
+            /// See [`get_co2_asc_state`](Sen66::get_co2_asc_state).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Idle state.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn get_co2_asc_state(&mut self) -> Result<AscState, Sen66Error<ERR>> {
+                self.sensor.get_co2_asc_state().await
+            }
+
+            /// See [`set_co2_asc_state`](Sen66::set_co2_asc_state).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Idle state.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+            /// enabled and the data read back is corrupted or wrong.
+            /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+            /// verification is enabled and the ASC state read back differs.
+            pub async fn set_co2_asc_state(
+                &mut self,
+                new_state: AscState,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.set_co2_asc_state(new_state).await
+            }
+
+            /// See [`get_ambient_pressure`](Sen66::get_ambient_pressure).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn get_ambient_pressure(
+                &mut self,
+            ) -> Result<AmbientPressure, Sen66Error<ERR>> {
+                self.sensor.get_ambient_pressure().await
+            }
+
+            /// See [`set_ambient_pressure`](Sen66::set_ambient_pressure).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+            /// enabled and the data read back is corrupted or wrong.
+            /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+            /// verification is enabled and the ambient pressure read back differs.
+            pub async fn set_ambient_pressure(
+                &mut self,
+                parameter: AmbientPressure,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.set_ambient_pressure(parameter).await
+            }
+
+            /// See [`get_sensor_altitude`](Sen66::get_sensor_altitude).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Idle state.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn get_sensor_altitude(&mut self) -> Result<SensorAltitude, Sen66Error<ERR>> {
+                self.sensor.get_sensor_altitude().await
+            }
+
+            /// See [`set_sensor_altitude`](Sen66::set_sensor_altitude).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Idle state.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+            /// enabled and the data read back is corrupted or wrong.
+            /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+            /// verification is enabled and the sensor altitude read back differs.
+            pub async fn set_sensor_altitude(
+                &mut self,
+                parameter: SensorAltitude,
+            ) -> Result<(), Sen66Error<ERR>> {
+                self.sensor.set_sensor_altitude(parameter).await
+            }
+
+            #[cfg(feature = "co2-calibration")]
+            /// See [`perform_forced_co2_recalibration`](Sen66::perform_forced_co2_recalibration).
+            ///
+            /// # Errors
+            ///
+            /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+            /// I2C bus occurs.
+            /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+            /// Idle state.
+            /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+            /// corrupted or wrong.
+            pub async fn perform_forced_co2_recalibration(
+                &mut self,
+                parameter: TargetCO2Concentration,
+            ) -> Result<Co2Correction, Sen66Error<ERR>> {
+                self.sensor
+                    .perform_forced_co2_recalibration(parameter)
+                    .await
+            }
+        }
+
+        impl<MODEL, DELAY: delay_trait, I2C: i2c_trait, ERR: embedded_hal::i2c::Error>
+            core::fmt::Debug for Sen6x<MODEL, DELAY, I2C>
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.sensor.fmt(f)
+            }
+        }
+
+        /// Probes the [`SCAN_ADDRESSES`](crate::scan::SCAN_ADDRESSES) of the SEN6x family and
+        /// reads the product name of each device found. Use it before creating the interface,
+        /// e.g. to check the wiring of a fixture.
+        /// - `delay`: Delay provider, implementing embedded_hal's `DelayNs` trait.
+        /// - `i2c`: I2C peripheral implementing embedded_hal's `I2c` trait.
+        /// Execution Time: 20ms per device found
+        ///
+        /// # Errors
+        ///
+        /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error other than a missing
+        /// acknowledgement occurs on the underlying I2C bus.
+        #[cfg(feature = "scan")]
+        pub async fn scan<DELAY: delay_trait, I2C: i2c_trait, ERR: embedded_hal::i2c::Error>(
+            delay: &mut DELAY,
+            i2c: &mut I2C,
+        ) -> Result<Vec<DiscoveredDevice>, Sen66Error<ERR>> {
+            scan_addresses(delay, i2c, &SCAN_ADDRESSES).await
+        }
+
+        /// Probes the `addresses` for SEN6x devices and reads the product name of each device
+        /// acknowledging its address, see [`scan`].
+        /// - `delay`: Delay provider, implementing embedded_hal's `DelayNs` trait.
+        /// - `i2c`: I2C peripheral implementing embedded_hal's `I2c` trait.
+        /// - `addresses`: 7-bit I2C addresses to probe.
+        /// Execution Time: 20ms per device found
+        ///
+        /// # Errors
+        ///
+        /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error other than a missing
+        /// acknowledgement occurs on the underlying I2C bus.
+        #[cfg(feature = "scan")]
+        pub async fn scan_addresses<
+            DELAY: delay_trait,
+            I2C: i2c_trait,
+            ERR: embedded_hal::i2c::Error,
+        >(
+            delay: &mut DELAY,
+            i2c: &mut I2C,
+            addresses: &[u8],
+        ) -> Result<Vec<DiscoveredDevice>, Sen66Error<ERR>> {
+            let command = Command::GetProductName;
+            let is_nack = |error: &ERR| matches!(error.kind(), ErrorKind::NoAcknowledge(_));
+            let mut devices = Vec::new();
+            for &address in addresses {
+                match i2c
+                    .write(address | WRITE_FLAG, &command.to_be_bytes())
+                    .await
+                {
+                    Ok(()) => {}
+                    Err(error) if is_nack(&error) => continue,
+                    Err(error) => return Err(Sen66Error::I2cError(error)),
+                }
+                delay.delay_ms(command.execution_time_ms()).await;
+                // The product name is the largest frame read from the sensor
+                let mut received = [0; RX_BUFFER_SIZE];
+                let product_name = match i2c.read(address | READ_FLAG, &mut received).await {
+                    Ok(()) => ProductName::try_from(&received[..]).ok(),
+                    Err(error) if is_nack(&error) => None,
+                    Err(error) => return Err(Sen66Error::I2cError(error)),
+                };
+                devices.push(DiscoveredDevice {
+                    address,
+                    product_name,
+                });
+            }
+            Ok(devices)
+        }
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+            use crate::correction::{Channel, ChannelCorrection, Polynomial};
+            use core::sync::atomic::{AtomicU64, Ordering};
+            use embedded_hal::i2c::NoAcknowledgeSource;
+            use embedded_hal_mock::eh1::{
+                delay::{CheckedDelay, NoopDelay, Transaction as DelayTransaction},
+                i2c::{Mock as I2cMock, Transaction as I2cTransaction},
+            };
+
+            #[test_macro]
+            async fn start_measurements_works() {
+                let expected_transaction = [I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21])];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+
+                sensor.start_measurement().await.unwrap();
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn attaching_to_measuring_sensor_skips_start() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let mut sensor = Sen66::assume_measuring(NoopDelay::new(), i2c);
+
+                #[cfg(not(feature = "lenient-state"))]
+                assert!(sensor.start_measurement().await.is_err());
+                assert_eq!(sensor.is_data_ready().await.unwrap(), DataStatus::Ready);
+                sensor.kill().await.1.done();
+
+                let sensor =
+                    Sen66::from_state(NoopDelay::new(), I2cMock::new(&[]), SensorState::Unknown);
+                assert_eq!(sensor.summary().state, SensorState::Unknown);
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn forced_state_overrides_driver_state() {
+                let expected_transaction = [I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21])];
+                let i2c = I2cMock::new(&expected_transaction);
+                let mut sensor = Sen66::assume_measuring(NoopDelay::new(), i2c);
+
+                sensor.force_state(SensorState::Idle);
+                assert_eq!(sensor.state(), SensorState::Idle);
+                sensor.start_measurement().await.unwrap();
+                assert_eq!(sensor.state(), SensorState::Measuring);
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(not(feature = "lenient-state"))]
+            #[test_macro]
+            async fn stop_measurement_in_idle_yields_error() {
+                let expected_transaction = [];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+
+                assert!(sensor.stop_measurement().await.is_err());
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(feature = "lenient-state")]
+            #[test_macro]
+            async fn lenient_state_leaves_rejection_to_sensor() {
+                let expected_transaction = [I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04])
+                    .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data))];
+                let i2c = I2cMock::new(&expected_transaction);
+                let mut sensor = Sen66::new(NoopDelay::new(), i2c);
+
+                assert_eq!(
+                    sensor.stop_measurement().await,
+                    Err(Sen66Error::I2cError(ErrorKind::NoAcknowledge(
+                        NoAcknowledgeSource::Data
+                    )))
+                );
+                assert_eq!(sensor.state(), SensorState::Idle);
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn stop_measurement_works() {
+                let expected_transaction = [I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04])];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
+
+                sensor.stop_measurement().await.unwrap();
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn if_data_ready_is_data_ready_yields_ready() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
+
+                assert_eq!(sensor.is_data_ready().await.unwrap(), DataStatus::Ready);
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn if_data_not_ready_is_data_ready_yields_not_ready() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
+
+                assert_eq!(sensor.is_data_ready().await.unwrap(), DataStatus::NotReady);
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn wait_for_data_ready_polls_until_ready() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = CheckedDelay::new(&[
+                    DelayTransaction::delay_ms(20),
+                    DelayTransaction::delay_ms(50),
+                    DelayTransaction::delay_ms(20),
+                ]);
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
+
+                sensor.wait_for_data_ready(50, 1_000).await.unwrap();
+                let (mut delay, mut i2c, _) = sensor.kill().await;
+                delay.done();
+                i2c.done();
+            }
+
+            #[test_macro]
+            async fn wait_for_data_ready_times_out() {
+                let mut expected_transaction = vec![];
+                for _ in 0..3 {
+                    expected_transaction.extend([
+                        I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                        I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81]),
+                    ]);
+                }
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = CheckedDelay::new(&[
+                    DelayTransaction::delay_ms(20),
+                    DelayTransaction::delay_ms(100),
+                    DelayTransaction::delay_ms(20),
+                    DelayTransaction::delay_ms(100),
+                    DelayTransaction::delay_ms(20),
+                ]);
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
+
+                assert_eq!(
+                    sensor.wait_for_data_ready(100, 250).await,
+                    Err(Sen66Error::DataReadyTimeout { timeout_ms: 250 })
+                );
+                let (mut delay, mut i2c, _) = sensor.kill().await;
+                delay.done();
+                i2c.done();
+            }
+
+            #[test_macro]
+            async fn read_measured_values_works() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x03, 0x00]),
+                    I2cTransaction::read(
+                        0x6B | 0x01,
+                        vec![
+                            0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A,
+                            0x00, 0x64, 0xFE, 0x00, 0xC8, 0x7F, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A,
+                            0x00, 0x01, 0xB0,
+                        ],
+                    ),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
+
+                assert_eq!(
+                    sensor.read_measured_values().await.unwrap(),
+                    Measurement {
+                        pm1_0: 1.0,
+                        pm2_5: 1.0,
+                        pm4_0: 1.0,
+                        pm10_0: 1.0,
+                        relative_humidity: 1.0,
+                        temperature: 1.0,
+                        voc_index: 1.0,
+                        nox_index: 1.0,
+                        co2: 1,
+                    }
+                );
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn read_measured_values_fixed_works() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x03, 0x00]),
+                    I2cTransaction::read(
+                        0x6B | 0x01,
+                        vec![
+                            0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A,
+                            0x00, 0x64, 0xFE, 0x00, 0xC8, 0x7F, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A,
+                            0x00, 0x01, 0xB0,
+                        ],
+                    ),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
+
+                assert_eq!(
+                    sensor.read_measured_values_fixed().await.unwrap(),
+                    MeasurementFixed {
+                        pm1_0: 10,
+                        pm2_5: 10,
+                        pm4_0: 10,
+                        pm10_0: 10,
+                        relative_humidity: 100,
+                        temperature: 200,
+                        voc_index: 10,
+                        nox_index: 10,
+                        co2: 1,
+                    }
+                );
+                assert_eq!(sensor.runtime_counters().samples, 1);
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn read_measured_values_applies_humidity_correction() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x03, 0x00]),
+                    I2cTransaction::read(
+                        0x6B | 0x01,
+                        vec![
+                            0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A,
+                            0x0F, 0xA0, 0xE4, 0x10, 0x68, 0xED, 0x03, 0xE8, 0xD4, 0x7F, 0xFF, 0x8F,
+                            0x01, 0xA4, 0x4D,
+                        ],
+                    ),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
+                sensor.set_humidity_correction(Some(HumidityCorrection::new(2.0, 1.0)));
+
+                let measurement = sensor.read_measured_values().await.unwrap();
+                assert_eq!(measurement.relative_humidity, 42.0);
+                assert_eq!(measurement.temperature, 21.0);
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn read_measured_values_applies_correction_model() {
+                static MODEL: ChannelCorrection<2> = ChannelCorrection::new()
+                    .with(Channel::Temperature, Polynomial::linear(-1.0, 1.0))
+                    .with(Channel::Co2, Polynomial::linear(0.0, 1.5));
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x03, 0x00]),
+                    I2cTransaction::read(
+                        0x6B | 0x01,
+                        vec![
+                            0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A,
+                            0x0F, 0xA0, 0xE4, 0x10, 0x68, 0xED, 0x03, 0xE8, 0xD4, 0x7F, 0xFF, 0x8F,
+                            0x01, 0xA4, 0x4D,
+                        ],
+                    ),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
+                sensor.set_correction_model(Some(&MODEL));
+
+                let measurement = sensor.read_measured_values().await.unwrap();
+                assert_eq!(measurement.temperature, 20.0);
+                assert_eq!(measurement.co2, 630);
+                assert_eq!(measurement.relative_humidity, 40.0);
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(feature = "raw-measurements")]
+            #[test_macro]
+            async fn read_measured_raw_values_works() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x04, 0x05]),
+                    I2cTransaction::read(
+                        0x6B | 0x01,
+                        vec![
+                            0x00, 0x64, 0xFe, 0x00, 0xC8, 0x7F, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A,
+                            0x00, 0x01, 0xB0,
+                        ],
+                    ),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
+
+                assert_eq!(
+                    sensor.read_measured_raw_values().await.unwrap(),
+                    RawMeasurement {
+                        relative_humidity: 1.0,
+                        temperature: 1.0,
+                        voc: 10,
+                        nox: 10,
+                        co2: 1,
+                    }
+                );
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(feature = "raw-measurements")]
+            #[test_macro]
+            async fn read_number_concentrations_works() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x03, 0x16]),
+                    I2cTransaction::read(
+                        0x6B | 0x01,
+                        vec![
+                            0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A,
+                            0x00, 0x0A, 0x5A,
+                        ],
+                    ),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
+
+                assert_eq!(
+                    sensor.read_number_concentrations().await.unwrap(),
+                    Concentrations {
+                        pm0_5: 1.0,
+                        pm1_0: 1.0,
+                        pm2_5: 1.0,
+                        pm4_0: 1.0,
+                        pm10_0: 1.0,
+                    },
+                );
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn set_temperature_offset_works() {
+                let expected_transaction = [I2cTransaction::write(
+                    0x6B | 0x00,
+                    vec![
+                        0x60, 0xB2, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00,
+                        0x00, 0x81,
+                    ],
+                )];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+
+                let offset = TemperatureOffset::new(0, 0, 0, 0).unwrap();
+                sensor.set_temperature_offset(offset).await.unwrap();
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn apply_temperature_offset_fit_writes_fitted_offset() {
+                let expected_transaction = [I2cTransaction::write(
+                    0x6B | 0x00,
+                    vec![
+                        0x60, 0xB2, 0xFF, 0x38, 0x7C, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00,
+                        0x01, 0xB0,
+                    ],
+                )];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+
+                let mut fit = TemperatureOffsetFit::new();
+                fit.add(22.0, 21.0);
+                fit.add(26.0, 25.0);
+                assert_eq!(
+                    sensor
+                        .apply_temperature_offset_fit(&fit, 0, 1)
+                        .await
+                        .unwrap(),
+                    TemperatureOffset::new(-1, 0, 0, 1).unwrap()
+                );
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn set_temperature_acceleration_works() {
+                let expected_transaction = [I2cTransaction::write(
+                    0x6B | 0x00,
+                    vec![
+                        0x61, 0x00, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00,
+                        0x00, 0x81,
+                    ],
+                )];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+
+                let acceleration = TemperatureAcceleration::new(0, 0, 0, 0).unwrap();
+                sensor
+                    .set_temperature_acceleration(acceleration)
+                    .await
+                    .unwrap();
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn get_product_name_works() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0xD0, 0x14]),
+                    I2cTransaction::read(
+                        0x6B | 0x01,
+                        vec![
+                            'S' as u8, 'E' as u8, 0x83, 'N' as u8, '6' as u8, 0x06, '6' as u8,
+                            '\0' as u8, 0x69, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81,
+                            0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81,
+                            0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81,
+                            0x00, 0x00, 0x81, 0x00, 0x00, 0x81,
+                        ],
+                    ),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
+
+                assert_eq!(
+                    sensor.get_product_name().await.unwrap().get_name_buffer(),
+                    [
+                        'S' as u8, 'E' as u8, 'N' as u8, '6' as u8, '6' as u8, '\0' as u8
+                    ]
+                );
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(feature = "scan")]
+            #[test_macro]
+            async fn scan_finds_devices() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0xD0, 0x14]),
+                    I2cTransaction::read(
+                        0x6B | 0x01,
+                        vec![
+                            b'S', b'E', 0x83, b'N', b'6', 0x06, b'6', b'\0', 0x69, 0x00, 0x00,
+                            0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00,
+                            0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00,
+                            0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00,
+                            0x81,
+                        ],
+                    ),
+                    I2cTransaction::write(0x6C | 0x00, vec![0xD0, 0x14])
+                        .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)),
+                    I2cTransaction::write(0x10 | 0x00, vec![0xD0, 0x14]),
+                    I2cTransaction::read(0x10 | 0x01, vec![0xFF; 48]),
+                ];
+                let mut i2c = I2cMock::new(&expected_transaction);
+                let mut delay = NoopDelay::new();
+
+                let devices = scan_addresses(&mut delay, &mut i2c, &[0x6B, 0x6C, 0x10])
+                    .await
+                    .unwrap();
+                assert_eq!(devices.len(), 2);
+                assert_eq!(devices[0].address, 0x6B);
+                assert_eq!(
+                    devices[0].product_name.as_ref().map(ProductName::as_str),
+                    Some("SEN66")
+                );
+                assert_eq!(
+                    devices[1],
+                    DiscoveredDevice {
+                        address: 0x10,
+                        product_name: None
+                    }
+                );
+                i2c.done();
+            }
+
+            #[test_macro]
+            async fn get_serial_number_works() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0xD0, 0x33]),
+                    I2cTransaction::read(
+                        0x6B | 0x01,
+                        vec![
+                            'S' as u8, 'E' as u8, 0x83, 'N' as u8, '6' as u8, 0x06, '6' as u8,
+                            '\0' as u8, 0x69, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81,
+                            0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81,
+                            0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81,
+                            0x00, 0x00, 0x81, 0x00, 0x00, 0x81,
+                        ],
+                    ),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
+
+                assert_eq!(
+                    sensor
+                        .get_serial_number()
+                        .await
+                        .unwrap()
+                        .get_serial_buffer(),
+                    [
+                        'S' as u8, 'E' as u8, 'N' as u8, '6' as u8, '6' as u8, '\0' as u8
+                    ]
+                );
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn read_device_status_works() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0xD2, 0x06]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81, 0x00, 0x00, 0x81]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
+
+                assert!(
+                    sensor
+                        .read_device_status()
+                        .await
+                        .unwrap()
+                        .has_error()
+                        .is_ok()
+                );
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn read_and_clear_device_status_works() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0xD2, 0x10]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81, 0x00, 0x00, 0x81]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
+
+                assert!(
+                    sensor
+                        .read_and_clear_device_status()
+                        .await
+                        .unwrap()
+                        .has_error()
+                        .is_ok()
+                );
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn reset_device_works() {
+                let expected_transaction = [I2cTransaction::write(0x6B | 0x00, vec![0xD3, 0x04])];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+
+                sensor.reset_device().await.unwrap();
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(feature = "maintenance")]
+            #[test_macro]
+            async fn start_fan_cleaning_works() {
+                let expected_transaction = [I2cTransaction::write(0x6B | 0x00, vec![0x56, 0x07])];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+
+                sensor.start_fan_cleaning().await.unwrap();
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(all(feature = "maintenance", feature = "maintenance-schedule"))]
+            #[test_macro]
+            async fn fan_cleaning_is_scheduled_in_wall_clock_time() {
+                static EPOCH_S: AtomicU64 = AtomicU64::new(1_700_000_000);
+                let expected_transaction = [I2cTransaction::write(0x6B | 0x00, vec![0x56, 0x07])];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.set_maintenance_schedule(MaintenanceSchedule::new(3_600, 0));
+                assert_eq!(sensor.maintenance_due(), None);
+
+                sensor.set_epoch_source(|| EPOCH_S.load(Ordering::Relaxed));
+                assert_eq!(sensor.maintenance_due(), Some(MaintenanceTask::FanCleaning));
+                sensor.start_fan_cleaning().await.unwrap();
+                assert_eq!(sensor.maintenance_due(), None);
+                let schedule = sensor.maintenance_schedule();
+                assert_eq!(
+                    schedule.last_performed_s(MaintenanceTask::FanCleaning),
+                    Some(1_700_000_000)
+                );
+
+                EPOCH_S.store(1_700_003_600, Ordering::Relaxed);
+                assert_eq!(sensor.maintenance_due(), Some(MaintenanceTask::FanCleaning));
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(feature = "maintenance")]
+            #[test_macro]
+            async fn activate_sht_heater_works() {
+                let expected_transaction = [I2cTransaction::write(0x6B | 0x00, vec![0x37, 0x30])];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+
+                sensor.activate_sht_heater().await.unwrap();
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(all(feature = "maintenance", feature = "cooldown"))]
+            #[test_macro]
+            async fn heater_cooldown_is_enforced() {
+                static NOW_MS: AtomicU64 = AtomicU64::new(1_000);
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x37, 0x30]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = CheckedDelay::new(&[
+                    DelayTransaction::delay_ms(1_300),
+                    DelayTransaction::delay_ms(18_700),
+                    DelayTransaction::delay_ms(50),
+                ]);
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.set_time_source(|| NOW_MS.load(Ordering::Relaxed));
+                sensor.set_cooldown_policy(CooldownPolicy::Reject);
+
+                sensor.activate_sht_heater().await.unwrap();
+                NOW_MS.store(2_300, Ordering::Relaxed);
+                assert_eq!(
+                    sensor.start_measurement().await,
+                    Err(Sen66Error::CooldownActive {
+                        remaining_ms: 18_700
+                    })
+                );
+                assert_eq!(sensor.state, SensorState::Idle);
+
+                sensor.set_cooldown_policy(CooldownPolicy::Wait);
+                sensor.start_measurement().await.unwrap();
+                let (mut delay, mut i2c, _) = sensor.kill().await;
+                delay.done();
+                i2c.done();
+            }
+
+            #[cfg(feature = "tuning")]
+            #[test_macro]
+            async fn get_voc_tuning_parameters_works() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x60, 0xD0]),
+                    I2cTransaction::read(
+                        0x6B | 0x01,
+                        vec![
+                            0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x00, 0x81,
+                            0x00, 0x0A, 0x5A, 0x00, 0x01, 0xB0,
+                        ],
+                    ),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Idle;
+
+                assert_eq!(
+                    sensor.get_voc_tuning_parameters().await.unwrap(),
+                    VocTuning::new(1, 1, 1, 0, 10, 1).unwrap()
+                );
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(feature = "tuning")]
+            #[test_macro]
+            async fn set_voc_tuning_parameters_works() {
+                let expected_transaction = [I2cTransaction::write(
+                    0x6B | 0x00,
+                    vec![
+                        0x60, 0xD0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00,
+                        0x00, 0x81, 0x00, 0x0A, 0x5A, 0x00, 0x01, 0xB0,
+                    ],
+                )];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Idle;
+
+                sensor
+                    .set_voc_tuning_parameters(VocTuning::new(1, 1, 1, 0, 10, 1).unwrap())
+                    .await
+                    .unwrap();
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(feature = "tuning")]
+            #[test_macro]
+            async fn get_voc_algorithm_state_works() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x61, 0x81]),
+                    I2cTransaction::read(
+                        0x6B | 0x01,
+                        vec![
+                            0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0,
+                        ],
+                    ),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Idle;
+
+                assert_eq!(
+                    <[u16; 4]>::from(sensor.get_voc_algorithm_state().await.unwrap()),
+                    [0x0001, 0x0001, 0x0001, 0x0001]
+                );
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(feature = "tuning")]
+            #[test_macro]
+            async fn voc_state_is_checkpointed_when_due() {
+                use crate::conditioning::{FnStore, VocCheckpointScheduler};
+                use core::sync::atomic::{AtomicU64, Ordering};
+
+                static NOW_MS: AtomicU64 = AtomicU64::new(1_000);
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x61, 0x81]),
+                    I2cTransaction::read(
+                        0x6B | 0x01,
+                        vec![
+                            0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0,
+                        ],
+                    ),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let mut sensor = Sen66::new(NoopDelay::new(), i2c);
+                sensor.state = SensorState::Measuring;
+                let mut saved = 0;
+                let mut scheduler = VocCheckpointScheduler::with_interval(
+                    FnStore(|_: &VocAlgorithmState| -> Result<(), ()> {
+                        saved += 1;
+                        Ok(())
+                    }),
+                    || NOW_MS.load(Ordering::Relaxed),
+                    60_000,
+                );
+
+                assert_eq!(sensor.checkpoint_voc_state(&mut scheduler).await, Ok(None));
+                NOW_MS.store(61_000, Ordering::Relaxed);
+                assert_eq!(
+                    sensor.checkpoint_voc_state(&mut scheduler).await,
+                    Ok(Some(VocAlgorithmState::from([1, 1, 1, 1])))
+                );
+                assert_eq!(sensor.checkpoint_voc_state(&mut scheduler).await, Ok(None));
+                assert_eq!(saved, 1);
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(feature = "tuning")]
+            #[test_macro]
+            async fn set_voc_algorithm_state_works() {
+                let expected_transaction = [I2cTransaction::write(
+                    0x6B | 0x00,
+                    vec![
+                        0x61, 0x81, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00,
+                        0x01, 0xB0,
+                    ],
+                )];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Idle;
+
+                let state = VocAlgorithmState::try_from(
+                    &(vec![
+                        0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0,
+                    ])[..],
+                )
+                .unwrap();
+                sensor.set_voc_algorithm_state(state).await.unwrap();
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(feature = "tuning")]
+            #[test_macro]
+            async fn get_nox_tuning_parameters_works() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x60, 0xE1]),
+                    I2cTransaction::read(
+                        0x6B | 0x01,
+                        vec![
+                            0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x00, 0x81,
+                            0x00, 0x32, 0x26, 0x00, 0x01, 0xB0,
+                        ],
+                    ),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Idle;
+
+                assert_eq!(
+                    sensor.get_nox_tuning_parameters().await.unwrap(),
+                    NoxTuning::new(1, 1, 1, 0, 1).unwrap()
+                );
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(feature = "tuning")]
+            #[test_macro]
+            async fn set_nox_tuning_parameters_works() {
+                let expected_transaction = [I2cTransaction::write(
+                    0x6B | 0x00,
+                    vec![
+                        0x60, 0xE1, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00,
+                        0x00, 0x81, 0x00, 0x32, 0x26, 0x00, 0x01, 0xB0,
+                    ],
+                )];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Idle;
+
+                sensor
+                    .set_nox_tuning_parameters(NoxTuning::new(1, 1, 1, 0, 1).unwrap())
+                    .await
+                    .unwrap();
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(feature = "co2-calibration")]
+            #[test_macro]
+            async fn perform_forced_co2_recalibration_works() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x07, 0x03, 0xE8, 0xD4]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x83, 0xE8, 0xF7]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Idle;
+                assert_eq!(
+                    sensor
+                        .perform_forced_co2_recalibration(TargetCO2Concentration::from(1000))
+                        .await
+                        .unwrap()
+                        .ppm(),
+                    Some(1000)
+                );
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn get_co2_asc_state_is_enabled_yields_enabled() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x11]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xb0]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Idle;
+                assert_eq!(sensor.get_co2_asc_state().await.unwrap(), AscState::Enabled);
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn get_co2_asc_state_is_disabled_yields_disabled() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x11]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Idle;
+                assert_eq!(
+                    sensor.get_co2_asc_state().await.unwrap(),
+                    AscState::Disabled
+                );
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn set_co2_asc_state_works() {
+                let expected_transaction = [I2cTransaction::write(
+                    0x6B | 0x00,
+                    vec![0x67, 0x11, 0x00, 0x01, 0xB0],
+                )];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Idle;
+                sensor.set_co2_asc_state(AscState::Enabled).await.unwrap();
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn get_ambient_pressure_works() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x20]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x02, 0xBC, 0x9A]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Idle;
+                assert_eq!(
+                    sensor.get_ambient_pressure().await.unwrap(),
+                    AmbientPressure::try_from(700).unwrap()
+                );
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn set_ambient_pressure_works() {
+                let expected_transaction = [I2cTransaction::write(
+                    0x6B | 0x00,
+                    vec![0x67, 0x20, 0x02, 0xBC, 0x9A],
+                )];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Idle;
+                sensor
+                    .set_ambient_pressure(AmbientPressure::try_from(700).unwrap())
+                    .await
+                    .unwrap();
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn set_ambient_pressure_verifies_written_value() {
                 let expected_transaction = [
-                    I2cTransaction::write(0x6B | 0x00, vec![0x03, 0x00]),
-                    I2cTransaction::read(
-                        0x6B | 0x01,
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x20, 0x02, 0xBC, 0x9A]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x20]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x02, 0xBC, 0x9A]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x20, 0x02, 0xBC, 0x9A]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x20]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x02, 0xBD, 0xAB]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.set_write_verification(true);
+                let pressure = AmbientPressure::try_from(700).unwrap();
+                assert_eq!(sensor.set_ambient_pressure(pressure).await, Ok(()));
+                assert_eq!(
+                    sensor.set_ambient_pressure(pressure).await,
+                    Err(Sen66Error::VerificationFailed("ambient pressure"))
+                );
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(feature = "config-cache")]
+            #[test_macro]
+            async fn write_verification_bypasses_config_cache() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x20, 0x02, 0xBC, 0x9A]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x20, 0x02, 0xBC, 0x9A]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x20]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x02, 0xBC, 0x9A]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                let pressure = AmbientPressure::try_from(700).unwrap();
+                sensor.set_ambient_pressure(pressure).await.unwrap();
+                sensor.set_ambient_pressure(pressure).await.unwrap();
+                sensor.set_write_verification(true);
+                assert_eq!(sensor.set_ambient_pressure(pressure).await, Ok(()));
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn get_sensor_altitude_works() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x36]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x02, 0xBC, 0x9A]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Idle;
+                assert_eq!(
+                    sensor.get_sensor_altitude().await.unwrap(),
+                    SensorAltitude::try_from(700).unwrap()
+                );
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn set_sensor_altitude_works() {
+                let expected_transaction = [I2cTransaction::write(
+                    0x6B | 0x00,
+                    vec![0x67, 0x36, 0x02, 0xBC, 0x9A],
+                )];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Idle;
+                sensor
+                    .set_sensor_altitude(SensorAltitude::try_from(700).unwrap())
+                    .await
+                    .unwrap();
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(feature = "config-cache")]
+            #[test_macro]
+            async fn setters_skip_cached_values() {
+                let write = I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x36, 0x02, 0xBC, 0x9A]);
+                let expected_transaction = [
+                    write.clone(),
+                    write.clone(),
+                    I2cTransaction::write(0x6B | 0x00, vec![0xD3, 0x04]),
+                    write,
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                let altitude = SensorAltitude::try_from(700).unwrap();
+
+                sensor.set_sensor_altitude(altitude).await.unwrap();
+                sensor.set_sensor_altitude(altitude).await.unwrap();
+                assert!(
+                    sensor
+                        .config_cache()
+                        .contains(&Setting::SensorAltitude(altitude))
+                );
+                sensor.set_config_cache_bypass(true);
+                sensor.set_sensor_altitude(altitude).await.unwrap();
+                sensor.set_config_cache_bypass(false);
+                sensor.reset_device().await.unwrap();
+                sensor.set_sensor_altitude(altitude).await.unwrap();
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn failed_start_measurement_keeps_idle_state() {
+                let expected_transaction = [I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21])
+                    .with_error(embedded_hal::i2c::ErrorKind::Other)];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+
+                assert!(sensor.start_measurement().await.is_err());
+                assert_eq!(sensor.state, SensorState::Idle);
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(not(feature = "lenient-state"))]
+            #[test_macro]
+            async fn unknown_state_rejects_regular_commands() {
+                let expected_transaction = [];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Unknown;
+
+                assert_eq!(
+                    sensor.start_measurement().await.unwrap_err(),
+                    Sen66Error::WrongState("Unknown")
+                );
+                assert!(sensor.read_measured_values().await.is_err());
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn stop_measurement_recovers_from_unknown_state() {
+                let expected_transaction = [I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04])];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Unknown;
+
+                sensor.stop_measurement().await.unwrap();
+                assert_eq!(sensor.state, SensorState::Idle);
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn reset_device_recovers_from_unknown_state() {
+                let expected_transaction = [I2cTransaction::write(0x6B | 0x00, vec![0xD3, 0x04])];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Unknown;
+
+                sensor.reset_device().await.unwrap();
+                assert_eq!(sensor.state, SensorState::Idle);
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn apply_settings_in_idle_applies_all_settings() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x36, 0x02, 0xBC, 0x9A]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x20, 0x02, 0xBC, 0x9A]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+
+                let mut results = [const { Ok(()) }; 2];
+                sensor
+                    .apply_settings(
+                        &[
+                            Setting::AmbientPressure(AmbientPressure::try_from(700).unwrap()),
+                            Setting::SensorAltitude(SensorAltitude::try_from(700).unwrap()),
+                        ],
+                        &mut results,
+                    )
+                    .await
+                    .unwrap();
+                assert!(results.iter().all(Result::is_ok));
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(feature = "tuning")]
+            #[test_macro]
+            async fn apply_profile_writes_profile_settings() {
+                let expected_transaction = [
+                    I2cTransaction::write(
+                        0x6B | 0x00,
                         vec![
-                            0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A,
-                            0x00, 0x64, 0xFE, 0x00, 0xC8, 0x7F, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A,
-                            0x00, 0x01, 0xB0,
+                            0x60, 0xD0, 0x00, 0x64, 0xFE, 0x00, 0x18, 0x7B, 0x00, 0x0C, 0xFC, 0x01,
+                            0x68, 0x77, 0x00, 0x32, 0x26, 0x00, 0x96, 0x1E,
+                        ],
+                    ),
+                    I2cTransaction::write(
+                        0x6B | 0x00,
+                        vec![
+                            0x60, 0xE1, 0x00, 0x01, 0xB0, 0x00, 0x18, 0x7B, 0x00, 0x0C, 0xFC, 0x02,
+                            0xD0, 0x5C, 0x00, 0x32, 0x26, 0x00, 0x96, 0x1E,
+                        ],
+                    ),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x11, 0x00, 0x01, 0xB0]),
+                    I2cTransaction::write(
+                        0x6B | 0x00,
+                        vec![
+                            0x60, 0xB2, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00,
+                            0x00, 0x81,
                         ],
                     ),
                 ];
                 let i2c = I2cMock::new(&expected_transaction);
                 let delay = NoopDelay::new();
                 let mut sensor = Sen66::new(delay, i2c);
+
+                sensor.apply_profile(Profile::Workshop).await.unwrap();
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(feature = "tuning")]
+            #[test_macro]
+            async fn config_read_from_sensor_is_written_back() {
+                let voc = vec![
+                    0x00, 0x64, 0xFE, 0x00, 0x0C, 0xFC, 0x00, 0x0C, 0xFC, 0x00, 0xB4, 0xFA, 0x00,
+                    0x32, 0x26, 0x00, 0xE6, 0xE6,
+                ];
+                let nox = vec![
+                    0x00, 0x01, 0xB0, 0x00, 0x0C, 0xFC, 0x00, 0x0C, 0xFC, 0x02, 0xD0, 0x5C, 0x00,
+                    0x32, 0x26, 0x00, 0xE6, 0xE6,
+                ];
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x36]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x20]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x03, 0xF5, 0xDB]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x11]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x60, 0xD0]),
+                    I2cTransaction::read(0x6B | 0x01, voc.clone()),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x60, 0xE1]),
+                    I2cTransaction::read(0x6B | 0x01, nox.clone()),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x36, 0x00, 0x00, 0x81]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x20, 0x03, 0xF5, 0xDB]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x11, 0x00, 0x01, 0xB0]),
+                    I2cTransaction::write(0x6B | 0x00, [vec![0x60, 0xD0], voc].concat()),
+                    I2cTransaction::write(0x6B | 0x00, [vec![0x60, 0xE1], nox].concat()),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let mut sensor = Sen66::new(NoopDelay::new(), i2c);
+
+                let config = sensor.read_config().await.unwrap();
+                assert_eq!(config.asc_state, AscState::Enabled);
+                assert_eq!(config.temperature_offset, None);
+                #[cfg(feature = "config-cache")]
+                sensor.set_config_cache_bypass(true);
+                sensor.state = SensorState::Measuring;
+                sensor.apply_config(&config).await.unwrap();
+                assert_eq!(sensor.state, SensorState::Measuring);
+                sensor.kill().await.1.done();
+            }
+
+            #[cfg(feature = "tuning")]
+            #[test_macro]
+            async fn suspended_state_is_restored_after_power_cycle() {
+                let voc = vec![
+                    0x00, 0x64, 0xFE, 0x00, 0x0C, 0xFC, 0x00, 0x0C, 0xFC, 0x00, 0xB4, 0xFA, 0x00,
+                    0x32, 0x26, 0x00, 0xE6, 0xE6,
+                ];
+                let nox = vec![
+                    0x00, 0x01, 0xB0, 0x00, 0x0C, 0xFC, 0x00, 0x0C, 0xFC, 0x02, 0xD0, 0x5C, 0x00,
+                    0x32, 0x26, 0x00, 0xE6, 0xE6,
+                ];
+                let voc_state = vec![
+                    0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0,
+                ];
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x36]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x02, 0xBC, 0x9A]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x20]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x03, 0xF5, 0xDB]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x11]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x60, 0xD0]),
+                    I2cTransaction::read(0x6B | 0x01, voc.clone()),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x60, 0xE1]),
+                    I2cTransaction::read(0x6B | 0x01, nox.clone()),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x61, 0x81]),
+                    I2cTransaction::read(0x6B | 0x01, voc_state.clone()),
+                    I2cTransaction::write(0x6B | 0x00, vec![0xD3, 0x04]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x36, 0x02, 0xBC, 0x9A]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x20, 0x03, 0xF5, 0xDB]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x11, 0x00, 0x01, 0xB0]),
+                    I2cTransaction::write(0x6B | 0x00, [vec![0x60, 0xD0], voc].concat()),
+                    I2cTransaction::write(0x6B | 0x00, [vec![0x60, 0xE1], nox].concat()),
+                    I2cTransaction::write(0x6B | 0x00, [vec![0x61, 0x81], voc_state].concat()),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+                ];
+                let mut i2c = I2cMock::new(&expected_transaction);
+                let mut sensor = Sen66::new(NoopDelay::new(), &mut i2c);
                 sensor.state = SensorState::Measuring;
 
+                let suspended = sensor.suspend().await.unwrap();
+                assert!(suspended.measuring);
+                assert_eq!(u16::from(suspended.persistent.config.sensor_altitude), 700);
                 assert_eq!(
-                    sensor.read_measured_values().await.unwrap(),
-                    Measurement {
-                        pm1_0: 1.0,
-                        pm2_5: 1.0,
-                        pm4_0: 1.0,
-                        pm10_0: 1.0,
-                        relative_humidity: 1.0,
-                        temperature: 1.0,
-                        voc_index: 1.0,
-                        nox_index: 1.0,
-                        co2: 1,
-                    }
+                    suspended.persistent.voc_state,
+                    Some(VocAlgorithmState::from([1, 1, 1, 1]))
                 );
+
+                let mut sensor = Sen66::new(NoopDelay::new(), &mut i2c);
+                sensor.state = SensorState::Unknown;
+                sensor.resume_from(&suspended).await.unwrap();
+                assert_eq!(sensor.state, SensorState::Measuring);
+                i2c.done();
+            }
+
+            #[test_macro]
+            async fn apply_settings_while_measuring_interrupts_measurement_once() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x36, 0x02, 0xBC, 0x9A]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x11, 0x00, 0x01, 0xB0]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
+
+                let mut results = [const { Ok(()) }; 2];
+                sensor
+                    .apply_settings(
+                        &[
+                            Setting::SensorAltitude(SensorAltitude::try_from(700).unwrap()),
+                            Setting::Co2AutomaticSelfCalibration(AscState::Enabled),
+                        ],
+                        &mut results,
+                    )
+                    .await
+                    .unwrap();
+                assert!(results.iter().all(Result::is_ok));
+                assert_eq!(sensor.state, SensorState::Measuring);
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn apply_settings_restarts_measurement_before_measuring_settings() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x36, 0x02, 0xBC, 0x9A]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x11, 0x00, 0x01, 0xB0]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x20, 0x02, 0xBC, 0x9A]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = CheckedDelay::new(&[
+                    DelayTransaction::delay_us(1_000_000),
+                    DelayTransaction::delay_us(20_000),
+                    DelayTransaction::delay_us(20_000),
+                    DelayTransaction::delay_us(50_000),
+                    DelayTransaction::delay_us(20_000),
+                ]);
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
+
+                let mut results = [const { Ok(()) }; 3];
+                sensor
+                    .apply_settings(
+                        &[
+                            Setting::AmbientPressure(AmbientPressure::try_from(700).unwrap()),
+                            Setting::SensorAltitude(SensorAltitude::try_from(700).unwrap()),
+                            Setting::Co2AutomaticSelfCalibration(AscState::Enabled),
+                        ],
+                        &mut results,
+                    )
+                    .await
+                    .unwrap();
+                assert!(results.iter().all(Result::is_ok));
+                assert_eq!(sensor.state, SensorState::Measuring);
+                let (mut delay, mut i2c, _) = sensor.kill().await;
+                delay.done();
+                i2c.done();
+            }
+
+            #[test_macro]
+            async fn apply_settings_while_measuring_without_idle_settings_keeps_measuring() {
+                let expected_transaction = [I2cTransaction::write(
+                    0x6B | 0x00,
+                    vec![0x67, 0x20, 0x02, 0xBC, 0x9A],
+                )];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
+
+                let mut results = [const { Ok(()) }; 1];
+                sensor
+                    .apply_settings(
+                        &[Setting::AmbientPressure(
+                            AmbientPressure::try_from(700).unwrap(),
+                        )],
+                        &mut results,
+                    )
+                    .await
+                    .unwrap();
+                assert!(results[0].is_ok());
+                sensor.kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn apply_settings_skips_settings_without_result() {
+                let expected_transaction =
+                    [
+                        I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x36, 0x02, 0xBC, 0x9A])
+                            .with_error(ErrorKind::Other),
+                    ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+
+                let mut results = [const { Ok(()) }; 1];
+                sensor
+                    .apply_settings(
+                        &[
+                            Setting::SensorAltitude(SensorAltitude::try_from(700).unwrap()),
+                            Setting::AmbientPressure(AmbientPressure::try_from(700).unwrap()),
+                        ],
+                        &mut results,
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(results, [Err(Sen66Error::I2cError(ErrorKind::Other))]);
                 sensor.kill().await.1.done();
             }
 
             #[test_macro]
-            async fn read_measured_raw_values_works() {
+            async fn finishing_session_stops_measurement() {
                 let expected_transaction = [
-                    I2cTransaction::write(0x6B | 0x00, vec![0x04, 0x05]),
-                    I2cTransaction::read(
-                        0x6B | 0x01,
-                        vec![
-                            0x00, 0x64, 0xFe, 0x00, 0xC8, 0x7F, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A,
-                            0x00, 0x01, 0xB0,
-                        ],
-                    ),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04]),
                 ];
                 let i2c = I2cMock::new(&expected_transaction);
                 let delay = NoopDelay::new();
                 let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Measuring;
 
-                assert_eq!(
-                    sensor.read_measured_raw_values().await.unwrap(),
-                    RawMeasurement {
-                        relative_humidity: 1.0,
-                        temperature: 1.0,
-                        voc: 10,
-                        nox: 10,
-                        co2: 1,
-                    }
-                );
+                let mut session = sensor.start_session().await.unwrap();
+                assert_eq!(session.is_data_ready().await.unwrap(), DataStatus::Ready);
+                session.finish().await.unwrap();
+                assert_eq!(sensor.state, SensorState::Idle);
                 sensor.kill().await.1.done();
             }
 
             #[test_macro]
-            async fn read_number_concentrations_works() {
+            async fn typestate_driver_changes_type_with_state() {
                 let expected_transaction = [
-                    I2cTransaction::write(0x6B | 0x00, vec![0x03, 0x16]),
-                    I2cTransaction::read(
-                        0x6B | 0x01,
-                        vec![
-                            0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A,
-                            0x00, 0x0A, 0x5A,
-                        ],
-                    ),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21])
+                        .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04]),
                 ];
                 let i2c = I2cMock::new(&expected_transaction);
                 let delay = NoopDelay::new();
-                let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Measuring;
+                let sensor = Sen66::new(delay, i2c);
+                let sensor = Sen66Typed::<_, _, Measuring>::try_from(sensor).unwrap_err();
+                let sensor = Sen66Typed::<_, _, Idle>::try_from(sensor).unwrap();
+
+                let (sensor, error) = sensor.start_measurement().await.err().unwrap();
+                assert!(matches!(error, Sen66Error::I2cError(_)));
+                let mut sensor = sensor.start_measurement().await.ok().unwrap();
+                assert_eq!(sensor.driver().state, SensorState::Measuring);
+                assert_eq!(sensor.is_data_ready().await.unwrap(), DataStatus::Ready);
+                let sensor = sensor.stop_measurement().await.ok().unwrap();
+                sensor.into_inner().kill().await.1.done();
+            }
+
+            #[test_macro]
+            async fn split_phase_reports_busy_sensor() {
+                let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00; 3]).with_error(nack),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x20, 0x03, 0xE8, 0xD4])
+                        .with_error(nack),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x20, 0x03, 0xE8, 0xD4]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let mut sensor = Sen66SplitPhase::new(i2c);
 
+                sensor.issue(Command::GetDataReady, None).await.unwrap();
                 assert_eq!(
-                    sensor.read_number_concentrations().await.unwrap(),
-                    Concentrations {
-                        pm0_5: 1.0,
-                        pm1_0: 1.0,
-                        pm2_5: 1.0,
-                        pm4_0: 1.0,
-                        pm10_0: 1.0,
-                    },
+                    sensor.try_fetch::<DataStatus>().await,
+                    Err(Sen66Error::WouldBlock)
                 );
-                sensor.kill().await.1.done();
+                assert_eq!(
+                    sensor.try_fetch::<DataStatus>().await.unwrap(),
+                    DataStatus::Ready
+                );
+                let pressure = Some(&[1_000][..]);
+                assert_eq!(
+                    sensor
+                        .issue(Command::SetReadAmbientPreassure, pressure)
+                        .await,
+                    Err(Sen66Error::WouldBlock)
+                );
+                sensor
+                    .issue(Command::SetReadAmbientPreassure, pressure)
+                    .await
+                    .unwrap();
+                sensor.release().done();
             }
 
             #[test_macro]
-            async fn set_temperature_offset_works() {
-                let expected_transaction = [I2cTransaction::write(
-                    0x6B | 0x00,
-                    vec![
-                        0x60, 0xB2, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00,
-                        0x00, 0x81,
-                    ],
-                )];
+            async fn split_phase_rejects_oversized_data() {
+                let i2c = I2cMock::new(&[]);
+                let mut sensor = Sen66SplitPhase::new(i2c);
+                assert_eq!(
+                    sensor
+                        .issue(Command::SetReadVocTuningParameters, Some(&[0; 7]))
+                        .await,
+                    Err(Sen66Error::InvalidLength { words: 7 })
+                );
+                sensor.release().done();
+            }
+
+            #[test_macro]
+            async fn driver_works_over_borrowed_peripherals() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                ];
+                let mut i2c = I2cMock::new(&expected_transaction);
+                let mut delay = NoopDelay::new();
+
+                let mut sensor = Sen66::new(&mut delay, &mut i2c);
+                sensor.start_measurement().await.unwrap();
+                let mut other = Sen66SplitPhase::new(&mut i2c);
+                other.issue(Command::GetDataReady, None).await.unwrap();
+                i2c.done();
+            }
+
+            #[test_macro]
+            async fn builder_applies_settings_in_order() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x36, 0x02, 0xBC, 0x9A]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x11, 0x00, 0x00, 0x81]),
+                ];
+                let mut i2c = I2cMock::new(&expected_transaction);
+
+                assert!(
+                    Sen66::builder(NoopDelay::new(), &mut i2c)
+                        .altitude(3_500)
+                        .is_err_and(|error| matches!(error, DataError::ValueOutOfRange { .. }))
+                );
+                let sensor = Sen66::builder(NoopDelay::new(), &mut i2c)
+                    .asc(false)
+                    .altitude(700)
+                    .unwrap()
+                    .build()
+                    .await
+                    .unwrap();
+                assert_eq!(sensor.state, SensorState::Idle);
+                i2c.done();
+            }
+
+            #[test_macro]
+            async fn init_resets_and_configures_sensor() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0xD3, 0x04]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0xD2, 0x10]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81, 0x00, 0x00, 0x81]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x20, 0x03, 0xE8, 0xD4]),
+                ];
                 let i2c = I2cMock::new(&expected_transaction);
-                let delay = NoopDelay::new();
+                let delay = CheckedDelay::new(&[
+                    DelayTransaction::delay_ms(20),
+                    DelayTransaction::delay_ms(100),
+                    DelayTransaction::delay_ms(20),
+                    DelayTransaction::delay_ms(20),
+                ]);
                 let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
 
-                let offset = TemperatureOffset::new(0, 0, 0, 0).unwrap();
-                sensor.set_temperature_offset(offset).await.unwrap();
+                let pressure = AmbientPressure::try_from(1_000).unwrap();
+                let status = sensor
+                    .init(&[Setting::AmbientPressure(pressure)])
+                    .await
+                    .unwrap();
+                assert!(status.has_error().is_ok());
+                assert_eq!(sensor.state, SensorState::Idle);
+                let (mut delay, mut i2c, _) = sensor.kill().await;
+                delay.done();
+                i2c.done();
+            }
+
+            #[test_macro]
+            async fn probing_verifies_product_name() {
+                let mut name = vec![b'S', b'E', 0x83, b'N', b'6', 0x06, b'6', b'\0', 0x69];
+                name.extend([0x00, 0x00, 0x81].repeat(13));
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0xD0, 0x14]),
+                    I2cTransaction::read(0x6B | 0x01, name.clone()),
+                    I2cTransaction::write(0x6B | 0x00, vec![0xD0, 0x14]),
+                    I2cTransaction::read(0x6B | 0x01, name),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let sensor = Sen66::try_new(NoopDelay::new(), i2c).await.unwrap();
+                let mut sensor: Sen6x<crate::model::Sen65, _, _> =
+                    Sen6x::new(NoopDelay::new(), sensor.kill().await.1);
+
+                let error = sensor.probe().await.unwrap_err();
+                assert!(matches!(
+                    error,
+                    Sen66Error::WrongDevice { expected: "SEN65", found } if found.as_str() == "SEN66"
+                ));
                 sensor.kill().await.1.done();
             }
 
             #[test_macro]
-            async fn set_temperature_acceleration_works() {
-                let expected_transaction = [I2cTransaction::write(
-                    0x6B | 0x00,
-                    vec![
-                        0x61, 0x00, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00,
-                        0x00, 0x81,
-                    ],
-                )];
+            async fn sen6x_reads_layout_of_model() {
+                let mut received = [0x00, 0x00, 0x81].repeat(6);
+                received.extend([0x03, 0xE8, 0xD4]);
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x04, 0x71]),
+                    I2cTransaction::read(0x6B | 0x01, received),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x20, 0x03, 0xE8, 0xD4]),
+                ];
                 let i2c = I2cMock::new(&expected_transaction);
-                let delay = NoopDelay::new();
-                let mut sensor = Sen66::new(delay, i2c);
+                let mut sensor: Sen6x<crate::model::Sen63C, _, _> =
+                    Sen6x::new(NoopDelay::new(), i2c);
 
-                let acceleration = TemperatureAcceleration::new(0, 0, 0, 0).unwrap();
+                sensor.start_measurement().await.unwrap();
+                let measurement = sensor.read_measured_values().await.unwrap();
+                assert_eq!(measurement.co2, 1_000);
                 sensor
-                    .set_temperature_acceleration(acceleration)
+                    .set_ambient_pressure(AmbientPressure::try_from(1_000).unwrap())
                     .await
                     .unwrap();
                 sensor.kill().await.1.done();
             }
 
             #[test_macro]
-            async fn get_product_name_works() {
+            #[cfg(feature = "sen68")]
+            async fn sen68_reads_formaldehyde() {
+                let mut received = [0x00, 0x00, 0x81].repeat(8);
+                received.extend([0x03, 0xE8, 0xD4]);
                 let expected_transaction = [
-                    I2cTransaction::write(0x6B | 0x00, vec![0xD0, 0x14]),
-                    I2cTransaction::read(
-                        0x6B | 0x01,
-                        vec![
-                            'S' as u8, 'E' as u8, 0x83, 'N' as u8, '6' as u8, 0x06, '6' as u8,
-                            '\0' as u8, 0x69, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81,
-                            0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81,
-                            0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81,
-                            0x00, 0x00, 0x81, 0x00, 0x00, 0x81,
-                        ],
-                    ),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x04, 0x67]),
+                    I2cTransaction::read(0x6B | 0x01, received),
                 ];
                 let i2c = I2cMock::new(&expected_transaction);
-                let delay = NoopDelay::new();
-                let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Measuring;
+                let mut sensor = Sen68::new(NoopDelay::new(), i2c);
 
-                assert_eq!(
-                    sensor.get_product_name().await.unwrap().get_name_buffer(),
-                    [
-                        'S' as u8, 'E' as u8, 'N' as u8, '6' as u8, '6' as u8, '\0' as u8
-                    ]
-                );
+                #[cfg(not(feature = "lenient-state"))]
+                assert!(sensor.read_measured_values().await.is_err());
+                sensor.start_measurement().await.unwrap();
+                let measurement = sensor.read_measured_values().await.unwrap();
+                assert_eq!(measurement.hcho(), Some(100.0));
+                assert_eq!(measurement.pm2_5, 0.0);
+                assert_eq!(sensor.driver().runtime_counters().samples, 1);
                 sensor.kill().await.1.done();
             }
 
             #[test_macro]
-            async fn get_serial_number_works() {
+            async fn split_phase_returns_wait_hints() {
                 let expected_transaction = [
-                    I2cTransaction::write(0x6B | 0x00, vec![0xD0, 0x33]),
-                    I2cTransaction::read(
-                        0x6B | 0x01,
-                        vec![
-                            'S' as u8, 'E' as u8, 0x83, 'N' as u8, '6' as u8, 0x06, '6' as u8,
-                            '\0' as u8, 0x69, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81,
-                            0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81,
-                            0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81,
-                            0x00, 0x00, 0x81, 0x00, 0x00, 0x81,
-                        ],
-                    ),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
                 ];
                 let i2c = I2cMock::new(&expected_transaction);
-                let delay = NoopDelay::new();
-                let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Measuring;
+                let mut sensor = Sen66SplitPhase::new(i2c);
 
                 assert_eq!(
                     sensor
-                        .get_serial_number()
+                        .issue(Command::StartContinuousMeasurement, None)
                         .await
-                        .unwrap()
-                        .get_serial_buffer(),
-                    [
-                        'S' as u8, 'E' as u8, 'N' as u8, '6' as u8, '6' as u8, '\0' as u8
-                    ]
+                        .unwrap(),
+                    50
                 );
+                let pending = sensor
+                    .request::<DataStatus>(Command::GetDataReady, None)
+                    .await
+                    .unwrap();
+                assert_eq!(pending.command(), Command::GetDataReady);
+                assert_eq!(pending.wait_ms(), 20);
+                assert_eq!(sensor.complete(pending).await.unwrap(), DataStatus::Ready);
+                sensor.release().done();
+            }
+
+            #[cfg(feature = "co2-calibration")]
+            #[test_macro]
+            async fn perform_calibration_works() {
+                let mut serial_number = vec![0x31, 0x32, 0x60, 0x33, 0x00, 0x1E];
+                serial_number.extend([0x00, 0x00, 0x81].repeat(14));
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0xD0, 0x33]),
+                    I2cTransaction::read(0x6B | 0x01, serial_number),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x07, 0x01, 0xA4, 0x4D]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x80, 0x0A, 0x79]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x11]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x36]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x20]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x03, 0xF5, 0xDB]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = NoopDelay::new();
+                let mut sensor = Sen66::new(delay, i2c);
+
+                let report = sensor
+                    .perform_calibration(TargetCO2Concentration::from(420), 42)
+                    .await
+                    .unwrap();
+                assert_eq!(report.correction.ppm(), Some(10));
+                assert_eq!(report.asc_state, AscState::Enabled);
+                assert_eq!(report.sensor_altitude, SensorAltitude::default());
+                assert_eq!(report.ambient_pressure, AmbientPressure::default());
+                assert_eq!(report.timestamp, 42);
                 sensor.kill().await.1.done();
             }
 
             #[test_macro]
-            async fn read_device_status_works() {
+            async fn self_test_works() {
                 let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0xD2, 0x10]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81, 0x00, 0x00, 0x81]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x03, 0x00]),
+                    I2cTransaction::read(
+                        0x6B | 0x01,
+                        vec![
+                            0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A,
+                            0x0F, 0xA0, 0xE4, 0x10, 0x68, 0xED, 0x03, 0xE8, 0xD4, 0x7F, 0xFF, 0x8F,
+                            0x01, 0xA4, 0x4D,
+                        ],
+                    ),
                     I2cTransaction::write(0x6B | 0x00, vec![0xD2, 0x06]),
                     I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81, 0x00, 0x00, 0x81]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
                 ];
                 let i2c = I2cMock::new(&expected_transaction);
                 let delay = NoopDelay::new();
                 let mut sensor = Sen66::new(delay, i2c);
                 sensor.state = SensorState::Measuring;
 
-                assert!(
-                    sensor
-                        .read_device_status()
-                        .await
-                        .unwrap()
-                        .has_error()
-                        .is_ok()
-                );
+                let report = sensor.self_test().await.unwrap();
+                assert!(report.passed());
+                assert_eq!(sensor.state, SensorState::Measuring);
                 sensor.kill().await.1.done();
             }
 
             #[test_macro]
-            async fn read_and_clear_device_status_works() {
+            async fn burn_in_works() {
+                let measurement = vec![
+                    0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x0F,
+                    0xA0, 0xE4, 0x10, 0x68, 0xED, 0x03, 0xF2, 0x4C, 0x00, 0x0A, 0x5A, 0x01, 0xA4,
+                    0x4D,
+                ];
                 let expected_transaction = [
-                    I2cTransaction::write(0x6B | 0x00, vec![0xD2, 0x10]),
-                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81, 0x00, 0x00, 0x81]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x03, 0x00]),
+                    I2cTransaction::read(0x6B | 0x01, measurement.clone()),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x03, 0x00]),
+                    I2cTransaction::read(0x6B | 0x01, measurement),
                 ];
                 let i2c = I2cMock::new(&expected_transaction);
                 let delay = NoopDelay::new();
                 let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Measuring;
 
-                assert!(
-                    sensor
-                        .read_and_clear_device_status()
-                        .await
-                        .unwrap()
-                        .has_error()
-                        .is_ok()
-                );
+                let mut burn_in = BurnIn::new(2);
+                let status = sensor.burn_in(&mut burn_in).await.unwrap();
+                assert_eq!(status.elapsed_s, 2);
+                assert!(status.is_ready());
+                assert_eq!(sensor.state, SensorState::Measuring);
                 sensor.kill().await.1.done();
             }
 
             #[test_macro]
-            async fn reset_device_works() {
-                let expected_transaction = [I2cTransaction::write(0x6B | 0x00, vec![0xD3, 0x04])];
+            async fn run_duty_cycle_works() {
+                let measurement = |pm: u8, crc: u8| {
+                    let mut data = [0x00, pm, crc].repeat(4);
+                    data.extend([
+                        0x0F, 0xA0, 0xE4, 0x10, 0x68, 0xED, 0x03, 0xE8, 0xD4, 0x00, 0x0A, 0x5A,
+                        0x01, 0xA4, 0x4D,
+                    ]);
+                    data
+                };
+                let mut expected_transaction =
+                    vec![I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21])];
+                for (pm, crc) in [(0x64, 0xFE), (0x0A, 0x5A), (0x1E, 0xDD)] {
+                    expected_transaction.extend([
+                        I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                        I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+                        I2cTransaction::write(0x6B | 0x00, vec![0x03, 0x00]),
+                        I2cTransaction::read(0x6B | 0x01, measurement(pm, crc)),
+                    ]);
+                }
+                expected_transaction.push(I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04]));
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = CheckedDelay::new(&[
+                    DelayTransaction::delay_ms(50),
+                    DelayTransaction::delay_ms(20),
+                    DelayTransaction::delay_ms(20),
+                    DelayTransaction::delay_ms(20),
+                    DelayTransaction::delay_ms(20),
+                    DelayTransaction::delay_ms(20),
+                    DelayTransaction::delay_ms(20),
+                    DelayTransaction::delay_ms(1_000),
+                    DelayTransaction::delay_ms(5_000),
+                ]);
+                let mut sensor = Sen66::new(delay, i2c);
+
+                let cycle = DutyCycle::new(3, 5, 1).unwrap();
+                let average = sensor.run_duty_cycle(&cycle).await.unwrap();
+                assert_eq!(average.samples(), 2);
+                assert_eq!(average.pm1_0(), Some(2.0));
+                assert_eq!(sensor.state, SensorState::Idle);
+                let (mut delay, mut i2c, _) = sensor.kill().await;
+                delay.done();
+                i2c.done();
+            }
+
+            #[test_macro]
+            async fn measure_once_works() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x03, 0x00]),
+                    I2cTransaction::read(
+                        0x6B | 0x01,
+                        vec![
+                            0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A,
+                            0x00, 0x64, 0xFE, 0x00, 0xC8, 0x7F, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A,
+                            0x00, 0x01, 0xB0,
+                        ],
+                    ),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04]),
+                ];
                 let i2c = I2cMock::new(&expected_transaction);
-                let delay = NoopDelay::new();
+                let delay = CheckedDelay::new(&[
+                    DelayTransaction::delay_ms(50),
+                    DelayTransaction::delay_ms(1_000),
+                    DelayTransaction::delay_ms(20),
+                    DelayTransaction::delay_ms(100),
+                    DelayTransaction::delay_ms(20),
+                    DelayTransaction::delay_ms(20),
+                    DelayTransaction::delay_ms(1_000),
+                ]);
                 let mut sensor = Sen66::new(delay, i2c);
 
-                sensor.reset_device().await.unwrap();
-                sensor.kill().await.1.done();
+                let measurement = sensor.measure_once().await.unwrap();
+                assert_eq!(measurement.co2, 1);
+                assert_eq!(sensor.state, SensorState::Idle);
+                let (mut delay, mut i2c, _) = sensor.kill().await;
+                delay.done();
+                i2c.done();
             }
 
             #[test_macro]
-            async fn start_fan_cleaning_works() {
-                let expected_transaction = [I2cTransaction::write(0x6B | 0x00, vec![0x56, 0x07])];
+            async fn measure_once_stops_after_failed_read() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0x00]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04]),
+                ];
                 let i2c = I2cMock::new(&expected_transaction);
                 let delay = NoopDelay::new();
                 let mut sensor = Sen66::new(delay, i2c);
 
-                sensor.start_fan_cleaning().await.unwrap();
+                assert_eq!(
+                    sensor.measure_once().await,
+                    Err(Sen66Error::DataError(crate::error::DataError::CrcFailed))
+                );
+                assert_eq!(sensor.state, SensorState::Idle);
                 sensor.kill().await.1.done();
             }
 
             #[test_macro]
-            async fn activate_sht_heater_works() {
-                let expected_transaction = [I2cTransaction::write(0x6B | 0x00, vec![0x37, 0x30])];
+            async fn run_duty_cycle_stops_after_i2c_error() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02])
+                        .with_error(ErrorKind::Other),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04]),
+                ];
                 let i2c = I2cMock::new(&expected_transaction);
                 let delay = NoopDelay::new();
                 let mut sensor = Sen66::new(delay, i2c);
 
-                sensor.activate_sht_heater().await.unwrap();
+                let cycle = DutyCycle::new(3, 5, 1).unwrap();
+                assert_eq!(
+                    sensor.run_duty_cycle(&cycle).await,
+                    Err(Sen66Error::I2cError(ErrorKind::Other))
+                );
+                assert_eq!(sensor.state, SensorState::Idle);
                 sensor.kill().await.1.done();
             }
 
+            #[cfg(feature = "deadline")]
             #[test_macro]
-            async fn get_voc_tuning_parameters_works() {
+            async fn run_duty_cycle_with_deadline_stops_on_timeout() {
+                static NOW_MS: AtomicU64 = AtomicU64::new(1_000);
                 let expected_transaction = [
-                    I2cTransaction::write(0x6B | 0x00, vec![0x60, 0xD0]),
-                    I2cTransaction::read(
-                        0x6B | 0x01,
-                        vec![
-                            0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x00, 0x81,
-                            0x00, 0x0A, 0x5A, 0x00, 0x01, 0xB0,
-                        ],
-                    ),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04]),
                 ];
                 let i2c = I2cMock::new(&expected_transaction);
-                let delay = NoopDelay::new();
+                let delay = CheckedDelay::new(&[
+                    DelayTransaction::delay_ms(50),
+                    DelayTransaction::delay_ms(20),
+                    DelayTransaction::delay_ms(50),
+                    DelayTransaction::delay_ms(1_000),
+                ]);
                 let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Idle;
+                sensor.set_time_source(|| NOW_MS.load(Ordering::Relaxed));
 
+                let cycle = DutyCycle::new(3, 5, 1).unwrap();
                 assert_eq!(
-                    sensor.get_voc_tuning_parameters().await.unwrap(),
-                    VocTuning::new(1, 1, 1, 0, 10, 1).unwrap()
+                    sensor.run_duty_cycle_with_deadline(&cycle, 1_050).await,
+                    Err(Sen66Error::Timeout)
                 );
-                sensor.kill().await.1.done();
+                assert_eq!(sensor.state, SensorState::Idle);
+                assert_eq!(sensor.deadline_ms, None);
+                let (mut delay, mut i2c, _) = sensor.kill().await;
+                delay.done();
+                i2c.done();
             }
 
+            #[cfg(feature = "adaptive-polling")]
             #[test_macro]
-            async fn set_voc_tuning_parameters_works() {
-                let expected_transaction = [I2cTransaction::write(
-                    0x6B | 0x00,
-                    vec![
-                        0x60, 0xD0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00,
-                        0x00, 0x81, 0x00, 0x0A, 0x5A, 0x00, 0x01, 0xB0,
-                    ],
-                )];
+            async fn poll_data_ready_adapts_to_measurement_interval() {
+                static NOW_MS: AtomicU64 = AtomicU64::new(1_000);
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+                ];
                 let i2c = I2cMock::new(&expected_transaction);
-                let delay = NoopDelay::new();
+                let delay = CheckedDelay::new(&[
+                    DelayTransaction::delay_ms(20),
+                    DelayTransaction::delay_ms(750),
+                    DelayTransaction::delay_ms(20),
+                    DelayTransaction::delay_ms(10),
+                    DelayTransaction::delay_ms(20),
+                ]);
                 let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Idle;
+                sensor.state = SensorState::Measuring;
+                sensor.set_time_source(|| NOW_MS.load(Ordering::Relaxed));
 
-                sensor
-                    .set_voc_tuning_parameters(VocTuning::new(1, 1, 1, 0, 10, 1).unwrap())
-                    .await
-                    .unwrap();
-                sensor.kill().await.1.done();
+                assert_eq!(sensor.poll_data_ready(3).await, Ok(Some(0)));
+                NOW_MS.store(1_200, Ordering::Relaxed);
+                assert_eq!(sensor.poll_data_ready(3).await, Ok(Some(1)));
+                let (mut delay, mut i2c, _) = sensor.kill().await;
+                delay.done();
+                i2c.done();
             }
 
+            #[cfg(feature = "rate-limit")]
             #[test_macro]
-            async fn get_voc_algorithm_state_works() {
+            async fn poll_rate_limit_delays_rapid_polls() {
+                static NOW_MS: AtomicU64 = AtomicU64::new(1_000);
                 let expected_transaction = [
-                    I2cTransaction::write(0x6B | 0x00, vec![0x61, 0x81]),
-                    I2cTransaction::read(
-                        0x6B | 0x01,
-                        vec![
-                            0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0,
-                        ],
-                    ),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
                 ];
                 let i2c = I2cMock::new(&expected_transaction);
-                let delay = NoopDelay::new();
+                let delay = CheckedDelay::new(&[
+                    DelayTransaction::delay_ms(20),
+                    DelayTransaction::delay_ms(70),
+                    DelayTransaction::delay_ms(20),
+                ]);
                 let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Idle;
+                sensor.state = SensorState::Measuring;
+                sensor.set_time_source(|| NOW_MS.load(Ordering::Relaxed));
+                sensor.set_poll_rate_limit(100);
+                assert_eq!(sensor.poll_rate_limit(), 100);
 
-                assert_eq!(
-                    <[u16; 4]>::from(sensor.get_voc_algorithm_state().await.unwrap()),
-                    [0x0001, 0x0001, 0x0001, 0x0001]
-                );
-                sensor.kill().await.1.done();
+                assert_eq!(sensor.is_data_ready().await, Ok(DataStatus::NotReady));
+                NOW_MS.store(1_030, Ordering::Relaxed);
+                assert_eq!(sensor.is_data_ready().await, Ok(DataStatus::Ready));
+                let (mut delay, mut i2c, _) = sensor.kill().await;
+                delay.done();
+                i2c.done();
             }
 
+            #[cfg(feature = "deadline")]
             #[test_macro]
-            async fn set_voc_algorithm_state_works() {
-                let expected_transaction = [I2cTransaction::write(
-                    0x6B | 0x00,
-                    vec![
-                        0x61, 0x81, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00,
-                        0x01, 0xB0,
-                    ],
-                )];
-                let i2c = I2cMock::new(&expected_transaction);
+            async fn passed_deadline_aborts_before_bus_access() {
+                static NOW_MS: AtomicU64 = AtomicU64::new(5_000);
+                let i2c = I2cMock::new(&[]);
                 let delay = NoopDelay::new();
                 let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Idle;
+                sensor.set_time_source(|| NOW_MS.load(Ordering::Relaxed));
 
-                let state = VocAlgorithmState::try_from(
-                    &(vec![
-                        0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0,
-                    ])[..],
-                )
-                .unwrap();
-                sensor.set_voc_algorithm_state(state).await.unwrap();
+                assert_eq!(
+                    sensor.self_test_with_deadline(5_000).await,
+                    Err(Sen66Error::Timeout)
+                );
+                assert_eq!(sensor.state, SensorState::Idle);
                 sensor.kill().await.1.done();
             }
 
             #[test_macro]
-            async fn get_nox_tuning_parameters_works() {
+            async fn runtime_counters_accumulate() {
+                static NOW_MS: AtomicU64 = AtomicU64::new(1_000);
                 let expected_transaction = [
-                    I2cTransaction::write(0x6B | 0x00, vec![0x60, 0xE1]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x03, 0x00]),
                     I2cTransaction::read(
                         0x6B | 0x01,
                         vec![
-                            0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x00, 0x81,
-                            0x00, 0x32, 0x26, 0x00, 0x01, 0xB0,
+                            0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A,
+                            0x00, 0x64, 0xFE, 0x00, 0xC8, 0x7F, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A,
+                            0x00, 0x01, 0xB0,
                         ],
                     ),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04]),
                 ];
                 let i2c = I2cMock::new(&expected_transaction);
                 let delay = NoopDelay::new();
                 let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Idle;
+                sensor.set_time_source(|| NOW_MS.load(Ordering::Relaxed));
+                sensor.restore_runtime_counters(RuntimeCounters {
+                    measuring_time_ms: 10_000,
+                    samples: 10,
+                });
 
+                sensor.start_measurement().await.unwrap();
+                NOW_MS.store(3_000, Ordering::Relaxed);
+                sensor.read_measured_values().await.unwrap();
+                assert_eq!(sensor.runtime_counters().measuring_time_ms, 12_000);
+                NOW_MS.store(4_000, Ordering::Relaxed);
+                sensor.stop_measurement().await.unwrap();
+                NOW_MS.store(9_000, Ordering::Relaxed);
                 assert_eq!(
-                    sensor.get_nox_tuning_parameters().await.unwrap(),
-                    NoxTuning::new(1, 1, 1, 0, 1).unwrap()
+                    sensor.runtime_counters(),
+                    RuntimeCounters {
+                        measuring_time_ms: 13_000,
+                        samples: 11,
+                    }
                 );
                 sensor.kill().await.1.done();
             }
 
             #[test_macro]
-            async fn set_nox_tuning_parameters_works() {
-                let expected_transaction = [I2cTransaction::write(
-                    0x6B | 0x00,
-                    vec![
-                        0x60, 0xE1, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00, 0x01, 0xB0, 0x00,
-                        0x00, 0x81, 0x00, 0x32, 0x26, 0x00, 0x01, 0xB0,
-                    ],
-                )];
-                let i2c = I2cMock::new(&expected_transaction);
+            async fn summary_reports_driver_health() {
+                static NOW_MS: AtomicU64 = AtomicU64::new(1_000);
+                let i2c = I2cMock::new(&[]);
                 let delay = NoopDelay::new();
                 let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Idle;
+                assert_eq!(sensor.summary().measuring_for_ms, None);
+                sensor.set_time_source(|| NOW_MS.load(Ordering::Relaxed));
+                sensor.restore_runtime_counters(RuntimeCounters {
+                    measuring_time_ms: 10_000,
+                    samples: 10,
+                });
+                sensor.state = SensorState::Measuring;
+                sensor.measuring_since_ms = Some(1_000);
+                NOW_MS.store(3_000, Ordering::Relaxed);
 
-                sensor
-                    .set_nox_tuning_parameters(NoxTuning::new(1, 1, 1, 0, 1).unwrap())
-                    .await
-                    .unwrap();
+                let summary = sensor.summary();
+                assert_eq!(summary.state, SensorState::Measuring);
+                assert_eq!(summary.measuring_for_ms, Some(2_000));
+                assert_eq!(summary.counters.measuring_time_ms, 12_000);
+                assert_eq!(summary.counters.samples, 10);
+                assert!(!summary.pending_stop);
+                assert!(!summary.corrected);
+                let debug = format!("{sensor:?}");
+                assert!(debug.starts_with("Sen66 { state: Measuring, counters: RuntimeCounters"));
+                assert!(!debug.contains("i2c"));
+                assert!(debug.ends_with(", .. }"));
                 sensor.kill().await.1.done();
             }
 
+            #[cfg(feature = "hot-plug")]
             #[test_macro]
-            async fn perform_forced_co2_recalibration_works() {
+            async fn supervise_reinitializes_reconnected_sensor() {
+                let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
                 let expected_transaction = [
-                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x07, 0x03, 0xE8, 0xD4]),
-                    I2cTransaction::read(0x6B | 0x01, vec![0x83, 0xE8, 0xF7]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x03, 0x00]).with_error(nack),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x03, 0x00]).with_error(nack),
+                    I2cTransaction::write(0x6B | 0x00, vec![0xD2, 0x06]).with_error(nack),
+                    I2cTransaction::write(0x6B | 0x00, vec![0xD2, 0x06]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81, 0x00, 0x00, 0x81]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
                 ];
                 let i2c = I2cMock::new(&expected_transaction);
                 let delay = NoopDelay::new();
                 let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Idle;
-                assert_eq!(
-                    u16::from(
-                        sensor
-                            .perform_forced_co2_recalibration(TargetCO2Concentration::from(1000))
-                            .await
-                            .unwrap()
-                    ),
-                    1000
-                );
+                sensor.set_hot_plug_supervision(2);
+                sensor.state = SensorState::Measuring;
+
+                assert_eq!(sensor.supervise().await.unwrap(), HotPlugEvent::Present);
+                assert!(sensor.read_measured_values().await.is_err());
+                assert_eq!(sensor.supervise().await.unwrap(), HotPlugEvent::Present);
+                assert!(sensor.read_measured_values().await.is_err());
+                assert_eq!(sensor.supervise().await.unwrap(), HotPlugEvent::Absent);
+                assert_eq!(sensor.supervise().await.unwrap(), HotPlugEvent::Reconnected);
+                assert_eq!(sensor.state, SensorState::Measuring);
+                assert_eq!(sensor.supervise().await.unwrap(), HotPlugEvent::Present);
                 sensor.kill().await.1.done();
             }
 
             #[test_macro]
-            async fn get_co2_asc_state_is_enabled_yields_enabled() {
+            async fn resume_restores_hibernated_driver() {
+                static NOW_MS: AtomicU64 = AtomicU64::new(1_000);
                 let expected_transaction = [
-                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x11]),
-                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xb0]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04]),
                 ];
                 let i2c = I2cMock::new(&expected_transaction);
                 let delay = NoopDelay::new();
                 let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Idle;
-                assert_eq!(sensor.get_co2_asc_state().await.unwrap(), AscState::Enabled);
+                sensor.set_time_source(|| NOW_MS.load(Ordering::Relaxed));
+                sensor.set_bus_pacing(100);
+                #[cfg(feature = "rate-limit")]
+                sensor.set_poll_rate_limit(200);
+                sensor.start_measurement().await.unwrap();
+                NOW_MS.store(3_000, Ordering::Relaxed);
+
+                let (delay, i2c, buffers, snapshot) = sensor.hibernate();
+                assert!(buffers.is_none());
+                assert_eq!(snapshot.state(), SensorState::Measuring);
+                assert_eq!(snapshot.runtime_counters().measuring_time_ms, 2_000);
+                NOW_MS.store(10_000, Ordering::Relaxed);
+                let mut sensor = Sen66::resume(snapshot, delay, i2c);
+                sensor.set_time_source(|| NOW_MS.load(Ordering::Relaxed));
+                assert_eq!(sensor.bus_pacing(), 100);
+                #[cfg(feature = "rate-limit")]
+                assert_eq!(sensor.poll_rate_limit(), 200);
+                NOW_MS.store(11_000, Ordering::Relaxed);
+                sensor.stop_measurement().await.unwrap();
+                assert_eq!(sensor.runtime_counters().measuring_time_ms, 3_000);
                 sensor.kill().await.1.done();
             }
 
+            #[cfg(feature = "reset-recovery")]
             #[test_macro]
-            async fn get_co2_asc_state_is_disabled_yields_disabled() {
+            async fn recover_from_reset_restarts_measurement() {
+                let measurement = |co2: [u8; 3]| {
+                    let mut data = vec![
+                        0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A,
+                        0x00, 0x64, 0xFE, 0x00, 0xC8, 0x7F, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A,
+                    ];
+                    data.extend(co2);
+                    data
+                };
                 let expected_transaction = [
-                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x11]),
-                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x03, 0x00]),
+                    I2cTransaction::read(0x6B | 0x01, measurement([0x00, 0x01, 0xB0])),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x03, 0x00]),
+                    I2cTransaction::read(0x6B | 0x01, measurement([0xFF, 0xFF, 0xAC])),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04])
+                        .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data)),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
                 ];
                 let i2c = I2cMock::new(&expected_transaction);
                 let delay = NoopDelay::new();
                 let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Idle;
+                sensor.state = SensorState::Measuring;
+
+                assert_eq!(sensor.recover_from_reset().await.unwrap(), None);
+                sensor.read_measured_values().await.unwrap();
+                sensor.read_measured_values().await.unwrap();
                 assert_eq!(
-                    sensor.get_co2_asc_state().await.unwrap(),
-                    AscState::Disabled
+                    sensor.recover_from_reset().await.unwrap(),
+                    Some(ResetCause::Co2Unavailable)
                 );
+                assert_eq!(sensor.state, SensorState::Measuring);
+                assert_eq!(sensor.recover_from_reset().await.unwrap(), None);
                 sensor.kill().await.1.done();
             }
 
+            #[cfg(all(feature = "config-cache", feature = "hot-plug"))]
             #[test_macro]
-            async fn set_co2_asc_state_works() {
-                let expected_transaction = [I2cTransaction::write(
-                    0x6B | 0x00,
-                    vec![0x67, 0x11, 0x00, 0x01, 0xB0],
-                )];
+            async fn supervise_restores_cached_configuration() {
+                let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x36, 0x02, 0xBC, 0x9A]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0xD2, 0x06]).with_error(nack),
+                    I2cTransaction::write(0x6B | 0x00, vec![0xD2, 0x06]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81, 0x00, 0x00, 0x81]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x36, 0x02, 0xBC, 0x9A]),
+                ];
                 let i2c = I2cMock::new(&expected_transaction);
                 let delay = NoopDelay::new();
                 let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Idle;
-                sensor.set_co2_asc_state(AscState::Enabled).await.unwrap();
+                sensor.set_hot_plug_supervision(1);
+                let altitude = SensorAltitude::try_from(700).unwrap();
+                sensor.set_sensor_altitude(altitude).await.unwrap();
+
+                assert!(sensor.read_device_status().await.is_err());
+                assert_eq!(sensor.supervise().await.unwrap(), HotPlugEvent::Reconnected);
+                assert_eq!(sensor.state, SensorState::Idle);
+                assert!(
+                    sensor
+                        .config_cache()
+                        .contains(&Setting::SensorAltitude(altitude))
+                );
                 sensor.kill().await.1.done();
             }
 
             #[test_macro]
-            async fn get_ambient_pressure_works() {
+            async fn static_buffers_are_used_for_transfers() {
                 let expected_transaction = [
-                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x20]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x36, 0x02, 0xBC, 0x9A]),
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x36]),
                     I2cTransaction::read(0x6B | 0x01, vec![0x02, 0xBC, 0x9A]),
                 ];
                 let i2c = I2cMock::new(&expected_transaction);
                 let delay = NoopDelay::new();
-                let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Idle;
-                assert_eq!(
-                    sensor.get_ambient_pressure().await.unwrap(),
-                    AmbientPressure::try_from(700).unwrap()
+                let buffers = StaticBuffers::new(
+                    Box::leak(Box::new([0; TX_BUFFER_SIZE])),
+                    Box::leak(Box::new([0; RX_BUFFER_SIZE])),
                 );
-                sensor.kill().await.1.done();
-            }
+                let mut sensor = Sen66::new_with_buffers(delay, i2c, buffers);
 
-            #[test_macro]
-            async fn set_ambient_pressure_works() {
-                let expected_transaction = [I2cTransaction::write(
-                    0x6B | 0x00,
-                    vec![0x67, 0x20, 0x02, 0xBC, 0x9A],
-                )];
-                let i2c = I2cMock::new(&expected_transaction);
-                let delay = NoopDelay::new();
-                let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Idle;
                 sensor
-                    .set_ambient_pressure(AmbientPressure::try_from(700).unwrap())
+                    .set_sensor_altitude(SensorAltitude::try_from(700).unwrap())
                     .await
                     .unwrap();
-                sensor.kill().await.1.done();
+                assert_eq!(
+                    sensor.get_sensor_altitude().await.unwrap(),
+                    SensorAltitude::try_from(700).unwrap()
+                );
+                let (_, mut i2c, buffers) = sensor.kill().await;
+                let buffers = buffers.unwrap();
+                assert_eq!(buffers.tx[..5], [0x67, 0x36, 0x02, 0xBC, 0x9A]);
+                assert_eq!(buffers.rx[..3], [0x02, 0xBC, 0x9A]);
+                i2c.done();
             }
 
             #[test_macro]
-            async fn get_sensor_altitude_works() {
+            async fn static_buffers_survive_hibernation() {
                 let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x36, 0x02, 0xBC, 0x9A]),
                     I2cTransaction::write(0x6B | 0x00, vec![0x67, 0x36]),
                     I2cTransaction::read(0x6B | 0x01, vec![0x02, 0xBC, 0x9A]),
                 ];
                 let i2c = I2cMock::new(&expected_transaction);
                 let delay = NoopDelay::new();
-                let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Idle;
+                let buffers = StaticBuffers::new(
+                    Box::leak(Box::new([0; TX_BUFFER_SIZE])),
+                    Box::leak(Box::new([0; RX_BUFFER_SIZE])),
+                );
+                let mut sensor = Sen66::new_with_buffers(delay, i2c, buffers);
+
+                sensor
+                    .set_sensor_altitude(SensorAltitude::try_from(700).unwrap())
+                    .await
+                    .unwrap();
+                let (delay, i2c, buffers, snapshot) = sensor.hibernate();
+                let buffers = buffers.unwrap();
+                assert_eq!(buffers.tx[..5], [0x67, 0x36, 0x02, 0xBC, 0x9A]);
+                let mut sensor = Sen66::resume_with_buffers(snapshot, delay, i2c, buffers);
                 assert_eq!(
                     sensor.get_sensor_altitude().await.unwrap(),
                     SensorAltitude::try_from(700).unwrap()
                 );
-                sensor.kill().await.1.done();
+                let (_, mut i2c, buffers) = sensor.kill().await;
+                let buffers = buffers.unwrap();
+                assert_eq!(buffers.rx[..3], [0x02, 0xBC, 0x9A]);
+                i2c.done();
             }
 
             #[test_macro]
-            async fn set_sensor_altitude_works() {
-                let expected_transaction = [I2cTransaction::write(
-                    0x6B | 0x00,
-                    vec![0x67, 0x36, 0x02, 0xBC, 0x9A],
-                )];
+            async fn without_bus_pacing_only_execution_time_is_waited() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+                ];
                 let i2c = I2cMock::new(&expected_transaction);
-                let delay = NoopDelay::new();
+                let delay = CheckedDelay::new(&[DelayTransaction::delay_ms(20)]);
                 let mut sensor = Sen66::new(delay, i2c);
-                sensor.state = SensorState::Idle;
-                sensor
-                    .set_sensor_altitude(SensorAltitude::try_from(700).unwrap())
-                    .await
-                    .unwrap();
-                sensor.kill().await.1.done();
+                sensor.state = SensorState::Measuring;
+
+                sensor.is_data_ready().await.unwrap();
+                let (mut delay, mut i2c, _) = sensor.kill().await;
+                delay.done();
+                i2c.done();
+            }
+
+            #[test_macro]
+            async fn bus_pacing_longer_than_execution_time_extends_waits() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = CheckedDelay::new(&[
+                    DelayTransaction::delay_us(50_000),
+                    DelayTransaction::delay_us(50_000),
+                ]);
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
+                sensor.set_bus_pacing(50_000);
+
+                sensor.is_data_ready().await.unwrap();
+                let (mut delay, mut i2c, _) = sensor.kill().await;
+                delay.done();
+                i2c.done();
+            }
+
+            #[test_macro]
+            async fn bus_pacing_shorter_than_execution_time_only_delays_after_read() {
+                let expected_transaction = [
+                    I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+                    I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+                ];
+                let i2c = I2cMock::new(&expected_transaction);
+                let delay = CheckedDelay::new(&[
+                    DelayTransaction::delay_ms(20),
+                    DelayTransaction::delay_us(500),
+                ]);
+                let mut sensor = Sen66::new(delay, i2c);
+                sensor.state = SensorState::Measuring;
+                sensor.set_bus_pacing(500);
+
+                sensor.is_data_ready().await.unwrap();
+                let (mut delay, mut i2c, _) = sensor.kill().await;
+                delay.done();
+                i2c.done();
             }
         }
     }
 
     #[cfg(feature=feature_)]
     pub use inner::*;
+    // The async flavor has no public items of its own.
+    #[cfg(feature=feature_)]
+    #[allow(unused_imports)]
+    pub use crate::interface::flavor::*;
 }
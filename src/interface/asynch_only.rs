@@ -0,0 +1,178 @@
+//! Parts of the async interface without a blocking counterpart.
+
+use super::asynch::Sen66;
+use crate::data::SensorState;
+
+impl<
+    DELAY: embedded_hal_async::delay::DelayNs,
+    I2C: embedded_hal_async::i2c::I2c<Error = ERR>,
+    ERR: embedded_hal::i2c::Error,
+> Sen66<DELAY, I2C>
+{
+    /// Converts the interface into the blocking interface, e.g. to run a synchronous
+    /// shutdown routine. The operating state, bus pacing, transfer buffers, runtime
+    /// counters and corrections are carried over, so no stop/start cycle is required.
+    /// Requires the delay and I2C peripheral to also implement the blocking traits.
+    #[cfg(feature = "blocking")]
+    pub fn into_blocking(self) -> super::blocking::Sen66<DELAY, I2C>
+    where
+        DELAY: embedded_hal::delay::DelayNs,
+        I2C: embedded_hal::i2c::I2c<Error = ERR>,
+    {
+        let (delay, i2c, state) = self.into_driver_state();
+        super::blocking::Sen66::from_driver_state(delay, i2c, state)
+    }
+
+    /// Marks the measurement of a dropped
+    /// [`MeasurementSession`](super::asynch::MeasurementSession) to be stopped before the next
+    /// command is sent, as the bus cannot be accessed while dropping.
+    pub(super) fn abandon_session(&mut self) {
+        self.pending_stop = true;
+        self.state = SensorState::Idle;
+        self.commit_measuring_time();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_hal_mock::eh1::{
+        delay::NoopDelay,
+        i2c::{Mock as I2cMock, Transaction as I2cTransaction},
+    };
+
+    #[cfg(feature = "blocking")]
+    #[tokio::test]
+    async fn into_blocking_carries_over_state() {
+        let expected_transaction = [
+            I2cTransaction::write(0x6B | 0x00, vec![0x03, 0x00]),
+            I2cTransaction::read(
+                0x6B | 0x01,
+                vec![
+                    0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x0F,
+                    0xA0, 0xE4, 0x10, 0x68, 0xED, 0x03, 0xE8, 0xD4, 0x7F, 0xFF, 0x8F, 0x01, 0xA4,
+                    0x4D,
+                ],
+            ),
+        ];
+        let i2c = I2cMock::new(&expected_transaction);
+        let delay = NoopDelay::new();
+        let mut sensor = Sen66::assume_measuring(delay, i2c);
+        sensor.restore_runtime_counters(crate::maintenance::RuntimeCounters {
+            measuring_time_ms: 0,
+            samples: 3,
+        });
+
+        let mut sensor = sensor.into_blocking();
+        assert_eq!(sensor.read_measured_values().unwrap().co2, 420);
+        assert_eq!(sensor.runtime_counters().samples, 4);
+        sensor.kill().1.done();
+    }
+
+    #[cfg(feature = "maintenance")]
+    #[tokio::test]
+    async fn dropping_session_stops_measurement_before_next_command() {
+        use embedded_hal_mock::eh1::delay::{CheckedDelay, Transaction as DelayTransaction};
+
+        let expected_transaction = [
+            I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+            I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04]),
+            I2cTransaction::write(0x6B | 0x00, vec![0x56, 0x07]),
+        ];
+        let i2c = I2cMock::new(&expected_transaction);
+        let delay = CheckedDelay::new(&[
+            DelayTransaction::delay_us(100_000),
+            DelayTransaction::delay_us(1_000_000),
+            DelayTransaction::delay_us(100_000),
+        ]);
+        let mut sensor = Sen66::new(delay, i2c);
+        sensor.set_bus_pacing(100_000);
+
+        drop(sensor.start_session().await.unwrap());
+        assert_eq!(sensor.state, SensorState::Idle);
+        sensor.start_fan_cleaning().await.unwrap();
+        let (mut delay, mut i2c, _) = sensor.kill().await;
+        delay.done();
+        i2c.done();
+    }
+
+    #[cfg(feature = "hot-plug")]
+    #[tokio::test]
+    async fn unacknowledged_deferred_stop_is_tracked_by_hot_plug() {
+        use crate::maintenance::HotPlugEvent;
+        use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+
+        let expected_transaction = [
+            I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+            I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04])
+                .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)),
+            I2cTransaction::write(0x6B | 0x00, vec![0xD2, 0x06]),
+            I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81, 0x00, 0x00, 0x81]),
+            I2cTransaction::write(0x6B | 0x00, vec![0x56, 0x07]),
+        ];
+        let i2c = I2cMock::new(&expected_transaction);
+        let delay = NoopDelay::new();
+        let mut sensor = Sen66::new(delay, i2c);
+        sensor.set_hot_plug_supervision(1);
+
+        drop(sensor.start_session().await.unwrap());
+        assert!(sensor.start_fan_cleaning().await.is_err());
+        assert!(sensor.pending_stop);
+        assert_eq!(sensor.supervise().await.unwrap(), HotPlugEvent::Reconnected);
+        assert!(!sensor.pending_stop);
+        sensor.start_fan_cleaning().await.unwrap();
+        sensor.kill().await.1.done();
+    }
+
+    #[cfg(feature = "cooldown")]
+    #[tokio::test]
+    async fn deferred_stop_starts_cooldown_before_next_measurement() {
+        use crate::{error::Sen66Error, maintenance::CooldownPolicy};
+
+        let expected_transaction = [
+            I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+            I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04]),
+        ];
+        let i2c = I2cMock::new(&expected_transaction);
+        let delay = NoopDelay::new();
+        let mut sensor = Sen66::new(delay, i2c);
+        sensor.set_time_source(|| 1_000);
+        sensor.set_cooldown_policy(CooldownPolicy::Reject);
+
+        drop(sensor.start_session().await.unwrap());
+        assert_eq!(
+            sensor.start_measurement().await,
+            Err(Sen66Error::CooldownActive {
+                remaining_ms: 1_000
+            })
+        );
+        assert_eq!(sensor.state, SensorState::Idle);
+        assert!(!sensor.pending_stop);
+        sensor.kill().await.1.done();
+    }
+
+    #[tokio::test]
+    async fn shutdown_sends_deferred_stop() {
+        let expected_transaction = [
+            I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+            I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04]),
+        ];
+        let i2c = I2cMock::new(&expected_transaction);
+        let delay = NoopDelay::new();
+        let mut sensor = Sen66::new(delay, i2c);
+
+        drop(sensor.start_session().await.unwrap());
+        sensor.shutdown().await.unwrap().1.done();
+    }
+
+    #[tokio::test]
+    async fn kill_discards_deferred_stop() {
+        let expected_transaction = [I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21])];
+        let i2c = I2cMock::new(&expected_transaction);
+        let delay = NoopDelay::new();
+        let mut sensor = Sen66::new(delay, i2c);
+
+        drop(sensor.start_session().await.unwrap());
+        sensor.kill().await.1.done();
+    }
+}
@@ -0,0 +1,205 @@
+//! Parts of the blocking interface without an async counterpart.
+
+use super::blocking::Sen66;
+use crate::{
+    data::{Measurement, SensorState},
+    error::Sen66Error,
+};
+
+impl<
+    DELAY: embedded_hal::delay::DelayNs,
+    I2C: embedded_hal::i2c::I2c<Error = ERR>,
+    ERR: embedded_hal::i2c::Error,
+> Sen66<DELAY, I2C>
+{
+    /// Returns an iterator yielding a new measurement whenever the sensor provides one.
+    /// Between measurements data-ready is polled every 100ms using the delay provider.
+    /// The iterator only ends after yielding an error, so a `for` loop terminates on a bus
+    /// or data error. Call `iter_measurements` again to resume.
+    /// <div class="warning">Only available in measuring state</div>
+    ///
+    /// # Errors
+    ///
+    /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+    ///   Idle state.
+    pub fn iter_measurements(&mut self) -> Result<Measurements<'_, DELAY, I2C>, Sen66Error<ERR>> {
+        self.ensure_state(SensorState::Measuring)?;
+        Ok(Measurements {
+            sensor: self,
+            failed: false,
+        })
+    }
+
+    /// Converts the interface into the async interface, e.g. to hand a sensor configured
+    /// by synchronous boot code to an async runtime. The operating state, bus pacing,
+    /// transfer buffers, runtime counters and corrections are carried over, so no
+    /// stop/start cycle is required. Requires the delay and I2C peripheral to also
+    /// implement the async traits.
+    #[cfg(feature = "async")]
+    pub fn into_async(self) -> super::asynch::Sen66<DELAY, I2C>
+    where
+        DELAY: embedded_hal_async::delay::DelayNs,
+        I2C: embedded_hal_async::i2c::I2c<Error = ERR>,
+    {
+        let (delay, i2c, state) = self.into_driver_state();
+        super::asynch::Sen66::from_driver_state(delay, i2c, state)
+    }
+
+    /// Stops the measurement of a dropped
+    /// [`MeasurementSession`](super::blocking::MeasurementSession).
+    pub(super) fn abandon_session(&mut self) {
+        // Errors cannot be reported from drop, use `finish` to observe them.
+        let _ = self.stop_measurement();
+    }
+}
+
+/// Iterator over the measurements of a running measurement, created by
+/// [`iter_measurements`](Sen66::iter_measurements).
+pub struct Measurements<'a, DELAY, I2C> {
+    sensor: &'a mut Sen66<DELAY, I2C>,
+    failed: bool,
+}
+
+impl<
+    DELAY: embedded_hal::delay::DelayNs,
+    I2C: embedded_hal::i2c::I2c<Error = ERR>,
+    ERR: embedded_hal::i2c::Error,
+> Iterator for Measurements<'_, DELAY, I2C>
+{
+    type Item = Result<Measurement, Sen66Error<ERR>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let result = self
+            .sensor
+            .poll_data_ready(u32::MAX)
+            .and_then(|_| self.sensor.read_measured_values());
+        self.failed = result.is_err();
+        Some(result)
+    }
+}
+
+impl<
+    DELAY: embedded_hal::delay::DelayNs,
+    I2C: embedded_hal::i2c::I2c<Error = ERR>,
+    ERR: embedded_hal::i2c::Error,
+> core::iter::FusedIterator for Measurements<'_, DELAY, I2C>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_hal_mock::eh1::{
+        delay::NoopDelay,
+        i2c::{Mock as I2cMock, Transaction as I2cTransaction},
+    };
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn into_async_carries_over_state() {
+        let expected_transaction = [
+            I2cTransaction::write(0x6B | 0x00, vec![0x03, 0x00]),
+            I2cTransaction::read(
+                0x6B | 0x01,
+                vec![
+                    0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x0F,
+                    0xA0, 0xE4, 0x10, 0x68, 0xED, 0x03, 0xE8, 0xD4, 0x7F, 0xFF, 0x8F, 0x01, 0xA4,
+                    0x4D,
+                ],
+            ),
+        ];
+        let i2c = I2cMock::new(&expected_transaction);
+        let delay = NoopDelay::new();
+        let mut sensor = Sen66::assume_measuring(delay, i2c);
+        sensor.restore_runtime_counters(crate::maintenance::RuntimeCounters {
+            measuring_time_ms: 0,
+            samples: 3,
+        });
+
+        let mut sensor = sensor.into_async();
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let measurement = runtime.block_on(sensor.read_measured_values()).unwrap();
+        assert_eq!(measurement.co2, 420);
+        assert_eq!(sensor.runtime_counters().samples, 4);
+        runtime.block_on(sensor.kill()).1.done();
+    }
+
+    #[test]
+    fn dropping_session_stops_measurement() {
+        let expected_transaction = [
+            I2cTransaction::write(0x6B | 0x00, vec![0x00, 0x21]),
+            I2cTransaction::write(0x6B | 0x00, vec![0x01, 0x04]),
+        ];
+        let i2c = I2cMock::new(&expected_transaction);
+        let delay = NoopDelay::new();
+        let mut sensor = Sen66::new(delay, i2c);
+
+        drop(sensor.start_session().unwrap());
+        assert_eq!(sensor.state, SensorState::Idle);
+        sensor.kill().1.done();
+    }
+
+    #[test]
+    fn iterating_measurements_polls_data_ready() {
+        let expected_transaction = [
+            I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+            I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81]),
+            I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02]),
+            I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+            I2cTransaction::write(0x6B | 0x00, vec![0x03, 0x00]),
+            I2cTransaction::read(
+                0x6B | 0x01,
+                vec![
+                    0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00,
+                    0x64, 0xFE, 0x00, 0xC8, 0x7F, 0x00, 0x0A, 0x5A, 0x00, 0x0A, 0x5A, 0x00, 0x01,
+                    0xB0,
+                ],
+            ),
+        ];
+        let i2c = I2cMock::new(&expected_transaction);
+        let delay = NoopDelay::new();
+        let mut sensor = Sen66::assume_measuring(delay, i2c);
+
+        let measurement = sensor.iter_measurements().unwrap().next().unwrap();
+        assert!(measurement.is_ok());
+        sensor.kill().1.done();
+    }
+
+    #[test]
+    fn iterating_measurements_ends_after_error() {
+        let expected_transaction = [I2cTransaction::write(0x6B | 0x00, vec![0x02, 0x02])
+            .with_error(embedded_hal::i2c::ErrorKind::Other)];
+        let i2c = I2cMock::new(&expected_transaction);
+        let delay = NoopDelay::new();
+        let mut sensor = Sen66::assume_measuring(delay, i2c);
+
+        let mut measurements = sensor.iter_measurements().unwrap();
+        assert_eq!(
+            measurements.next(),
+            Some(Err(Sen66Error::I2cError(
+                embedded_hal::i2c::ErrorKind::Other
+            )))
+        );
+        assert_eq!(measurements.next(), None);
+        sensor.kill().1.done();
+    }
+
+    #[cfg(not(feature = "lenient-state"))]
+    #[test]
+    fn iterating_measurements_requires_measuring_state() {
+        let i2c = I2cMock::new(&[]);
+        let delay = NoopDelay::new();
+        let mut sensor = Sen66::new(delay, i2c);
+
+        assert!(matches!(
+            sensor.iter_measurements(),
+            Err(Sen66Error::WrongState(_))
+        ));
+        sensor.kill().1.done();
+    }
+}
@@ -0,0 +1,110 @@
+/// Interval in which the raw CO2 concentration is updated in s.
+const RAW_UPDATE_INTERVAL_S: u16 = 5;
+/// Raw value reported for the CO2 concentration while it is not yet available.
+const CO2_UNAVAILABLE: u16 = 0xFFFF;
+
+/// Smooths the raw CO2 concentration of a [`RawMeasurement`](crate::data::RawMeasurement),
+/// which is only updated every 5 s, to a 1 Hz signal. Like the sensor's own interpolation, each
+/// update is approached linearly over one update interval, delaying the signal by that interval.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Co2Smoother {
+    update_interval_s: u16,
+    from: f32,
+    to: f32,
+    step: u16,
+    initialized: bool,
+}
+
+impl Co2Smoother {
+    /// Creates a smoother for the raw update interval of 5 s.
+    pub const fn new() -> Self {
+        Self::with_update_interval(RAW_UPDATE_INTERVAL_S)
+    }
+
+    /// Creates a smoother approaching each update over `update_interval_s` seconds. Longer
+    /// intervals result in a smoother, but more delayed signal.
+    pub const fn with_update_interval(update_interval_s: u16) -> Self {
+        Self {
+            update_interval_s,
+            from: 0.0,
+            to: 0.0,
+            step: 0,
+            initialized: false,
+        }
+    }
+
+    /// Processes the raw CO2 concentration of a sample taken every second and returns the
+    /// smoothed CO2 concentration in ppm. Unavailable values (`0xFFFF`) are passed through
+    /// without affecting the smoother.
+    pub fn process(&mut self, raw_co2: u16) -> u16 {
+        if raw_co2 == CO2_UNAVAILABLE {
+            return raw_co2;
+        }
+        let raw_co2 = f32::from(raw_co2);
+        if !self.initialized {
+            self.from = raw_co2;
+            self.to = raw_co2;
+            self.step = self.update_interval_s;
+            self.initialized = true;
+        } else if raw_co2 != self.to {
+            self.from = self.value();
+            self.to = raw_co2;
+            self.step = 0;
+        }
+        self.step = self.step.saturating_add(1).min(self.update_interval_s);
+        (self.value() + 0.5) as u16
+    }
+
+    /// Resets the smoother, e.g. after restarting the measurement.
+    pub fn reset(&mut self) {
+        *self = Self::with_update_interval(self.update_interval_s);
+    }
+
+    fn value(&self) -> f32 {
+        if self.update_interval_s == 0 {
+            return self.to;
+        }
+        let progress = f32::from(self.step) / f32::from(self.update_interval_s);
+        self.from + (self.to - self.from) * progress
+    }
+}
+
+impl Default for Co2Smoother {
+    /// Creates a smoother for the raw update interval of 5 s.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn co2_smoother_ramps_between_updates() {
+        let mut smoother = Co2Smoother::new();
+        assert_eq!(smoother.process(400), 400);
+        for _ in 0..4 {
+            assert_eq!(smoother.process(400), 400);
+        }
+        let smoothed: Vec<u16> = [500; 6].iter().map(|&co2| smoother.process(co2)).collect();
+        assert_eq!(smoothed, [420, 440, 460, 480, 500, 500]);
+    }
+
+    #[test]
+    fn co2_smoother_continues_from_intermediate_value() {
+        let mut smoother = Co2Smoother::new();
+        smoother.process(400);
+        smoother.process(500);
+        smoother.process(500);
+        assert_eq!(smoother.process(440), 440);
+        assert_eq!(smoother.process(440), 440);
+    }
+
+    #[test]
+    fn co2_smoother_passes_unavailable_values() {
+        let mut smoother = Co2Smoother::new();
+        assert_eq!(smoother.process(0xFFFF), 0xFFFF);
+        assert_eq!(smoother.process(600), 600);
+    }
+}
@@ -0,0 +1,7 @@
+//! Interpolation of measurement series.
+
+mod co2;
+mod resample;
+
+pub use co2::Co2Smoother;
+pub use resample::{Interpolate, Resampled, Resampler};
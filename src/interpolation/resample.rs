@@ -0,0 +1,226 @@
+use crate::data::{Measurement, Timestamped};
+
+/// Values which can be linearly interpolated by the [`Resampler`].
+pub trait Interpolate: Copy {
+    /// Returns the value at `fraction` between `self` (0.0) and `to` (1.0).
+    fn interpolate(&self, to: &Self, fraction: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, to: &Self, fraction: f32) -> Self {
+        self + (to - self) * fraction
+    }
+}
+
+impl Interpolate for Measurement {
+    /// Interpolates each value separately. Values not available at either end are reported as
+    /// unavailable.
+    fn interpolate(&self, to: &Self, fraction: f32) -> Self {
+        let value = |from: f32, to: f32, unavailable: f32| {
+            if from == unavailable || to == unavailable {
+                unavailable
+            } else {
+                from.interpolate(&to, fraction)
+            }
+        };
+        let co2 = if self.co2 == Self::CO2_UNAVAILABLE || to.co2 == Self::CO2_UNAVAILABLE {
+            Self::CO2_UNAVAILABLE
+        } else {
+            (f32::from(self.co2).interpolate(&f32::from(to.co2), fraction) + 0.5) as u16
+        };
+        Self {
+            pm1_0: value(self.pm1_0, to.pm1_0, Self::PM_UNAVAILABLE),
+            pm2_5: value(self.pm2_5, to.pm2_5, Self::PM_UNAVAILABLE),
+            pm4_0: value(self.pm4_0, to.pm4_0, Self::PM_UNAVAILABLE),
+            pm10_0: value(self.pm10_0, to.pm10_0, Self::PM_UNAVAILABLE),
+            relative_humidity: value(
+                self.relative_humidity,
+                to.relative_humidity,
+                Self::RH_UNAVAILABLE,
+            ),
+            temperature: value(
+                self.temperature,
+                to.temperature,
+                Self::TEMPERATURE_UNAVAILABLE,
+            ),
+            voc_index: value(self.voc_index, to.voc_index, Self::INDEX_UNAVAILABLE),
+            nox_index: value(self.nox_index, to.nox_index, Self::INDEX_UNAVAILABLE),
+            co2,
+        }
+    }
+}
+
+/// Linearly resamples irregularly timestamped values, e.g. after retries or duty-cycling, onto a
+/// fixed time grid at multiples of the interval. Samples are processed as a stream, so the
+/// required memory is bounded to the previous sample.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Resampler<T> {
+    interval_ms: u64,
+    max_gap_ms: Option<u64>,
+    previous: Option<Timestamped<T>>,
+}
+
+impl<T: Interpolate> Resampler<T> {
+    /// Creates a resampler with grid points every `interval_ms` ms. An interval of 0 is treated
+    /// as 1 ms.
+    pub const fn new(interval_ms: u64) -> Self {
+        Self {
+            interval_ms: if interval_ms == 0 { 1 } else { interval_ms },
+            max_gap_ms: None,
+            previous: None,
+        }
+    }
+
+    /// Returns the resampler without interpolating across gaps between samples longer than
+    /// `max_gap_ms` ms, e.g. while the sensor was not measuring.
+    pub const fn with_max_gap(mut self, max_gap_ms: u64) -> Self {
+        self.max_gap_ms = Some(max_gap_ms);
+        self
+    }
+
+    /// Processes the next `sample` and returns the values at all grid points since the previous
+    /// sample up to and including the sample's timestamp. Samples not newer than the previous
+    /// one are ignored.
+    pub fn push(&mut self, sample: Timestamped<T>) -> Resampled<T> {
+        let interval_ms = self.interval_ms;
+        let (previous, next_ms) = match self.previous {
+            Some(previous) if sample.timestamp_ms <= previous.timestamp_ms => {
+                return Resampled::empty(sample);
+            }
+            Some(previous)
+                if self
+                    .max_gap_ms
+                    .is_none_or(|gap| sample.timestamp_ms - previous.timestamp_ms <= gap) =>
+            {
+                let next_ms = (previous.timestamp_ms / interval_ms + 1) * interval_ms;
+                (previous, next_ms)
+            }
+            // Only a sample on the grid itself is resampled without a preceding sample
+            _ => (
+                sample,
+                sample.timestamp_ms.div_ceil(interval_ms) * interval_ms,
+            ),
+        };
+        self.previous = Some(sample);
+        Resampled {
+            from: previous,
+            to: sample,
+            next_ms,
+            interval_ms,
+        }
+    }
+
+    /// Resets the resampler, e.g. after restarting the measurement.
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+/// Values at grid points returned by [`Resampler::push`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Resampled<T> {
+    from: Timestamped<T>,
+    to: Timestamped<T>,
+    next_ms: u64,
+    interval_ms: u64,
+}
+
+impl<T> Resampled<T> {
+    fn empty(sample: Timestamped<T>) -> Self
+    where
+        T: Copy,
+    {
+        Self {
+            from: sample,
+            to: sample,
+            next_ms: u64::MAX,
+            interval_ms: 1,
+        }
+    }
+}
+
+impl<T: Interpolate> Iterator for Resampled<T> {
+    type Item = Timestamped<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_ms > self.to.timestamp_ms {
+            return None;
+        }
+        let timestamp_ms = self.next_ms;
+        self.next_ms = self.next_ms.saturating_add(self.interval_ms);
+        let span_ms = self.to.timestamp_ms - self.from.timestamp_ms;
+        let fraction = if span_ms == 0 {
+            1.0
+        } else {
+            (timestamp_ms - self.from.timestamp_ms) as f32 / span_ms as f32
+        };
+        Some(Timestamped::new(
+            timestamp_ms,
+            self.from.value.interpolate(&self.to.value, fraction),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resample(resampler: &mut Resampler<f32>, timestamp_ms: u64, value: f32) -> Vec<(u64, f32)> {
+        resampler
+            .push(Timestamped::new(timestamp_ms, value))
+            .map(|sample| (sample.timestamp_ms, sample.value))
+            .collect()
+    }
+
+    #[test]
+    fn resampler_interpolates_onto_grid() {
+        let mut resampler = Resampler::new(1_000);
+        assert_eq!(resample(&mut resampler, 500, 0.0), []);
+        assert_eq!(
+            resample(&mut resampler, 3_500, 30.0),
+            [(1_000, 5.0), (2_000, 15.0), (3_000, 25.0)]
+        );
+        assert_eq!(resample(&mut resampler, 4_000, 40.0), [(4_000, 40.0)]);
+        assert_eq!(resample(&mut resampler, 4_000, 50.0), []);
+        assert_eq!(resample(&mut resampler, 3_000, 50.0), []);
+    }
+
+    #[test]
+    fn resampler_does_not_interpolate_across_gaps() {
+        let mut resampler = Resampler::new(1_000).with_max_gap(2_000);
+        assert_eq!(resample(&mut resampler, 0, 0.0), [(0, 0.0)]);
+        assert_eq!(resample(&mut resampler, 5_500, 10.0), []);
+        assert_eq!(resample(&mut resampler, 6_500, 20.0), [(6_000, 15.0)]);
+        resampler.reset();
+        assert_eq!(resample(&mut resampler, 7_000, 30.0), [(7_000, 30.0)]);
+    }
+
+    #[test]
+    fn measurement_interpolation_keeps_unavailable_values() {
+        let from = Measurement {
+            pm2_5: 2.0,
+            pm4_0: 3.0,
+            pm10_0: 4.0,
+            temperature: 20.0,
+            voc_index: Measurement::INDEX_UNAVAILABLE,
+            co2: 400,
+            ..Measurement::fixture()
+        };
+        let to = Measurement {
+            pm1_0: 3.0,
+            temperature: 22.0,
+            voc_index: 100.0,
+            co2: Measurement::CO2_UNAVAILABLE,
+            ..from
+        };
+        assert_eq!(
+            from.interpolate(&to, 0.5),
+            Measurement {
+                pm1_0: 2.0,
+                temperature: 21.0,
+                co2: Measurement::CO2_UNAVAILABLE,
+                ..from
+            }
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! JSON encoding of measurements, e.g. as HTTP or MQTT payload.
+//!
+//! Each measurement is rendered as one flat object with stable keys suffixed with the unit of the
+//! value. Values which are not yet available are rendered as `null`:
+//!
+//! ```json
+//! {"pm1_0_ug_m3":1.0,"pm2_5_ug_m3":2.5,"pm4_0_ug_m3":3.0,"pm10_0_ug_m3":4.0,
+//!  "relative_humidity_pct":40.0,"temperature_c":21.5,"voc_index":100.0,"nox_index":null,
+//!  "co2_ppm":420}
+//! ```
+
+use serde::Serialize;
+
+use crate::{
+    data::{Channel, Measurement, Timestamped},
+    error::{DataError, SinkError},
+    sink::MeasurementSink,
+};
+
+/// JSON object rendered for a [`Measurement`].
+#[derive(Serialize)]
+struct JsonMeasurement {
+    pm1_0_ug_m3: Option<f32>,
+    pm2_5_ug_m3: Option<f32>,
+    pm4_0_ug_m3: Option<f32>,
+    pm10_0_ug_m3: Option<f32>,
+    relative_humidity_pct: Option<f32>,
+    temperature_c: Option<f32>,
+    voc_index: Option<f32>,
+    nox_index: Option<f32>,
+    co2_ppm: Option<u16>,
+}
+
+impl From<&Measurement> for JsonMeasurement {
+    fn from(measurement: &Measurement) -> Self {
+        Self {
+            pm1_0_ug_m3: Channel::Pm1_0.value(measurement),
+            pm2_5_ug_m3: Channel::Pm2_5.value(measurement),
+            pm4_0_ug_m3: Channel::Pm4_0.value(measurement),
+            pm10_0_ug_m3: Channel::Pm10_0.value(measurement),
+            relative_humidity_pct: Channel::RelativeHumidity.value(measurement),
+            temperature_c: Channel::Temperature.value(measurement),
+            voc_index: Channel::VocIndex.value(measurement),
+            nox_index: Channel::NoxIndex.value(measurement),
+            co2_ppm: (measurement.co2 != Measurement::CO2_UNAVAILABLE).then_some(measurement.co2),
+        }
+    }
+}
+
+impl Measurement {
+    /// Renders the measurement as JSON object into `buffer` and returns the number of bytes
+    /// written. See the [`json`](crate::json) module for the keys.
+    ///
+    /// # Errors
+    ///
+    /// - [`BufferTooSmall`](crate::error::DataError::BufferTooSmall): If the rendered object
+    ///   does not fit into `buffer`.
+    pub fn write_json(&self, buffer: &mut [u8]) -> Result<usize, DataError> {
+        serde_json_core::to_slice(&JsonMeasurement::from(self), buffer)
+            .map_err(|_| DataError::BufferTooSmall)
+    }
+}
+
+/// [`MeasurementSink`] rendering each measurement as JSON object into a buffer and handing the
+/// rendered bytes to `transport`, e.g. a closure posting them to an HTTP endpoint. The timestamp
+/// of the measurement is monotonic and therefore not rendered.
+#[derive(Debug)]
+pub struct JsonSink<'a, F> {
+    buffer: &'a mut [u8],
+    transport: F,
+}
+
+impl<'a, F> JsonSink<'a, F> {
+    /// Creates a sink rendering the measurements into `buffer`.
+    pub fn new(buffer: &'a mut [u8], transport: F) -> Self {
+        Self { buffer, transport }
+    }
+}
+
+impl<F, E> MeasurementSink for JsonSink<'_, F>
+where
+    F: FnMut(&[u8]) -> Result<(), E>,
+{
+    type Error = SinkError<E>;
+
+    fn publish(&mut self, measurement: &Timestamped<Measurement>) -> Result<(), Self::Error> {
+        let len = measurement.value.write_json(self.buffer)?;
+        (self.transport)(&self.buffer[..len]).map_err(SinkError::Transport)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement() -> Measurement {
+        Measurement {
+            pm2_5: 2.5,
+            pm4_0: 3.0,
+            pm10_0: 4.0,
+            temperature: 21.5,
+            nox_index: Measurement::INDEX_UNAVAILABLE,
+            ..Measurement::fixture()
+        }
+    }
+
+    #[test]
+    fn measurement_is_rendered_with_unit_suffixed_keys() {
+        let mut buffer = [0; 256];
+        let len = measurement().write_json(&mut buffer).unwrap();
+        assert_eq!(
+            core::str::from_utf8(&buffer[..len]).unwrap(),
+            "{\"pm1_0_ug_m3\":1.0,\"pm2_5_ug_m3\":2.5,\"pm4_0_ug_m3\":3.0,\"pm10_0_ug_m3\":4.0,\
+             \"relative_humidity_pct\":40.0,\"temperature_c\":21.5,\"voc_index\":100.0,\
+             \"nox_index\":null,\"co2_ppm\":420}"
+        );
+        assert_eq!(
+            measurement().write_json(&mut buffer[..16]),
+            Err(DataError::BufferTooSmall)
+        );
+    }
+
+    #[test]
+    fn json_sink_publishes_rendered_object() {
+        let mut buffer = [0; 256];
+        let mut published = Vec::new();
+        JsonSink::new(&mut buffer, |rendered: &[u8]| -> Result<(), ()> {
+            published.push(rendered.to_vec());
+            Ok(())
+        })
+        .publish(&Timestamped::new(0, measurement()))
+        .unwrap();
+
+        let mut expected = [0; 256];
+        let len = measurement().write_json(&mut expected).unwrap();
+        assert_eq!(published, [&expected[..len]]);
+    }
+}
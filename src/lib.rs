@@ -1,13 +1,51 @@
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 #![doc = include_str!("../README.md")]
 #![deny(missing_docs)]
+#![forbid(unsafe_code)]
 
+#[cfg(feature = "scan")]
+extern crate alloc;
+
+pub mod alarms;
+pub mod analysis;
+pub mod aqi;
 pub mod command;
+pub mod conditioning;
 pub mod configuration;
+pub mod correction;
+pub mod csv;
 pub mod data;
+#[cfg(feature = "derived")]
+pub mod derived;
 pub mod error;
+pub mod exposure;
+pub mod filter;
+#[cfg(feature = "gas-index")]
+pub mod gas_index;
 mod interface;
+pub mod interpolation;
+#[cfg(feature = "serde-json-core")]
+pub mod json;
+pub mod maintenance;
+pub mod model;
+#[cfg(feature = "postcard")]
+pub mod postcard;
+pub mod prometheus;
+#[cfg(feature = "psychrometrics")]
+pub mod psychrometrics;
+pub mod report;
+#[cfg(feature = "scan")]
+pub mod scan;
+#[cfg(feature = "senml")]
+pub mod senml;
+#[cfg(feature = "shared")]
+pub mod shared;
+pub mod sink;
+#[cfg(feature = "storage")]
+pub mod storage;
 mod util;
+#[cfg(feature = "embedded-graphics")]
+pub mod widgets;
 
 #[cfg(feature = "async")]
 /// Async interface for the SEN66
@@ -0,0 +1,84 @@
+/// Minimum time between stopping and restarting a measurement in ms.
+pub(crate) const STOP_COOLDOWN_MS: u32 = 1_000;
+/// Minimum time between starting the fan cleaning and starting a measurement in ms.
+#[cfg(feature = "maintenance")]
+pub(crate) const FAN_CLEANING_COOLDOWN_MS: u32 = 10_000;
+/// Minimum time between activating the SHT heater and starting a measurement in ms, allowing the
+/// heat to dissipate.
+#[cfg(feature = "maintenance")]
+pub(crate) const HEATER_COOLDOWN_MS: u32 = 20_000;
+
+/// Behavior of the driver when a measurement is started during the cooldown following stopping a
+/// measurement, cleaning the fan or activating the SHT heater.
+#[cfg(feature = "cooldown")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CooldownPolicy {
+    /// Wait for the cooldown to pass before sending the command.
+    #[default]
+    Wait,
+    /// Return [`CooldownActive`](crate::error::Sen66Error::CooldownActive) without sending the
+    /// command.
+    Reject,
+}
+
+/// Tracks the end of the cooldown after timing-sensitive commands.
+#[cfg(feature = "cooldown")]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct Cooldown {
+    policy: CooldownPolicy,
+    until_ms: Option<u64>,
+}
+
+#[cfg(feature = "cooldown")]
+impl Cooldown {
+    pub(crate) const fn new() -> Self {
+        Self {
+            policy: CooldownPolicy::Wait,
+            until_ms: None,
+        }
+    }
+
+    pub(crate) fn set_policy(&mut self, policy: CooldownPolicy) {
+        self.policy = policy;
+    }
+
+    pub(crate) fn policy(&self) -> CooldownPolicy {
+        self.policy
+    }
+
+    /// Starts a cooldown of `cooldown_ms` after a command completed at `now_ms`, extending an
+    /// ongoing cooldown.
+    pub(crate) fn start(&mut self, now_ms: Option<u64>, cooldown_ms: u32) {
+        if let Some(now_ms) = now_ms {
+            let until_ms = now_ms + u64::from(cooldown_ms);
+            self.until_ms = Some(self.until_ms.map_or(until_ms, |ms| ms.max(until_ms)));
+        }
+    }
+
+    /// Returns the remaining cooldown at `now_ms`.
+    pub(crate) fn remaining(&self, now_ms: Option<u64>) -> u32 {
+        match (now_ms, self.until_ms) {
+            (Some(now_ms), Some(until_ms)) => until_ms.saturating_sub(now_ms) as u32,
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "cooldown"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cooldowns_extend_each_other() {
+        let mut cooldown = Cooldown::new();
+        cooldown.start(None, 20_000);
+        assert_eq!(cooldown.remaining(Some(0)), 0);
+        cooldown.start(Some(1_000), 20_000);
+        cooldown.start(Some(2_000), STOP_COOLDOWN_MS);
+        assert_eq!(cooldown.remaining(Some(11_000)), 10_000);
+        assert_eq!(cooldown.remaining(Some(21_000)), 0);
+        assert_eq!(cooldown.remaining(None), 0);
+    }
+}
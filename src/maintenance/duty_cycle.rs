@@ -0,0 +1,161 @@
+use crate::{data::Measurement, error::DataError, util::check_range};
+
+/// Configuration for running measurements in a duty cycle, extending the lifetime of the fan and
+/// the PM sensor's laser. Use with [`run_duty_cycle`](crate::asynch::Sen66::run_duty_cycle).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DutyCycle {
+    on_s: u16,
+    off_s: u16,
+    discard_s: u16,
+}
+
+impl DutyCycle {
+    /// Creates a duty cycle configuration.
+    /// - `on_s`: Duration of the measurement in s. At least 1 s.
+    /// - `off_s`: Duration the sensor idles after the measurement in s.
+    /// - `discard_s`: Duration at the start of the measurement in s, whose readings are discarded
+    ///   while the PM readings stabilize. Must be shorter than `on_s`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValueOutOfRange`](crate::error::DataError::ValueOutOfRange): If `on_s` is 0 or
+    ///   `discard_s` is not shorter than `on_s`.
+    pub fn new(on_s: u16, off_s: u16, discard_s: u16) -> Result<Self, DataError> {
+        check_range(on_s, 1, u16::MAX, "On Period", "s")?;
+        check_range(discard_s, 0, on_s - 1, "Stabilization Period", "s")?;
+        Ok(Self {
+            on_s,
+            off_s,
+            discard_s,
+        })
+    }
+
+    /// Returns the duration of the measurement in s.
+    pub fn on_s(&self) -> u16 {
+        self.on_s
+    }
+
+    /// Returns the duration the sensor idles after the measurement in s.
+    pub fn off_s(&self) -> u16 {
+        self.off_s
+    }
+
+    /// Returns the duration at the start of the measurement whose readings are discarded in s.
+    pub fn discard_s(&self) -> u16 {
+        self.discard_s
+    }
+}
+
+impl Default for DutyCycle {
+    /// Returns a duty cycle measuring for 30 s, discarding the first 10 s, and idling for 120 s.
+    fn default() -> Self {
+        Self {
+            on_s: 30,
+            off_s: 120,
+            discard_s: 10,
+        }
+    }
+}
+
+/// Average of the PM mass concentrations over multiple measurements.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PmAverage {
+    sums: [f32; 4],
+    samples: u32,
+}
+
+impl PmAverage {
+    /// Creates an empty average.
+    pub const fn new() -> Self {
+        Self {
+            sums: [0.0; 4],
+            samples: 0,
+        }
+    }
+
+    /// Adds the PM readings of the `measurement` to the average.
+    pub fn add(&mut self, measurement: &Measurement) {
+        let values = [
+            measurement.pm1_0,
+            measurement.pm2_5,
+            measurement.pm4_0,
+            measurement.pm10_0,
+        ];
+        for (sum, value) in self.sums.iter_mut().zip(values) {
+            *sum += value;
+        }
+        self.samples += 1;
+    }
+
+    /// Returns the number of averaged measurements.
+    pub fn samples(&self) -> u32 {
+        self.samples
+    }
+
+    /// Returns the average mass concentration for PM1.0 in ug/m³ or `None` if empty.
+    pub fn pm1_0(&self) -> Option<f32> {
+        self.mean(0)
+    }
+
+    /// Returns the average mass concentration for PM2.5 in ug/m³ or `None` if empty.
+    pub fn pm2_5(&self) -> Option<f32> {
+        self.mean(1)
+    }
+
+    /// Returns the average mass concentration for PM4.0 in ug/m³ or `None` if empty.
+    pub fn pm4_0(&self) -> Option<f32> {
+        self.mean(2)
+    }
+
+    /// Returns the average mass concentration for PM10.0 in ug/m³ or `None` if empty.
+    pub fn pm10_0(&self) -> Option<f32> {
+        self.mean(3)
+    }
+
+    fn mean(&self, index: usize) -> Option<f32> {
+        (self.samples > 0).then(|| self.sums[index] / self.samples as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(pm: f32) -> Measurement {
+        Measurement {
+            pm1_0: pm,
+            pm2_5: pm * 2.0,
+            pm4_0: pm * 3.0,
+            pm10_0: pm * 4.0,
+            ..Measurement::fixture()
+        }
+    }
+
+    #[test]
+    fn duty_cycle_checks_periods() {
+        assert!(DutyCycle::new(30, 120, 29).is_ok());
+        assert!(DutyCycle::new(0, 120, 0).is_err());
+        assert_eq!(
+            DutyCycle::new(30, 120, 30),
+            Err(DataError::ValueOutOfRange {
+                parameter: "Stabilization Period",
+                min: 0,
+                max: 29,
+                unit: "s"
+            })
+        );
+    }
+
+    #[test]
+    fn pm_average_averages_readings() {
+        let mut average = PmAverage::new();
+        assert_eq!(average.pm2_5(), None);
+        average.add(&measurement(1.0));
+        average.add(&measurement(3.0));
+        assert_eq!(average.samples(), 2);
+        assert_eq!(average.pm1_0(), Some(2.0));
+        assert_eq!(average.pm2_5(), Some(4.0));
+        assert_eq!(average.pm4_0(), Some(6.0));
+        assert_eq!(average.pm10_0(), Some(8.0));
+    }
+}
@@ -0,0 +1,151 @@
+use crate::data::DeviceStatusRegister;
+
+/// Time after starting a measurement during which fan-speed warnings are considered transients
+/// of the fan spinning up in s.
+const STARTUP_GRACE_S: u32 = 60;
+/// Number of consecutive fan-speed warnings after which the warning is considered persistent.
+const PERSISTENT_WARNINGS: u32 = 10;
+
+/// Assessment of the fan's condition by [`FanTrend`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FanAssessment {
+    /// No persistent fan-speed warnings are present.
+    Healthy,
+    /// Fan-speed warnings persist, cleaning the fan via
+    /// [`start_fan_cleaning`](crate::asynch::Sen66::start_fan_cleaning) is recommended.
+    CleaningRecommended,
+    /// Fan-speed warnings persist despite cleaning or a fan error is present, the fan is degraded.
+    FanDegraded,
+}
+
+/// Tracks the fan-speed warning of the [`DeviceStatusRegister`] over time. Warnings shortly after
+/// starting a measurement are ignored as startup transients, while persistent warnings lead to
+/// a cleaning recommendation. If warnings persist after a cleaning, the fan is assessed as
+/// degraded.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FanTrend {
+    consecutive_warnings: u32,
+    total_warnings: u32,
+    cleaned: bool,
+    fan_error: bool,
+}
+
+impl FanTrend {
+    /// Creates a tracker for a healthy fan.
+    pub const fn new() -> Self {
+        Self {
+            consecutive_warnings: 0,
+            total_warnings: 0,
+            cleaned: false,
+            fan_error: false,
+        }
+    }
+
+    /// Records a device `status` read `measuring_s` seconds after starting the measurement.
+    pub fn update(&mut self, status: &DeviceStatusRegister, measuring_s: u32) {
+        self.fan_error |= status.fan_error();
+        if measuring_s < STARTUP_GRACE_S {
+            return;
+        }
+        if status.fan_speed_warning() {
+            self.consecutive_warnings = self.consecutive_warnings.saturating_add(1);
+            self.total_warnings = self.total_warnings.saturating_add(1);
+        } else {
+            self.consecutive_warnings = 0;
+        }
+    }
+
+    /// Records that the fan has been cleaned. Warnings persisting afterwards indicate a degraded
+    /// fan. A cleaning without persistent warnings, e.g. a repeated one, keeps the outcome of a
+    /// previous cleaning.
+    pub fn record_cleaning(&mut self) {
+        self.cleaned |= self.consecutive_warnings >= PERSISTENT_WARNINGS;
+        self.consecutive_warnings = 0;
+    }
+
+    /// Returns the number of fan-speed warnings recorded outside of startup transients.
+    pub fn total_warnings(&self) -> u32 {
+        self.total_warnings
+    }
+
+    /// Returns the current assessment of the fan.
+    pub fn assessment(&self) -> FanAssessment {
+        let persistent = self.consecutive_warnings >= PERSISTENT_WARNINGS;
+        if self.fan_error || (persistent && self.cleaned) {
+            FanAssessment::FanDegraded
+        } else if persistent {
+            FanAssessment::CleaningRecommended
+        } else {
+            FanAssessment::Healthy
+        }
+    }
+}
+
+impl Default for FanTrend {
+    /// Returns a tracker for a healthy fan.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WARNING: [u8; 6] = [0x00, 0x20, 0x07, 0x00, 0x00, 0x81];
+    const NO_WARNING: [u8; 6] = [0x00, 0x00, 0x81, 0x00, 0x00, 0x81];
+    const FAN_ERROR: [u8; 6] = [0x00, 0x00, 0x81, 0x00, 0x10, 0xC2];
+
+    fn status(data: &[u8; 6]) -> DeviceStatusRegister {
+        DeviceStatusRegister::try_from(&data[..]).unwrap()
+    }
+
+    #[test]
+    fn fan_trend_ignores_startup_transients() {
+        let mut trend = FanTrend::new();
+        for second in 0..STARTUP_GRACE_S {
+            trend.update(&status(&WARNING), second);
+        }
+        assert_eq!(trend.total_warnings(), 0);
+        assert_eq!(trend.assessment(), FanAssessment::Healthy);
+    }
+
+    #[test]
+    fn fan_trend_recommends_cleaning_then_degrades() {
+        let mut trend = FanTrend::new();
+        for second in 0..PERSISTENT_WARNINGS {
+            trend.update(&status(&WARNING), STARTUP_GRACE_S + second);
+        }
+        assert_eq!(trend.assessment(), FanAssessment::CleaningRecommended);
+
+        trend.record_cleaning();
+        assert_eq!(trend.assessment(), FanAssessment::Healthy);
+        trend.update(&status(&NO_WARNING), STARTUP_GRACE_S);
+        for second in 0..PERSISTENT_WARNINGS {
+            trend.update(&status(&WARNING), STARTUP_GRACE_S + second);
+        }
+        assert_eq!(trend.assessment(), FanAssessment::FanDegraded);
+    }
+
+    #[test]
+    fn fan_trend_keeps_cleaning_on_repeated_cleaning() {
+        let mut trend = FanTrend::new();
+        for second in 0..PERSISTENT_WARNINGS {
+            trend.update(&status(&WARNING), STARTUP_GRACE_S + second);
+        }
+        trend.record_cleaning();
+        trend.record_cleaning();
+        for second in 0..PERSISTENT_WARNINGS {
+            trend.update(&status(&WARNING), STARTUP_GRACE_S + second);
+        }
+        assert_eq!(trend.assessment(), FanAssessment::FanDegraded);
+    }
+
+    #[test]
+    fn fan_trend_degrades_on_fan_error() {
+        let mut trend = FanTrend::new();
+        trend.update(&status(&FAN_ERROR), 0);
+        assert_eq!(trend.assessment(), FanAssessment::FanDegraded);
+    }
+}
@@ -0,0 +1,96 @@
+/// Presence of the sensor reported by the hot-plug supervision, see
+/// [`supervise`](crate::asynch::Sen66::supervise).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum HotPlugEvent {
+    /// The sensor is present.
+    Present,
+    /// The sensor did not acknowledge its address repeatedly and is considered unplugged.
+    Absent,
+    /// The sensor reappeared and has been re-initialized.
+    Reconnected,
+}
+
+/// Tracks address NACKs of the sensor to detect it being unplugged.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct HotPlugMonitor {
+    nack_limit: u8,
+    nack_streak: u8,
+    absent: bool,
+    resume_measurement: bool,
+}
+
+impl HotPlugMonitor {
+    pub(crate) const fn new() -> Self {
+        Self {
+            nack_limit: 0,
+            nack_streak: 0,
+            absent: false,
+            resume_measurement: false,
+        }
+    }
+
+    /// Sets the number of consecutive address NACKs after which the sensor is considered absent.
+    /// `0` disables the detection.
+    pub(crate) fn set_nack_limit(&mut self, nack_limit: u8) {
+        *self = Self {
+            nack_limit,
+            ..Self::new()
+        };
+    }
+
+    pub(crate) fn nack_limit(&self) -> u8 {
+        self.nack_limit
+    }
+
+    /// Records the outcome of a bus transfer. `measuring` tells whether the measurement is to be
+    /// resumed once the sensor reappears.
+    pub(crate) fn record(&mut self, address_nack: bool, measuring: bool) {
+        if !address_nack {
+            self.nack_streak = 0;
+            return;
+        }
+        self.nack_streak = self.nack_streak.saturating_add(1);
+        if self.nack_limit > 0 && self.nack_streak >= self.nack_limit && !self.absent {
+            self.absent = true;
+            self.resume_measurement = measuring;
+        }
+    }
+
+    pub(crate) fn is_absent(&self) -> bool {
+        self.absent
+    }
+
+    /// Marks the sensor as present again and returns whether the measurement is to be resumed.
+    pub(crate) fn reconnect(&mut self) -> bool {
+        let resume = self.resume_measurement;
+        self.set_nack_limit(self.nack_limit);
+        resume
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consecutive_nacks_mark_sensor_absent() {
+        let mut monitor = HotPlugMonitor::new();
+        monitor.record(true, true);
+        monitor.record(true, true);
+        assert!(!monitor.is_absent());
+
+        monitor.set_nack_limit(2);
+        monitor.record(true, true);
+        monitor.record(false, true);
+        monitor.record(true, true);
+        assert!(!monitor.is_absent());
+        monitor.record(true, true);
+        assert!(monitor.is_absent());
+        monitor.record(true, false);
+        assert!(monitor.reconnect());
+        assert!(!monitor.is_absent());
+        assert_eq!(monitor.nack_limit(), 2);
+    }
+}
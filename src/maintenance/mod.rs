@@ -0,0 +1,43 @@
+//! Helpers for operating and maintaining the sensor over its lifetime.
+
+mod cooldown;
+mod duty_cycle;
+mod fan_trend;
+#[cfg(feature = "hot-plug")]
+mod hot_plug;
+mod power;
+#[cfg(feature = "rate-limit")]
+mod rate_limit;
+#[cfg(feature = "reset-recovery")]
+mod reset;
+mod runtime;
+#[cfg(feature = "maintenance-schedule")]
+mod schedule;
+mod snapshot;
+mod summary;
+
+#[cfg(feature = "cooldown")]
+pub(crate) use cooldown::Cooldown;
+#[cfg(feature = "cooldown")]
+pub use cooldown::CooldownPolicy;
+pub(crate) use cooldown::STOP_COOLDOWN_MS;
+#[cfg(feature = "maintenance")]
+pub(crate) use cooldown::{FAN_CLEANING_COOLDOWN_MS, HEATER_COOLDOWN_MS};
+pub use duty_cycle::{DutyCycle, PmAverage};
+pub use fan_trend::{FanAssessment, FanTrend};
+#[cfg(feature = "hot-plug")]
+pub use hot_plug::HotPlugEvent;
+#[cfg(feature = "hot-plug")]
+pub(crate) use hot_plug::HotPlugMonitor;
+pub use power::PowerEstimator;
+#[cfg(feature = "rate-limit")]
+pub(crate) use rate_limit::RateLimiter;
+#[cfg(feature = "reset-recovery")]
+pub use reset::ResetCause;
+#[cfg(feature = "reset-recovery")]
+pub(crate) use reset::ResetMonitor;
+pub use runtime::RuntimeCounters;
+#[cfg(feature = "maintenance-schedule")]
+pub use schedule::{MaintenanceSchedule, MaintenanceTask};
+pub use snapshot::DriverSnapshot;
+pub use summary::DriverSummary;
@@ -0,0 +1,78 @@
+use crate::maintenance::DutyCycle;
+
+/// Typical supply current in idle state in mA.
+const IDLE_CURRENT_MA: f32 = 2.6;
+/// Typical supply current in measuring state in mA.
+const MEASURING_CURRENT_MA: f32 = 70.0;
+/// Typical supply voltage in V.
+const SUPPLY_VOLTAGE_V: f32 = 3.3;
+
+/// Estimates the power consumption of the sensor operated in a [`DutyCycle`], e.g. to trade
+/// sampling cadence against battery life. Transitions between the states are not accounted for.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PowerEstimator {
+    idle_ma: f32,
+    measuring_ma: f32,
+    supply_v: f32,
+}
+
+impl PowerEstimator {
+    /// Creates an estimator from measured or datasheet figures.
+    /// - `idle_ma`: Supply current in idle state in mA.
+    /// - `measuring_ma`: Supply current in measuring state in mA.
+    /// - `supply_v`: Supply voltage in V.
+    pub const fn new(idle_ma: f32, measuring_ma: f32, supply_v: f32) -> Self {
+        Self {
+            idle_ma,
+            measuring_ma,
+            supply_v,
+        }
+    }
+
+    /// Returns the average supply current over the `cycle` in mA.
+    pub fn average_current_ma(&self, cycle: &DutyCycle) -> f32 {
+        let on_s = f32::from(cycle.on_s());
+        let off_s = f32::from(cycle.off_s());
+        (on_s * self.measuring_ma + off_s * self.idle_ma) / (on_s + off_s)
+    }
+
+    /// Returns the average power consumption over the `cycle` in mW.
+    pub fn average_power_mw(&self, cycle: &DutyCycle) -> f32 {
+        self.average_current_ma(cycle) * self.supply_v
+    }
+
+    /// Returns the energy consumed during one `cycle` in mJ.
+    pub fn energy_per_cycle_mj(&self, cycle: &DutyCycle) -> f32 {
+        let duration_s = f32::from(cycle.on_s()) + f32::from(cycle.off_s());
+        self.average_power_mw(cycle) * duration_s
+    }
+
+    /// Returns the estimated runtime in h when supplied by a battery with a capacity of
+    /// `capacity_mah` mAh.
+    pub fn battery_life_h(&self, cycle: &DutyCycle, capacity_mah: f32) -> f32 {
+        capacity_mah / self.average_current_ma(cycle)
+    }
+}
+
+impl Default for PowerEstimator {
+    /// Returns an estimator using the typical figures of the datasheet: 2.6 mA in idle state and
+    /// 70 mA in measuring state at a supply of 3.3 V.
+    fn default() -> Self {
+        Self::new(IDLE_CURRENT_MA, MEASURING_CURRENT_MA, SUPPLY_VOLTAGE_V)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn power_estimator_weights_states_by_duration() {
+        let estimator = PowerEstimator::new(2.0, 70.0, 3.0);
+        let cycle = DutyCycle::new(30, 90, 0).unwrap();
+        assert_eq!(estimator.average_current_ma(&cycle), 19.0);
+        assert_eq!(estimator.average_power_mw(&cycle), 57.0);
+        assert_eq!(estimator.energy_per_cycle_mj(&cycle), 6_840.0);
+        assert_eq!(estimator.battery_life_h(&cycle, 1_900.0), 100.0);
+    }
+}
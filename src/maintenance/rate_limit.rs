@@ -0,0 +1,98 @@
+/// Enforces the execution time of a command before the next one is sent and caps the frequency
+/// of data-ready polls.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct RateLimiter {
+    poll_interval_ms: u32,
+    last_poll_ms: Option<u64>,
+    polled: bool,
+    busy_until_ms: Option<u64>,
+}
+
+impl RateLimiter {
+    pub(crate) const fn new() -> Self {
+        Self {
+            poll_interval_ms: 0,
+            last_poll_ms: None,
+            polled: false,
+            busy_until_ms: None,
+        }
+    }
+
+    /// Sets the minimum interval between two data-ready polls. `0` disables the cap.
+    pub(crate) fn set_poll_interval(&mut self, interval_ms: u32) {
+        self.poll_interval_ms = interval_ms;
+    }
+
+    pub(crate) fn poll_interval(&self) -> u32 {
+        self.poll_interval_ms
+    }
+
+    /// Returns the time to wait before the next data-ready poll at `now_ms` and records the poll.
+    /// Without a time source, every poll but the first waits for the full interval.
+    pub(crate) fn poll(&mut self, now_ms: Option<u64>) -> u32 {
+        let interval_ms = u64::from(self.poll_interval_ms);
+        let wait_ms = match (now_ms, self.last_poll_ms) {
+            (Some(now_ms), Some(last_ms)) => (last_ms + interval_ms).saturating_sub(now_ms),
+            (Some(_), None) => 0,
+            (None, _) if self.polled => interval_ms,
+            (None, _) => 0,
+        };
+        self.polled = true;
+        self.last_poll_ms = now_ms.map(|now_ms| now_ms + wait_ms);
+        wait_ms as u32
+    }
+
+    /// Records a command sent at `now_ms` with its `execution_time_ms`.
+    pub(crate) fn command_sent(&mut self, now_ms: Option<u64>, execution_time_ms: u32) {
+        self.busy_until_ms = now_ms.map(|now_ms| now_ms + u64::from(execution_time_ms));
+    }
+
+    /// Records that the execution time of the previous command was waited for.
+    pub(crate) fn command_completed(&mut self) {
+        self.busy_until_ms = None;
+    }
+
+    /// Returns the remaining execution time of the previous command at `now_ms`. Only non-zero if
+    /// waiting for the execution time was cancelled.
+    pub(crate) fn busy(&self, now_ms: Option<u64>) -> u32 {
+        match (now_ms, self.busy_until_ms) {
+            (Some(now_ms), Some(busy_until_ms)) => busy_until_ms.saturating_sub(now_ms) as u32,
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn polls_are_spaced_by_interval() {
+        let mut limiter = RateLimiter::new();
+        assert_eq!(limiter.poll(Some(0)), 0);
+        assert_eq!(limiter.poll(Some(1)), 0);
+
+        limiter.set_poll_interval(100);
+        assert_eq!(limiter.poll(Some(10)), 91);
+        assert_eq!(limiter.poll(Some(150)), 51);
+        assert_eq!(limiter.poll(Some(500)), 0);
+
+        let mut limiter = RateLimiter::new();
+        limiter.set_poll_interval(100);
+        assert_eq!(limiter.poll(None), 0);
+        assert_eq!(limiter.poll(None), 100);
+    }
+
+    #[test]
+    fn cancelled_execution_time_is_waited_for() {
+        let mut limiter = RateLimiter::new();
+        limiter.command_sent(None, 20);
+        assert_eq!(limiter.busy(Some(0)), 0);
+        limiter.command_sent(Some(100), 1_000);
+        assert_eq!(limiter.busy(Some(600)), 500);
+        assert_eq!(limiter.busy(Some(1_200)), 0);
+        limiter.command_sent(Some(100), 1_000);
+        limiter.command_completed();
+        assert_eq!(limiter.busy(Some(600)), 0);
+    }
+}
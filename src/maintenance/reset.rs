@@ -0,0 +1,145 @@
+use crate::data::Measurement;
+
+/// Time without new data after which the data ready flag is considered stuck in milliseconds.
+/// Five times the measurement interval.
+const DATA_READY_STUCK_MS: u64 = 5_000;
+
+/// Symptom by which an uncommanded sensor reset, e.g. due to a brown-out, was detected, see
+/// [`recover_from_reset`](crate::asynch::Sen66::recover_from_reset).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ResetCause {
+    /// The CO2 concentration became unavailable again after valid values were reported during
+    /// the same measurement.
+    Co2Unavailable,
+    /// No new data became available for several measurement intervals while measuring.
+    DataReadyStuck,
+    /// Error flags of the device status register disappeared without being cleared.
+    StatusCleared,
+}
+
+/// Watches the sensor's responses for symptoms of an uncommanded reset.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct ResetMonitor {
+    co2_seen: bool,
+    ready_since_ms: Option<u64>,
+    errors_latched: bool,
+    suspected: Option<ResetCause>,
+}
+
+impl ResetMonitor {
+    pub(crate) const fn new() -> Self {
+        Self {
+            co2_seen: false,
+            ready_since_ms: None,
+            errors_latched: false,
+            suspected: None,
+        }
+    }
+
+    /// Records the start of a measurement at `now_ms`, if a time source is configured.
+    pub(crate) fn started(&mut self, now_ms: Option<u64>) {
+        self.co2_seen = false;
+        self.ready_since_ms = now_ms;
+    }
+
+    /// Records the end of a measurement.
+    pub(crate) fn stopped(&mut self) {
+        self.co2_seen = false;
+        self.ready_since_ms = None;
+    }
+
+    /// Records the raw CO2 concentration of a measurement.
+    pub(crate) fn record_co2(&mut self, co2: u16) {
+        if co2 != Measurement::CO2_UNAVAILABLE {
+            self.co2_seen = true;
+        } else if self.co2_seen {
+            self.suspect(ResetCause::Co2Unavailable);
+        }
+    }
+
+    /// Records the data ready flag read at `now_ms`, if a time source is configured.
+    pub(crate) fn record_data_ready(&mut self, ready: bool, now_ms: Option<u64>) {
+        let Some(now_ms) = now_ms else {
+            return;
+        };
+        match self.ready_since_ms {
+            _ if ready => self.ready_since_ms = Some(now_ms),
+            Some(since_ms) if now_ms.saturating_sub(since_ms) > DATA_READY_STUCK_MS => {
+                self.suspect(ResetCause::DataReadyStuck);
+            }
+            Some(_) => {}
+            None => self.ready_since_ms = Some(now_ms),
+        }
+    }
+
+    /// Records whether the device status register reported sticky error flags.
+    pub(crate) fn record_status(&mut self, has_error: bool) {
+        if self.errors_latched && !has_error {
+            self.suspect(ResetCause::StatusCleared);
+        }
+        self.errors_latched = has_error;
+    }
+
+    /// Records that the device status register was cleared on purpose.
+    pub(crate) fn status_cleared(&mut self) {
+        self.errors_latched = false;
+    }
+
+    fn suspect(&mut self, cause: ResetCause) {
+        self.suspected.get_or_insert(cause);
+    }
+
+    /// Returns the cause of a suspected reset, resetting the monitor.
+    pub(crate) fn take(&mut self) -> Option<ResetCause> {
+        let cause = self.suspected;
+        if cause.is_some() {
+            *self = Self::new();
+        }
+        cause
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn co2_dropping_out_is_suspicious() {
+        let mut monitor = ResetMonitor::new();
+        monitor.started(None);
+        monitor.record_co2(Measurement::CO2_UNAVAILABLE);
+        assert_eq!(monitor.take(), None);
+        monitor.record_co2(600);
+        monitor.record_co2(Measurement::CO2_UNAVAILABLE);
+        assert_eq!(monitor.take(), Some(ResetCause::Co2Unavailable));
+        assert_eq!(monitor.take(), None);
+    }
+
+    #[test]
+    fn stuck_data_ready_is_suspicious() {
+        let mut monitor = ResetMonitor::new();
+        monitor.record_data_ready(false, None);
+        monitor.started(Some(0));
+        monitor.record_data_ready(false, Some(4_000));
+        monitor.record_data_ready(true, Some(5_000));
+        monitor.record_data_ready(false, Some(10_000));
+        assert_eq!(monitor.take(), None);
+        monitor.record_data_ready(false, Some(10_001));
+        assert_eq!(monitor.take(), Some(ResetCause::DataReadyStuck));
+    }
+
+    #[test]
+    fn vanishing_error_flags_are_suspicious() {
+        let mut monitor = ResetMonitor::new();
+        monitor.record_status(true);
+        monitor.status_cleared();
+        monitor.record_status(false);
+        assert_eq!(monitor.take(), None);
+        monitor.record_status(true);
+        monitor.record_status(true);
+        monitor.record_status(false);
+        assert_eq!(monitor.take(), Some(ResetCause::StatusCleared));
+    }
+}
@@ -0,0 +1,135 @@
+#[cfg(feature = "storage")]
+use embedded_storage::{ReadStorage, Storage};
+
+use crate::{error::DataError, util::ByteReader};
+#[cfg(feature = "storage")]
+use crate::{
+    error::StorageError,
+    storage::{read_record, write_record},
+};
+
+/// Accumulated operating counters of a sensor, maintained by the driver. Several maintenance
+/// tasks, e.g. fan cleaning intervals or the fan's lifetime, depend on them. Persist them across
+/// power cycles by saving them to an `embedded_storage::Storage` with the `storage` feature, or
+/// using [`to_bytes`](RuntimeCounters::to_bytes) and
+/// [`TryFrom<&[u8]>`](RuntimeCounters::try_from). Hand the restored counters to
+/// [`restore_runtime_counters`](crate::asynch::Sen66::restore_runtime_counters).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RuntimeCounters {
+    /// Accumulated time spent in measuring state in ms.
+    pub measuring_time_ms: u64,
+    /// Number of measurements read via
+    /// [`read_measured_values`](crate::asynch::Sen66::read_measured_values).
+    pub samples: u64,
+}
+
+impl RuntimeCounters {
+    /// Size of the persisted representation in bytes.
+    pub const SERIALIZED_SIZE: usize = 16;
+
+    /// Returns the accumulated time spent in measuring state in s.
+    pub fn measuring_time_s(&self) -> u64 {
+        self.measuring_time_ms / 1_000
+    }
+
+    /// Returns the persisted representation of the counters.
+    pub fn to_bytes(&self) -> [u8; Self::SERIALIZED_SIZE] {
+        let mut data = [0; Self::SERIALIZED_SIZE];
+        data[0..8].copy_from_slice(&self.measuring_time_ms.to_be_bytes());
+        data[8..16].copy_from_slice(&self.samples.to_be_bytes());
+        data
+    }
+
+    /// Saves the counters with a CRC to the `storage` at `offset`, occupying
+    /// [`SERIALIZED_SIZE`](RuntimeCounters::SERIALIZED_SIZE) + 1 bytes.
+    ///
+    /// # Errors
+    ///
+    /// - [`Flash`](crate::error::StorageError::Flash): If writing the storage fails.
+    #[cfg(feature = "storage")]
+    pub fn save<S: Storage>(
+        &self,
+        storage: &mut S,
+        offset: u32,
+    ) -> Result<(), StorageError<S::Error>> {
+        write_record(storage, offset, &self.to_bytes())
+    }
+
+    /// Loads counters saved with [`save`](RuntimeCounters::save) from the `storage` at `offset`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Flash`](crate::error::StorageError::Flash): If reading the storage fails.
+    /// - [`Data`](crate::error::StorageError::Data): If the stored data is corrupted or was never
+    ///   written.
+    #[cfg(feature = "storage")]
+    pub fn load<S: ReadStorage>(
+        storage: &mut S,
+        offset: u32,
+    ) -> Result<Self, StorageError<S::Error>> {
+        let data = read_record::<_, { Self::SERIALIZED_SIZE }>(storage, offset)?;
+        Ok(Self::try_from(&data[..])?)
+    }
+}
+
+impl TryFrom<&[u8]> for RuntimeCounters {
+    type Error = DataError;
+
+    /// Restores the counters from their persisted representation.
+    ///
+    /// # Errors
+    ///
+    /// - [`ReceivedBufferWrongSize`](crate::error::DataError::ReceivedBufferWrongSize): If the
+    ///   data is not the expected size.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let mut reader = ByteReader::new(data);
+        let counters = Self {
+            measuring_time_ms: u64::from_be_bytes(reader.take()?),
+            samples: u64::from_be_bytes(reader.take()?),
+        };
+        reader.finish()?;
+        Ok(counters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runtime_counters_roundtrip_through_bytes() {
+        let counters = RuntimeCounters {
+            measuring_time_ms: 3_600_500,
+            samples: 3_600,
+        };
+        assert_eq!(counters.measuring_time_s(), 3_600);
+        let data = counters.to_bytes();
+        assert_eq!(RuntimeCounters::try_from(&data[..]).unwrap(), counters);
+        assert_eq!(
+            RuntimeCounters::try_from(&data[..15]),
+            Err(DataError::ReceivedBufferWrongSize)
+        );
+    }
+
+    #[cfg(feature = "storage")]
+    #[test]
+    fn runtime_counters_roundtrip_through_storage() {
+        let mut storage = crate::storage::MockStorage([0xFF; 64]);
+        assert_eq!(
+            RuntimeCounters::load(&mut storage, 0),
+            Err(StorageError::Data(DataError::CrcFailed))
+        );
+        let counters = RuntimeCounters {
+            measuring_time_ms: 3_600_500,
+            samples: 3_600,
+        };
+        counters.save(&mut storage, 0).unwrap();
+        assert_eq!(RuntimeCounters::load(&mut storage, 0), Ok(counters));
+        assert_eq!(
+            RuntimeCounters::load(&mut storage, 60),
+            Err(StorageError::Flash(()))
+        );
+    }
+}
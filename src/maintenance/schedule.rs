@@ -0,0 +1,173 @@
+use crate::{error::DataError, util::ByteReader};
+
+/// Fan cleaning interval recommended by Sensirion, one week in s.
+const DEFAULT_FAN_CLEANING_INTERVAL_S: u64 = 7 * 24 * 3_600;
+
+/// Recurring maintenance task of the sensor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MaintenanceTask {
+    /// Cleaning the fan, see [`start_fan_cleaning`](crate::asynch::Sen66::start_fan_cleaning).
+    FanCleaning,
+    /// Forced recalibration of the CO2 sensor, see
+    /// [`perform_forced_co2_recalibration`](crate::asynch::Sen66::perform_forced_co2_recalibration).
+    Co2Recalibration,
+}
+
+/// Schedule of the recurring [`MaintenanceTask`]s in wall-clock time, based on the UNIX epoch
+/// time of an RTC. Unlike the [`RuntimeCounters`](crate::maintenance::RuntimeCounters), time
+/// passes while the sensor is idle or the MCU is in deep sleep. Persist it across power cycles
+/// using [`to_bytes`](MaintenanceSchedule::to_bytes) and restore it with
+/// [`TryFrom<&[u8]>`](MaintenanceSchedule::try_from) and
+/// [`set_maintenance_schedule`](crate::asynch::Sen66::set_maintenance_schedule).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MaintenanceSchedule {
+    fan_cleaning_interval_s: u64,
+    recalibration_interval_s: u64,
+    last_fan_cleaning_s: Option<u64>,
+    last_recalibration_s: Option<u64>,
+}
+
+impl MaintenanceSchedule {
+    /// Size of the persisted representation in bytes.
+    pub const SERIALIZED_SIZE: usize = 34;
+
+    /// Creates a schedule without any recorded maintenance.
+    /// - `fan_cleaning_interval_s`: Interval between two fan cleanings in s. `0` disables it.
+    /// - `recalibration_interval_s`: Interval between two forced CO2 recalibrations in s. `0`
+    ///   disables it.
+    pub const fn new(fan_cleaning_interval_s: u64, recalibration_interval_s: u64) -> Self {
+        Self {
+            fan_cleaning_interval_s,
+            recalibration_interval_s,
+            last_fan_cleaning_s: None,
+            last_recalibration_s: None,
+        }
+    }
+
+    /// Returns the interval of the `task` in s. `0` if the task is not scheduled.
+    pub fn interval_s(&self, task: MaintenanceTask) -> u64 {
+        match task {
+            MaintenanceTask::FanCleaning => self.fan_cleaning_interval_s,
+            MaintenanceTask::Co2Recalibration => self.recalibration_interval_s,
+        }
+    }
+
+    /// Returns when the `task` was last performed in s since the UNIX epoch.
+    pub fn last_performed_s(&self, task: MaintenanceTask) -> Option<u64> {
+        match task {
+            MaintenanceTask::FanCleaning => self.last_fan_cleaning_s,
+            MaintenanceTask::Co2Recalibration => self.last_recalibration_s,
+        }
+    }
+
+    /// Records that the `task` was performed at `epoch_s` in s since the UNIX epoch.
+    pub fn record(&mut self, task: MaintenanceTask, epoch_s: u64) {
+        match task {
+            MaintenanceTask::FanCleaning => self.last_fan_cleaning_s = Some(epoch_s),
+            MaintenanceTask::Co2Recalibration => self.last_recalibration_s = Some(epoch_s),
+        }
+    }
+
+    /// Returns when the `task` is due next in s since the UNIX epoch. `None` if the task is not
+    /// scheduled. A task never performed is due immediately.
+    pub fn next_due_s(&self, task: MaintenanceTask) -> Option<u64> {
+        let interval_s = self.interval_s(task);
+        (interval_s > 0).then(|| {
+            self.last_performed_s(task)
+                .map_or(0, |last_s| last_s.saturating_add(interval_s))
+        })
+    }
+
+    /// Returns the first task due at `epoch_s` in s since the UNIX epoch.
+    pub fn due(&self, epoch_s: u64) -> Option<MaintenanceTask> {
+        [
+            MaintenanceTask::FanCleaning,
+            MaintenanceTask::Co2Recalibration,
+        ]
+        .into_iter()
+        .find(|&task| self.next_due_s(task).is_some_and(|due_s| due_s <= epoch_s))
+    }
+
+    /// Returns the persisted representation of the schedule.
+    pub fn to_bytes(&self) -> [u8; Self::SERIALIZED_SIZE] {
+        let mut data = [0; Self::SERIALIZED_SIZE];
+        data[0..8].copy_from_slice(&self.fan_cleaning_interval_s.to_be_bytes());
+        data[8..16].copy_from_slice(&self.recalibration_interval_s.to_be_bytes());
+        data[16] = u8::from(self.last_fan_cleaning_s.is_some());
+        data[17..25].copy_from_slice(&self.last_fan_cleaning_s.unwrap_or_default().to_be_bytes());
+        data[25] = u8::from(self.last_recalibration_s.is_some());
+        data[26..34].copy_from_slice(&self.last_recalibration_s.unwrap_or_default().to_be_bytes());
+        data
+    }
+}
+
+impl Default for MaintenanceSchedule {
+    /// Creates a schedule cleaning the fan weekly, without scheduled recalibrations.
+    fn default() -> Self {
+        Self::new(DEFAULT_FAN_CLEANING_INTERVAL_S, 0)
+    }
+}
+
+impl TryFrom<&[u8]> for MaintenanceSchedule {
+    type Error = DataError;
+
+    /// Restores the schedule from its persisted representation.
+    ///
+    /// # Errors
+    ///
+    /// - [`ReceivedBufferWrongSize`](crate::error::DataError::ReceivedBufferWrongSize): If the
+    ///   data is not the expected size.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let mut reader = ByteReader::new(data);
+        let schedule = Self {
+            fan_cleaning_interval_s: u64::from_be_bytes(reader.take()?),
+            recalibration_interval_s: u64::from_be_bytes(reader.take()?),
+            last_fan_cleaning_s: read_timestamp(&mut reader)?,
+            last_recalibration_s: read_timestamp(&mut reader)?,
+        };
+        reader.finish()?;
+        Ok(schedule)
+    }
+}
+
+/// Reads an optional epoch timestamp, prefixed by a presence flag.
+fn read_timestamp(reader: &mut ByteReader<'_>) -> Result<Option<u64>, DataError> {
+    let [present] = reader.take()?;
+    let epoch_s = u64::from_be_bytes(reader.take()?);
+    Ok((present != 0).then_some(epoch_s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tasks_become_due_after_interval() {
+        let mut schedule = MaintenanceSchedule::new(100, 0);
+        assert_eq!(schedule.due(0), Some(MaintenanceTask::FanCleaning));
+        schedule.record(MaintenanceTask::FanCleaning, 1_000);
+        assert_eq!(
+            schedule.next_due_s(MaintenanceTask::FanCleaning),
+            Some(1_100)
+        );
+        assert_eq!(schedule.next_due_s(MaintenanceTask::Co2Recalibration), None);
+        assert_eq!(schedule.due(1_099), None);
+        assert_eq!(schedule.due(1_100), Some(MaintenanceTask::FanCleaning));
+    }
+
+    #[test]
+    fn schedule_roundtrips_through_bytes() {
+        let mut schedule = MaintenanceSchedule::default();
+        schedule.record(MaintenanceTask::Co2Recalibration, 1_700_000_000);
+        let data = schedule.to_bytes();
+        assert_eq!(MaintenanceSchedule::try_from(&data[..]).unwrap(), schedule);
+        assert_eq!(
+            MaintenanceSchedule::try_from(&data[..33]),
+            Err(DataError::ReceivedBufferWrongSize)
+        );
+    }
+}
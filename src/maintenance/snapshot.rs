@@ -0,0 +1,104 @@
+#[cfg(feature = "config-cache")]
+use crate::configuration::ConfigCache;
+#[cfg(feature = "tuning")]
+use crate::data::VocAlgorithmState;
+#[cfg(feature = "cooldown")]
+use crate::maintenance::CooldownPolicy;
+#[cfg(feature = "maintenance-schedule")]
+use crate::maintenance::MaintenanceSchedule;
+use crate::{correction::HumidityCorrection, data::SensorState, maintenance::RuntimeCounters};
+
+/// Driver state captured by [`hibernate`](crate::asynch::Sen66::hibernate), e.g. to keep it in
+/// retained memory or serialize it while the MCU is in deep sleep. Restore the driver with
+/// [`resume`](crate::asynch::Sen66::resume).
+///
+/// Captures the operating state, the cached configuration, the VOC algorithm state last read or
+/// written, the runtime counters, the maintenance schedule and the driver's settings, as far as
+/// the enabled features provide them. The time and epoch sources and correction model are
+/// references into the application and have to be configured again. Transfer buffers are handed
+/// back by `hibernate` alongside the snapshot and passed to
+/// [`resume_with_buffers`](crate::asynch::Sen66::resume_with_buffers).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DriverSnapshot {
+    pub(crate) state: SensorState,
+    pub(crate) pending_stop: bool,
+    pub(crate) counters: RuntimeCounters,
+    pub(crate) bus_gap_us: u32,
+    #[cfg(feature = "rate-limit")]
+    pub(crate) poll_rate_limit_ms: u32,
+    #[cfg(feature = "hot-plug")]
+    pub(crate) hot_plug_nack_limit: u8,
+    pub(crate) verify_writes: bool,
+    #[cfg(feature = "cooldown")]
+    pub(crate) cooldown_policy: CooldownPolicy,
+    pub(crate) humidity_correction: Option<HumidityCorrection>,
+    #[cfg(feature = "maintenance-schedule")]
+    pub(crate) schedule: MaintenanceSchedule,
+    #[cfg(feature = "tuning")]
+    pub(crate) voc_state: Option<VocAlgorithmState>,
+    #[cfg(feature = "config-cache")]
+    pub(crate) config_cache: ConfigCache,
+    #[cfg(feature = "config-cache")]
+    pub(crate) bypass_config_cache: bool,
+}
+
+impl DriverSnapshot {
+    /// Returns the state of the sensor when the snapshot was taken.
+    pub fn state(&self) -> SensorState {
+        self.state
+    }
+
+    /// Returns the runtime counters when the snapshot was taken, including the measuring time
+    /// up to then.
+    pub fn runtime_counters(&self) -> RuntimeCounters {
+        self.counters
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_roundtrips_through_postcard() {
+        #[cfg(feature = "config-cache")]
+        let mut config_cache = ConfigCache::new();
+        #[cfg(feature = "config-cache")]
+        config_cache.record(crate::configuration::Setting::TemperatureOffset(
+            crate::configuration::TemperatureOffset::new(-1, 0, 10, 2).unwrap(),
+        ));
+        let snapshot = DriverSnapshot {
+            state: SensorState::Measuring,
+            pending_stop: false,
+            counters: RuntimeCounters {
+                measuring_time_ms: 60_000,
+                samples: 60,
+            },
+            bus_gap_us: 0,
+            #[cfg(feature = "rate-limit")]
+            poll_rate_limit_ms: 100,
+            #[cfg(feature = "hot-plug")]
+            hot_plug_nack_limit: 3,
+            verify_writes: true,
+            #[cfg(feature = "cooldown")]
+            cooldown_policy: CooldownPolicy::Reject,
+            humidity_correction: None,
+            #[cfg(feature = "maintenance-schedule")]
+            schedule: MaintenanceSchedule::default(),
+            #[cfg(feature = "tuning")]
+            voc_state: None,
+            #[cfg(feature = "config-cache")]
+            config_cache,
+            #[cfg(feature = "config-cache")]
+            bypass_config_cache: false,
+        };
+
+        let mut buffer = [0; 128];
+        let encoded = postcard::to_slice(&snapshot, &mut buffer).unwrap();
+        assert_eq!(
+            postcard::from_bytes::<DriverSnapshot>(encoded).unwrap(),
+            snapshot
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{data::SensorState, maintenance::RuntimeCounters};
+
+/// Compact snapshot of the driver's health, e.g. to capture it in a single log line during fault
+/// analysis. Obtained with [`summary`](crate::asynch::Sen66::summary).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DriverSummary {
+    /// State of the sensor as tracked by the driver.
+    pub state: SensorState,
+    /// Accumulated runtime counters, including an ongoing measurement.
+    pub counters: RuntimeCounters,
+    /// Time spent in the ongoing measurement in ms. `None` if idle or without a time source.
+    pub measuring_for_ms: Option<u64>,
+    /// Whether a stop command is deferred until the next command.
+    pub pending_stop: bool,
+    /// Whether written settings are read back and compared.
+    pub verify_writes: bool,
+    /// Whether a humidity or correction model is applied to measurements.
+    pub corrected: bool,
+    /// Number of settings the sensor is known to hold.
+    #[cfg(feature = "config-cache")]
+    pub cached_settings: usize,
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for DriverSummary {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "state: {}, samples: {}, measuring time: {} ms, pending stop: {}, verify writes: {}, corrected: {}",
+            self.state,
+            self.counters.samples,
+            self.counters.measuring_time_ms,
+            self.pending_stop,
+            self.verify_writes,
+            self.corrected,
+        );
+        #[cfg(feature = "config-cache")]
+        defmt::write!(f, ", cached settings: {}", self.cached_settings);
+    }
+}
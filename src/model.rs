@@ -0,0 +1,95 @@
+//! Models of the SEN6x family, selecting the measurement layout and the commands available in
+//! the generic [`Sen6x`](crate::asynch::Sen6x) driver.
+//!
+//! The SEN63C, SEN65, SEN66 and SEN68 share the command set and I2C address, but differ in the
+//! measured values and therefore in the commands configuring them. The SEN60 uses a different
+//! command set and address and is not supported.
+
+#[cfg(feature = "sen68")]
+use crate::data::Sen68Measurement;
+use crate::{
+    command::{Command, Response},
+    data::{Measurement, Sen63cMeasurement, Sen65Measurement},
+};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Model of the SEN6x family.
+pub trait Sen6xModel: sealed::Sealed {
+    /// Product name reported by the model.
+    const PRODUCT_NAME: &'static str;
+    /// Command reading the measured values of the model.
+    const READ_MEASUREMENT: Command;
+    /// Measured values reported by the model.
+    type Measurement: Response;
+}
+
+/// Model measuring the CO2 concentration, providing the CO2 configuration commands.
+pub trait Co2Model: Sen6xModel {}
+
+/// Model measuring the VOC and NOx index, providing the gas index algorithm commands.
+pub trait GasIndexModel: Sen6xModel {}
+
+/// SEN63C, measuring PM, relative humidity, temperature and CO2.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sen63C;
+
+impl sealed::Sealed for Sen63C {}
+
+impl Sen6xModel for Sen63C {
+    const PRODUCT_NAME: &'static str = "SEN63C";
+    const READ_MEASUREMENT: Command = Command::ReadSen63cMeasurement;
+    type Measurement = Sen63cMeasurement;
+}
+
+impl Co2Model for Sen63C {}
+
+/// SEN65, measuring PM, relative humidity, temperature, VOC and NOx.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sen65;
+
+impl sealed::Sealed for Sen65 {}
+
+impl Sen6xModel for Sen65 {
+    const PRODUCT_NAME: &'static str = "SEN65";
+    const READ_MEASUREMENT: Command = Command::ReadSen65Measurement;
+    type Measurement = Sen65Measurement;
+}
+
+impl GasIndexModel for Sen65 {}
+
+/// SEN66, measuring PM, relative humidity, temperature, VOC, NOx and CO2.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sen66;
+
+impl sealed::Sealed for Sen66 {}
+
+impl Sen6xModel for Sen66 {
+    const PRODUCT_NAME: &'static str = "SEN66";
+    const READ_MEASUREMENT: Command = Command::ReadMeasurement;
+    type Measurement = Measurement;
+}
+
+impl Co2Model for Sen66 {}
+
+impl GasIndexModel for Sen66 {}
+
+/// SEN68, measuring PM, relative humidity, temperature, VOC, NOx and formaldehyde.
+#[cfg(feature = "sen68")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sen68;
+
+#[cfg(feature = "sen68")]
+impl sealed::Sealed for Sen68 {}
+
+#[cfg(feature = "sen68")]
+impl Sen6xModel for Sen68 {
+    const PRODUCT_NAME: &'static str = "SEN68";
+    const READ_MEASUREMENT: Command = Command::ReadSen68Measurement;
+    type Measurement = Sen68Measurement;
+}
+
+#[cfg(feature = "sen68")]
+impl GasIndexModel for Sen68 {}
@@ -0,0 +1,152 @@
+//! Compact `postcard` encoding of measurements and configurations, e.g. to append them to flash
+//! logs or radio frames without allocation.
+//!
+//! Floating point values are encoded with a fixed size and integers as varints, so each record
+//! has a documented upper bound of its encoded size. Size buffers and log slots with
+//! [`MAX_ENCODED_SIZE`](PostcardRecord::MAX_ENCODED_SIZE).
+
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::{
+    configuration::Sen66Config,
+    data::{Measurement, Timestamped},
+    error::DataError,
+};
+
+/// Maximum encoded size of a `u16` varint in bytes.
+const U16_MAX_SIZE: usize = 3;
+/// Maximum encoded size of a `u64` varint in bytes.
+const U64_MAX_SIZE: usize = 10;
+/// Encoded size of a `f32` in bytes.
+const F32_SIZE: usize = 4;
+
+/// Record with a bounded `postcard` encoding.
+pub trait PostcardRecord: Serialize + DeserializeOwned {
+    /// Maximum size of the encoded record in bytes.
+    const MAX_ENCODED_SIZE: usize;
+
+    /// Encodes the record into `buffer` and returns the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// - [`BufferTooSmall`](crate::error::DataError::BufferTooSmall): If the buffer is too small
+    ///   for the encoded record.
+    fn to_postcard(&self, buffer: &mut [u8]) -> Result<usize, DataError> {
+        ::postcard::to_slice(self, buffer)
+            .map(|encoded| encoded.len())
+            .map_err(|_| DataError::BufferTooSmall)
+    }
+
+    /// Decodes a record from the start of `data` and returns it with the remaining bytes, e.g. to
+    /// iterate the records appended to a log.
+    ///
+    /// # Errors
+    ///
+    /// - [`ReceivedBufferWrongSize`](crate::error::DataError::ReceivedBufferWrongSize): If the
+    ///   data ends within the record.
+    /// - [`InvalidEncoding`](crate::error::DataError::InvalidEncoding): If the data is not a
+    ///   valid encoding of the record, e.g. with a parameter out of range.
+    fn from_postcard(data: &[u8]) -> Result<(Self, &[u8]), DataError> {
+        ::postcard::take_from_bytes(data).map_err(|error| match error {
+            ::postcard::Error::DeserializeUnexpectedEnd => DataError::ReceivedBufferWrongSize,
+            _ => DataError::InvalidEncoding,
+        })
+    }
+}
+
+impl PostcardRecord for Measurement {
+    /// Eight fixed-size values and the CO2 concentration.
+    const MAX_ENCODED_SIZE: usize = 8 * F32_SIZE + U16_MAX_SIZE;
+}
+
+impl PostcardRecord for Sen66Config {
+    /// Altitude, pressure, ASC state, the optional temperature parameters and the tuning
+    /// parameters if the `tuning` feature is enabled.
+    const MAX_ENCODED_SIZE: usize = 2 * U16_MAX_SIZE + 1 + 2 * (1 + 4 * U16_MAX_SIZE) + TUNING_SIZE;
+}
+
+/// Maximum encoded size of the VOC and NOx tuning parameters.
+#[cfg(feature = "tuning")]
+const TUNING_SIZE: usize = 2 * 6 * U16_MAX_SIZE;
+#[cfg(not(feature = "tuning"))]
+const TUNING_SIZE: usize = 0;
+
+impl<T: PostcardRecord> PostcardRecord for Timestamped<T> {
+    /// The timestamp and the value.
+    const MAX_ENCODED_SIZE: usize = U64_MAX_SIZE + T::MAX_ENCODED_SIZE;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configuration::{SensorAltitude, TemperatureAcceleration, TemperatureOffset};
+
+    fn measurement() -> Measurement {
+        Measurement {
+            pm1_0: 1.5,
+            pm2_5: 2.5,
+            pm4_0: 3.5,
+            pm10_0: 4.5,
+            co2: u16::MAX,
+            ..Measurement::fixture()
+        }
+    }
+
+    #[test]
+    fn records_are_appended_and_read_back() {
+        let mut log = [0; 2 * Timestamped::<Measurement>::MAX_ENCODED_SIZE];
+        let first = Timestamped::new(u64::MAX, measurement());
+        let second = Timestamped::new(1_000, measurement());
+        let len = first.to_postcard(&mut log).unwrap();
+        assert_eq!(len, Timestamped::<Measurement>::MAX_ENCODED_SIZE);
+        second.to_postcard(&mut log[len..]).unwrap();
+
+        let (decoded, rest) = Timestamped::<Measurement>::from_postcard(&log).unwrap();
+        assert_eq!(decoded, first);
+        assert_eq!(
+            Timestamped::from_postcard(rest).map(|(record, _)| record),
+            Ok(second)
+        );
+        assert_eq!(
+            Measurement::from_postcard(&log[..10]),
+            Err(DataError::ReceivedBufferWrongSize)
+        );
+        assert_eq!(
+            measurement().to_postcard(&mut log[..10]),
+            Err(DataError::BufferTooSmall)
+        );
+    }
+
+    #[test]
+    fn config_with_all_parameters_fits_maximum_size() {
+        let config = Sen66Config {
+            temperature_offset: Some(
+                TemperatureOffset::try_from([u16::MAX, u16::MAX, u16::MAX, 4]).unwrap(),
+            ),
+            temperature_acceleration: Some(TemperatureAcceleration::from([u16::MAX; 4])),
+            ..Sen66Config::default()
+        };
+        let mut buffer = [0; Sen66Config::MAX_ENCODED_SIZE];
+        let len = config.to_postcard(&mut buffer).unwrap();
+        assert_eq!(
+            Sen66Config::from_postcard(&buffer[..len]),
+            Ok((config, &[][..]))
+        );
+    }
+
+    #[test]
+    fn out_of_range_parameters_are_rejected() {
+        let config = Sen66Config {
+            sensor_altitude: SensorAltitude::try_from(3_000).unwrap(),
+            ..Sen66Config::default()
+        };
+        let mut buffer = [0; Sen66Config::MAX_ENCODED_SIZE];
+        let len = config.to_postcard(&mut buffer).unwrap();
+        // The sensor altitude is the first value, encoded as a varint.
+        buffer[0] += 1;
+        assert_eq!(
+            Sen66Config::from_postcard(&buffer[..len]),
+            Err(DataError::InvalidEncoding)
+        );
+    }
+}
@@ -0,0 +1,229 @@
+//! Prometheus text exposition of measurements, e.g. served by a Linux gateway scraped by
+//! Prometheus.
+//!
+//! Each value is exposed as a gauge named after the quantity and its unit with `HELP` and
+//! `TYPE` lines, e.g.:
+//!
+//! ```text
+//! # HELP sen66_temperature_celsius Ambient temperature.
+//! # TYPE sen66_temperature_celsius gauge
+//! sen66_temperature_celsius{room="kitchen"} 21.5
+//! ```
+//!
+//! Samples of values which are not yet available are omitted.
+
+use core::fmt::{Display, Result, Write};
+
+use crate::{
+    data::{Channel, DeviceStatusRegister, Measurement},
+    maintenance::RuntimeCounters,
+};
+
+/// Prefix of all metric names.
+const PREFIX: &str = "sen66_";
+
+/// Renders a measurement and optionally the device status and runtime counters as Prometheus
+/// exposition text into any [`core::fmt::Write`] sink, without allocating.
+#[derive(Clone, Copy, Debug)]
+pub struct PrometheusExposition<'a> {
+    measurement: &'a Measurement,
+    labels: &'a str,
+    status: Option<&'a DeviceStatusRegister>,
+    counters: Option<&'a RuntimeCounters>,
+}
+
+impl<'a> PrometheusExposition<'a> {
+    /// Creates an exposition of `measurement`.
+    pub fn new(measurement: &'a Measurement) -> Self {
+        Self {
+            measurement,
+            labels: "",
+            status: None,
+            counters: None,
+        }
+    }
+
+    /// Adds labels to all samples, e.g. to distinguish several sensors.
+    /// - `labels`: Comma-separated label pairs with escaped values, e.g. `room="kitchen"`.
+    pub fn with_labels(mut self, labels: &'a str) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Adds the flags of the device status to the exposition.
+    /// - `status`: Status register as read with
+    ///   [`read_device_status`](crate::asynch::Sen66::read_device_status).
+    pub fn with_status(mut self, status: &'a DeviceStatusRegister) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Adds the runtime counters to the exposition.
+    /// - `counters`: Counters as read with
+    ///   [`runtime_counters`](crate::asynch::Sen66::runtime_counters).
+    pub fn with_counters(mut self, counters: &'a RuntimeCounters) -> Self {
+        self.counters = Some(counters);
+        self
+    }
+
+    /// Writes the exposition into `sink`, terminating each line with `\n`.
+    ///
+    /// # Errors
+    ///
+    /// - [`core::fmt::Error`]: If the sink fails, e.g. because it is full.
+    pub fn write_to<W: Write>(&self, sink: &mut W) -> Result {
+        let m = self.measurement;
+        let name = "pm_mass_concentration_micrograms_per_cubic_meter";
+        self.write_family(
+            sink,
+            name,
+            "Particulate matter mass concentration.",
+            "gauge",
+        )?;
+        for (channel, size) in [
+            (Channel::Pm1_0, "1.0"),
+            (Channel::Pm2_5, "2.5"),
+            (Channel::Pm4_0, "4.0"),
+            (Channel::Pm10_0, "10.0"),
+        ] {
+            if let Some(value) = channel.value(m) {
+                self.write_sample(sink, name, Some(("size", size)), value)?;
+            }
+        }
+        for (channel, name, help) in [
+            (
+                Channel::RelativeHumidity,
+                "relative_humidity_percent",
+                "Relative humidity.",
+            ),
+            (
+                Channel::Temperature,
+                "temperature_celsius",
+                "Ambient temperature.",
+            ),
+            (Channel::VocIndex, "voc_index", "VOC index."),
+            (Channel::NoxIndex, "nox_index", "NOx index."),
+        ] {
+            self.write_family(sink, name, help, "gauge")?;
+            if let Some(value) = channel.value(m) {
+                self.write_sample(sink, name, None, value)?;
+            }
+        }
+        let name = "co2_ppm";
+        self.write_family(sink, name, "CO2 concentration.", "gauge")?;
+        if m.co2 != Measurement::CO2_UNAVAILABLE {
+            self.write_sample(sink, name, None, m.co2)?;
+        }
+
+        if let Some(status) = self.status {
+            let name = "device_status";
+            self.write_family(
+                sink,
+                name,
+                "Flags set in the device status register.",
+                "gauge",
+            )?;
+            for (flag, set) in [
+                ("fan_speed_warning", status.fan_speed_warning()),
+                ("pm_error", status.pm_sensor_error()),
+                ("co2_error", status.co2_sensor_error()),
+                ("gas_error", status.gas_sensor_error()),
+                ("rht_error", status.rht_sensor_error()),
+                ("fan_error", status.fan_error()),
+            ] {
+                self.write_sample(sink, name, Some(("flag", flag)), u8::from(set))?;
+            }
+        }
+        if let Some(counters) = self.counters {
+            let name = "measuring_time_seconds_total";
+            self.write_family(sink, name, "Time spent in measuring state.", "counter")?;
+            self.write_sample(sink, name, None, counters.measuring_time_s())?;
+            let name = "samples_total";
+            self.write_family(sink, name, "Measurements read from the sensor.", "counter")?;
+            self.write_sample(sink, name, None, counters.samples)?;
+        }
+        Ok(())
+    }
+
+    fn write_family<W: Write>(&self, sink: &mut W, name: &str, help: &str, kind: &str) -> Result {
+        writeln!(sink, "# HELP {PREFIX}{name} {help}")?;
+        writeln!(sink, "# TYPE {PREFIX}{name} {kind}")
+    }
+
+    fn write_sample<W: Write>(
+        &self,
+        sink: &mut W,
+        name: &str,
+        label: Option<(&str, &str)>,
+        value: impl Display,
+    ) -> Result {
+        write!(sink, "{PREFIX}{name}")?;
+        match (self.labels, label) {
+            ("", None) => {}
+            (labels, None) => write!(sink, "{{{labels}}}")?,
+            ("", Some((key, label))) => write!(sink, "{{{key}=\"{label}\"}}")?,
+            (labels, Some((key, label))) => write!(sink, "{{{labels},{key}=\"{label}\"}}")?,
+        }
+        writeln!(sink, " {value}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement() -> Measurement {
+        Measurement {
+            pm2_5: 2.5,
+            pm4_0: 3.0,
+            pm10_0: 4.0,
+            temperature: 21.5,
+            nox_index: Measurement::INDEX_UNAVAILABLE,
+            ..Measurement::fixture()
+        }
+    }
+
+    #[test]
+    fn measurement_is_exposed_with_labels() {
+        let measurement = measurement();
+        let mut output = String::new();
+        PrometheusExposition::new(&measurement)
+            .with_labels("room=\"kitchen\"")
+            .write_to(&mut output)
+            .unwrap();
+        assert!(output.starts_with(
+            "# HELP sen66_pm_mass_concentration_micrograms_per_cubic_meter Particulate matter \
+             mass concentration.\n\
+             # TYPE sen66_pm_mass_concentration_micrograms_per_cubic_meter gauge\n\
+             sen66_pm_mass_concentration_micrograms_per_cubic_meter{room=\"kitchen\",size=\"1.0\"} 1\n"
+        ));
+        assert!(output.contains("sen66_temperature_celsius{room=\"kitchen\"} 21.5\n"));
+        assert!(output.contains("# TYPE sen66_nox_index gauge\n# HELP sen66_co2_ppm"));
+        assert!(output.ends_with("sen66_co2_ppm{room=\"kitchen\"} 420\n"));
+    }
+
+    #[test]
+    fn status_and_counters_are_exposed() {
+        let measurement = measurement();
+        let data = [0x00, 0x20, 0x07, 0x00, 0x10, 0xC2];
+        let status = DeviceStatusRegister::try_from(&data[..]).unwrap();
+        let counters = RuntimeCounters {
+            measuring_time_ms: 3_600_000,
+            samples: 3_600,
+        };
+        let mut output = String::new();
+        PrometheusExposition::new(&measurement)
+            .with_status(&status)
+            .with_counters(&counters)
+            .write_to(&mut output)
+            .unwrap();
+        assert!(output.contains("sen66_device_status{flag=\"fan_speed_warning\"} 1\n"));
+        assert!(output.contains("sen66_device_status{flag=\"pm_error\"} 0\n"));
+        assert!(output.contains("sen66_device_status{flag=\"fan_error\"} 1\n"));
+        assert!(output.contains(
+            "# TYPE sen66_measuring_time_seconds_total counter\n\
+             sen66_measuring_time_seconds_total 3600\n"
+        ));
+        assert!(output.ends_with("sen66_samples_total 3600\n"));
+    }
+}
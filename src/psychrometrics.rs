@@ -0,0 +1,116 @@
+//! Psychrometric properties of the ambient air derived from the SEN66's RH/T readings.
+//!
+//! Useful for ventilation and filter-monitoring applications combining the SEN66 with
+//! differential-pressure flow sensors, e.g. to convert a measured volume flow into a mass flow.
+
+use libm::{copysignf, fabsf, sqrtf};
+
+use crate::{
+    configuration::AmbientPressure,
+    data::Measurement,
+    util::{magnus_vapor_pressure, rh_t},
+};
+
+/// Specific gas constant of dry air in J/(kg·K).
+const GAS_CONSTANT_DRY_AIR: f32 = 287.058;
+/// Specific gas constant of water vapor in J/(kg·K).
+const GAS_CONSTANT_WATER_VAPOR: f32 = 461.495;
+/// Offset between °C and K.
+const ZERO_CELSIUS_K: f32 = 273.15;
+
+/// Density of dry air at standard conditions of 20 °C and 1,013.25 hPa in kg/m³, the reference
+/// commonly used by flow sensors reporting standard volume flows.
+pub const STANDARD_AIR_DENSITY: f32 = 1.204;
+
+/// Returns the saturation vapor pressure over water at `temperature` in °C in hPa, using the
+/// Magnus formula with the coefficients of Sonntag (1990).
+pub fn saturation_vapor_pressure(temperature: f32) -> f32 {
+    magnus_vapor_pressure(temperature)
+}
+
+/// Returns the density of moist air in kg/m³.
+/// - `temperature`: Air temperature in °C.
+/// - `relative_humidity`: Relative humidity in %.
+/// - `pressure`: Ambient pressure, e.g. the one configured for the CO2 compensation.
+pub fn air_density(temperature: f32, relative_humidity: f32, pressure: AmbientPressure) -> f32 {
+    let pressure_pa = f32::from(u16::from(pressure)) * 100.0;
+    let vapor_pa = relative_humidity.clamp(0.0, 100.0) / 100.0
+        * saturation_vapor_pressure(temperature)
+        * 100.0;
+    let temperature_k = temperature + ZERO_CELSIUS_K;
+    (pressure_pa - vapor_pa) / (GAS_CONSTANT_DRY_AIR * temperature_k)
+        + vapor_pa / (GAS_CONSTANT_WATER_VAPOR * temperature_k)
+}
+
+/// Returns the density of moist air at the conditions of the `measurement` in kg/m³. `None` if
+/// the temperature or relative humidity is not available.
+/// - `measurement`: Measurement providing the temperature and relative humidity.
+/// - `pressure`: Ambient pressure, e.g. the one configured for the CO2 compensation.
+pub fn measured_air_density(measurement: &Measurement, pressure: AmbientPressure) -> Option<f32> {
+    rh_t(measurement).map(|(temperature, relative_humidity)| {
+        air_density(temperature, relative_humidity, pressure)
+    })
+}
+
+/// Returns the mass flow in kg/h.
+/// - `volume_flow`: Volume flow at the actual conditions in m³/h.
+/// - `density`: Density of the air in kg/m³.
+pub fn mass_flow(volume_flow: f32, density: f32) -> f32 {
+    volume_flow * density
+}
+
+/// Returns the volume flow at the actual conditions in m³/h.
+/// - `mass_flow`: Mass flow in kg/h.
+/// - `density`: Density of the air in kg/m³.
+pub fn volume_flow(mass_flow: f32, density: f32) -> f32 {
+    mass_flow / density
+}
+
+/// Returns the volume flow at the actual conditions from a standard volume flow, as reported by
+/// thermal mass flow sensors, in the unit of `standard_flow`.
+/// - `standard_flow`: Volume flow referenced to the [`STANDARD_AIR_DENSITY`].
+/// - `density`: Density of the air in kg/m³.
+pub fn actual_volume_flow(standard_flow: f32, density: f32) -> f32 {
+    standard_flow * STANDARD_AIR_DENSITY / density
+}
+
+/// Returns the flow velocity in m/s from the dynamic pressure measured by a pitot tube or
+/// differential-pressure flow sensor. Negative pressures yield negative velocities.
+/// - `differential_pressure`: Dynamic pressure in Pa.
+/// - `density`: Density of the air in kg/m³.
+pub fn flow_velocity(differential_pressure: f32, density: f32) -> f32 {
+    copysignf(
+        sqrtf(2.0 * fabsf(differential_pressure) / density),
+        differential_pressure,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-3, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn air_density_matches_reference_values() {
+        let pressure = AmbientPressure::try_from(1_013).unwrap();
+        assert_close(saturation_vapor_pressure(20.0), 23.326);
+        assert_close(air_density(20.0, 0.0, pressure), 1.2038);
+        assert_close(air_density(20.0, 100.0, pressure), 1.1933);
+        assert_close(
+            air_density(25.0, 50.0, AmbientPressure::try_from(900).unwrap()),
+            1.0446,
+        );
+    }
+
+    #[test]
+    fn flows_are_converted_with_density() {
+        assert_close(mass_flow(100.0, 1.2), 120.0);
+        assert_close(volume_flow(120.0, 1.2), 100.0);
+        assert_close(actual_volume_flow(100.0, STANDARD_AIR_DENSITY), 100.0);
+        assert_close(flow_velocity(24.0, 1.2), 6.3246);
+        assert_close(flow_velocity(-24.0, 1.2), -6.3246);
+    }
+}
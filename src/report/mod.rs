@@ -0,0 +1,7 @@
+//! Compact textual reports for character LCDs and serial consoles.
+
+mod sparkline;
+mod status;
+
+pub use sparkline::{Sparkline, SparklineStyle};
+pub use status::StatusReport;
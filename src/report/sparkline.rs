@@ -0,0 +1,177 @@
+use core::fmt::{Result, Write};
+
+/// Characters of the ASCII style, from the lowest to the highest level.
+const ASCII_LEVELS: [char; 8] = ['_', '.', ':', '-', '=', '+', '*', '#'];
+/// Braille pattern without any dots.
+const BRAILLE_BLANK: u32 = 0x2800;
+/// Dots of the left and right column of a braille character, from the bottom to the top row.
+const BRAILLE_DOTS: [[u32; 4]; 2] = [[0x40, 0x04, 0x02, 0x01], [0x80, 0x20, 0x10, 0x08]];
+
+/// Characters used to render a [`Sparkline`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SparklineStyle {
+    /// One sample per character in 8 levels of ASCII characters, e.g. `_.:-=+*#`. Works on any
+    /// terminal and character display.
+    Ascii,
+    /// Two samples per character as bars of 4 levels using Unicode braille patterns. Doubles the
+    /// resolution on terminals supporting them.
+    Braille,
+}
+
+impl SparklineStyle {
+    /// Returns the number of samples rendered per character.
+    pub const fn samples_per_char(self) -> usize {
+        match self {
+            SparklineStyle::Ascii => 1,
+            SparklineStyle::Braille => 2,
+        }
+    }
+
+    /// Returns the number of distinguishable levels.
+    const fn levels(self) -> usize {
+        match self {
+            SparklineStyle::Ascii => ASCII_LEVELS.len(),
+            SparklineStyle::Braille => 4,
+        }
+    }
+}
+
+/// Renders a window of samples of one channel, e.g. from
+/// [`History::values`](crate::analysis::History::values), as a fixed-width line of text. The
+/// most recent samples are drawn at the right, missing or unavailable samples as blanks.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sparkline {
+    style: SparklineStyle,
+    width: usize,
+    range: Option<(f32, f32)>,
+}
+
+impl Sparkline {
+    /// Creates a sparkline of `width` characters in the `style`. The value axis is scaled to the
+    /// minimum and maximum of the rendered samples.
+    pub const fn new(style: SparklineStyle, width: usize) -> Self {
+        Self {
+            style,
+            width,
+            range: None,
+        }
+    }
+
+    /// Returns the sparkline with a fixed value axis from `min` to `max`. Values outside are
+    /// clamped.
+    pub const fn with_range(mut self, min: f32, max: f32) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Returns the number of samples rendered, older samples are skipped.
+    pub const fn window(&self) -> usize {
+        self.width * self.style.samples_per_char()
+    }
+
+    /// Writes the sparkline of the `samples`, oldest first, into `sink`. Exactly `width`
+    /// characters are written.
+    ///
+    /// # Errors
+    ///
+    /// - [`core::fmt::Error`]: If the sink fails, e.g. because it is full.
+    pub fn write_to<I, W>(&self, samples: I, sink: &mut W) -> Result
+    where
+        I: IntoIterator<Item = Option<f32>>,
+        I::IntoIter: Clone,
+        W: Write,
+    {
+        let samples = samples.into_iter();
+        let len = samples.clone().count();
+        let skipped = len.saturating_sub(self.window());
+        let padding = self.window().saturating_sub(len);
+        let range = self.range.or_else(|| {
+            samples
+                .clone()
+                .skip(skipped)
+                .flatten()
+                .fold(None, |range, value| match range {
+                    None => Some((value, value)),
+                    Some((min, max)) => Some((value.min(min), value.max(max))),
+                })
+        });
+        let mut levels = core::iter::repeat_n(None, padding)
+            .chain(samples.skip(skipped))
+            .map(|value| {
+                value
+                    .zip(range)
+                    .map(|(value, range)| self.level(value, range))
+            });
+        for _ in 0..self.width {
+            let c = match self.style {
+                SparklineStyle::Ascii => levels
+                    .next()
+                    .flatten()
+                    .and_then(|level| ASCII_LEVELS.get(level).copied())
+                    .unwrap_or(' '),
+                SparklineStyle::Braille => {
+                    let mut pattern = BRAILLE_BLANK;
+                    for dots in BRAILLE_DOTS {
+                        if let Some(level) = levels.next().flatten() {
+                            pattern |= dots.iter().take(level + 1).sum::<u32>();
+                        }
+                    }
+                    char::from_u32(pattern).unwrap_or(' ')
+                }
+            };
+            sink.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the level of the `value` within the value axis.
+    fn level(&self, value: f32, (min, max): (f32, f32)) -> usize {
+        let top = self.style.levels() - 1;
+        if max > min {
+            let share = ((value - min) / (max - min)).clamp(0.0, 1.0);
+            (share * top as f32 + 0.5) as usize
+        } else {
+            top / 2
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(sparkline: Sparkline, samples: &[Option<f32>]) -> String {
+        let mut output = String::new();
+        sparkline
+            .write_to(samples.iter().copied(), &mut output)
+            .unwrap();
+        output
+    }
+
+    #[test]
+    fn ascii_sparkline_has_fixed_width() {
+        let sparkline = Sparkline::new(SparklineStyle::Ascii, 6);
+        assert_eq!(
+            render(sparkline, &[Some(0.0), Some(7.0), None, Some(3.0)]),
+            "  _# -"
+        );
+        let samples: Vec<_> = (0..10).map(|value| Some(value as f32)).collect();
+        assert_eq!(render(sparkline, &samples), "_.-=*#");
+        assert_eq!(
+            render(sparkline.with_range(0.0, 70.0), &samples[..3]),
+            "   ___"
+        );
+        assert_eq!(render(sparkline, &[]), "      ");
+    }
+
+    #[test]
+    fn braille_sparkline_packs_two_samples() {
+        let sparkline = Sparkline::new(SparklineStyle::Braille, 2);
+        assert_eq!(sparkline.window(), 4);
+        assert_eq!(
+            render(sparkline, &[Some(0.0), Some(3.0), None, Some(1.0)]),
+            "\u{28F8}\u{28A0}"
+        );
+    }
+}
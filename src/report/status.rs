@@ -0,0 +1,212 @@
+use core::fmt::{Result, Write};
+
+use crate::data::{DeviceStatusRegister, Measurement};
+
+/// Placeholder rendered for values which are not available.
+const UNAVAILABLE: &str = "--";
+
+/// Renders the latest measurement, derived values and the device status as a small multi-line
+/// report into any [`core::fmt::Write`] sink, without allocating. Each line fits the 20 columns
+/// of common character LCDs for values within the sensor's specified ranges, e.g.:
+///
+/// ```text
+/// T 21.4C RH 45.0%
+/// PM2.5 12.3 CO2 612
+/// VOC 100 NOx 1
+/// Dew 9.1C AQI 51
+/// Status OK
+/// ```
+///
+/// Lines of derived values and the status are only rendered if the respective values are
+/// provided.
+#[derive(Clone, Copy, Debug)]
+pub struct StatusReport<'a> {
+    measurement: &'a Measurement,
+    status: Option<&'a DeviceStatusRegister>,
+    dew_point: Option<f32>,
+    aqi: Option<u16>,
+}
+
+impl<'a> StatusReport<'a> {
+    /// Creates a report of `measurement`.
+    pub fn new(measurement: &'a Measurement) -> Self {
+        Self {
+            measurement,
+            status: None,
+            dew_point: None,
+            aqi: None,
+        }
+    }
+
+    /// Adds the device status to the report.
+    /// - `status`: Status register as read with
+    ///   [`read_device_status`](crate::asynch::Sen66::read_device_status).
+    pub fn with_status(mut self, status: &'a DeviceStatusRegister) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Adds the dew point to the report.
+    /// - `dew_point`: Dew point in °C.
+    pub fn with_dew_point(mut self, dew_point: f32) -> Self {
+        self.dew_point = Some(dew_point);
+        self
+    }
+
+    /// Adds an air quality index to the report.
+    /// - `aqi`: Air quality index.
+    pub fn with_aqi(mut self, aqi: u16) -> Self {
+        self.aqi = Some(aqi);
+        self
+    }
+
+    /// Writes the report into `sink`, separating lines with `\n`.
+    ///
+    /// # Errors
+    ///
+    /// - [`core::fmt::Error`]: If the sink fails, e.g. because it is full.
+    pub fn write_to<W: Write>(&self, sink: &mut W) -> Result {
+        let m = self.measurement;
+        sink.write_str("T ")?;
+        write_value(
+            sink,
+            m.temperature,
+            Measurement::TEMPERATURE_UNAVAILABLE,
+            "C",
+        )?;
+        sink.write_str(" RH ")?;
+        write_value(sink, m.relative_humidity, Measurement::RH_UNAVAILABLE, "%")?;
+        sink.write_str("\nPM2.5 ")?;
+        write_value(sink, m.pm2_5, Measurement::PM_UNAVAILABLE, "")?;
+        sink.write_str(" CO2 ")?;
+        if m.co2 == Measurement::CO2_UNAVAILABLE {
+            sink.write_str(UNAVAILABLE)?;
+        } else {
+            write!(sink, "{}", m.co2)?;
+        }
+        sink.write_str("\nVOC ")?;
+        write_index(sink, m.voc_index)?;
+        sink.write_str(" NOx ")?;
+        write_index(sink, m.nox_index)?;
+        if self.dew_point.is_some() || self.aqi.is_some() {
+            sink.write_char('\n')?;
+            let mut separator = "";
+            if let Some(dew_point) = self.dew_point {
+                write!(sink, "Dew {dew_point:.1}C")?;
+                separator = " ";
+            }
+            if let Some(aqi) = self.aqi {
+                write!(sink, "{separator}AQI {aqi}")?;
+            }
+        }
+        if let Some(status) = self.status {
+            sink.write_str("\nStatus")?;
+            write_status(sink, status)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_value<W: Write>(sink: &mut W, value: f32, unavailable: f32, unit: &str) -> Result {
+    if value == unavailable {
+        sink.write_str(UNAVAILABLE)
+    } else {
+        write!(sink, "{value:.1}{unit}")
+    }
+}
+
+fn write_index<W: Write>(sink: &mut W, index: f32) -> Result {
+    if index == Measurement::INDEX_UNAVAILABLE {
+        sink.write_str(UNAVAILABLE)
+    } else {
+        write!(sink, "{index:.0}")
+    }
+}
+
+fn write_status<W: Write>(sink: &mut W, status: &DeviceStatusRegister) -> Result {
+    let faults = [
+        (status.pm_sensor_error(), " PM"),
+        (status.co2_sensor_error(), " CO2"),
+        (status.gas_sensor_error(), " Gas"),
+        (status.rht_sensor_error(), " RHT"),
+        (status.fan_error(), " Fan"),
+    ];
+    if faults.iter().any(|&(set, _)| set) {
+        sink.write_str(" Err")?;
+        for (_, name) in faults.iter().filter(|&&(set, _)| set) {
+            sink.write_str(name)?;
+        }
+    } else if status.fan_speed_warning() {
+        sink.write_str(" Fan warning")?;
+    } else {
+        sink.write_str(" OK")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement() -> Measurement {
+        Measurement {
+            pm1_0: 10.0,
+            pm2_5: 12.3,
+            pm4_0: 14.0,
+            pm10_0: 15.0,
+            relative_humidity: 45.0,
+            temperature: 21.4,
+            co2: 612,
+            ..Measurement::fixture()
+        }
+    }
+
+    fn status(high: u16, low: u16) -> DeviceStatusRegister {
+        let data: Vec<u8> = [high, low]
+            .iter()
+            .flat_map(|word| {
+                let [msb, lsb] = word.to_be_bytes();
+                [msb, lsb, crate::util::compute_crc8(&[msb, lsb])]
+            })
+            .collect();
+        DeviceStatusRegister::try_from(&data[..]).unwrap()
+    }
+
+    #[test]
+    fn report_fits_character_lcd() {
+        let measurement = measurement();
+        let status = status(0, 0);
+        let mut output = String::new();
+        StatusReport::new(&measurement)
+            .with_dew_point(9.06)
+            .with_aqi(51)
+            .with_status(&status)
+            .write_to(&mut output)
+            .unwrap();
+        assert_eq!(
+            output,
+            "T 21.4C RH 45.0%\nPM2.5 12.3 CO2 612\nVOC 100 NOx 1\nDew 9.1C AQI 51\nStatus OK"
+        );
+        assert!(output.lines().all(|line| line.len() <= 20));
+    }
+
+    #[test]
+    fn report_marks_unavailable_values_and_errors() {
+        let measurement = Measurement {
+            temperature: Measurement::TEMPERATURE_UNAVAILABLE,
+            voc_index: Measurement::INDEX_UNAVAILABLE,
+            co2: Measurement::CO2_UNAVAILABLE,
+            ..measurement()
+        };
+        let status = status(0x0020, 0x0210);
+        let mut output = String::new();
+        StatusReport::new(&measurement)
+            .with_status(&status)
+            .write_to(&mut output)
+            .unwrap();
+        assert_eq!(
+            output,
+            "T -- RH 45.0%\nPM2.5 12.3 CO2 --\nVOC -- NOx 1\nStatus Err CO2 Fan"
+        );
+    }
+}
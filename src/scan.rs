@@ -0,0 +1,21 @@
+//! Discovery of SEN6x devices on an I2C bus, e.g. for command line tools, factory fixtures or
+//! bringing up multiple sensors.
+//!
+//! [`scan`](crate::asynch::scan) and its blocking counterpart probe the [`SCAN_ADDRESSES`] and
+//! read the product name of each device acknowledging its address.
+
+use crate::data::ProductName;
+
+/// I2C addresses used by the SEN6x family: `0x6B` for the SEN63C, SEN65, SEN66 and SEN68 and
+/// `0x6C` for the SEN60.
+pub const SCAN_ADDRESSES: [u8; 2] = [0x6B, 0x6C];
+
+/// Device found by a bus scan.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DiscoveredDevice {
+    /// I2C address the device acknowledged.
+    pub address: u8,
+    /// Product name reported by the device. `None` if it could not be read, e.g. because the
+    /// device is not a SEN6x.
+    pub product_name: Option<ProductName>,
+}
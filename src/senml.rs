@@ -0,0 +1,485 @@
+//! SenML encoding of measurements according to RFC 8428.
+//!
+//! Each available value of a [`Measurement`] is encoded as one record with the SenML unit of its
+//! channel. The base name of the first record identifies the device, e.g. with a `urn:dev:sn:`
+//! name derived from the sensor's [`SerialNumber`].
+
+use core::fmt::Write;
+
+use crate::{
+    data::{Measurement, SerialNumber, Timestamped},
+    error::{DataError, SinkError},
+    sink::MeasurementSink,
+};
+
+/// CBOR label of the base name.
+const LABEL_BASE_NAME: i8 = -2;
+/// CBOR label of the base time.
+const LABEL_BASE_TIME: i8 = -3;
+/// CBOR label of the name.
+const LABEL_NAME: i8 = 0;
+/// CBOR label of the unit.
+const LABEL_UNIT: i8 = 1;
+/// CBOR label of the value.
+const LABEL_VALUE: i8 = 2;
+
+/// Base name identifying the device in the encoded records.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum BaseName<'a> {
+    Custom(&'a str),
+    SerialNumber(&'a str),
+}
+
+impl BaseName<'_> {
+    fn parts(&self) -> [&str; 3] {
+        match self {
+            BaseName::Custom(name) => [name, "", ""],
+            BaseName::SerialNumber(serial) => ["urn:dev:sn:", serial, ":"],
+        }
+    }
+}
+
+/// Encodes measurements as SenML packs in JSON or CBOR representation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Senml<'a> {
+    base_name: BaseName<'a>,
+    base_time: Option<u64>,
+}
+
+impl<'a> Senml<'a> {
+    /// Creates an encoder using `base_name` as base name of the records.
+    pub const fn new(base_name: &'a str) -> Self {
+        Self {
+            base_name: BaseName::Custom(base_name),
+            base_time: None,
+        }
+    }
+
+    /// Creates an encoder using `urn:dev:sn:<serial_number>:` as base name of the records.
+    pub fn with_serial_number(serial_number: &'a SerialNumber) -> Self {
+        Self {
+            base_name: BaseName::SerialNumber(serial_number.as_str()),
+            base_time: None,
+        }
+    }
+
+    /// Returns the encoder with `base_time` in s since the UNIX epoch added to the records.
+    pub const fn with_base_time(mut self, base_time: u64) -> Self {
+        self.base_time = Some(base_time);
+        self
+    }
+
+    /// Returns the encoder with the wall-clock `timestamp` added to the records as base time,
+    /// e.g. of a [`UtcTimestamped`](crate::data::UtcTimestamped) measurement. Times before the
+    /// UNIX epoch are mapped to 0.
+    #[cfg(feature = "std")]
+    pub fn with_timestamp(self, timestamp: chrono::DateTime<chrono::Utc>) -> Self {
+        self.with_base_time(u64::try_from(timestamp.timestamp()).unwrap_or_default())
+    }
+
+    /// Encodes the `measurement` as SenML JSON into `buffer` and returns the number of bytes
+    /// written.
+    ///
+    /// # Errors
+    ///
+    /// - [`BufferTooSmall`](crate::error::DataError::BufferTooSmall): If the encoded pack does
+    ///   not fit into `buffer`.
+    pub fn encode_json(
+        &self,
+        measurement: &Measurement,
+        buffer: &mut [u8],
+    ) -> Result<usize, DataError> {
+        let mut writer = Writer::new(buffer);
+        self.write_json(measurement, &mut writer)
+            .map_err(|_| DataError::BufferTooSmall)?;
+        Ok(writer.position)
+    }
+
+    /// Encodes the `measurement` as SenML CBOR into `buffer` and returns the number of bytes
+    /// written.
+    ///
+    /// # Errors
+    ///
+    /// - [`BufferTooSmall`](crate::error::DataError::BufferTooSmall): If the encoded pack does
+    ///   not fit into `buffer`.
+    pub fn encode_cbor(
+        &self,
+        measurement: &Measurement,
+        buffer: &mut [u8],
+    ) -> Result<usize, DataError> {
+        let mut writer = Writer::new(buffer);
+        self.write_cbor(measurement, &mut writer)?;
+        Ok(writer.position)
+    }
+
+    fn write_json(&self, measurement: &Measurement, writer: &mut Writer) -> core::fmt::Result {
+        writer.write_char('[')?;
+        for (i, record) in records(measurement).enumerate() {
+            if i == 0 {
+                writer.write_str("{\"bn\":\"")?;
+                for part in self.base_name.parts() {
+                    write_json_escaped(writer, part)?;
+                }
+                writer.write_str("\",")?;
+                if let Some(base_time) = self.base_time {
+                    write!(writer, "\"bt\":{base_time},")?;
+                }
+            } else {
+                writer.write_str(",{")?;
+            }
+            write!(writer, "\"n\":\"{}\",", record.name)?;
+            if !record.unit.is_empty() {
+                write!(writer, "\"u\":\"{}\",", record.unit)?;
+            }
+            write!(writer, "\"v\":{}}}", record.value)?;
+        }
+        writer.write_char(']')
+    }
+
+    fn write_cbor(&self, measurement: &Measurement, writer: &mut Writer) -> Result<(), DataError> {
+        writer.cbor_head(4, records(measurement).count() as u64)?;
+        for (i, record) in records(measurement).enumerate() {
+            let mut entries = 2 + u64::from(!record.unit.is_empty());
+            if i == 0 {
+                entries += 1 + u64::from(self.base_time.is_some());
+            }
+            writer.cbor_head(5, entries)?;
+            if i == 0 {
+                writer.cbor_int(LABEL_BASE_NAME)?;
+                let parts = self.base_name.parts();
+                writer.cbor_head(3, parts.iter().map(|part| part.len() as u64).sum())?;
+                for part in parts {
+                    writer.bytes(part.as_bytes())?;
+                }
+                if let Some(base_time) = self.base_time {
+                    writer.cbor_int(LABEL_BASE_TIME)?;
+                    writer.cbor_head(0, base_time)?;
+                }
+            }
+            writer.cbor_int(LABEL_NAME)?;
+            writer.cbor_text(record.name)?;
+            if !record.unit.is_empty() {
+                writer.cbor_int(LABEL_UNIT)?;
+                writer.cbor_text(record.unit)?;
+            }
+            writer.cbor_int(LABEL_VALUE)?;
+            writer.bytes(&[0xFA])?;
+            writer.bytes(&record.value.to_be_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+/// Representation of the packs encoded by a [`SenmlSink`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SenmlFormat {
+    /// SenML JSON, e.g. as MQTT payload.
+    Json,
+    /// SenML CBOR, e.g. for CoAP or LoRaWAN.
+    Cbor,
+}
+
+/// [`MeasurementSink`] encoding each measurement as SenML pack into a buffer and handing the
+/// encoded bytes to `transport`, e.g. a closure publishing them via MQTT. The timestamp of the
+/// measurement is monotonic and therefore not encoded, set a base time on the encoder instead.
+#[derive(Debug)]
+pub struct SenmlSink<'a, F> {
+    encoder: Senml<'a>,
+    format: SenmlFormat,
+    buffer: &'a mut [u8],
+    transport: F,
+}
+
+impl<'a, F> SenmlSink<'a, F> {
+    /// Creates a sink encoding the measurements with `encoder` in `format` into `buffer`.
+    pub fn new(
+        encoder: Senml<'a>,
+        format: SenmlFormat,
+        buffer: &'a mut [u8],
+        transport: F,
+    ) -> Self {
+        Self {
+            encoder,
+            format,
+            buffer,
+            transport,
+        }
+    }
+}
+
+impl<F, E> MeasurementSink for SenmlSink<'_, F>
+where
+    F: FnMut(&[u8]) -> Result<(), E>,
+{
+    type Error = SinkError<E>;
+
+    fn publish(&mut self, measurement: &Timestamped<Measurement>) -> Result<(), Self::Error> {
+        let len = match self.format {
+            SenmlFormat::Json => self.encoder.encode_json(&measurement.value, self.buffer),
+            SenmlFormat::Cbor => self.encoder.encode_cbor(&measurement.value, self.buffer),
+        }?;
+        let encoded = self.buffer.get(..len).ok_or(DataError::BufferTooSmall)?;
+        (self.transport)(encoded).map_err(SinkError::Transport)
+    }
+}
+
+/// One SenML record of a measurement.
+struct Record {
+    name: &'static str,
+    unit: &'static str,
+    value: f32,
+}
+
+/// Returns the records of all available values of the `measurement`.
+fn records(measurement: &Measurement) -> impl Iterator<Item = Record> {
+    let pm = |value: f32| (value != Measurement::PM_UNAVAILABLE).then_some(value);
+    let index = |value: f32| (value != Measurement::INDEX_UNAVAILABLE).then_some(value);
+    [
+        ("pm1_0", "ug/m3", pm(measurement.pm1_0)),
+        ("pm2_5", "ug/m3", pm(measurement.pm2_5)),
+        ("pm4_0", "ug/m3", pm(measurement.pm4_0)),
+        ("pm10_0", "ug/m3", pm(measurement.pm10_0)),
+        (
+            "humidity",
+            "%RH",
+            (measurement.relative_humidity != Measurement::RH_UNAVAILABLE)
+                .then_some(measurement.relative_humidity),
+        ),
+        (
+            "temperature",
+            "Cel",
+            (measurement.temperature != Measurement::TEMPERATURE_UNAVAILABLE)
+                .then_some(measurement.temperature),
+        ),
+        ("voc_index", "", index(measurement.voc_index)),
+        ("nox_index", "", index(measurement.nox_index)),
+        (
+            "co2",
+            "ppm",
+            (measurement.co2 != Measurement::CO2_UNAVAILABLE).then_some(f32::from(measurement.co2)),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(name, unit, value)| {
+        value
+            .filter(|value| value.is_finite())
+            .map(|value| Record { name, unit, value })
+    })
+}
+
+fn write_json_escaped(writer: &mut Writer, text: &str) -> core::fmt::Result {
+    for c in text.chars() {
+        match c {
+            '"' | '\\' => write!(writer, "\\{c}")?,
+            c if c.is_control() => write!(writer, "\\u{:04x}", c as u32)?,
+            c => writer.write_char(c)?,
+        }
+    }
+    Ok(())
+}
+
+/// Writes encoded data into a fixed buffer.
+struct Writer<'a> {
+    buffer: &'a mut [u8],
+    position: usize,
+}
+
+impl<'a> Writer<'a> {
+    fn new(buffer: &'a mut [u8]) -> Self {
+        Self {
+            buffer,
+            position: 0,
+        }
+    }
+
+    fn bytes(&mut self, data: &[u8]) -> Result<(), DataError> {
+        let end = self.position + data.len();
+        self.buffer
+            .get_mut(self.position..end)
+            .ok_or(DataError::BufferTooSmall)?
+            .copy_from_slice(data);
+        self.position = end;
+        Ok(())
+    }
+
+    /// Writes the CBOR head of a data item of the `major` type with the `argument`.
+    fn cbor_head(&mut self, major: u8, argument: u64) -> Result<(), DataError> {
+        let major = major << 5;
+        match argument {
+            0..24 => self.bytes(&[major | argument as u8]),
+            24..0x100 => self.bytes(&[major | 24, argument as u8]),
+            0x100..0x1_0000 => {
+                self.bytes(&[major | 25])?;
+                self.bytes(&(argument as u16).to_be_bytes())
+            }
+            0x1_0000..0x1_0000_0000 => {
+                self.bytes(&[major | 26])?;
+                self.bytes(&(argument as u32).to_be_bytes())
+            }
+            _ => {
+                self.bytes(&[major | 27])?;
+                self.bytes(&argument.to_be_bytes())
+            }
+        }
+    }
+
+    fn cbor_int(&mut self, value: i8) -> Result<(), DataError> {
+        if value < 0 {
+            self.cbor_head(1, (-1 - i64::from(value)) as u64)
+        } else {
+            self.cbor_head(0, value as u64)
+        }
+    }
+
+    fn cbor_text(&mut self, text: &str) -> Result<(), DataError> {
+        self.cbor_head(3, text.len() as u64)?;
+        self.bytes(text.as_bytes())
+    }
+}
+
+impl Write for Writer<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.bytes(s.as_bytes()).map_err(|_| core::fmt::Error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement() -> Measurement {
+        Measurement {
+            pm2_5: 2.5,
+            pm4_0: 3.0,
+            pm10_0: 4.0,
+            temperature: 21.5,
+            nox_index: Measurement::INDEX_UNAVAILABLE,
+            ..Measurement::fixture()
+        }
+    }
+
+    #[test]
+    fn senml_encodes_json_pack() {
+        let mut buffer = [0; 512];
+        let len = Senml::new("urn:dev:mac:0024befffe804ff1:")
+            .with_base_time(1_700_000_000)
+            .encode_json(&measurement(), &mut buffer)
+            .unwrap();
+        assert_eq!(
+            core::str::from_utf8(&buffer[..len]).unwrap(),
+            "[{\"bn\":\"urn:dev:mac:0024befffe804ff1:\",\"bt\":1700000000,\"n\":\"pm1_0\",\
+             \"u\":\"ug/m3\",\"v\":1},{\"n\":\"pm2_5\",\"u\":\"ug/m3\",\"v\":2.5},\
+             {\"n\":\"pm4_0\",\"u\":\"ug/m3\",\"v\":3},{\"n\":\"pm10_0\",\"u\":\"ug/m3\",\"v\":4},\
+             {\"n\":\"humidity\",\"u\":\"%RH\",\"v\":40},\
+             {\"n\":\"temperature\",\"u\":\"Cel\",\"v\":21.5},\
+             {\"n\":\"voc_index\",\"v\":100},{\"n\":\"co2\",\"u\":\"ppm\",\"v\":420}]"
+        );
+    }
+
+    #[test]
+    fn senml_sink_publishes_encoded_pack() {
+        let mut buffer = [0; 256];
+        let mut published = Vec::new();
+        SenmlSink::new(
+            Senml::new("sen66:"),
+            SenmlFormat::Cbor,
+            &mut buffer,
+            |encoded: &[u8]| -> Result<(), ()> {
+                published.push(encoded.to_vec());
+                Ok(())
+            },
+        )
+        .publish(&Timestamped::new(0, measurement()))
+        .unwrap();
+
+        let mut expected = [0; 256];
+        let len = Senml::new("sen66:")
+            .encode_cbor(&measurement(), &mut expected)
+            .unwrap();
+        assert_eq!(published, [&expected[..len]]);
+
+        let mut buffer = [0; 8];
+        let mut sink = SenmlSink::new(
+            Senml::new("sen66:"),
+            SenmlFormat::Json,
+            &mut buffer,
+            |_: &[u8]| -> Result<(), ()> { Ok(()) },
+        );
+        assert_eq!(
+            sink.publish(&Timestamped::new(0, measurement())),
+            Err(SinkError::Encoding(DataError::BufferTooSmall))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn senml_uses_wall_clock_timestamp() {
+        let stamped = crate::data::UtcTimestamped::new(
+            chrono::DateTime::from_timestamp_millis(1_700_000_000_900).unwrap(),
+            measurement(),
+        );
+        assert_eq!(
+            Senml::new("d:").with_timestamp(stamped.timestamp),
+            Senml::new("d:").with_base_time(1_700_000_000)
+        );
+    }
+
+    #[test]
+    fn senml_uses_serial_number_urn() {
+        let serial = SerialNumber::try_from(
+            &[
+                0x31, 0x32, 0x60, 0x33, 0x00, 0x1E, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00,
+                0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00,
+                0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81, 0x00, 0x00, 0x81,
+                0x00, 0x00, 0x81, 0x00, 0x00, 0x81,
+            ][..],
+        )
+        .unwrap();
+        let mut buffer = [0; 512];
+        let len = Senml::with_serial_number(&serial)
+            .encode_json(&measurement(), &mut buffer)
+            .unwrap();
+        assert!(buffer[..len].starts_with(b"[{\"bn\":\"urn:dev:sn:123:\",\"n\":\"pm1_0\""));
+    }
+
+    #[test]
+    fn senml_encodes_cbor_pack() {
+        let measurement = Measurement {
+            pm1_0: Measurement::PM_UNAVAILABLE,
+            pm2_5: Measurement::PM_UNAVAILABLE,
+            pm4_0: Measurement::PM_UNAVAILABLE,
+            pm10_0: Measurement::PM_UNAVAILABLE,
+            relative_humidity: Measurement::RH_UNAVAILABLE,
+            temperature: Measurement::TEMPERATURE_UNAVAILABLE,
+            nox_index: Measurement::INDEX_UNAVAILABLE,
+            ..measurement()
+        };
+        let mut buffer = [0; 64];
+        let len = Senml::new("d:")
+            .with_base_time(1)
+            .encode_cbor(&measurement, &mut buffer)
+            .unwrap();
+        assert_eq!(
+            buffer[..len],
+            [
+                0x82, 0xA4, 0x21, 0x62, b'd', b':', 0x22, 0x01, 0x00, 0x69, b'v', b'o', b'c', b'_',
+                b'i', b'n', b'd', b'e', b'x', 0x02, 0xFA, 0x42, 0xC8, 0x00, 0x00, 0xA3, 0x00, 0x63,
+                b'c', b'o', b'2', 0x01, 0x63, b'p', b'p', b'm', 0x02, 0xFA, 0x43, 0xD2, 0x00, 0x00,
+            ]
+        );
+    }
+
+    #[test]
+    fn senml_reports_small_buffer() {
+        let mut buffer = [0; 16];
+        let senml = Senml::new("device:");
+        assert_eq!(
+            senml.encode_json(&measurement(), &mut buffer),
+            Err(DataError::BufferTooSmall)
+        );
+        assert_eq!(
+            senml.encode_cbor(&measurement(), &mut buffer),
+            Err(DataError::BufferTooSmall)
+        );
+    }
+}
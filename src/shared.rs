@@ -0,0 +1,493 @@
+//! Shared access to an async [`Sen66`] from multiple tasks.
+//!
+//! [`Sen66Shared`] owns the driver behind an async mutex. Tasks obtain lightweight
+//! [`Sen66Handle`]s which can be copied freely and lock the driver for the duration of each
+//! command. For sequences of commands which must not be interleaved with other tasks, lock the
+//! driver explicitly with [`Sen66Handle::lock`]. Alternatively, [`Sen66Shared::split`] the driver
+//! into a [`Sen66Reader`] and a [`Sen66Configurator`] to hand each task only the commands it
+//! needs.
+
+use embassy_sync::{
+    blocking_mutex::raw::RawMutex,
+    mutex::{Mutex, MutexGuard},
+};
+use embedded_hal_async::{delay::DelayNs, i2c::I2c};
+
+#[cfg(feature = "tuning")]
+use crate::configuration::{NoxTuning, VocTuning};
+#[cfg(feature = "raw-measurements")]
+use crate::data::{Concentrations, RawMeasurement};
+use crate::{
+    asynch::Sen66,
+    configuration::{AmbientPressure, SensorAltitude, TemperatureAcceleration, TemperatureOffset},
+    data::{AscState, DataStatus, Measurement},
+    error::Sen66Error,
+};
+
+/// Driver wrapped in an async mutex, allowing multiple tasks to use the sensor concurrently.
+///
+/// The raw mutex `M` selects the locking strategy, e.g.
+/// `embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex` to share the driver between
+/// interrupt priorities or `NoopRawMutex` within a single executor.
+pub struct Sen66Shared<M: RawMutex, DELAY, I2C> {
+    sensor: Mutex<M, Sen66<DELAY, I2C>>,
+}
+
+impl<M: RawMutex, DELAY, I2C> Sen66Shared<M, DELAY, I2C> {
+    /// Wraps the `sensor` for shared access.
+    pub const fn new(sensor: Sen66<DELAY, I2C>) -> Self {
+        Self {
+            sensor: Mutex::new(sensor),
+        }
+    }
+
+    /// Creates a handle to the shared driver. Handles are [`Copy`] and can be passed to
+    /// multiple tasks.
+    pub fn handle(&self) -> Sen66Handle<'_, M, DELAY, I2C> {
+        Sen66Handle { shared: self }
+    }
+
+    /// Splits the driver into a [`Sen66Reader`] for the measurement data and a
+    /// [`Sen66Configurator`] for the configuration, e.g. to read data in a fast task while a slow
+    /// task occasionally adjusts the ambient pressure. Each command of either half locks the
+    /// driver for its duration.
+    pub fn split(
+        &self,
+    ) -> (
+        Sen66Reader<'_, M, DELAY, I2C>,
+        Sen66Configurator<'_, M, DELAY, I2C>,
+    ) {
+        (
+            Sen66Reader {
+                handle: self.handle(),
+            },
+            Sen66Configurator {
+                handle: self.handle(),
+            },
+        )
+    }
+
+    /// Returns the wrapped driver.
+    pub fn into_inner(self) -> Sen66<DELAY, I2C> {
+        self.sensor.into_inner()
+    }
+}
+
+/// Lightweight handle to a [`Sen66Shared`] driver. Each command locks the driver for its
+/// duration.
+pub struct Sen66Handle<'a, M: RawMutex, DELAY, I2C> {
+    shared: &'a Sen66Shared<M, DELAY, I2C>,
+}
+
+impl<M: RawMutex, DELAY, I2C> Clone for Sen66Handle<'_, M, DELAY, I2C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M: RawMutex, DELAY, I2C> Copy for Sen66Handle<'_, M, DELAY, I2C> {}
+
+impl<'a, M: RawMutex, DELAY, I2C> Sen66Handle<'a, M, DELAY, I2C> {
+    /// Locks the driver for exclusive access, waiting for other tasks to release it. The lock is
+    /// held until the returned guard is dropped.
+    pub async fn lock(&self) -> MutexGuard<'a, M, Sen66<DELAY, I2C>> {
+        self.shared.sensor.lock().await
+    }
+}
+
+impl<M: RawMutex, DELAY: DelayNs, I2C: I2c<Error = ERR>, ERR: embedded_hal::i2c::Error>
+    Sen66Handle<'_, M, DELAY, I2C>
+{
+    /// See [`is_data_ready`](Sen66::is_data_ready).
+    ///
+    /// # Errors
+    ///
+    /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+    ///   I2C bus occurs.
+    /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+    ///   corrupted or wrong.
+    /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+    ///   Idle state.
+    pub async fn is_data_ready(&self) -> Result<DataStatus, Sen66Error<ERR>> {
+        self.lock().await.is_data_ready().await
+    }
+
+    /// See [`read_measured_values`](Sen66::read_measured_values).
+    ///
+    /// # Errors
+    ///
+    /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+    ///   I2C bus occurs.
+    /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+    ///   corrupted or wrong.
+    /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+    ///   Idle state.
+    pub async fn read_measured_values(&self) -> Result<Measurement, Sen66Error<ERR>> {
+        self.lock().await.read_measured_values().await
+    }
+
+    #[cfg(feature = "raw-measurements")]
+    /// See [`read_measured_raw_values`](Sen66::read_measured_raw_values).
+    ///
+    /// # Errors
+    ///
+    /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+    ///   I2C bus occurs.
+    /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+    ///   corrupted or wrong.
+    /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+    ///   Idle state.
+    pub async fn read_measured_raw_values(&self) -> Result<RawMeasurement, Sen66Error<ERR>> {
+        self.lock().await.read_measured_raw_values().await
+    }
+
+    #[cfg(feature = "raw-measurements")]
+    /// See [`read_number_concentrations`](Sen66::read_number_concentrations).
+    ///
+    /// # Errors
+    ///
+    /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+    ///   I2C bus occurs.
+    /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+    ///   corrupted or wrong.
+    /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+    ///   Idle state.
+    pub async fn read_number_concentrations(&self) -> Result<Concentrations, Sen66Error<ERR>> {
+        self.lock().await.read_number_concentrations().await
+    }
+}
+
+/// Half of a split [`Sen66Shared`] driver reading the measurement data, see
+/// [`Sen66Shared::split`].
+pub struct Sen66Reader<'a, M: RawMutex, DELAY, I2C> {
+    handle: Sen66Handle<'a, M, DELAY, I2C>,
+}
+
+impl<M: RawMutex, DELAY: DelayNs, I2C: I2c<Error = ERR>, ERR: embedded_hal::i2c::Error>
+    Sen66Reader<'_, M, DELAY, I2C>
+{
+    /// See [`is_data_ready`](Sen66Handle::is_data_ready).
+    ///
+    /// # Errors
+    ///
+    /// - See [`is_data_ready`](Sen66Handle::is_data_ready).
+    pub async fn is_data_ready(&self) -> Result<DataStatus, Sen66Error<ERR>> {
+        self.handle.is_data_ready().await
+    }
+
+    /// See [`read_measured_values`](Sen66Handle::read_measured_values).
+    ///
+    /// # Errors
+    ///
+    /// - See [`read_measured_values`](Sen66Handle::read_measured_values).
+    pub async fn read_measured_values(&self) -> Result<Measurement, Sen66Error<ERR>> {
+        self.handle.read_measured_values().await
+    }
+
+    #[cfg(feature = "raw-measurements")]
+    /// See [`read_measured_raw_values`](Sen66Handle::read_measured_raw_values).
+    ///
+    /// # Errors
+    ///
+    /// - See [`read_measured_raw_values`](Sen66Handle::read_measured_raw_values).
+    pub async fn read_measured_raw_values(&self) -> Result<RawMeasurement, Sen66Error<ERR>> {
+        self.handle.read_measured_raw_values().await
+    }
+
+    #[cfg(feature = "raw-measurements")]
+    /// See [`read_number_concentrations`](Sen66Handle::read_number_concentrations).
+    ///
+    /// # Errors
+    ///
+    /// - See [`read_number_concentrations`](Sen66Handle::read_number_concentrations).
+    pub async fn read_number_concentrations(&self) -> Result<Concentrations, Sen66Error<ERR>> {
+        self.handle.read_number_concentrations().await
+    }
+}
+
+/// Half of a split [`Sen66Shared`] driver adjusting the sensor's configuration, see
+/// [`Sen66Shared::split`].
+pub struct Sen66Configurator<'a, M: RawMutex, DELAY, I2C> {
+    handle: Sen66Handle<'a, M, DELAY, I2C>,
+}
+
+impl<M: RawMutex, DELAY: DelayNs, I2C: I2c<Error = ERR>, ERR: embedded_hal::i2c::Error>
+    Sen66Configurator<'_, M, DELAY, I2C>
+{
+    /// See [`set_temperature_offset`](Sen66::set_temperature_offset).
+    ///
+    /// # Errors
+    ///
+    /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+    ///   I2C bus occurs.
+    pub async fn set_temperature_offset(
+        &self,
+        parameter: TemperatureOffset,
+    ) -> Result<(), Sen66Error<ERR>> {
+        self.handle
+            .lock()
+            .await
+            .set_temperature_offset(parameter)
+            .await
+    }
+
+    /// See [`set_temperature_acceleration`](Sen66::set_temperature_acceleration).
+    ///
+    /// # Errors
+    ///
+    /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+    ///   I2C bus occurs.
+    /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+    ///   Measuring state.
+    pub async fn set_temperature_acceleration(
+        &self,
+        parameter: TemperatureAcceleration,
+    ) -> Result<(), Sen66Error<ERR>> {
+        self.handle
+            .lock()
+            .await
+            .set_temperature_acceleration(parameter)
+            .await
+    }
+
+    /// See [`get_ambient_pressure`](Sen66::get_ambient_pressure).
+    ///
+    /// # Errors
+    ///
+    /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+    ///   I2C bus occurs.
+    /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+    ///   corrupted or wrong.
+    pub async fn get_ambient_pressure(&self) -> Result<AmbientPressure, Sen66Error<ERR>> {
+        self.handle.lock().await.get_ambient_pressure().await
+    }
+
+    /// See [`set_ambient_pressure`](Sen66::set_ambient_pressure).
+    ///
+    /// # Errors
+    ///
+    /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+    ///   I2C bus occurs.
+    /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+    ///   enabled and the data read back is corrupted or wrong.
+    /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+    ///   verification is enabled and the ambient pressure read back differs.
+    pub async fn set_ambient_pressure(
+        &self,
+        parameter: AmbientPressure,
+    ) -> Result<(), Sen66Error<ERR>> {
+        self.handle
+            .lock()
+            .await
+            .set_ambient_pressure(parameter)
+            .await
+    }
+
+    /// See [`get_sensor_altitude`](Sen66::get_sensor_altitude).
+    ///
+    /// # Errors
+    ///
+    /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+    ///   I2C bus occurs.
+    /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+    ///   Idle state.
+    /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+    ///   corrupted or wrong.
+    pub async fn get_sensor_altitude(&self) -> Result<SensorAltitude, Sen66Error<ERR>> {
+        self.handle.lock().await.get_sensor_altitude().await
+    }
+
+    /// See [`set_sensor_altitude`](Sen66::set_sensor_altitude).
+    ///
+    /// # Errors
+    ///
+    /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+    ///   I2C bus occurs.
+    /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+    ///   Idle state.
+    /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+    ///   enabled and the data read back is corrupted or wrong.
+    /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+    ///   verification is enabled and the sensor altitude read back differs.
+    pub async fn set_sensor_altitude(
+        &self,
+        parameter: SensorAltitude,
+    ) -> Result<(), Sen66Error<ERR>> {
+        self.handle
+            .lock()
+            .await
+            .set_sensor_altitude(parameter)
+            .await
+    }
+
+    /// See [`get_co2_asc_state`](Sen66::get_co2_asc_state).
+    ///
+    /// # Errors
+    ///
+    /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+    ///   I2C bus occurs.
+    /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+    ///   Idle state.
+    /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+    ///   corrupted or wrong.
+    pub async fn get_co2_asc_state(&self) -> Result<AscState, Sen66Error<ERR>> {
+        self.handle.lock().await.get_co2_asc_state().await
+    }
+
+    /// See [`set_co2_asc_state`](Sen66::set_co2_asc_state).
+    ///
+    /// # Errors
+    ///
+    /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+    ///   I2C bus occurs.
+    /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+    ///   Idle state.
+    /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+    ///   enabled and the data read back is corrupted or wrong.
+    /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+    ///   verification is enabled and the ASC state read back differs.
+    pub async fn set_co2_asc_state(&self, new_state: AscState) -> Result<(), Sen66Error<ERR>> {
+        self.handle.lock().await.set_co2_asc_state(new_state).await
+    }
+
+    #[cfg(feature = "tuning")]
+    /// See [`get_voc_tuning_parameters`](Sen66::get_voc_tuning_parameters).
+    ///
+    /// # Errors
+    ///
+    /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+    ///   I2C bus occurs.
+    /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+    ///   Idle state.
+    /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+    ///   corrupted or wrong.
+    pub async fn get_voc_tuning_parameters(&self) -> Result<VocTuning, Sen66Error<ERR>> {
+        self.handle.lock().await.get_voc_tuning_parameters().await
+    }
+
+    #[cfg(feature = "tuning")]
+    /// See [`set_voc_tuning_parameters`](Sen66::set_voc_tuning_parameters).
+    ///
+    /// # Errors
+    ///
+    /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+    ///   I2C bus occurs.
+    /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+    ///   Idle state.
+    /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+    ///   enabled and the data read back is corrupted or wrong.
+    /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+    ///   verification is enabled and the VOC tuning parameters read back differs.
+    pub async fn set_voc_tuning_parameters(
+        &self,
+        parameter: VocTuning,
+    ) -> Result<(), Sen66Error<ERR>> {
+        self.handle
+            .lock()
+            .await
+            .set_voc_tuning_parameters(parameter)
+            .await
+    }
+
+    #[cfg(feature = "tuning")]
+    /// See [`get_nox_tuning_parameters`](Sen66::get_nox_tuning_parameters).
+    ///
+    /// # Errors
+    ///
+    /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+    ///   I2C bus occurs.
+    /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+    ///   Idle state.
+    /// - [`DataError`](crate::error::Sen66Error::DataError): If the received data is
+    ///   corrupted or wrong.
+    pub async fn get_nox_tuning_parameters(&self) -> Result<NoxTuning, Sen66Error<ERR>> {
+        self.handle.lock().await.get_nox_tuning_parameters().await
+    }
+
+    #[cfg(feature = "tuning")]
+    /// See [`set_nox_tuning_parameters`](Sen66::set_nox_tuning_parameters).
+    ///
+    /// # Errors
+    ///
+    /// - [`I2cError`](crate::error::Sen66Error::I2cError): If an error on the underlying
+    ///   I2C bus occurs.
+    /// - [`WrongState`](crate::error::Sen66Error::WrongState): If the command is called in
+    ///   Idle state.
+    /// - [`DataError`](crate::error::Sen66Error::DataError): If write verification is
+    ///   enabled and the data read back is corrupted or wrong.
+    /// - [`VerificationFailed`](crate::error::Sen66Error::VerificationFailed): If write
+    ///   verification is enabled and the NOx tuning parameters read back differs.
+    pub async fn set_nox_tuning_parameters(
+        &self,
+        parameter: NoxTuning,
+    ) -> Result<(), Sen66Error<ERR>> {
+        self.handle
+            .lock()
+            .await
+            .set_nox_tuning_parameters(parameter)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use embassy_sync::blocking_mutex::raw::NoopRawMutex;
+    use embedded_hal_mock::eh1::{
+        delay::NoopDelay,
+        i2c::{Mock as I2cMock, Transaction as I2cTransaction},
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn handles_share_driver() {
+        let expected_transaction = [
+            I2cTransaction::write(0x6B, vec![0x00, 0x21]),
+            I2cTransaction::write(0x6B, vec![0x02, 0x02]),
+            I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+            I2cTransaction::write(0x6B, vec![0x02, 0x02]),
+            I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x00, 0x81]),
+        ];
+        let i2c = I2cMock::new(&expected_transaction);
+        let shared: Sen66Shared<NoopRawMutex, _, _> =
+            Sen66Shared::new(Sen66::new(NoopDelay::new(), i2c));
+        let display = shared.handle();
+        let telemetry = display;
+
+        display.lock().await.start_measurement().await.unwrap();
+        assert_eq!(display.is_data_ready().await.unwrap(), DataStatus::Ready);
+        assert_eq!(
+            telemetry.is_data_ready().await.unwrap(),
+            DataStatus::NotReady
+        );
+        shared.into_inner().kill().await.1.done();
+    }
+
+    #[tokio::test]
+    async fn split_halves_share_driver() {
+        let expected_transaction = [
+            I2cTransaction::write(0x6B, vec![0x00, 0x21]),
+            I2cTransaction::write(0x6B, vec![0x67, 0x20, 0x03, 0xE8, 0xD4]),
+            I2cTransaction::write(0x6B, vec![0x02, 0x02]),
+            I2cTransaction::read(0x6B | 0x01, vec![0x00, 0x01, 0xB0]),
+        ];
+        let i2c = I2cMock::new(&expected_transaction);
+        let shared: Sen66Shared<NoopRawMutex, _, _> =
+            Sen66Shared::new(Sen66::new(NoopDelay::new(), i2c));
+        shared
+            .handle()
+            .lock()
+            .await
+            .start_measurement()
+            .await
+            .unwrap();
+        let (reader, configurator) = shared.split();
+
+        configurator
+            .set_ambient_pressure(AmbientPressure::try_from(1_000).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(reader.is_data_ready().await.unwrap(), DataStatus::Ready);
+        shared.into_inner().kill().await.1.done();
+    }
+}
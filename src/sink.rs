@@ -0,0 +1,119 @@
+//! Destinations for sampled measurements, e.g. a local history, a SenML payload published via
+//! MQTT or a log file.
+//!
+//! A [`MeasurementSink`] receives each [`Timestamped`] measurement. Sinks are combined into a
+//! fan-out by pairing them in tuples, which may be nested to publish to more than two sinks,
+//! e.g. `(&mut history, (senml, log))`.
+
+use core::convert::Infallible;
+
+use crate::{
+    analysis::History,
+    data::{Measurement, Timestamped},
+    error::FanoutError,
+};
+
+/// Destination a sampled measurement is published to.
+///
+/// Implemented for the [`History`] ring buffer, for closures wrapped in a [`FnSink`], for the
+/// [`CsvSink`](crate::csv::CsvSink), for the `SenmlSink` of the `senml` feature, for the
+/// `JsonSink` of the `serde-json-core` feature, for mutable references to sinks and for pairs of
+/// sinks, which publish to both.
+pub trait MeasurementSink {
+    /// Error emitted if publishing fails.
+    type Error;
+
+    /// Publishes the `measurement`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Self::Error`]: If the measurement could not be published.
+    fn publish(&mut self, measurement: &Timestamped<Measurement>) -> Result<(), Self::Error>;
+}
+
+impl<S: MeasurementSink + ?Sized> MeasurementSink for &mut S {
+    type Error = S::Error;
+
+    fn publish(&mut self, measurement: &Timestamped<Measurement>) -> Result<(), Self::Error> {
+        (**self).publish(measurement)
+    }
+}
+
+/// [`MeasurementSink`] calling a closure with each measurement, e.g. to hand it to a transport
+/// the crate does not provide an encoder for.
+#[derive(Clone, Copy, Debug)]
+pub struct FnSink<F>(pub F);
+
+impl<F, E> MeasurementSink for FnSink<F>
+where
+    F: FnMut(&Timestamped<Measurement>) -> Result<(), E>,
+{
+    type Error = E;
+
+    fn publish(&mut self, measurement: &Timestamped<Measurement>) -> Result<(), E> {
+        (self.0)(measurement)
+    }
+}
+
+impl<const N: usize> MeasurementSink for History<N> {
+    type Error = Infallible;
+
+    /// Adds the measurement to the history, dropping its timestamp.
+    fn publish(&mut self, measurement: &Timestamped<Measurement>) -> Result<(), Infallible> {
+        self.push(measurement.value);
+        Ok(())
+    }
+}
+
+impl<A: MeasurementSink, B: MeasurementSink> MeasurementSink for (A, B) {
+    type Error = FanoutError<A::Error, B::Error>;
+
+    /// Publishes the measurement to both sinks, even if the first one fails.
+    fn publish(&mut self, measurement: &Timestamped<Measurement>) -> Result<(), Self::Error> {
+        let first = self.0.publish(measurement);
+        let second = self.1.publish(measurement);
+        first.map_err(FanoutError::First)?;
+        second.map_err(FanoutError::Second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(co2: u16) -> Timestamped<Measurement> {
+        Timestamped::new(
+            u64::from(co2),
+            Measurement {
+                co2,
+                ..Measurement::fixture()
+            },
+        )
+    }
+
+    #[test]
+    fn fanout_publishes_to_all_sinks() {
+        let mut history = History::<4>::new();
+        let mut timestamps = Vec::new();
+        let mut failing = FnSink(|_: &Timestamped<Measurement>| Err("offline"));
+        {
+            let mut sinks = (
+                &mut history,
+                (
+                    &mut failing,
+                    FnSink(|measurement: &Timestamped<Measurement>| -> Result<(), ()> {
+                        timestamps.push(measurement.timestamp_ms);
+                        Ok(())
+                    }),
+                ),
+            );
+            assert_eq!(
+                sinks.publish(&measurement(400)),
+                Err(FanoutError::Second(FanoutError::First("offline")))
+            );
+            assert!(sinks.publish(&measurement(500)).is_err());
+        }
+        assert_eq!(history.len(), 2);
+        assert_eq!(timestamps, [400, 500]);
+    }
+}
@@ -0,0 +1,415 @@
+//! Checkpointing of the [`PersistentState`], e.g. the VOC algorithm state, to a NOR flash region
+//! of any [`embedded_storage::nor_flash::NorFlash`]. Smaller trackers, e.g. the
+//! [`NoxConditioning`](crate::conditioning::NoxConditioning), are saved to a fixed offset of any
+//! [`embedded_storage::Storage`] instead.
+//!
+//! The region is divided into slots. Every checkpoint is written to the slot following the
+//! latest one, so the erase cycles are spread over the whole region. A sector is only erased
+//! once the rotation reaches it again, and the latest checkpoint always remains in another
+//! sector until the new one is written. Interrupted writes are detected and skipped when
+//! loading.
+
+use core::ops::Range;
+
+use embedded_storage::{ReadStorage, Storage, nor_flash::NorFlash};
+
+#[cfg(feature = "tuning")]
+use crate::{conditioning::VocStateStore, data::VocAlgorithmState};
+use crate::{
+    configuration::PersistentState,
+    error::{DataError, StorageError},
+    util::compute_crc8,
+};
+
+/// Size of a checkpoint: the sequence number, its CRC and the persisted state.
+const RECORD_SIZE: usize = 5 + PersistentState::SERIALIZED_SIZE;
+/// Largest supported slot size, limiting the write granularity of the flash.
+const MAX_SLOT_SIZE: usize = 256;
+/// Value of erased flash.
+const ERASED: u8 = 0xFF;
+
+/// Latest checkpoint found in the region.
+#[derive(Clone, Copy)]
+struct Latest {
+    address: u32,
+    sequence: u32,
+}
+
+/// Wear-leveled storage of [`PersistentState`] checkpoints in a region of a NOR flash.
+///
+/// The region must be aligned to the flash's erase sectors and span at least two of them.
+pub struct FlashCheckpoint<F> {
+    flash: F,
+    region: Range<u32>,
+    latest: Option<Latest>,
+    scanned: bool,
+}
+
+impl<F: NorFlash> FlashCheckpoint<F> {
+    /// Size of a slot, the record padded to the flash's read and write granularity.
+    const SLOT_SIZE: usize = RECORD_SIZE.next_multiple_of(if F::READ_SIZE > F::WRITE_SIZE {
+        F::READ_SIZE
+    } else {
+        F::WRITE_SIZE
+    });
+
+    /// Creates checkpoint storage in `region` of the `flash`. The region is scanned for the
+    /// latest checkpoint on first use.
+    /// - `flash`: NOR flash holding the checkpoints.
+    /// - `region`: Byte range of the flash reserved for the checkpoints.
+    ///
+    /// # Errors
+    ///
+    /// - [`InvalidRegion`](crate::error::StorageError::InvalidRegion): If the region is not
+    ///   aligned to the erase sectors, spans fewer than two sectors or exceeds the flash, or if a
+    ///   slot does not fit into a sector.
+    pub fn new(flash: F, region: Range<u32>) -> Result<Self, StorageError<F::Error>> {
+        let erase_size = F::ERASE_SIZE as u32;
+        let valid = Self::SLOT_SIZE <= MAX_SLOT_SIZE
+            && Self::SLOT_SIZE <= F::ERASE_SIZE
+            && region.start % erase_size == 0
+            && region.end % erase_size == 0
+            && region.end.saturating_sub(region.start) >= 2 * erase_size
+            && region.end as usize <= flash.capacity();
+        if !valid {
+            return Err(StorageError::InvalidRegion);
+        }
+        Ok(Self {
+            flash,
+            region,
+            latest: None,
+            scanned: false,
+        })
+    }
+
+    /// Releases the flash.
+    pub fn release(self) -> F {
+        self.flash
+    }
+
+    /// Returns the latest valid checkpoint, `None` if the region does not hold any.
+    ///
+    /// # Errors
+    ///
+    /// - [`Flash`](crate::error::StorageError::Flash): If reading the flash fails.
+    pub fn load(&mut self) -> Result<Option<PersistentState>, StorageError<F::Error>> {
+        let mut buffer = [0; MAX_SLOT_SIZE];
+        let mut latest = None;
+        for address in self.slots() {
+            if let Some((sequence, state)) = self.read_slot(address, &mut buffer)? {
+                if latest.is_none_or(|(latest, _): (Latest, _)| sequence > latest.sequence) {
+                    latest = Some((Latest { address, sequence }, state));
+                }
+            }
+        }
+        self.latest = latest.map(|(latest, _)| latest);
+        self.scanned = true;
+        Ok(latest.map(|(_, state)| state))
+    }
+
+    /// Writes `state` as the new checkpoint to the slot following the latest one, erasing the
+    /// slot's sector if the rotation wrapped around to it.
+    /// - `state`: State to persist.
+    ///
+    /// # Errors
+    ///
+    /// - [`Flash`](crate::error::StorageError::Flash): If reading, erasing or writing the flash
+    ///   fails.
+    pub fn store(&mut self, state: &PersistentState) -> Result<(), StorageError<F::Error>> {
+        if !self.scanned {
+            self.load()?;
+        }
+        let mut buffer = [ERASED; MAX_SLOT_SIZE];
+        let slot = &mut buffer[..Self::SLOT_SIZE];
+        let mut address = self.next_slot();
+        self.flash
+            .read(address, slot)
+            .map_err(StorageError::Flash)?;
+        if slot.iter().any(|&byte| byte != ERASED) {
+            // Stale or partially written data, start over in a freshly erased sector.
+            if !self.is_sector_start(address) {
+                address = self.next_sector(address);
+            }
+            self.flash
+                .erase(address, address + F::ERASE_SIZE as u32)
+                .map_err(StorageError::Flash)?;
+        }
+
+        let sequence = self
+            .latest
+            .map_or(0, |latest| latest.sequence.wrapping_add(1));
+        slot.fill(ERASED);
+        slot[..4].copy_from_slice(&sequence.to_be_bytes());
+        slot[4] = compute_crc8(&slot[..4]);
+        state.to_persistent_bytes(&mut slot[5..])?;
+        self.flash
+            .write(address, slot)
+            .map_err(StorageError::Flash)?;
+        self.latest = Some(Latest { address, sequence });
+        Ok(())
+    }
+
+    /// Returns the start addresses of all slots in the region.
+    fn slots(&self) -> impl Iterator<Item = u32> + use<F> {
+        let per_sector = (F::ERASE_SIZE / Self::SLOT_SIZE) as u32;
+        let slot_size = Self::SLOT_SIZE as u32;
+        (self.region.start..self.region.end)
+            .step_by(F::ERASE_SIZE)
+            .flat_map(move |sector| (0..per_sector).map(move |slot| sector + slot * slot_size))
+    }
+
+    /// Reads the checkpoint in the slot at `address`, `None` if it is empty or invalid.
+    fn read_slot(
+        &mut self,
+        address: u32,
+        buffer: &mut [u8; MAX_SLOT_SIZE],
+    ) -> Result<Option<(u32, PersistentState)>, StorageError<F::Error>> {
+        let slot = &mut buffer[..Self::SLOT_SIZE];
+        self.flash
+            .read(address, slot)
+            .map_err(StorageError::Flash)?;
+        let (header, record) = slot[..RECORD_SIZE].split_at(5);
+        if compute_crc8(&header[..4]) != header[4] {
+            return Ok(None);
+        }
+        let sequence = u32::from_be_bytes([header[0], header[1], header[2], header[3]]);
+        Ok(PersistentState::from_persistent_bytes(record)
+            .ok()
+            .map(|state| (sequence, state)))
+    }
+
+    /// Returns the slot following the latest checkpoint.
+    fn next_slot(&self) -> u32 {
+        let Some(latest) = self.latest else {
+            return self.region.start;
+        };
+        let address = latest.address + Self::SLOT_SIZE as u32;
+        let sector_end = self.sector_start(latest.address) + F::ERASE_SIZE as u32;
+        if address + Self::SLOT_SIZE as u32 > sector_end {
+            self.wrap(sector_end)
+        } else {
+            address
+        }
+    }
+
+    /// Returns the start of the sector following the one containing `address`.
+    fn next_sector(&self, address: u32) -> u32 {
+        self.wrap(self.sector_start(address) + F::ERASE_SIZE as u32)
+    }
+
+    /// Wraps `address` around to the start of the region when it reaches its end.
+    fn wrap(&self, address: u32) -> u32 {
+        if address >= self.region.end {
+            self.region.start
+        } else {
+            address
+        }
+    }
+
+    fn sector_start(&self, address: u32) -> u32 {
+        address - (address - self.region.start) % F::ERASE_SIZE as u32
+    }
+
+    fn is_sector_start(&self, address: u32) -> bool {
+        self.sector_start(address) == address
+    }
+}
+
+#[cfg(feature = "tuning")]
+impl<F: NorFlash> VocStateStore for FlashCheckpoint<F> {
+    type Error = StorageError<F::Error>;
+
+    /// Checkpoints the VOC algorithm `state`, keeping the configuration of the latest checkpoint.
+    fn save(&mut self, state: &VocAlgorithmState) -> Result<(), Self::Error> {
+        let mut persistent = self.load()?.unwrap_or_default();
+        persistent.voc_state = Some(*state);
+        self.store(&persistent)
+    }
+}
+
+/// Writes `data` followed by its CRC to the `storage` at `offset`.
+pub(crate) fn write_record<S: Storage>(
+    storage: &mut S,
+    offset: u32,
+    data: &[u8],
+) -> Result<(), StorageError<S::Error>> {
+    storage.write(offset, data).map_err(StorageError::Flash)?;
+    storage
+        .write(offset + data.len() as u32, &[compute_crc8(data)])
+        .map_err(StorageError::Flash)
+}
+
+/// Reads a record written by [`write_record`] from the `storage` at `offset`.
+pub(crate) fn read_record<S: ReadStorage, const N: usize>(
+    storage: &mut S,
+    offset: u32,
+) -> Result<[u8; N], StorageError<S::Error>> {
+    let mut data = [0; N];
+    let mut crc = [0];
+    storage
+        .read(offset, &mut data)
+        .map_err(StorageError::Flash)?;
+    storage
+        .read(offset + N as u32, &mut crc)
+        .map_err(StorageError::Flash)?;
+    if compute_crc8(&data) != crc[0] {
+        return Err(DataError::CrcFailed.into());
+    }
+    Ok(data)
+}
+
+/// Byte-addressable storage backed by memory, for tests of the types saved with
+/// [`write_record`].
+#[cfg(test)]
+pub(crate) struct MockStorage(pub [u8; 64]);
+
+#[cfg(test)]
+impl ReadStorage for MockStorage {
+    type Error = ();
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        let offset = offset as usize;
+        bytes.copy_from_slice(self.0.get(offset..offset + bytes.len()).ok_or(())?);
+        Ok(())
+    }
+
+    fn capacity(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[cfg(test)]
+impl Storage for MockStorage {
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        let offset = offset as usize;
+        self.0
+            .get_mut(offset..offset + bytes.len())
+            .ok_or(())?
+            .copy_from_slice(bytes);
+        Ok(())
+    }
+}
+
+impl<F> core::fmt::Debug for FlashCheckpoint<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FlashCheckpoint")
+            .field("region", &self.region)
+            .field("latest", &self.latest.map(|latest| latest.sequence))
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_storage::nor_flash::{ErrorType, NorFlashErrorKind, ReadNorFlash};
+
+    use super::*;
+    #[cfg(not(feature = "tuning"))]
+    use crate::data::VocAlgorithmState;
+
+    struct MockFlash {
+        data: [u8; 1024],
+        erases: usize,
+    }
+
+    impl MockFlash {
+        fn new() -> Self {
+            Self {
+                data: [ERASED; 1024],
+                erases: 0,
+            }
+        }
+    }
+
+    impl ErrorType for MockFlash {
+        type Error = NorFlashErrorKind;
+    }
+
+    impl ReadNorFlash for MockFlash {
+        const READ_SIZE: usize = 1;
+
+        fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+            let offset = offset as usize;
+            bytes.copy_from_slice(&self.data[offset..offset + bytes.len()]);
+            Ok(())
+        }
+
+        fn capacity(&self) -> usize {
+            self.data.len()
+        }
+    }
+
+    impl NorFlash for MockFlash {
+        const WRITE_SIZE: usize = 4;
+        const ERASE_SIZE: usize = 256;
+
+        fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
+            self.data[from as usize..to as usize].fill(ERASED);
+            self.erases += 1;
+            Ok(())
+        }
+
+        fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+            for (cell, byte) in self.data[offset as usize..].iter_mut().zip(bytes) {
+                *cell &= byte;
+            }
+            Ok(())
+        }
+    }
+
+    fn state(value: u16) -> PersistentState {
+        PersistentState {
+            voc_state: Some(VocAlgorithmState::from([value, 0, 0, 0])),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn checkpoints_rotate_through_region() {
+        let mut checkpoint = FlashCheckpoint::new(MockFlash::new(), 256..768).unwrap();
+        assert_eq!(checkpoint.load(), Ok(None));
+        // Four 64 byte slots per sector, so 20 checkpoints wrap around the two sectors twice.
+        for value in 0..20 {
+            checkpoint.store(&state(value)).unwrap();
+        }
+        assert_eq!(checkpoint.load(), Ok(Some(state(19))));
+
+        let flash = checkpoint.release();
+        assert_eq!(flash.erases, 3);
+        assert!(flash.data[..256].iter().all(|&byte| byte == ERASED));
+        let mut checkpoint = FlashCheckpoint::new(flash, 256..768).unwrap();
+        checkpoint.store(&state(20)).unwrap();
+        assert_eq!(checkpoint.load(), Ok(Some(state(20))));
+    }
+
+    #[test]
+    fn corrupted_checkpoint_falls_back_to_previous() {
+        let mut checkpoint = FlashCheckpoint::new(MockFlash::new(), 0..512).unwrap();
+        checkpoint.store(&state(1)).unwrap();
+        checkpoint.store(&state(2)).unwrap();
+        let mut flash = checkpoint.release();
+        flash.data[64 + 20] ^= 0x01;
+        let mut checkpoint = FlashCheckpoint::new(flash, 0..512).unwrap();
+        assert_eq!(checkpoint.load(), Ok(Some(state(1))));
+        // The corrupted slot is skipped by moving on to the next sector.
+        checkpoint.store(&state(3)).unwrap();
+        assert_eq!(checkpoint.load(), Ok(Some(state(3))));
+        assert_eq!(checkpoint.release().erases, 1);
+    }
+
+    #[test]
+    fn misaligned_regions_are_rejected() {
+        assert!(matches!(
+            FlashCheckpoint::new(MockFlash::new(), 0..256),
+            Err(StorageError::InvalidRegion)
+        ));
+        assert!(matches!(
+            FlashCheckpoint::new(MockFlash::new(), 128..640),
+            Err(StorageError::InvalidRegion)
+        ));
+        assert!(matches!(
+            FlashCheckpoint::new(MockFlash::new(), 512..1280),
+            Err(StorageError::InvalidRegion)
+        ));
+    }
+}
@@ -23,17 +23,53 @@ pub(crate) fn compute_crc8(data: &[u8]) -> u8 {
     crc
 }
 
-pub(crate) fn check_deserialization(data: &[u8], expected_len: usize) -> Result<(), DataError> {
-    if data.len() != expected_len {
+/// Checks the size and CRCs of data received from the sensor and returns the `N` contained
+/// words.
+pub(crate) fn decode_words<const N: usize>(data: &[u8]) -> Result<[u16; N], DataError> {
+    if data.len() != N * 3 {
         return Err(DataError::ReceivedBufferWrongSize);
     }
-    if data
-        .chunks(3)
-        .any(|chunk| !crc8_matches(&chunk[..2], chunk[2]))
-    {
-        return Err(DataError::CrcFailed);
+    let mut words = [0; N];
+    for (word, chunk) in words.iter_mut().zip(data.chunks_exact(3)) {
+        let &[msb, lsb, crc] = chunk else {
+            return Err(DataError::ReceivedBufferWrongSize);
+        };
+        if !crc8_matches(&[msb, lsb], crc) {
+            return Err(DataError::CrcFailed);
+        }
+        *word = u16::from_be_bytes([msb, lsb]);
+    }
+    Ok(words)
+}
+
+/// Reads fixed-size fields from a persisted representation without panicking on short data.
+pub(crate) struct ByteReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// Reads the next `N` bytes.
+    pub(crate) fn take<const N: usize>(&mut self) -> Result<[u8; N], DataError> {
+        let (bytes, rest) = self
+            .data
+            .split_first_chunk::<N>()
+            .ok_or(DataError::ReceivedBufferWrongSize)?;
+        self.data = rest;
+        Ok(*bytes)
+    }
+
+    /// Ensures that all data has been read.
+    pub(crate) fn finish(self) -> Result<(), DataError> {
+        if self.data.is_empty() {
+            Ok(())
+        } else {
+            Err(DataError::ReceivedBufferWrongSize)
+        }
     }
-    Ok(())
 }
 
 pub(crate) fn check_scaling<T>(
@@ -86,6 +122,32 @@ pub(crate) const fn is_set(value: u32, bit: u32) -> bool {
     value & (1 << bit) != 0
 }
 
+/// Magnus coefficient β over water.
+#[cfg(any(feature = "derived", feature = "psychrometrics"))]
+pub(crate) const MAGNUS_BETA: f32 = 17.62;
+/// Magnus coefficient λ over water in °C.
+#[cfg(any(feature = "derived", feature = "psychrometrics"))]
+pub(crate) const MAGNUS_LAMBDA: f32 = 243.12;
+/// Magnus coefficient for the saturation vapor pressure over water at 0 °C in hPa.
+#[cfg(any(feature = "derived", feature = "psychrometrics"))]
+const MAGNUS_PRESSURE: f32 = 6.112;
+
+/// Returns the saturation vapor pressure over water at `temperature` in °C in hPa, using the
+/// Magnus formula with the coefficients of Sonntag (1990).
+#[cfg(any(feature = "derived", feature = "psychrometrics"))]
+pub(crate) fn magnus_vapor_pressure(temperature: f32) -> f32 {
+    MAGNUS_PRESSURE * libm::expf(MAGNUS_BETA * temperature / (MAGNUS_LAMBDA + temperature))
+}
+
+/// Returns the temperature and relative humidity of the `measurement` if both are available.
+#[cfg(any(feature = "derived", feature = "psychrometrics"))]
+pub(crate) fn rh_t(measurement: &crate::data::Measurement) -> Option<(f32, f32)> {
+    use crate::data::Measurement;
+    (measurement.temperature != Measurement::TEMPERATURE_UNAVAILABLE
+        && measurement.relative_humidity != Measurement::RH_UNAVAILABLE)
+        .then_some((measurement.temperature, measurement.relative_humidity))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -96,4 +158,150 @@ mod tests {
         let result = compute_crc8(&data);
         assert_eq!(result, 0x92);
     }
+
+    fn encode(words: &[u16]) -> Vec<u8> {
+        words
+            .iter()
+            .flat_map(|word| {
+                let [msb, lsb] = word.to_be_bytes();
+                [msb, lsb, compute_crc8(&[msb, lsb])]
+            })
+            .collect()
+    }
+
+    /// Checks that `valid` parses, while any other length or any single bit flip is rejected.
+    fn assert_rejects_malformed<T>(valid: &[u8])
+    where
+        T: for<'a> TryFrom<&'a [u8], Error = DataError>,
+    {
+        assert!(T::try_from(valid).is_ok());
+        for len in 0..=2 * valid.len() + 3 {
+            if len == valid.len() {
+                continue;
+            }
+            let data: Vec<u8> = valid.iter().copied().cycle().take(len).collect();
+            assert_eq!(
+                T::try_from(&data[..]).err(),
+                Some(DataError::ReceivedBufferWrongSize)
+            );
+        }
+        for i in 0..valid.len() {
+            for bit in 0..8 {
+                let mut data = valid.to_vec();
+                data[i] ^= 1 << bit;
+                assert_eq!(T::try_from(&data[..]).err(), Some(DataError::CrcFailed));
+            }
+        }
+    }
+
+    /// Checks that persisted representations of any other length are rejected.
+    fn assert_rejects_wrong_size<T>(valid: &[u8])
+    where
+        T: for<'a> TryFrom<&'a [u8], Error = DataError>,
+    {
+        assert!(T::try_from(valid).is_ok());
+        for len in (0..=2 * valid.len()).filter(|&len| len != valid.len()) {
+            assert_eq!(
+                T::try_from(&vec![0xFF; len][..]).err(),
+                Some(DataError::ReceivedBufferWrongSize)
+            );
+        }
+    }
+
+    #[test]
+    fn decode_words_checks_size_and_crc() {
+        assert_eq!(decode_words::<2>(&encode(&[0xBEEF, 1])), Ok([0xBEEF, 1]));
+        assert_eq!(decode_words::<0>(&[]), Ok([]));
+        assert_eq!(
+            decode_words::<1>(&[0xBE, 0xEF]),
+            Err(DataError::ReceivedBufferWrongSize)
+        );
+        assert_eq!(
+            decode_words::<1>(&[0xBE, 0xEF, 0x93]),
+            Err(DataError::CrcFailed)
+        );
+    }
+
+    #[test]
+    fn byte_reader_rejects_short_and_long_data() {
+        let mut reader = ByteReader::new(&[1, 2, 3]);
+        assert_eq!(reader.take::<2>(), Ok([1, 2]));
+        assert_eq!(reader.take::<2>(), Err(DataError::ReceivedBufferWrongSize));
+        assert_eq!(reader.finish(), Err(DataError::ReceivedBufferWrongSize));
+        let mut reader = ByteReader::new(&[1]);
+        assert_eq!(reader.take::<1>(), Ok([1]));
+        assert_eq!(reader.finish(), Ok(()));
+    }
+
+    #[test]
+    fn sensor_data_parsers_reject_malformed_input() {
+        use crate::{
+            configuration::{AmbientPressure, SensorAltitude},
+            data::{
+                AscState, DataStatus, DeviceStatusRegister, Measurement, ProductName, SerialNumber,
+            },
+        };
+
+        assert_rejects_malformed::<Measurement>(&encode(&[0; 9]));
+        assert_rejects_malformed::<DataStatus>(&encode(&[1]));
+        assert_rejects_malformed::<AscState>(&encode(&[1]));
+        assert_rejects_malformed::<DeviceStatusRegister>(&encode(&[0; 2]));
+        assert_rejects_malformed::<AmbientPressure>(&encode(&[1013]));
+        assert_rejects_malformed::<SensorAltitude>(&encode(&[0]));
+        let mut text = [0; 16];
+        text[0] = u16::from_be_bytes(*b"SE");
+        text[1] = u16::from_be_bytes(*b"N6");
+        text[2] = u16::from_be_bytes(*b"6\0");
+        assert_rejects_malformed::<ProductName>(&encode(&text));
+        assert_rejects_malformed::<SerialNumber>(&encode(&text));
+    }
+
+    #[cfg(feature = "co2-calibration")]
+    #[test]
+    fn co2_correction_parses_every_word() {
+        use crate::configuration::Co2Correction;
+
+        assert_rejects_malformed::<Co2Correction>(&encode(&[0x8000]));
+        for word in 0..=u16::MAX {
+            let correction = Co2Correction::try_from(&encode(&[word])[..]).unwrap();
+            assert_eq!(correction.is_valid(), word != 0xFFFF);
+        }
+    }
+
+    #[cfg(feature = "raw-measurements")]
+    #[test]
+    fn raw_parsers_reject_malformed_input() {
+        use crate::data::{Concentrations, RawMeasurement};
+
+        assert_rejects_malformed::<RawMeasurement>(&encode(&[0; 5]));
+        assert_rejects_malformed::<Concentrations>(&encode(&[0; 5]));
+    }
+
+    #[cfg(feature = "tuning")]
+    #[test]
+    fn tuning_parsers_reject_malformed_input() {
+        use crate::{
+            configuration::{NoxTuning, VocTuning},
+            data::VocAlgorithmState,
+        };
+
+        assert_rejects_malformed::<VocTuning>(&encode(&<[u16; 6]>::from(VocTuning::default())));
+        assert_rejects_malformed::<NoxTuning>(&encode(&<[u16; 6]>::from(NoxTuning::default())));
+        assert_rejects_malformed::<VocAlgorithmState>(&encode(&[0; 4]));
+    }
+
+    #[test]
+    fn persisted_parsers_reject_wrong_size() {
+        use crate::{
+            conditioning::NoxConditioning, correction::Co2ReferenceCorrection, exposure::DailyDose,
+            maintenance::RuntimeCounters,
+        };
+
+        assert_rejects_wrong_size::<NoxConditioning>(&NoxConditioning::default().to_bytes());
+        assert_rejects_wrong_size::<RuntimeCounters>(&RuntimeCounters::default().to_bytes());
+        assert_rejects_wrong_size::<Co2ReferenceCorrection>(
+            &Co2ReferenceCorrection::default().to_bytes(),
+        );
+        assert_rejects_wrong_size::<DailyDose>(&DailyDose::default().to_bytes());
+    }
 }
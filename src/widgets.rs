@@ -0,0 +1,321 @@
+//! Drawable widgets for air quality displays based on
+//! [`embedded-graphics`](https://docs.rs/embedded-graphics). Displays only need to compose and
+//! position them.
+
+use core::fmt::Write;
+
+use embedded_graphics::{
+    mono_font::MonoTextStyle,
+    prelude::*,
+    primitives::{Circle, Line, PrimitiveStyle, Rectangle},
+    text::{Baseline, Text},
+};
+
+use crate::{analysis::History, data::Channel, report::StatusReport};
+
+/// Size of the buffer the values panel is rendered into in bytes.
+const PANEL_BUFFER_SIZE: usize = 128;
+
+/// Panel showing the current values as rendered by a [`StatusReport`], e.g. on an OLED display.
+#[derive(Clone, Copy, Debug)]
+pub struct ValuesPanel<'a, C> {
+    report: StatusReport<'a>,
+    top_left: Point,
+    style: MonoTextStyle<'a, C>,
+}
+
+impl<'a, C: PixelColor> ValuesPanel<'a, C> {
+    /// Creates a panel drawing the `report` with its top left corner at `top_left`, using the
+    /// text `style`.
+    pub fn new(report: StatusReport<'a>, top_left: Point, style: MonoTextStyle<'a, C>) -> Self {
+        Self {
+            report,
+            top_left,
+            style,
+        }
+    }
+}
+
+impl<C: PixelColor> Drawable for ValuesPanel<'_, C> {
+    type Color = C;
+    type Output = ();
+
+    /// Draws the panel. A report exceeding the panel's buffer is truncated.
+    fn draw<D: DrawTarget<Color = C>>(&self, target: &mut D) -> Result<(), D::Error> {
+        let mut text = TextBuffer::<PANEL_BUFFER_SIZE>::new();
+        // A full buffer only truncates the report
+        let _ = self.report.write_to(&mut text);
+        Text::with_baseline(text.as_str(), self.top_left, self.style, Baseline::Top)
+            .draw(target)?;
+        Ok(())
+    }
+}
+
+/// Colors of a [`Co2TrafficLight`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TrafficLightColors<C> {
+    /// Color for CO2 concentrations below the moderate threshold.
+    pub good: C,
+    /// Color for CO2 concentrations between the moderate and the poor threshold.
+    pub moderate: C,
+    /// Color for CO2 concentrations above the poor threshold.
+    pub poor: C,
+}
+
+/// Filled circle indicating the ventilation need by the CO2 concentration. Nothing is drawn while
+/// the CO2 concentration is not available.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Co2TrafficLight<C> {
+    co2: Option<f32>,
+    top_left: Point,
+    diameter: u32,
+    colors: TrafficLightColors<C>,
+    moderate_ppm: f32,
+    poor_ppm: f32,
+}
+
+impl<C: PixelColor> Co2TrafficLight<C> {
+    /// Creates a traffic light for the `co2` concentration in ppm as returned by
+    /// [`Channel::value`], switching to moderate at 1000 ppm and to poor at 1400 ppm.
+    /// - `top_left`: Top left corner of the bounding box of the circle.
+    /// - `diameter`: Diameter of the circle in pixels.
+    /// - `colors`: Colors of the levels.
+    pub fn new(
+        co2: Option<f32>,
+        top_left: Point,
+        diameter: u32,
+        colors: TrafficLightColors<C>,
+    ) -> Self {
+        Self {
+            co2,
+            top_left,
+            diameter,
+            colors,
+            moderate_ppm: 1_000.0,
+            poor_ppm: 1_400.0,
+        }
+    }
+
+    /// Returns the traffic light using the thresholds `moderate_ppm` and `poor_ppm` in ppm.
+    pub fn with_thresholds(mut self, moderate_ppm: f32, poor_ppm: f32) -> Self {
+        self.moderate_ppm = moderate_ppm;
+        self.poor_ppm = poor_ppm;
+        self
+    }
+
+    /// Returns the color the traffic light is drawn with. `None` if the CO2 concentration is not
+    /// available.
+    pub fn color(&self) -> Option<C> {
+        self.co2.map(|co2| {
+            if co2 >= self.poor_ppm {
+                self.colors.poor
+            } else if co2 >= self.moderate_ppm {
+                self.colors.moderate
+            } else {
+                self.colors.good
+            }
+        })
+    }
+}
+
+impl<C: PixelColor> Drawable for Co2TrafficLight<C> {
+    type Color = C;
+    type Output = ();
+
+    fn draw<D: DrawTarget<Color = C>>(&self, target: &mut D) -> Result<(), D::Error> {
+        if let Some(color) = self.color() {
+            Circle::new(self.top_left, self.diameter)
+                .into_styled(PrimitiveStyle::with_fill(color))
+                .draw(target)?;
+        }
+        Ok(())
+    }
+}
+
+/// Line chart of one [`Channel`] over the measurements of a [`History`]. Each of the `N` slots of
+/// the history takes an equal share of the chart's width, the oldest measurement is drawn at the
+/// left. Unavailable values interrupt the line.
+#[derive(Clone, Copy, Debug)]
+pub struct TrendChart<'a, const N: usize, C: PixelColor> {
+    history: &'a History<N>,
+    channel: Channel,
+    area: Rectangle,
+    style: PrimitiveStyle<C>,
+    range: Option<(f32, f32)>,
+}
+
+impl<'a, const N: usize, C: PixelColor> TrendChart<'a, N, C> {
+    /// Creates a chart of the `channel` drawn into `area` with the line `style`. The value axis
+    /// is scaled to the minimum and maximum of the drawn values.
+    pub fn new(
+        history: &'a History<N>,
+        channel: Channel,
+        area: Rectangle,
+        style: PrimitiveStyle<C>,
+    ) -> Self {
+        Self {
+            history,
+            channel,
+            area,
+            style,
+            range: None,
+        }
+    }
+
+    /// Returns the chart with a fixed value axis from `min` to `max`. Values outside are clamped.
+    pub fn with_range(mut self, min: f32, max: f32) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Returns the range of the value axis.
+    fn value_range(&self) -> Option<(f32, f32)> {
+        self.range.or_else(|| {
+            self.history
+                .values(self.channel)
+                .flatten()
+                .fold(None, |range, value| match range {
+                    None => Some((value, value)),
+                    Some((min, max)) => Some((value.min(min), value.max(max))),
+                })
+        })
+    }
+
+    /// Returns the position of the `value` of slot `index`.
+    fn point(&self, index: usize, value: f32, (min, max): (f32, f32)) -> Point {
+        let width = self.area.size.width.saturating_sub(1) as f32;
+        let height = self.area.size.height.saturating_sub(1) as f32;
+        let x = index as f32 * width / N.saturating_sub(1).max(1) as f32;
+        let share = if max > min {
+            ((value - min) / (max - min)).clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        self.area.top_left + Point::new(x as i32, (height - share * height + 0.5) as i32)
+    }
+}
+
+impl<const N: usize, C: PixelColor> Drawable for TrendChart<'_, N, C> {
+    type Color = C;
+    type Output = ();
+
+    fn draw<D: DrawTarget<Color = C>>(&self, target: &mut D) -> Result<(), D::Error> {
+        let Some(range) = self.value_range() else {
+            return Ok(());
+        };
+        let mut previous = None;
+        for (index, value) in self.history.values(self.channel).enumerate() {
+            let point = value.map(|value| self.point(index, value, range));
+            if let Some(point) = point {
+                Line::new(previous.unwrap_or(point), point)
+                    .into_styled(self.style)
+                    .draw(target)?;
+            }
+            previous = point;
+        }
+        Ok(())
+    }
+}
+
+/// Fixed-size text buffer, silently failing once full.
+struct TextBuffer<const N: usize> {
+    data: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> TextBuffer<N> {
+    fn new() -> Self {
+        Self {
+            data: [0; N],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        self.data
+            .get(..self.len)
+            .and_then(|text| core::str::from_utf8(text).ok())
+            .unwrap_or_default()
+    }
+}
+
+impl<const N: usize> Write for TextBuffer<N> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        let target = self.data.get_mut(self.len..end).ok_or(core::fmt::Error)?;
+        target.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        mono_font::ascii::FONT_6X10,
+        pixelcolor::{BinaryColor, Gray8},
+    };
+
+    use super::*;
+    use crate::data::Measurement;
+
+    fn measurement(co2: u16) -> Measurement {
+        Measurement {
+            co2,
+            ..Measurement::fixture()
+        }
+    }
+
+    #[test]
+    fn traffic_light_selects_color_by_level() {
+        let colors = TrafficLightColors {
+            good: Gray8::new(0),
+            moderate: Gray8::new(1),
+            poor: Gray8::new(2),
+        };
+        let light = |co2| Co2TrafficLight::new(co2, Point::zero(), 4, colors);
+        assert_eq!(light(Some(600.0)).color(), Some(colors.good));
+        assert_eq!(light(Some(1_000.0)).color(), Some(colors.moderate));
+        assert_eq!(light(Some(1_500.0)).color(), Some(colors.poor));
+        assert_eq!(
+            light(Some(900.0)).with_thresholds(800.0, 850.0).color(),
+            Some(colors.poor)
+        );
+        assert_eq!(light(None).color(), None);
+    }
+
+    #[test]
+    fn trend_chart_draws_history() {
+        let mut history = History::<4>::new();
+        for co2 in [400, 500, Measurement::CO2_UNAVAILABLE, 600] {
+            history.push(measurement(co2));
+        }
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        TrendChart::new(
+            &history,
+            Channel::Co2,
+            Rectangle::new(Point::zero(), Size::new(4, 3)),
+            PrimitiveStyle::with_stroke(BinaryColor::On, 1),
+        )
+        .draw(&mut display)
+        .unwrap();
+        display.assert_pattern(&["   #", " #  ", "#   "]);
+    }
+
+    #[test]
+    fn values_panel_draws_report() {
+        let measurement = measurement(600);
+        let mut display = MockDisplay::new();
+        display.set_allow_out_of_bounds_drawing(true);
+        ValuesPanel::new(
+            StatusReport::new(&measurement),
+            Point::zero(),
+            MonoTextStyle::new(&FONT_6X10, BinaryColor::On),
+        )
+        .draw(&mut display)
+        .unwrap();
+        assert!(display.affected_area().size.height > 20);
+    }
+}